@@ -37,6 +37,8 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                 "Circuit" => quote! {#id: &pyo3::Bound<pyo3::PyAny>},
                 "Option<Circuit>" => quote! {#id: &pyo3::Bound<pyo3::PyAny>},
                 "PauliHamiltonian" => quote! {#id: &pyo3::Bound<pyo3::PyAny>},
+                "BooleanExpression" => quote! {#id: &pyo3::Bound<pyo3::PyAny>},
+                "BitwiseOperator" => quote! {#id: &str},
                 _ => quote! {#id: #ty},
             },
             _ => quote! {#id: #ty},
@@ -72,6 +74,16 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                     quote! {
                     #id_extracted}
                 }
+                "BooleanExpression" => {
+                    let id_extracted = format_ident!("{}_extracted", id);
+                    quote! {
+                    #id_extracted}
+                }
+                "BitwiseOperator" => {
+                    let id_extracted = format_ident!("{}_extracted", id);
+                    quote! {
+                    #id_extracted}
+                }
                 _ => {
                     quote! {#id}
                 }
@@ -127,6 +139,22 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                     let #id_extracted: #ty = temp_op.clone();
                 }
             },
+            "BooleanExpression" => {
+                let id_extracted = format_ident!("{}_extracted", id);
+                quote! {
+                    let #id_extracted: #ty = convert_into_boolean_expression(#id).map_err(|x| {
+                        pyo3::exceptions::PyTypeError::new_err(format!("Argument cannot be converted to BooleanExpression: {x:?}"))
+                    })?;
+                }
+            },
+            "BitwiseOperator" => {
+                let id_extracted = format_ident!("{}_extracted", id);
+                quote! {
+                    let #id_extracted: #ty = #id.parse().map_err(|x| {
+                        pyo3::exceptions::PyValueError::new_err(format!("Argument cannot be converted to BitwiseOperator: {x:?}"))
+                    })?;
+                }
+            },
             _ => {
                 quote! {}
             }
@@ -178,6 +206,24 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                         }
                     }
                 }
+                "BooleanExpression" => {
+                    let msg = format!("Get value of struct field {id}");
+                    quote! {
+                        #[doc = #msg]
+                        pub fn #id(&self) -> BooleanExpressionWrapper{
+                            BooleanExpressionWrapper{internal: self.internal.#id().clone()}
+                        }
+                    }
+                }
+                "BitwiseOperator" => {
+                    let msg = format!("Get value of struct field {id}");
+                    quote! {
+                        #[doc = #msg]
+                        pub fn #id(&self) -> String{
+                            self.internal.#id().to_string()
+                        }
+                    }
+                }
                 _ => {
                     let msg = format!("Get value of struct field {id}");
                     quote! {
@@ -207,6 +253,7 @@ fn operate_struct(ds: DataStruct, ident: Ident) -> TokenStream {
         #[new]
         #[doc = #new_msg]
         #[pyo3(signature = (#(#formatted_input_arguments),*))]
+        #[allow(clippy::too_many_arguments)]
         fn new(#(#input_arguments),*) -> PyResult<Self>{
             #(#conversion_quotes)*
             Ok(Self{internal: #ident::new(#(#arguments),*)})
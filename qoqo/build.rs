@@ -486,6 +486,22 @@ fn main() {
                                     .map_err(|_| QoqoError::ConversionError)?;
                                     let #ident: struqture::spins::PauliHamiltonian = struqture_py::spins::PauliHamiltonianWrapper::from_pyany(#pyobject_name).map_err(|_| QoqoError::ConversionError)?;
                                 }},
+                                "BooleanExpression" => {quote!{
+                                    let #pyobject_name = &op
+                                    .call_method0(#ident_string)
+                                    .map_err(|_| QoqoError::ConversionError)?;
+                                    let #ident = crate::operations::convert_into_boolean_expression(#pyobject_name).map_err(|_|
+                                        QoqoError::ConversionError)?;
+                                }},
+                                "BitwiseOperator" => {quote!{
+                                    let #pyobject_name = &op
+                                    .call_method0(#ident_string)
+                                    .map_err(|_| QoqoError::ConversionError)?;
+                                    let #ident: #ty = #pyobject_name.extract::<String>()
+                                    .map_err(|_| QoqoError::ConversionError)?
+                                    .parse()
+                                    .map_err(|_| QoqoError::ConversionError)?;
+                                }},
                                 _ => {
                                     quote!{
                                     let #pyobject_name = &op
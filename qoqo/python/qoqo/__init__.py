@@ -21,6 +21,7 @@ qoqo is the HQS python package to represent quantum circuits.
 
     Circuit
     CircuitDag
+    CircuitStatistics
     operations
     QuantumProgram
     measurements
@@ -18,7 +18,7 @@ Measurements
     ClassicalRegister
 """
 
-from typing import Optional, List, Dict, Union
+from typing import Optional, List, Dict, Set, Tuple, Union
 from .qoqo import Circuit
 
 class PauliZProductInput:
@@ -456,6 +456,28 @@ class PauliZProduct:
             RuntimeError: Error evaluating PauliZ product measurement.
         """
 
+    def evaluate_statistics(
+        self, input_bit_registers: Dict[str, Union[List[List[int]], List[List[bool]]]]
+    ) -> Tuple[Dict[str, float], Dict[str, float], Dict[str, float]]:
+        """
+        Execute the PauliZ product measurement and estimate the statistical uncertainties.
+
+        The shot-noise variance of an expectation value H is the variance of its estimator when every
+        readout register is measured once, the sum of the variances of the parts of H measured in each readout.
+        Covariances between Pauli products from different readout registers are not accessible and treated as zero,
+        so the shot-noise variance equals the variance <H^2> - <H>^2 of the observable only
+        when all Pauli products of H are measured in the same readout register.
+
+        Args:
+            input_bit_registers (Dict[str, Union[List[List[int]], List[List[bool]]]]): The classical bit registers with the register name as key
+
+        Returns:
+            Tuple[Dict[str, float], Dict[str, float], Dict[str, float]]: The evaluated expectation values, their shot-noise variances and their standard errors.
+
+        Raises:
+            RuntimeError: Error evaluating PauliZ product measurement.
+        """
+
     def circuits(self) -> List[Circuit]:
         """
         Return the collection of quantum circuits for the separate basis rotations.
@@ -472,6 +494,17 @@ class PauliZProduct:
             Optional[Circuit]: The constant Circuit (None if not defined).
         """
 
+    def symbols(self) -> Set[str]:
+        """
+        Returns the names of the free symbols in the circuits of the measurement.
+
+        Returns:
+            Set[str]: The free symbols of the constant Circuit and of all measurement circuits.
+
+        Raises:
+            ValueError: A parametrized operation does not report its symbols.
+        """
+
     def input(self) -> PauliZProductInput:
         """
         Returns the measurement input data defining how to construct expectation values from measurements.
@@ -496,6 +529,28 @@ class PauliZProduct:
             substituted_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the Circuit.
         """
 
+    def bind_parameters(self, values: Dict[str, float]):
+        """
+        Return copy of Measurement with the given symbols replaced and the other symbols kept symbolic.
+
+        Args:
+            values (Dict[str, float]): The values of the symbols to substitute in the circuits.
+
+        Raises:
+            RuntimeError: Error substituting symbolic parameters.
+        """
+
+    def rename_parameters(self, mapping: Dict[str, str]):
+        """
+        Return copy of Measurement with the given symbols renamed.
+
+        Args:
+            mapping (Dict[str, str]): The new names of the symbols to rename in the circuits.
+
+        Raises:
+            ValueError: A new name is not a valid symbol name.
+        """
+
     def _internal_to_bincode(self):
         """
         Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
@@ -638,6 +693,17 @@ class CheatedPauliZProduct:
             Optional[Circuit]: The constant Circuit (None if not defined).
         """
 
+    def symbols(self) -> Set[str]:
+        """
+        Returns the names of the free symbols in the circuits of the measurement.
+
+        Returns:
+            Set[str]: The free symbols of the constant Circuit and of all measurement circuits.
+
+        Raises:
+            ValueError: A parametrized operation does not report its symbols.
+        """
+
     def input(self) -> CheatedPauliZProductInput:
         """
         Returns the measurement input data defining how to construct expectation values from measurements.
@@ -662,6 +728,28 @@ class CheatedPauliZProduct:
             substituted_parameters (Dict[str, float]): The dictionary containing the substitutions to use in the Circuit.
         """
 
+    def bind_parameters(self, values: Dict[str, float]):
+        """
+        Return copy of Measurement with the given symbols replaced and the other symbols kept symbolic.
+
+        Args:
+            values (Dict[str, float]): The values of the symbols to substitute in the circuits.
+
+        Raises:
+            RuntimeError: Error substituting symbolic parameters.
+        """
+
+    def rename_parameters(self, mapping: Dict[str, str]):
+        """
+        Return copy of Measurement with the given symbols renamed.
+
+        Args:
+            mapping (Dict[str, str]): The new names of the symbols to rename in the circuits.
+
+        Raises:
+            ValueError: A new name is not a valid symbol name.
+        """
+
     def _internal_to_bincode(self):
         """
         Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
@@ -804,6 +892,17 @@ class Cheated:
             Optional[Circuit]: The constant Circuit (None if not defined).
         """
 
+    def symbols(self) -> Set[str]:
+        """
+        Returns the names of the free symbols in the circuits of the measurement.
+
+        Returns:
+            Set[str]: The free symbols of the constant Circuit and of all measurement circuits.
+
+        Raises:
+            ValueError: A parametrized operation does not report its symbols.
+        """
+
     def input(self) -> CheatedInput:
         """
         Returns the measurement input data defining how to construct expectation values from measurements.
@@ -831,6 +930,28 @@ class Cheated:
             RuntimeError: Error substituting symbolic parameters.
         """
 
+    def bind_parameters(self, values: Dict[str, float]):
+        """
+        Return copy of Measurement with the given symbols replaced and the other symbols kept symbolic.
+
+        Args:
+            values (Dict[str, float]): The values of the symbols to substitute in the circuits.
+
+        Raises:
+            RuntimeError: Error substituting symbolic parameters.
+        """
+
+    def rename_parameters(self, mapping: Dict[str, str]):
+        """
+        Return copy of Measurement with the given symbols renamed.
+
+        Args:
+            mapping (Dict[str, str]): The new names of the symbols to rename in the circuits.
+
+        Raises:
+            ValueError: A new name is not a valid symbol name.
+        """
+
     def _internal_to_bincode(self):
         """
         Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
@@ -945,6 +1066,17 @@ class ClassicalRegister:
             Optional[Circuit]: The constant Circuit (None if not defined).
         """
 
+    def symbols(self) -> Set[str]:
+        """
+        Returns the names of the free symbols in the circuits of the measurement.
+
+        Returns:
+            Set[str]: The free symbols of the constant Circuit and of all measurement circuits.
+
+        Raises:
+            ValueError: A parametrized operation does not report its symbols.
+        """
+
     def measurement_type(self) -> str:
         """
         Returns the type of the measurement in string form.
@@ -964,6 +1096,28 @@ class ClassicalRegister:
             RuntimeError: Error substituting symbolic parameters.
         """
 
+    def bind_parameters(self, values: Dict[str, float]):
+        """
+        Return copy of Measurement with the given symbols replaced and the other symbols kept symbolic.
+
+        Args:
+            values (Dict[str, float]): The values of the symbols to substitute in the circuits.
+
+        Raises:
+            RuntimeError: Error substituting symbolic parameters.
+        """
+
+    def rename_parameters(self, mapping: Dict[str, str]):
+        """
+        Return copy of Measurement with the given symbols renamed.
+
+        Args:
+            mapping (Dict[str, str]): The new names of the symbols to rename in the circuits.
+
+        Raises:
+            ValueError: A new name is not a valid symbol name.
+        """
+
     def _internal_to_bincode(self):
         """
         Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
@@ -18,7 +18,7 @@ Operations:
 """
 
 import numpy
-from typing import Optional, List, Dict, Set, Union
+from typing import Optional, List, Tuple, Dict, Set, Union
 from .qoqo import Circuit, Operation
 
 class SingleQubitGate(Operation):
@@ -4109,6 +4109,132 @@ class MeasureQubit(Operation):
             str: The json schema serialized to json
         """
 
+class MeasureQubitAndReset(Operation):
+    """
+    Measurement gate operation that resets the measured qubit.
+
+    This Operation acts on one qubit writing the result of the measurement into a readout
+    and afterwards resets the qubit to the |0> state, so that it can be reused in the rest of the circuit.
+    In every shot the result is written into the same entry of the readout register.
+    The classical register for the readout needs to be defined in advance by using a Definition operation.
+
+    Args:
+        qubit (int): The measured qubit.
+        readout (string): The classical register for the readout.
+        readout_index (int): The index in the readout the result is saved to.
+
+    """
+
+    def __init__(self, qubit: int, readout: str, readout_index: int):
+        return
+
+    def readout(self):
+        """
+        Get value of struct field readout
+        """
+
+    def readout_index(self):
+        """
+        Get value of struct field readout_index
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Returns true if operation contains symbolic parameters
+
+        Returns:
+            bool: Whether or not the operation contains symbolic parameters.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Returns tags identifying the Operation
+
+        Returns:
+            List[str]: The tags identifying the operation
+        """
+
+    def hqslang(self) -> str:
+        """
+        Returns hqslang name of Operation
+
+        Returns:
+            str: The name
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> Operation:
+        """
+        Substitutes internal symbolic parameters with float values
+
+        Only available when all symbolic expressions can be evaluated to float with the
+        provided parameters.
+
+        Args:
+            substitution_parameters (Dict[str, float]): The substituted free parameters
+
+        Returns:
+            Operation: The operation with the parameters substituted
+
+        Raises:
+            RuntimeError: Parameter Substitution failed
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        """
+        Remap qubits
+
+        Args:
+            mapping (Dict[int, int]): The mapping
+
+        Returns:
+            Operation: The operation with the remapped qubits
+
+        Raises:
+            RuntimeError: Qubit remapping failed
+        """
+
+    def involved_qubits(self) -> Union[Set[int], str]:
+        """
+        List all involved Qubits
+
+        Returns:
+            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        """
+
+    def qubit(self):
+        """
+        Return the qubit the operation acts on
+
+        Returns:
+            int
+        """
+
+    def current_version(self) -> str:
+        """
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    def json_schema(self) -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
 class PragmaGetStateVector(Operation):
     """
     This PRAGMA measurement operation returns the statevector of a quantum register.
@@ -6930,6 +7056,940 @@ class PragmaConditional(Operation):
             str: The json schema serialized to json
         """
 
+class BooleanExpression:
+    """
+    A boolean expression over entries of classical bit registers.
+
+    Used as the condition of PragmaIfElse and PragmaWhileLoop and as the value assigned by PragmaSetBit.
+    Expressions are created with the static constructors and can be combined with the `~`, `&`, `|` and `^` operators.
+    """
+
+    def __init__(self):
+        return
+
+    def constant(self, value: bool) -> BooleanExpression:
+        """
+        Create an expression with a constant value.
+
+        Args:
+            value (bool): The value of the expression.
+
+        Returns:
+            BooleanExpression: The constant expression.
+        """
+
+    def bit(self, register: str, index: int) -> BooleanExpression:
+        """
+        Create an expression reading an entry of a classical bit register.
+
+        Args:
+            register (str): The name of the bit register.
+            index (int): The index in the bit register.
+
+        Returns:
+            BooleanExpression: The expression reading the entry.
+        """
+
+    def logical_not(self, expression: BooleanExpression) -> BooleanExpression:
+        """
+        Create the negation of an expression.
+
+        Args:
+            expression (BooleanExpression): The negated expression.
+
+        Returns:
+            BooleanExpression: The negation.
+
+        Raises:
+            TypeError: Argument cannot be converted to BooleanExpression.
+        """
+
+    def logical_and(self, expressions: List[BooleanExpression]) -> BooleanExpression:
+        """
+        Create an expression that is true when all expressions are true.
+
+        Args:
+            expressions (List[BooleanExpression]): The combined expressions.
+
+        Returns:
+            BooleanExpression: The conjunction.
+
+        Raises:
+            TypeError: Argument cannot be converted to BooleanExpression.
+        """
+
+    def logical_or(self, expressions: List[BooleanExpression]) -> BooleanExpression:
+        """
+        Create an expression that is true when at least one expression is true.
+
+        Args:
+            expressions (List[BooleanExpression]): The combined expressions.
+
+        Returns:
+            BooleanExpression: The disjunction.
+
+        Raises:
+            TypeError: Argument cannot be converted to BooleanExpression.
+        """
+
+    def logical_xor(self, expressions: List[BooleanExpression]) -> BooleanExpression:
+        """
+        Create an expression that is true when an odd number of expressions is true.
+
+        Args:
+            expressions (List[BooleanExpression]): The combined expressions.
+
+        Returns:
+            BooleanExpression: The parity.
+
+        Raises:
+            TypeError: Argument cannot be converted to BooleanExpression.
+        """
+
+    def involved_bits(self) -> Set[Tuple[str, int]]:
+        """
+        Return the bit register entries read by the expression.
+
+        Returns:
+            Set[Tuple[str, int]]: The (register name, index) pairs the expression depends on.
+        """
+
+    def evaluate(self, bit_registers: Dict[str, List[bool]]) -> bool:
+        """
+        Evaluate the expression for the given bit registers.
+
+        Args:
+            bit_registers (Dict[str, List[bool]]): The bit registers the expression is evaluated on.
+
+        Returns:
+            bool: The value of the expression.
+
+        Raises:
+            ValueError: A register is missing or an index is out of range.
+        """
+
+    def to_json(self) -> str:
+        """
+        Return the json representation of the BooleanExpression.
+
+        Returns:
+            str: The serialized form of BooleanExpression.
+
+        Raises:
+            ValueError: Cannot serialize BooleanExpression to json.
+        """
+
+    def from_json(self, input: str) -> BooleanExpression:
+        """
+        Convert the json representation of a BooleanExpression to a BooleanExpression.
+
+        Args:
+            input (str): The serialized BooleanExpression in json form.
+
+        Returns:
+            BooleanExpression: The deserialized BooleanExpression.
+
+        Raises:
+            ValueError: Input cannot be deserialized to BooleanExpression.
+        """
+
+class PragmaIfElse(Operation):
+    """
+    The if-else PRAGMA operation.
+
+    This PRAGMA executes if_circuit when the condition evaluated on the classical bit registers is true
+    and else_circuit otherwise.
+
+    Args:
+        condition (BooleanExpression): The boolean expression deciding which circuit is executed.
+        if_circuit (Circuit): The circuit executed if the condition is true.
+        else_circuit (Circuit): The circuit executed if the condition is false.
+    """
+
+    def __init__(
+        self,
+        condition: BooleanExpression,
+        if_circuit: Circuit,
+        else_circuit: Circuit,
+    ):
+        return
+
+    def condition(self):
+        """
+        Get value of struct field condition
+        """
+
+    def if_circuit(self):
+        """
+        Get value of struct field if_circuit
+        """
+
+    def else_circuit(self):
+        """
+        Get value of struct field else_circuit
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Returns true if operation contains symbolic parameters
+
+        Returns:
+            bool: Whether or not the operation contains symbolic parameters.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Returns tags identifying the Operation
+
+        Returns:
+            List[str]: The tags identifying the operation
+        """
+
+    def hqslang(self) -> str:
+        """
+        Returns hqslang name of Operation
+
+        Returns:
+            str: The name
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> Operation:
+        """
+        Substitutes internal symbolic parameters with float values
+
+        Only available when all symbolic expressions can be evaluated to float with the
+        provided parameters.
+
+        Args:
+            substitution_parameters (Dict[str, float]): The substituted free parameters
+
+        Returns:
+            Operation: The operation with the parameters substituted
+
+        Raises:
+            RuntimeError: Parameter Substitution failed
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        """
+        Remap qubits
+
+        Args:
+            mapping (Dict[int, int]): The mapping
+
+        Returns:
+            Operation: The operation with the remapped qubits
+
+        Raises:
+            RuntimeError: Qubit remapping failed
+        """
+
+    def involved_qubits(self) -> Union[Set[int], str]:
+        """
+        List all involved Qubits
+
+        Returns:
+            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        """
+
+    def current_version(self) -> str:
+        """
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    def json_schema(self) -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
+class PragmaWhileLoop(Operation):
+    """
+    The while-loop PRAGMA operation.
+
+    This PRAGMA repeatedly executes a circuit as long as the condition evaluated on the classical bit registers is true.
+    The loop stops after max_iterations executions of the circuit even if the condition is still true.
+
+    Args:
+        condition (BooleanExpression): The boolean expression checked before every iteration.
+        circuit (Circuit): The circuit executed while the condition is true.
+        max_iterations (int): The maximum number of times the circuit is executed.
+    """
+
+    def __init__(
+        self,
+        condition: BooleanExpression,
+        circuit: Circuit,
+        max_iterations: int,
+    ):
+        return
+
+    def condition(self):
+        """
+        Get value of struct field condition
+        """
+
+    def circuit(self):
+        """
+        Get value of struct field circuit
+        """
+
+    def max_iterations(self):
+        """
+        Get value of struct field max_iterations
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Returns true if operation contains symbolic parameters
+
+        Returns:
+            bool: Whether or not the operation contains symbolic parameters.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Returns tags identifying the Operation
+
+        Returns:
+            List[str]: The tags identifying the operation
+        """
+
+    def hqslang(self) -> str:
+        """
+        Returns hqslang name of Operation
+
+        Returns:
+            str: The name
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> Operation:
+        """
+        Substitutes internal symbolic parameters with float values
+
+        Only available when all symbolic expressions can be evaluated to float with the
+        provided parameters.
+
+        Args:
+            substitution_parameters (Dict[str, float]): The substituted free parameters
+
+        Returns:
+            Operation: The operation with the parameters substituted
+
+        Raises:
+            RuntimeError: Parameter Substitution failed
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        """
+        Remap qubits
+
+        Args:
+            mapping (Dict[int, int]): The mapping
+
+        Returns:
+            Operation: The operation with the remapped qubits
+
+        Raises:
+            RuntimeError: Qubit remapping failed
+        """
+
+    def involved_qubits(self) -> Union[Set[int], str]:
+        """
+        List all involved Qubits
+
+        Returns:
+            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        """
+
+    def current_version(self) -> str:
+        """
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    def json_schema(self) -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
+class PragmaSetBit(Operation):
+    """
+    The classical assignment PRAGMA operation.
+
+    This PRAGMA sets an entry of a classical bit register to the value of a boolean expression
+    evaluated on the classical bit registers.
+
+    Args:
+        register (str): The name of the bit register that is written to.
+        index (int): The index in the bit register that is written to.
+        value (BooleanExpression): The boolean expression whose value is assigned.
+    """
+
+    def __init__(self, register: str, index: int, value: BooleanExpression):
+        return
+
+    def register(self):
+        """
+        Get value of struct field register
+        """
+
+    def index(self):
+        """
+        Get value of struct field index
+        """
+
+    def value(self):
+        """
+        Get value of struct field value
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Returns true if operation contains symbolic parameters
+
+        Returns:
+            bool: Whether or not the operation contains symbolic parameters.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Returns tags identifying the Operation
+
+        Returns:
+            List[str]: The tags identifying the operation
+        """
+
+    def hqslang(self) -> str:
+        """
+        Returns hqslang name of Operation
+
+        Returns:
+            str: The name
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> Operation:
+        """
+        Substitutes internal symbolic parameters with float values
+
+        Only available when all symbolic expressions can be evaluated to float with the
+        provided parameters.
+
+        Args:
+            substitution_parameters (Dict[str, float]): The substituted free parameters
+
+        Returns:
+            Operation: The operation with the parameters substituted
+
+        Raises:
+            RuntimeError: Parameter Substitution failed
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        """
+        Remap qubits
+
+        Args:
+            mapping (Dict[int, int]): The mapping
+
+        Returns:
+            Operation: The operation with the remapped qubits
+
+        Raises:
+            RuntimeError: Qubit remapping failed
+        """
+
+    def involved_qubits(self) -> Union[Set[int], str]:
+        """
+        List all involved Qubits
+
+        Returns:
+            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        """
+
+    def current_version(self) -> str:
+        """
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    def json_schema(self) -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
+class PragmaBitwise(Operation):
+    """
+    The bitwise PRAGMA operation.
+
+    This PRAGMA sets the entries target_start..target_start + length of the target bit register
+    to the corresponding entries of the two input ranges combined with the bitwise operator.
+
+    Args:
+        operator (str): The bitwise operator, one of "AND", "OR" and "XOR".
+        first_register (str): The name of the bit register containing the first input range.
+        first_start (int): The index of the first entry of the first input range.
+        second_register (str): The name of the bit register containing the second input range.
+        second_start (int): The index of the first entry of the second input range.
+        target_register (str): The name of the bit register that is written to.
+        target_start (int): The index of the first entry of the target range.
+        length (int): The number of entries in each range.
+    """
+
+    def __init__(
+        self,
+        operator: str,
+        first_register: str,
+        first_start: int,
+        second_register: str,
+        second_start: int,
+        target_register: str,
+        target_start: int,
+        length: int,
+    ):
+        return
+
+    def operator(self):
+        """
+        Get value of struct field operator
+        """
+
+    def first_register(self):
+        """
+        Get value of struct field first_register
+        """
+
+    def first_start(self):
+        """
+        Get value of struct field first_start
+        """
+
+    def second_register(self):
+        """
+        Get value of struct field second_register
+        """
+
+    def second_start(self):
+        """
+        Get value of struct field second_start
+        """
+
+    def target_register(self):
+        """
+        Get value of struct field target_register
+        """
+
+    def target_start(self):
+        """
+        Get value of struct field target_start
+        """
+
+    def length(self):
+        """
+        Get value of struct field length
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Returns true if operation contains symbolic parameters
+
+        Returns:
+            bool: Whether or not the operation contains symbolic parameters.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Returns tags identifying the Operation
+
+        Returns:
+            List[str]: The tags identifying the operation
+        """
+
+    def hqslang(self) -> str:
+        """
+        Returns hqslang name of Operation
+
+        Returns:
+            str: The name
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> Operation:
+        """
+        Substitutes internal symbolic parameters with float values
+
+        Only available when all symbolic expressions can be evaluated to float with the
+        provided parameters.
+
+        Args:
+            substitution_parameters (Dict[str, float]): The substituted free parameters
+
+        Returns:
+            Operation: The operation with the parameters substituted
+
+        Raises:
+            RuntimeError: Parameter Substitution failed
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        """
+        Remap qubits
+
+        Args:
+            mapping (Dict[int, int]): The mapping
+
+        Returns:
+            Operation: The operation with the remapped qubits
+
+        Raises:
+            RuntimeError: Qubit remapping failed
+        """
+
+    def involved_qubits(self) -> Union[Set[int], str]:
+        """
+        List all involved Qubits
+
+        Returns:
+            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        """
+
+    def current_version(self) -> str:
+        """
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    def json_schema(self) -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
+class PragmaParity(Operation):
+    """
+    The parity PRAGMA operation.
+
+    This PRAGMA sets an entry of a bit register to the parity (XOR) of the given entries of a bit register.
+
+    Args:
+        register (str): The name of the bit register containing the input entries.
+        indices (List[int]): The indices of the input entries.
+        target_register (str): The name of the bit register that is written to.
+        target_index (int): The index in the target bit register that is written to.
+    """
+
+    def __init__(
+        self,
+        register: str,
+        indices: List[int],
+        target_register: str,
+        target_index: int,
+    ):
+        return
+
+    def register(self):
+        """
+        Get value of struct field register
+        """
+
+    def indices(self):
+        """
+        Get value of struct field indices
+        """
+
+    def target_register(self):
+        """
+        Get value of struct field target_register
+        """
+
+    def target_index(self):
+        """
+        Get value of struct field target_index
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Returns true if operation contains symbolic parameters
+
+        Returns:
+            bool: Whether or not the operation contains symbolic parameters.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Returns tags identifying the Operation
+
+        Returns:
+            List[str]: The tags identifying the operation
+        """
+
+    def hqslang(self) -> str:
+        """
+        Returns hqslang name of Operation
+
+        Returns:
+            str: The name
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> Operation:
+        """
+        Substitutes internal symbolic parameters with float values
+
+        Only available when all symbolic expressions can be evaluated to float with the
+        provided parameters.
+
+        Args:
+            substitution_parameters (Dict[str, float]): The substituted free parameters
+
+        Returns:
+            Operation: The operation with the parameters substituted
+
+        Raises:
+            RuntimeError: Parameter Substitution failed
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        """
+        Remap qubits
+
+        Args:
+            mapping (Dict[int, int]): The mapping
+
+        Returns:
+            Operation: The operation with the remapped qubits
+
+        Raises:
+            RuntimeError: Qubit remapping failed
+        """
+
+    def involved_qubits(self) -> Union[Set[int], str]:
+        """
+        List all involved Qubits
+
+        Returns:
+            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        """
+
+    def current_version(self) -> str:
+        """
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    def json_schema(self) -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
+class PragmaSetFloat(Operation):
+    """
+    The classical float arithmetic PRAGMA operation.
+
+    This PRAGMA sets an entry of a float register to the value of a symbolic expression.
+    The symbols listed in variables stand for entries of float or bit registers read while
+    the circuit runs (bits are read as 0.0 or 1.0). All other symbols are free parameters
+    of the circuit.
+
+    Args:
+        register (str): The name of the float register that is written to.
+        index (int): The index in the float register that is written to.
+        expression (CalculatorFloat): The expression whose value is assigned.
+        variables (Dict[str, Tuple[str, int]]): The register entries (register name, index) the symbols in the expression stand for.
+    """
+
+    def __init__(
+        self,
+        register: str,
+        index: int,
+        expression: Union[float, str],
+        variables: Dict[str,
+        Tuple[str,
+        int]],
+    ):
+        return
+
+    def register(self):
+        """
+        Get value of struct field register
+        """
+
+    def index(self):
+        """
+        Get value of struct field index
+        """
+
+    def expression(self):
+        """
+        Returns value of attribute expression
+        """
+
+    def variables(self):
+        """
+        Get value of struct field variables
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Returns true if operation contains symbolic parameters
+
+        Returns:
+            bool: Whether or not the operation contains symbolic parameters.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Returns tags identifying the Operation
+
+        Returns:
+            List[str]: The tags identifying the operation
+        """
+
+    def hqslang(self) -> str:
+        """
+        Returns hqslang name of Operation
+
+        Returns:
+            str: The name
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> Operation:
+        """
+        Substitutes internal symbolic parameters with float values
+
+        Only available when all symbolic expressions can be evaluated to float with the
+        provided parameters.
+
+        Args:
+            substitution_parameters (Dict[str, float]): The substituted free parameters
+
+        Returns:
+            Operation: The operation with the parameters substituted
+
+        Raises:
+            RuntimeError: Parameter Substitution failed
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> Operation:
+        """
+        Remap qubits
+
+        Args:
+            mapping (Dict[int, int]): The mapping
+
+        Returns:
+            Operation: The operation with the remapped qubits
+
+        Raises:
+            RuntimeError: Qubit remapping failed
+        """
+
+    def involved_qubits(self) -> Union[Set[int], str]:
+        """
+        List all involved Qubits
+
+        Returns:
+            Union[Set[int], str]: The involved qubits as a set or 'ALL' if all qubits are involved
+        """
+
+    def current_version(self) -> str:
+        """
+        Returns the current version of the qoqo library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:
+        """
+        Return the minimum version of qoqo that supports this object.
+
+        Returns:
+            str: The minimum version of the qoqo library to deserialize this object.
+        """
+
+    def json_schema(self) -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
 class PragmaChangeDevice(Operation):
     """
     A wrapper around backend specific PRAGMA operations capable of changing a device.
@@ -15,6 +15,7 @@ qoqo is the HQS python package to represent quantum circuits.
 
     Circuit
     CircuitDag
+    CircuitStatistics
     operations
     QuantumProgram
     measurements
@@ -23,7 +24,7 @@ qoqo is the HQS python package to represent quantum circuits.
 
 """
 
-from typing import Optional, List, Tuple, Dict, Set
+from typing import Any, Optional, List, Tuple, Dict, Set
 
 class Circuit:
     """
@@ -80,6 +81,55 @@ class Circuit:
             RuntimeError: The parameter substitution failed.
         """
 
+    def bind_parameters(self, values: Dict[str, float]) -> Circuit:
+        """
+        Substitute the given symbols in a clone of the Circuit and keep the other symbols symbolic.
+
+        Bracketed parts and function calls that no longer contain symbols are evaluated.
+
+        Args:
+            values (Dict[str, float]): The values of the symbols to substitute.
+
+        Returns:
+            self: The Circuit with the given symbols substituted.
+
+        Raises:
+            RuntimeError: The parameter substitution failed.
+        """
+
+    def rename_parameters(self, mapping: Dict[str, str]) -> Circuit:
+        """
+        Rename the given symbols in a clone of the Circuit.
+
+        The symbols are renamed in all operations, nested circuits and gate definitions.
+        The free parameters of gate definitions are local to the definitions and not renamed.
+
+        Args:
+            mapping (Dict[str, str]): The new names of the symbols to rename.
+
+        Returns:
+            self: The Circuit with the given symbols renamed.
+
+        Raises:
+            ValueError: A new name is not a valid symbol name.
+        """
+
+    def reparametrize(self, prefix: str) -> Circuit:
+        """
+        Prefix the names of all symbols in a clone of the Circuit.
+
+        Allows combining circuits that use the same symbol names without coupling their parameters.
+
+        Args:
+            prefix (str): The prefix added to the names of the symbols.
+
+        Returns:
+            self: The Circuit with the prefixed symbols.
+
+        Raises:
+            ValueError: The prefixed names are not valid symbol names.
+        """
+
     def remap_qubits(self, mapping: Dict[int, int]) -> Circuit:
         """
         Remap qubits in operations in clone of Circuit.
@@ -136,6 +186,27 @@ class Circuit:
             Set[str]: The operation types in the Circuit.
         """
 
+    def symbols(self) -> Set[str]:
+        """
+        Return the names of the free symbols in the symbolic parameters of the circuit.
+
+        Symbols set by an InputSymbolic definition and the free parameters of a GateDefinition are not free.
+
+        Returns:
+            Set[str]: The symbols that need to be substituted.
+
+        Raises:
+            ValueError: A parametrized operation does not report its symbols.
+        """
+
+    def operations_by_symbol(self) -> Dict[str, List[int]]:
+        """
+        Return the indices of the operations depending on each free symbol of the circuit.
+
+        Returns:
+            Dict[str, List[int]]: The ordered indices of the operations depending on each symbol.
+        """
+
     def _qoqo_versions(self) -> Tuple[str, str]:
         """
         Return the roqoqo and qoqo versions from when the code was compiled.
@@ -285,6 +356,10 @@ class Circuit:
 
         Args:
             op (Operation): The Operation to add to the Circuit.
+
+        Raises:
+            TypeError: Cannot convert python object to Operation.
+            ValueError: A CallDefinedGate does not match the qubits or free parameters of its GateDefinition.
         """
 
     def number_of_qubits(self) -> int:
@@ -295,6 +370,202 @@ class Circuit:
            int: The number of qubits in the Circuit.
         """
 
+    def is_clifford(self) -> bool:
+        """
+        Return whether the Circuit only contains Clifford operations.
+
+        Clifford circuits can be simulated efficiently with a stabilizer simulator.
+        Symbolic rotation angles are only accepted when they do not depend on free variables.
+
+        Returns:
+           bool: True if all operations in the Circuit are Clifford operations.
+        """
+
+    def qubit_reuses(self) -> Dict[int, int]:
+        """
+        Return how often every qubit of the Circuit is reused after being reset.
+
+        A qubit is reset by MeasureQubitAndReset or PragmaActiveReset.
+        Every later operation acting on a qubit that has been reset since its last use counts as one reuse.
+
+        Returns:
+           Dict[int, int]: The number of reuses of every qubit the operations of the Circuit act on.
+        """
+
+    def statistics(
+        self, device: Optional[Any] = None, noise_model: Optional[Any] = None
+    ) -> CircuitStatistics:
+        """
+        Return the structural statistics of the Circuit.
+
+        When a device is given, the runtime and fidelity of the Circuit on the device are estimated.
+        The runtime is the end time of the as-soon-as-possible schedule of the Circuit.
+        The fidelity takes into account the decoherence rates of the device and
+        the rates of a ContinuousDecoherenceModel or DecoherenceOnGateModel noise model.
+
+        Args:
+            device (Optional[Device]): The device providing gate times and decoherence rates.
+            noise_model (Optional[NoiseModel]): The noise model adding noise to the device.
+
+        Returns:
+            CircuitStatistics: The statistics of the Circuit.
+
+        Raises:
+            TypeError: Device or noise model cannot be converted.
+            ValueError: A gate of the Circuit is not available on the device.
+        """
+
+    def draw(self, packed: bool = False, output: str = "ascii") -> str:
+        """
+        Draw the Circuit as an ASCII diagram or an SVG image.
+
+        Qubits are drawn as wires, classical registers as double wires.
+        Gates are drawn as boxes, controls as dots connected to their targets
+        and measurements are connected to the classical registers they write to.
+        The circuits of loops, conditional and controlled pragmas are drawn inside a labelled frame.
+
+        Args:
+            packed (bool): Whether operations on different qubits are drawn in the same column.
+            output (str): The format of the drawing, either "ascii" or "svg".
+
+        Returns:
+            str: The ASCII diagram or SVG document of the Circuit.
+
+        Raises:
+            ValueError: Unknown output format.
+        """
+
+class CircuitStatistics:
+    """
+    Structural statistics and resource estimates of a Circuit.
+
+    Created with the `statistics` method of a Circuit.
+    The estimated runtime and fidelity are only available when a device has been passed to `statistics`.
+    """
+
+    def number_operations(self) -> int:
+        """
+        Return the number of operations in the Circuit.
+
+        Returns:
+            int: The number of operations.
+        """
+
+    def depth(self) -> int:
+        """
+        Return the length of the longest chain of dependent operations acting on qubits.
+
+        Returns:
+            int: The depth of the Circuit.
+        """
+
+    def two_qubit_depth(self) -> int:
+        """
+        Return the length of the longest chain of dependent two-qubit gates.
+
+        Returns:
+            int: The two-qubit depth of the Circuit.
+        """
+
+    def gate_counts(self) -> Dict[str, int]:
+        """
+        Return the number of gates for every gate type.
+
+        Returns:
+            Dict[str, int]: The number of gates for every hqslang name.
+        """
+
+    def qubit_gate_counts(self) -> Dict[int, int]:
+        """
+        Return the number of gates acting on every qubit.
+
+        Returns:
+            Dict[int, int]: The number of gates for every qubit.
+        """
+
+    def t_count(self) -> int:
+        """
+        Return the number of TGate and InvTGate gates.
+
+        Returns:
+            int: The T-count of the Circuit.
+        """
+
+    def number_measurements(self) -> int:
+        """
+        Return the number of measurement operations.
+
+        Returns:
+            int: The number of measurements.
+        """
+
+    def number_parametric_operations(self) -> int:
+        """
+        Return the number of operations with symbolic parameters.
+
+        Returns:
+            int: The number of parametric operations.
+        """
+
+    def classical_registers(self) -> Dict[str, int]:
+        """
+        Return the length of every classical register defined in the Circuit.
+
+        Returns:
+            Dict[str, int]: The length of every classical register.
+        """
+
+    def estimated_runtime(self) -> Optional[float]:
+        """
+        Return the estimated runtime of the Circuit on the device.
+
+        Returns:
+            Optional[float]: The estimated runtime, None when no device was given.
+        """
+
+    def estimated_fidelity(self) -> Optional[float]:
+        """
+        Return the estimated fidelity of the Circuit on the device.
+
+        Returns:
+            Optional[float]: The estimated fidelity, None when no device was given.
+        """
+
+    def to_json(self) -> str:
+        """
+        Return the json representation of the CircuitStatistics.
+
+        Returns:
+            str: The serialized form of CircuitStatistics.
+
+        Raises:
+            ValueError: Cannot serialize CircuitStatistics to json.
+        """
+
+    @staticmethod
+    def from_json(input: str) -> CircuitStatistics:
+        """
+        Convert the json representation of a CircuitStatistics to a CircuitStatistics.
+
+        Args:
+            input (str): The serialized CircuitStatistics in json form.
+
+        Returns:
+            CircuitStatistics: The deserialized CircuitStatistics.
+
+        Raises:
+            ValueError: Input cannot be deserialized to CircuitStatistics.
+        """
+
+    @staticmethod
+    def json_schema() -> str:
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
 class QuantumProgram:
     """
     Represents a quantum program evaluating measurements based on a one or more free float parameters.
@@ -332,6 +603,69 @@ class QuantumProgram:
             List of input parameter names.
         """
 
+    def symbols(self) -> Set[str]:
+        """
+        Returns the names of the free symbols in the circuits of the measurement.
+
+        Returns:
+            Set[str]: The free symbols of all circuits of the measurement.
+
+        Raises:
+            ValueError: A parametrized operation does not report its symbols.
+        """
+
+    def bind_parameters(self, values: Dict[str, float]) -> QuantumProgram:
+        """
+        Substitutes the given symbols in the measurement and keeps the other symbols symbolic.
+
+        The bound symbols are removed from the input parameter names.
+
+        Args:
+            values (Dict[str, float]): The values of the symbols to substitute.
+
+        Returns:
+            QuantumProgram: The QuantumProgram with the given symbols substituted.
+
+        Raises:
+            RuntimeError: Error substituting symbolic parameters.
+        """
+
+    def rename_parameters(self, mapping: Dict[str, str]) -> QuantumProgram:
+        """
+        Renames the given symbols in the measurement and in the input parameter names.
+
+        Args:
+            mapping (Dict[str, str]): The new names of the symbols to rename.
+
+        Returns:
+            QuantumProgram: The QuantumProgram with the given symbols renamed.
+
+        Raises:
+            ValueError: A new name is not a valid symbol name.
+        """
+
+    def reparametrize(self, prefix: str) -> QuantumProgram:
+        """
+        Prefixes the names of all input parameters and free symbols of the QuantumProgram.
+
+        Args:
+            prefix (str): The prefix added to the names of the symbols.
+
+        Returns:
+            QuantumProgram: The QuantumProgram with the prefixed symbols.
+
+        Raises:
+            ValueError: The prefixed names are not valid symbol names.
+        """
+
+    def validate_input_parameter_names(self):
+        """
+        Validates that the input parameter names cover exactly the free symbols of the circuits.
+
+        Raises:
+            ValueError: An input parameter is repeated or unused, or a symbol is not an input parameter.
+        """
+
     def run(self, backend: Backend, parameters: Optional[List[float]]):
         """
         Runs the QuantumProgram and returns expectation values.
@@ -25,7 +25,7 @@ use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use roqoqo::prelude::*;
 use roqoqo::{Circuit, OperationIterator, ROQOQO_VERSION};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::operations::{convert_operation_to_pyobject, convert_pyany_to_operation};
 
@@ -130,6 +130,69 @@ impl CircuitWrapper {
         })
     }
 
+    /// Substitute the given symbols in a clone of the Circuit and keep the other symbols symbolic.
+    ///
+    /// Bracketed parts and function calls that no longer contain symbols are evaluated.
+    ///
+    /// Args:
+    ///     values (Dict[str, float]): The values of the symbols to substitute.
+    ///
+    /// Returns:
+    ///     self: The Circuit with the given symbols substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    pub fn bind_parameters(&self, values: HashMap<String, f64>) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.bind_parameters(&values).map_err(|x| {
+                PyRuntimeError::new_err(format!("Parameter Substitution failed: {x:?}"))
+            })?,
+        })
+    }
+
+    /// Rename the given symbols in a clone of the Circuit.
+    ///
+    /// The symbols are renamed in all operations, nested circuits and gate definitions.
+    /// The free parameters of gate definitions are local to the definitions and not renamed.
+    ///
+    /// Args:
+    ///     mapping (Dict[str, str]): The new names of the symbols to rename.
+    ///
+    /// Returns:
+    ///     self: The Circuit with the given symbols renamed.
+    ///
+    /// Raises:
+    ///     ValueError: A new name is not a valid symbol name.
+    pub fn rename_parameters(&self, mapping: HashMap<String, String>) -> PyResult<Self> {
+        Ok(Self {
+            internal: self
+                .internal
+                .rename_parameters(&mapping)
+                .map_err(|x| PyValueError::new_err(format!("Renaming parameters failed: {x:?}")))?,
+        })
+    }
+
+    /// Prefix the names of all symbols in a clone of the Circuit.
+    ///
+    /// Allows combining circuits that use the same symbol names without coupling their parameters.
+    ///
+    /// Args:
+    ///     prefix (str): The prefix added to the names of the symbols.
+    ///
+    /// Returns:
+    ///     self: The Circuit with the prefixed symbols.
+    ///
+    /// Raises:
+    ///     ValueError: The prefixed names are not valid symbol names.
+    pub fn reparametrize(&self, prefix: &str) -> PyResult<Self> {
+        Ok(Self {
+            internal: self
+                .internal
+                .reparametrize(prefix)
+                .map_err(|x| PyValueError::new_err(format!("Renaming parameters failed: {x:?}")))?,
+        })
+    }
+
     /// Remap qubits in operations in clone of Circuit.
     ///
     /// Args:
@@ -206,6 +269,29 @@ impl CircuitWrapper {
         operations
     }
 
+    /// Return the names of the free symbols in the symbolic parameters of the circuit.
+    ///
+    /// Symbols set by an InputSymbolic definition and the free parameters of a GateDefinition are not free.
+    ///
+    /// Returns:
+    ///     Set[str]: The symbols that need to be substituted.
+    ///
+    /// Raises:
+    ///     ValueError: A parametrized operation does not report its symbols.
+    pub fn symbols(&self) -> PyResult<HashSet<String>> {
+        self.internal
+            .symbols()
+            .map_err(|err| PyValueError::new_err(format!("{err}")))
+    }
+
+    /// Return the indices of the operations depending on each free symbol of the circuit.
+    ///
+    /// Returns:
+    ///     Dict[str, List[int]]: The ordered indices of the operations depending on each symbol.
+    pub fn operations_by_symbol(&self) -> HashMap<String, Vec<usize>> {
+        self.internal.operations_by_symbol()
+    }
+
     /// Return a copy of the Circuit (copy here produces a deepcopy).
     ///
     /// Returns:
@@ -480,12 +566,17 @@ impl CircuitWrapper {
     ///
     /// Args:
     ///     op (Operation): The Operation to add to the Circuit.
+    ///
+    /// Raises:
+    ///     TypeError: Cannot convert python object to Operation.
+    ///     ValueError: A CallDefinedGate does not match the qubits or free parameters of its GateDefinition.
     pub fn add(&mut self, op: &Bound<PyAny>) -> PyResult<()> {
         let operation = convert_pyany_to_operation(op).map_err(|x| {
             PyTypeError::new_err(format!("Cannot convert python object to Operation {x:?}"))
         })?;
-        self.internal.add_operation(operation);
-        Ok(())
+        self.internal
+            .try_add_operation(operation)
+            .map_err(|err| PyValueError::new_err(format!("{err}")))
     }
 
     /// Return a string containing a formatted (string) representation of the Circuit.
@@ -601,25 +692,17 @@ impl CircuitWrapper {
     ///
     /// Raises:
     ///     TypeError: Right hand side cannot be converted to Operation or Circuit.
+    ///     ValueError: A CallDefinedGate does not match its GateDefinition.
     fn __iadd__(&mut self, other: &Bound<PyAny>) -> PyResult<()> {
         match convert_pyany_to_operation(other) {
-            Ok(x) => {
-                self.internal += x;
-                Ok(())
-            }
+            Ok(x) => self
+                .internal
+                .try_add_operation(x)
+                .map_err(|err| PyValueError::new_err(format!("{err}"))),
+            // Appending a Circuit is checked on a copy to leave self unchanged on errors
             Err(_) => {
-                let other = convert_into_circuit(other).map_err(|x| {
-                    pyo3::exceptions::PyTypeError::new_err(format!(
-                        "Right hand side cannot be converted to Operation or Circuit {x:?}"
-                    ))
-                });
-                match other {
-                    Ok(x) => {
-                        self.internal += x;
-                        Ok(())
-                    }
-                    Err(y) => Err(y),
-                }
+                self.internal = self.__add__(other)?.internal;
+                Ok(())
             }
         }
     }
@@ -636,25 +719,26 @@ impl CircuitWrapper {
     /// Raises:
     ///     TypeError: Left hand side can not be converted to Circuit.
     ///     TypeError: Right hand side cannot be converted to Operation or Circuit.
+    ///     ValueError: A CallDefinedGate does not match its GateDefinition.
     fn __add__(&mut self, other: &Bound<PyAny>) -> PyResult<CircuitWrapper> {
-        match convert_pyany_to_operation(other) {
-            Ok(x) => Ok(CircuitWrapper {
-                internal: self.internal.clone() + x,
-            }),
-            Err(_) => {
-                let other = convert_into_circuit(other).map_err(|x| {
+        let operations = match convert_pyany_to_operation(other) {
+            Ok(x) => vec![x],
+            Err(_) => convert_into_circuit(other)
+                .map_err(|x| {
                     pyo3::exceptions::PyTypeError::new_err(format!(
                         "Right hand side cannot be converted to Operation or Circuit {x:?}"
                     ))
-                });
-                match other {
-                    Ok(x) => Ok(CircuitWrapper {
-                        internal: self.internal.clone() + x,
-                    }),
-                    Err(y) => Err(y),
-                }
-            }
+                })?
+                .into_iter()
+                .collect(),
+        };
+        let mut internal = self.internal.clone();
+        for operation in operations {
+            internal
+                .try_add_operation(operation)
+                .map_err(|err| PyValueError::new_err(format!("{err}")))?;
         }
+        Ok(CircuitWrapper { internal })
     }
 
     /// Return the number of qubits in the Circuit.
@@ -664,6 +748,113 @@ impl CircuitWrapper {
     fn number_of_qubits(&self) -> usize {
         self.internal.number_of_qubits()
     }
+
+    /// Return whether the Circuit only contains Clifford operations.
+    ///
+    /// Clifford circuits can be simulated efficiently with a stabilizer simulator.
+    /// Symbolic rotation angles are only accepted when they do not depend on free variables.
+    ///
+    /// Returns:
+    ///    bool: True if all operations in the Circuit are Clifford operations.
+    fn is_clifford(&self) -> bool {
+        self.internal.is_clifford()
+    }
+
+    /// Return how often every qubit of the Circuit is reused after being reset.
+    ///
+    /// A qubit is reset by MeasureQubitAndReset or PragmaActiveReset.
+    /// Every later operation acting on a qubit that has been reset since its last use counts as one reuse.
+    ///
+    /// Returns:
+    ///    Dict[int, int]: The number of reuses of every qubit the operations of the Circuit act on.
+    fn qubit_reuses(&self) -> HashMap<usize, usize> {
+        self.internal.qubit_reuses()
+    }
+
+    #[cfg(feature = "circuitdag")]
+    /// Return the structural statistics of the Circuit.
+    ///
+    /// When a device is given, the runtime and fidelity of the Circuit on the device are estimated.
+    /// The runtime is the end time of the as-soon-as-possible schedule of the Circuit.
+    /// The fidelity takes into account the decoherence rates of the device and
+    /// the rates of a ContinuousDecoherenceModel or DecoherenceOnGateModel noise model.
+    ///
+    /// Args:
+    ///     device (Optional[Device]): The device providing gate times and decoherence rates.
+    ///     noise_model (Optional[NoiseModel]): The noise model adding noise to the device.
+    ///
+    /// Returns:
+    ///     CircuitStatistics: The statistics of the Circuit.
+    ///
+    /// Raises:
+    ///     TypeError: Device or noise model cannot be converted.
+    ///     ValueError: A gate of the Circuit is not available on the device.
+    #[pyo3(signature = (device=None, noise_model=None))]
+    fn statistics(
+        &self,
+        device: Option<&Bound<PyAny>>,
+        noise_model: Option<&Bound<PyAny>>,
+    ) -> PyResult<crate::CircuitStatisticsWrapper> {
+        let device = match device {
+            Some(device) => Some(
+                crate::devices::GenericDeviceWrapper::from_pyany(device).map_err(|err| {
+                    PyTypeError::new_err(format!("Device cannot be converted: {err}"))
+                })?,
+            ),
+            None => None,
+        };
+        let noise_model: Option<roqoqo::noise_models::NoiseModel> = match noise_model {
+            Some(noise_model) => {
+                let bytes = noise_model
+                    .call_method0("to_bincode")
+                    .and_then(|bytes| bytes.extract::<Vec<u8>>())
+                    .map_err(|_| {
+                        PyTypeError::new_err("Noise model cannot be converted to byte array")
+                    })?;
+                Some(deserialize(&bytes[..]).map_err(|err| {
+                    PyTypeError::new_err(format!("Noise model cannot be deserialized: {err}"))
+                })?)
+            }
+            None => None,
+        };
+        let internal = match device {
+            Some(device) => roqoqo::passes::CircuitStatistics::with_device(
+                &self.internal,
+                &device,
+                noise_model.as_ref(),
+            )
+            .map_err(|err| PyValueError::new_err(format!("{err}")))?,
+            None => roqoqo::passes::CircuitStatistics::new(&self.internal),
+        };
+        Ok(crate::CircuitStatisticsWrapper { internal })
+    }
+
+    /// Draw the Circuit as an ASCII diagram or an SVG image.
+    ///
+    /// Qubits are drawn as wires, classical registers as double wires.
+    /// Gates are drawn as boxes, controls as dots connected to their targets
+    /// and measurements are connected to the classical registers they write to.
+    /// The circuits of loops, conditional and controlled pragmas are drawn inside a labelled frame.
+    ///
+    /// Args:
+    ///     packed (bool): Whether operations on different qubits are drawn in the same column.
+    ///     output (str): The format of the drawing, either "ascii" or "svg".
+    ///
+    /// Returns:
+    ///     str: The ASCII diagram or SVG document of the Circuit.
+    ///
+    /// Raises:
+    ///     ValueError: Unknown output format.
+    #[pyo3(signature = (packed=false, output="ascii"))]
+    fn draw(&self, packed: bool, output: &str) -> PyResult<String> {
+        match output {
+            "ascii" => Ok(self.internal.draw_ascii(packed)),
+            "svg" => Ok(self.internal.draw_svg(packed)),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown output format {output}, expected \"ascii\" or \"svg\""
+            ))),
+        }
+    }
 }
 
 /// Convert generic python object to [roqoqo::Circuit].
@@ -0,0 +1,193 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Module containing the CircuitStatistics class summarizing the structure and resources of a circuit.
+//!
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use roqoqo::passes::CircuitStatistics;
+use std::collections::HashMap;
+
+/// Structural statistics and resource estimates of a Circuit.
+///
+/// Created with the `statistics` method of a Circuit.
+/// The estimated runtime and fidelity are only available when a device has been passed to `statistics`.
+#[pyclass(name = "CircuitStatistics", module = "qoqo")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CircuitStatisticsWrapper {
+    /// Internal storage of [roqoqo::passes::CircuitStatistics]
+    pub internal: CircuitStatistics,
+}
+
+#[pymethods]
+impl CircuitStatisticsWrapper {
+    /// Return the number of operations in the Circuit.
+    ///
+    /// Returns:
+    ///     int: The number of operations.
+    fn number_operations(&self) -> usize {
+        self.internal.number_operations
+    }
+
+    /// Return the length of the longest chain of dependent operations acting on qubits.
+    ///
+    /// Returns:
+    ///     int: The depth of the Circuit.
+    fn depth(&self) -> usize {
+        self.internal.depth
+    }
+
+    /// Return the length of the longest chain of dependent two-qubit gates.
+    ///
+    /// Returns:
+    ///     int: The two-qubit depth of the Circuit.
+    fn two_qubit_depth(&self) -> usize {
+        self.internal.two_qubit_depth
+    }
+
+    /// Return the number of gates for every gate type.
+    ///
+    /// Returns:
+    ///     Dict[str, int]: The number of gates for every hqslang name.
+    fn gate_counts(&self) -> HashMap<String, usize> {
+        self.internal.gate_counts.clone()
+    }
+
+    /// Return the number of gates acting on every qubit.
+    ///
+    /// Returns:
+    ///     Dict[int, int]: The number of gates for every qubit.
+    fn qubit_gate_counts(&self) -> HashMap<usize, usize> {
+        self.internal.qubit_gate_counts.clone()
+    }
+
+    /// Return the number of TGate and InvTGate gates.
+    ///
+    /// Returns:
+    ///     int: The T-count of the Circuit.
+    fn t_count(&self) -> usize {
+        self.internal.t_count
+    }
+
+    /// Return the number of measurement operations.
+    ///
+    /// Returns:
+    ///     int: The number of measurements.
+    fn number_measurements(&self) -> usize {
+        self.internal.number_measurements
+    }
+
+    /// Return the number of operations with symbolic parameters.
+    ///
+    /// Returns:
+    ///     int: The number of parametric operations.
+    fn number_parametric_operations(&self) -> usize {
+        self.internal.number_parametric_operations
+    }
+
+    /// Return the length of every classical register defined in the Circuit.
+    ///
+    /// Returns:
+    ///     Dict[str, int]: The length of every classical register.
+    fn classical_registers(&self) -> HashMap<String, usize> {
+        self.internal.classical_registers.clone()
+    }
+
+    /// Return the estimated runtime of the Circuit on the device.
+    ///
+    /// Returns:
+    ///     Optional[float]: The estimated runtime, None when no device was given.
+    fn estimated_runtime(&self) -> Option<f64> {
+        self.internal.estimated_runtime
+    }
+
+    /// Return the estimated fidelity of the Circuit on the device.
+    ///
+    /// Returns:
+    ///     Optional[float]: The estimated fidelity, None when no device was given.
+    fn estimated_fidelity(&self) -> Option<f64> {
+        self.internal.estimated_fidelity
+    }
+
+    /// Return the json representation of the CircuitStatistics.
+    ///
+    /// Returns:
+    ///     str: The serialized form of CircuitStatistics.
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize CircuitStatistics to json.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.internal)
+            .map_err(|_| PyValueError::new_err("Cannot serialize CircuitStatistics to json"))
+    }
+
+    /// Convert the json representation of a CircuitStatistics to a CircuitStatistics.
+    ///
+    /// Args:
+    ///     input (str): The serialized CircuitStatistics in json form.
+    ///
+    /// Returns:
+    ///     CircuitStatistics: The deserialized CircuitStatistics.
+    ///
+    /// Raises:
+    ///     ValueError: Input cannot be deserialized to CircuitStatistics.
+    #[staticmethod]
+    fn from_json(input: &str) -> PyResult<Self> {
+        Ok(Self {
+            internal: serde_json::from_str(input).map_err(|_| {
+                PyValueError::new_err("Input cannot be deserialized to CircuitStatistics")
+            })?,
+        })
+    }
+
+    #[cfg(feature = "json_schema")]
+    /// Return the JsonSchema for the json serialisation of the class.
+    ///
+    /// Returns:
+    ///     str: The json schema serialized to json
+    #[staticmethod]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(CircuitStatistics);
+        serde_json::to_string_pretty(&schema).expect("Unexpected failure to serialize schema")
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.internal)
+    }
+
+    /// Return a copy of the CircuitStatistics (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     CircuitStatistics: A deep copy of self.
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    /// Return a deep copy of the CircuitStatistics.
+    ///
+    /// Returns:
+    ///     CircuitStatistics: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> Self {
+        self.clone()
+    }
+
+    fn __richcmp__(&self, other: Self, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Eq => Ok(self.internal == other.internal),
+            pyo3::class::basic::CompareOp::Ne => Ok(self.internal != other.internal),
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+}
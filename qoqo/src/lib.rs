@@ -45,6 +45,11 @@ mod circuitdag;
 #[cfg(feature = "circuitdag")]
 pub use circuitdag::{convert_into_circuitdag, CircuitDagWrapper};
 
+#[cfg(feature = "circuitdag")]
+mod circuit_statistics;
+#[cfg(feature = "circuitdag")]
+pub use circuit_statistics::CircuitStatisticsWrapper;
+
 /// qoqo version information, used for qoqo import/export checks
 pub const QOQO_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -189,6 +194,8 @@ fn qoqo(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<QuantumProgramWrapper>()?;
     #[cfg(feature = "circuitdag")]
     module.add_class::<CircuitDagWrapper>()?;
+    #[cfg(feature = "circuitdag")]
+    module.add_class::<CircuitStatisticsWrapper>()?;
     module.add_function(wrap_pyfunction!(available_gates_hqslang, module)?)?;
     let wrapper = wrap_pymodule!(operations::operations);
     module.add_wrapped(wrapper)?;
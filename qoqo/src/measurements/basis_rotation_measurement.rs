@@ -24,7 +24,14 @@ use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputReg
 use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+// Expectation values, shot-noise variances and standard errors returned by evaluate_statistics
+type ExpValStatistics = (
+    HashMap<String, f64>,
+    HashMap<String, f64>,
+    HashMap<String, f64>,
+);
 
 #[pyclass(name = "PauliZProduct", module = "qoqo.measurements")]
 #[derive(Clone, Debug)]
@@ -145,6 +152,58 @@ impl PauliZProductWrapper {
             })
     }
 
+    /// Execute the PauliZ product measurement and estimate the statistical uncertainties.
+    ///
+    /// The shot-noise variance of an expectation value H is the variance of its estimator when every
+    /// readout register is measured once, the sum of the variances of the parts of H measured in each readout.
+    /// Covariances between Pauli products from different readout registers are not accessible and treated as zero,
+    /// so the shot-noise variance equals the variance <H^2> - <H>^2 of the observable only
+    /// when all Pauli products of H are measured in the same readout register.
+    ///
+    /// Args:
+    ///     input_bit_registers (Dict[str, Union[List[List[int]], List[List[bool]]]]): The classical bit registers with the register name as key
+    ///
+    /// Returns:
+    ///     Tuple[Dict[str, float], Dict[str, float], Dict[str, float]]: The evaluated expectation values, their shot-noise variances and their standard errors.
+    ///
+    /// Raises:
+    ///     RuntimeError: Error evaluating PauliZ product measurement.
+    pub fn evaluate_statistics(
+        &self,
+        input_bit_registers: &Bound<PyAny>,
+    ) -> PyResult<ExpValStatistics> {
+        let bit_registers: HashMap<String, BitOutputRegister> =
+            match input_bit_registers.extract::<HashMap<String, BitOutputRegister>>() {
+                Ok(bit_registers) => bit_registers,
+                Err(_) => input_bit_registers
+                    .extract::<HashMap<String, Vec<Vec<usize>>>>()?
+                    .into_iter()
+                    .map(|(name, output_reg)| {
+                        (
+                            name,
+                            output_reg
+                                .into_iter()
+                                .map(|reg| reg.into_iter().map(|x| !matches!(x, 0)).collect())
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            };
+        let statistics = self
+            .internal
+            .evaluate_statistics(bit_registers)
+            .map_err(|x| {
+                PyRuntimeError::new_err(format!(
+                    "Error evaluating PauliZ product measurement {x:?}"
+                ))
+            })?;
+        Ok((
+            statistics.expectation_values,
+            statistics.shot_noise_variances,
+            statistics.standard_errors,
+        ))
+    }
+
     /// Return the collection of quantum circuits for the separate basis rotations.
     ///
     /// Returns:
@@ -169,6 +228,19 @@ impl PauliZProductWrapper {
             .map(|c| CircuitWrapper { internal: c })
     }
 
+    /// Returns the names of the free symbols in the circuits of the measurement.
+    ///
+    /// Returns:
+    ///     Set[str]: The free symbols of the constant Circuit and of all measurement circuits.
+    ///
+    /// Raises:
+    ///     ValueError: A parametrized operation does not report its symbols.
+    pub fn symbols(&self) -> PyResult<HashSet<String>> {
+        self.internal
+            .symbols()
+            .map_err(|err| PyValueError::new_err(format!("{err}")))
+    }
+
     /// Returns the measurement input data defining how to construct expectation values from measurements.
     ///
     /// Returns:
@@ -204,6 +276,36 @@ impl PauliZProductWrapper {
         })
     }
 
+    /// Return copy of Measurement with the given symbols replaced and the other symbols kept symbolic.
+    ///
+    /// Args:
+    ///     values (Dict[str, float]): The values of the symbols to substitute in the circuits.
+    ///
+    /// Raises:
+    ///     RuntimeError: Error substituting symbolic parameters.
+    pub fn bind_parameters(&self, values: HashMap<String, f64>) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.bind_parameters(values).map_err(|x| {
+                PyRuntimeError::new_err(format!("Error substituting symbolic parameters {x:?}"))
+            })?,
+        })
+    }
+
+    /// Return copy of Measurement with the given symbols renamed.
+    ///
+    /// Args:
+    ///     mapping (Dict[str, str]): The new names of the symbols to rename in the circuits.
+    ///
+    /// Raises:
+    ///     ValueError: A new name is not a valid symbol name.
+    pub fn rename_parameters(&self, mapping: HashMap<String, String>) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.rename_parameters(mapping).map_err(|x| {
+                PyValueError::new_err(format!("Error renaming symbolic parameters {x:?}"))
+            })?,
+        })
+    }
+
     /// Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
     ///
     /// Returns:
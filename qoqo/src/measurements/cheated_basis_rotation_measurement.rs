@@ -24,7 +24,7 @@ use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputReg
 use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[pyclass(name = "CheatedPauliZProduct", module = "qoqo.measurements")]
 #[derive(Clone, Debug)]
@@ -170,6 +170,19 @@ impl CheatedPauliZProductWrapper {
             .map(|c| CircuitWrapper { internal: c })
     }
 
+    /// Returns the names of the free symbols in the circuits of the measurement.
+    ///
+    /// Returns:
+    ///     Set[str]: The free symbols of the constant Circuit and of all measurement circuits.
+    ///
+    /// Raises:
+    ///     ValueError: A parametrized operation does not report its symbols.
+    pub fn symbols(&self) -> PyResult<HashSet<String>> {
+        self.internal
+            .symbols()
+            .map_err(|err| PyValueError::new_err(format!("{err}")))
+    }
+
     /// Returns the measurement input data defining how to construct expectation values from measurements.
     ///
     /// Returns:
@@ -204,6 +217,36 @@ impl CheatedPauliZProductWrapper {
                 })?,
         })
     }
+
+    /// Return copy of Measurement with the given symbols replaced and the other symbols kept symbolic.
+    ///
+    /// Args:
+    ///     values (Dict[str, float]): The values of the symbols to substitute in the circuits.
+    ///
+    /// Raises:
+    ///     RuntimeError: Error substituting symbolic parameters.
+    pub fn bind_parameters(&self, values: HashMap<String, f64>) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.bind_parameters(values).map_err(|x| {
+                PyRuntimeError::new_err(format!("Error substituting symbolic parameters {x:?}"))
+            })?,
+        })
+    }
+
+    /// Return copy of Measurement with the given symbols renamed.
+    ///
+    /// Args:
+    ///     mapping (Dict[str, str]): The new names of the symbols to rename in the circuits.
+    ///
+    /// Raises:
+    ///     ValueError: A new name is not a valid symbol name.
+    pub fn rename_parameters(&self, mapping: HashMap<String, String>) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.rename_parameters(mapping).map_err(|x| {
+                PyValueError::new_err(format!("Error renaming symbolic parameters {x:?}"))
+            })?,
+        })
+    }
     /// Return the name of the measurement and the bincode representation of the Measurement using the [bincode] crate.
     ///
     /// Returns:
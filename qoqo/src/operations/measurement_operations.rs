@@ -38,6 +38,26 @@ pub struct MeasureQubit {
     readout_index: usize,
 }
 
+#[wrap(Operate, OperateSingleQubit, JsonSchema)]
+#[derive(Eq)]
+/// Measurement gate operation that resets the measured qubit.
+///
+/// This Operation acts on one qubit writing the result of the measurement into a readout
+/// and afterwards resets the qubit to the |0> state, so that it can be reused in the rest of the circuit.
+/// In every shot the result is written into the same entry of the readout register.
+/// The classical register for the readout needs to be defined in advance by using a Definition operation.
+///
+/// Args:
+///     qubit (int): The measured qubit.
+///     readout (string): The classical register for the readout.
+///     readout_index (int): The index in the readout the result is saved to.
+///
+pub struct MeasureQubitAndReset {
+    qubit: usize,
+    readout: String,
+    readout_index: usize,
+}
+
 #[wrap(Operate, OperatePragma, JsonSchema)]
 /// This PRAGMA measurement operation returns the statevector of a quantum register.
 ///
@@ -81,6 +81,7 @@ pub fn operations(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<DefinitionComplexWrapper>()?;
     m.add_class::<InputSymbolicWrapper>()?;
     m.add_class::<MeasureQubitWrapper>()?;
+    m.add_class::<MeasureQubitAndResetWrapper>()?;
     m.add_class::<PragmaGetStateVectorWrapper>()?;
     m.add_class::<PragmaGetDensityMatrixWrapper>()?;
     m.add_class::<PragmaGetOccupationProbabilityWrapper>()?;
@@ -104,6 +105,13 @@ pub fn operations(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PragmaRandomNoiseWrapper>()?;
     m.add_class::<PragmaGeneralNoiseWrapper>()?;
     m.add_class::<PragmaConditionalWrapper>()?;
+    m.add_class::<BooleanExpressionWrapper>()?;
+    m.add_class::<PragmaIfElseWrapper>()?;
+    m.add_class::<PragmaWhileLoopWrapper>()?;
+    m.add_class::<PragmaSetBitWrapper>()?;
+    m.add_class::<PragmaBitwiseWrapper>()?;
+    m.add_class::<PragmaParityWrapper>()?;
+    m.add_class::<PragmaSetFloatWrapper>()?;
     m.add_class::<PragmaChangeDeviceWrapper>()?;
     m.add_class::<CNOTWrapper>()?;
     m.add_class::<SWAPWrapper>()?;
@@ -11,11 +11,11 @@
 // limitations under the License.
 
 use crate::operations::convert_operation_to_pyobject;
-use crate::{convert_into_circuit, CircuitWrapper};
+use crate::{convert_into_circuit, CircuitWrapper, QoqoError};
 use ndarray::{Array1, Array2};
 use num_complex::Complex64;
 use numpy::{PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2, ToPyArray};
-use pyo3::exceptions::{PyRuntimeError, PyTypeError};
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use pyo3::types::PySet;
@@ -26,7 +26,7 @@ use roqoqo::operations::*;
 use roqoqo::Circuit;
 #[cfg(feature = "json_schema")]
 use roqoqo::ROQOQO_VERSION;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Wrap function automatically generates functions in these traits.
 #[wrap(Operate, OperatePragma, JsonSchema)]
@@ -1339,6 +1339,376 @@ pub struct PragmaConditional {
     circuit: Circuit,
 }
 
+/// A boolean expression over entries of classical bit registers.
+///
+/// Used as the condition of PragmaIfElse and PragmaWhileLoop and as the value assigned by PragmaSetBit.
+/// Expressions are created with the static constructors and can be combined with the `~`, `&`, `|` and `^` operators.
+#[pyclass(name = "BooleanExpression", module = "qoqo.operations")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BooleanExpressionWrapper {
+    /// Internal storage of [roqoqo::operations::BooleanExpression].
+    pub internal: BooleanExpression,
+}
+
+/// Convert generic python object to [roqoqo::operations::BooleanExpression].
+///
+/// Fallible conversion of generic python object to [roqoqo::operations::BooleanExpression].
+pub fn convert_into_boolean_expression(
+    input: &Bound<PyAny>,
+) -> Result<BooleanExpression, QoqoError> {
+    if let Ok(try_downcast) = input.extract::<BooleanExpressionWrapper>() {
+        return Ok(try_downcast.internal);
+    }
+    let get_json = input
+        .call_method0("to_json")
+        .map_err(|_| QoqoError::CannotExtractObject)?;
+    let json: String = get_json
+        .extract()
+        .map_err(|_| QoqoError::CannotExtractObject)?;
+    serde_json::from_str(&json).map_err(|_| QoqoError::CannotExtractObject)
+}
+
+// Converts a list of python objects to the arguments of a compound BooleanExpression
+fn convert_into_boolean_expressions(
+    expressions: Vec<Bound<PyAny>>,
+) -> PyResult<Vec<BooleanExpression>> {
+    expressions
+        .iter()
+        .map(|expression| {
+            convert_into_boolean_expression(expression).map_err(|_| {
+                PyTypeError::new_err("Argument cannot be converted to BooleanExpression")
+            })
+        })
+        .collect()
+}
+
+#[pymethods]
+impl BooleanExpressionWrapper {
+    /// Create an expression with a constant value.
+    ///
+    /// Args:
+    ///     value (bool): The value of the expression.
+    ///
+    /// Returns:
+    ///     BooleanExpression: The constant expression.
+    #[staticmethod]
+    fn constant(value: bool) -> Self {
+        Self {
+            internal: BooleanExpression::Constant(value),
+        }
+    }
+
+    /// Create an expression reading an entry of a classical bit register.
+    ///
+    /// Args:
+    ///     register (str): The name of the bit register.
+    ///     index (int): The index in the bit register.
+    ///
+    /// Returns:
+    ///     BooleanExpression: The expression reading the entry.
+    #[staticmethod]
+    fn bit(register: String, index: usize) -> Self {
+        Self {
+            internal: BooleanExpression::Bit(register, index),
+        }
+    }
+
+    /// Create the negation of an expression.
+    ///
+    /// Args:
+    ///     expression (BooleanExpression): The negated expression.
+    ///
+    /// Returns:
+    ///     BooleanExpression: The negation.
+    ///
+    /// Raises:
+    ///     TypeError: Argument cannot be converted to BooleanExpression.
+    #[staticmethod]
+    fn logical_not(expression: &Bound<PyAny>) -> PyResult<Self> {
+        let inner = convert_into_boolean_expressions(vec![expression.clone()])?.remove(0);
+        Ok(Self {
+            internal: BooleanExpression::Not(Box::new(inner)),
+        })
+    }
+
+    /// Create an expression that is true when all expressions are true.
+    ///
+    /// Args:
+    ///     expressions (List[BooleanExpression]): The combined expressions.
+    ///
+    /// Returns:
+    ///     BooleanExpression: The conjunction.
+    ///
+    /// Raises:
+    ///     TypeError: Argument cannot be converted to BooleanExpression.
+    #[staticmethod]
+    fn logical_and(expressions: Vec<Bound<PyAny>>) -> PyResult<Self> {
+        Ok(Self {
+            internal: BooleanExpression::And(convert_into_boolean_expressions(expressions)?),
+        })
+    }
+
+    /// Create an expression that is true when at least one expression is true.
+    ///
+    /// Args:
+    ///     expressions (List[BooleanExpression]): The combined expressions.
+    ///
+    /// Returns:
+    ///     BooleanExpression: The disjunction.
+    ///
+    /// Raises:
+    ///     TypeError: Argument cannot be converted to BooleanExpression.
+    #[staticmethod]
+    fn logical_or(expressions: Vec<Bound<PyAny>>) -> PyResult<Self> {
+        Ok(Self {
+            internal: BooleanExpression::Or(convert_into_boolean_expressions(expressions)?),
+        })
+    }
+
+    /// Create an expression that is true when an odd number of expressions is true.
+    ///
+    /// Args:
+    ///     expressions (List[BooleanExpression]): The combined expressions.
+    ///
+    /// Returns:
+    ///     BooleanExpression: The parity.
+    ///
+    /// Raises:
+    ///     TypeError: Argument cannot be converted to BooleanExpression.
+    #[staticmethod]
+    fn logical_xor(expressions: Vec<Bound<PyAny>>) -> PyResult<Self> {
+        Ok(Self {
+            internal: BooleanExpression::Xor(convert_into_boolean_expressions(expressions)?),
+        })
+    }
+
+    /// Return the bit register entries read by the expression.
+    ///
+    /// Returns:
+    ///     Set[Tuple[str, int]]: The (register name, index) pairs the expression depends on.
+    fn involved_bits(&self) -> HashSet<(String, usize)> {
+        self.internal.involved_bits()
+    }
+
+    /// Evaluate the expression for the given bit registers.
+    ///
+    /// Args:
+    ///     bit_registers (Dict[str, List[bool]]): The bit registers the expression is evaluated on.
+    ///
+    /// Returns:
+    ///     bool: The value of the expression.
+    ///
+    /// Raises:
+    ///     ValueError: A register is missing or an index is out of range.
+    fn evaluate(&self, bit_registers: HashMap<String, Vec<bool>>) -> PyResult<bool> {
+        self.internal
+            .evaluate(&bit_registers)
+            .map_err(|err| PyValueError::new_err(format!("{err:?}")))
+    }
+
+    /// Return the json representation of the BooleanExpression.
+    ///
+    /// Returns:
+    ///     str: The serialized form of BooleanExpression.
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize BooleanExpression to json.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.internal)
+            .map_err(|_| PyValueError::new_err("Cannot serialize BooleanExpression to json"))
+    }
+
+    /// Convert the json representation of a BooleanExpression to a BooleanExpression.
+    ///
+    /// Args:
+    ///     input (str): The serialized BooleanExpression in json form.
+    ///
+    /// Returns:
+    ///     BooleanExpression: The deserialized BooleanExpression.
+    ///
+    /// Raises:
+    ///     ValueError: Input cannot be deserialized to BooleanExpression.
+    #[staticmethod]
+    fn from_json(input: &str) -> PyResult<Self> {
+        Ok(Self {
+            internal: serde_json::from_str(input).map_err(|_| {
+                PyValueError::new_err("Input cannot be deserialized to BooleanExpression")
+            })?,
+        })
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> Self {
+        self.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.internal)
+    }
+
+    fn __invert__(&self) -> Self {
+        Self {
+            internal: BooleanExpression::Not(Box::new(self.internal.clone())),
+        }
+    }
+
+    fn __and__(&self, other: &Bound<PyAny>) -> PyResult<Self> {
+        Self::logical_and(vec![
+            self.clone().into_pyobject(other.py())?.into_any(),
+            other.clone(),
+        ])
+    }
+
+    fn __or__(&self, other: &Bound<PyAny>) -> PyResult<Self> {
+        Self::logical_or(vec![
+            self.clone().into_pyobject(other.py())?.into_any(),
+            other.clone(),
+        ])
+    }
+
+    fn __xor__(&self, other: &Bound<PyAny>) -> PyResult<Self> {
+        Self::logical_xor(vec![
+            self.clone().into_pyobject(other.py())?.into_any(),
+            other.clone(),
+        ])
+    }
+
+    fn __richcmp__(
+        &self,
+        other: &Bound<PyAny>,
+        op: pyo3::class::basic::CompareOp,
+    ) -> PyResult<bool> {
+        let other = convert_into_boolean_expression(other);
+        match op {
+            pyo3::class::basic::CompareOp::Eq => match other {
+                Ok(expression) => Ok(self.internal == expression),
+                _ => Ok(false),
+            },
+            pyo3::class::basic::CompareOp::Ne => match other {
+                Ok(expression) => Ok(self.internal != expression),
+                _ => Ok(true),
+            },
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented",
+            )),
+        }
+    }
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// The if-else PRAGMA operation.
+///
+/// This PRAGMA executes if_circuit when the condition evaluated on the classical bit registers is true
+/// and else_circuit otherwise.
+///
+/// Args:
+///     condition (BooleanExpression): The boolean expression deciding which circuit is executed.
+///     if_circuit (Circuit): The circuit executed if the condition is true.
+///     else_circuit (Circuit): The circuit executed if the condition is false.
+pub struct PragmaIfElse {
+    condition: BooleanExpression,
+    if_circuit: Circuit,
+    else_circuit: Circuit,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// The while-loop PRAGMA operation.
+///
+/// This PRAGMA repeatedly executes a circuit as long as the condition evaluated on the classical bit registers is true.
+/// The loop stops after max_iterations executions of the circuit even if the condition is still true.
+///
+/// Args:
+///     condition (BooleanExpression): The boolean expression checked before every iteration.
+///     circuit (Circuit): The circuit executed while the condition is true.
+///     max_iterations (int): The maximum number of times the circuit is executed.
+pub struct PragmaWhileLoop {
+    condition: BooleanExpression,
+    circuit: Circuit,
+    max_iterations: usize,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// The classical assignment PRAGMA operation.
+///
+/// This PRAGMA sets an entry of a classical bit register to the value of a boolean expression
+/// evaluated on the classical bit registers.
+///
+/// Args:
+///     register (str): The name of the bit register that is written to.
+///     index (int): The index in the bit register that is written to.
+///     value (BooleanExpression): The boolean expression whose value is assigned.
+pub struct PragmaSetBit {
+    register: String,
+    index: usize,
+    value: BooleanExpression,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// The bitwise PRAGMA operation.
+///
+/// This PRAGMA sets the entries target_start..target_start + length of the target bit register
+/// to the corresponding entries of the two input ranges combined with the bitwise operator.
+///
+/// Args:
+///     operator (str): The bitwise operator, one of "AND", "OR" and "XOR".
+///     first_register (str): The name of the bit register containing the first input range.
+///     first_start (int): The index of the first entry of the first input range.
+///     second_register (str): The name of the bit register containing the second input range.
+///     second_start (int): The index of the first entry of the second input range.
+///     target_register (str): The name of the bit register that is written to.
+///     target_start (int): The index of the first entry of the target range.
+///     length (int): The number of entries in each range.
+pub struct PragmaBitwise {
+    operator: BitwiseOperator,
+    first_register: String,
+    first_start: usize,
+    second_register: String,
+    second_start: usize,
+    target_register: String,
+    target_start: usize,
+    length: usize,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// The parity PRAGMA operation.
+///
+/// This PRAGMA sets an entry of a bit register to the parity (XOR) of the given entries of a bit register.
+///
+/// Args:
+///     register (str): The name of the bit register containing the input entries.
+///     indices (List[int]): The indices of the input entries.
+///     target_register (str): The name of the bit register that is written to.
+///     target_index (int): The index in the target bit register that is written to.
+pub struct PragmaParity {
+    register: String,
+    indices: Vec<usize>,
+    target_register: String,
+    target_index: usize,
+}
+
+#[wrap(Operate, OperatePragma, JsonSchema)]
+/// The classical float arithmetic PRAGMA operation.
+///
+/// This PRAGMA sets an entry of a float register to the value of a symbolic expression.
+/// The symbols listed in variables stand for entries of float or bit registers read while
+/// the circuit runs (bits are read as 0.0 or 1.0). All other symbols are free parameters
+/// of the circuit.
+///
+/// Args:
+///     register (str): The name of the float register that is written to.
+///     index (int): The index in the float register that is written to.
+///     expression (CalculatorFloat): The expression whose value is assigned.
+///     variables (Dict[str, Tuple[str, int]]): The register entries (register name, index) the symbols in the expression stand for.
+pub struct PragmaSetFloat {
+    register: String,
+    index: usize,
+    expression: CalculatorFloat,
+    variables: HashMap<String, (String, usize)>,
+}
+
 #[wrap(Operate, OperatePragma, JsonSchema)]
 /// A circuit controlled by a qubit.
 ///
@@ -10,7 +10,7 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::measurements::{
     CheatedPauliZProductWrapper, CheatedWrapper, ClassicalRegisterWrapper, PauliZProductWrapper,
@@ -223,6 +223,85 @@ impl QuantumProgramWrapper {
         }
     }
 
+    /// Returns the names of the free symbols in the circuits of the measurement.
+    ///
+    /// Returns:
+    ///     Set[str]: The free symbols of all circuits of the measurement.
+    ///
+    /// Raises:
+    ///     ValueError: A parametrized operation does not report its symbols.
+    pub fn symbols(&self) -> PyResult<HashSet<String>> {
+        self.internal
+            .symbols()
+            .map_err(|err| PyValueError::new_err(format!("{err}")))
+    }
+
+    /// Substitutes the given symbols in the measurement and keeps the other symbols symbolic.
+    ///
+    /// The bound symbols are removed from the input parameter names.
+    ///
+    /// Args:
+    ///     values (Dict[str, float]): The values of the symbols to substitute.
+    ///
+    /// Returns:
+    ///     QuantumProgram: The QuantumProgram with the given symbols substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: Error substituting symbolic parameters.
+    pub fn bind_parameters(&self, values: HashMap<String, f64>) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.bind_parameters(&values).map_err(|x| {
+                PyRuntimeError::new_err(format!("Error substituting symbolic parameters {x:?}"))
+            })?,
+        })
+    }
+
+    /// Renames the given symbols in the measurement and in the input parameter names.
+    ///
+    /// Args:
+    ///     mapping (Dict[str, str]): The new names of the symbols to rename.
+    ///
+    /// Returns:
+    ///     QuantumProgram: The QuantumProgram with the given symbols renamed.
+    ///
+    /// Raises:
+    ///     ValueError: A new name is not a valid symbol name.
+    pub fn rename_parameters(&self, mapping: HashMap<String, String>) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.rename_parameters(&mapping).map_err(|x| {
+                PyValueError::new_err(format!("Error renaming symbolic parameters {x:?}"))
+            })?,
+        })
+    }
+
+    /// Prefixes the names of all input parameters and free symbols of the QuantumProgram.
+    ///
+    /// Args:
+    ///     prefix (str): The prefix added to the names of the symbols.
+    ///
+    /// Returns:
+    ///     QuantumProgram: The QuantumProgram with the prefixed symbols.
+    ///
+    /// Raises:
+    ///     ValueError: The prefixed names are not valid symbol names.
+    pub fn reparametrize(&self, prefix: &str) -> PyResult<Self> {
+        Ok(Self {
+            internal: self.internal.reparametrize(prefix).map_err(|x| {
+                PyValueError::new_err(format!("Error renaming symbolic parameters {x:?}"))
+            })?,
+        })
+    }
+
+    /// Validates that the input parameter names cover exactly the free symbols of the circuits.
+    ///
+    /// Raises:
+    ///     ValueError: An input parameter is repeated or unused, or a symbol is not an input parameter.
+    pub fn validate_input_parameter_names(&self) -> PyResult<()> {
+        self.internal
+            .validate_input_parameter_names()
+            .map_err(|err| PyValueError::new_err(format!("{err}")))
+    }
+
     /// Runs the QuantumProgram and returns expectation values.
     ///
     /// Runs the quantum programm for a given set of parameters passed in the same order as the parameters
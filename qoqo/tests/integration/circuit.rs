@@ -10,7 +10,7 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 use qoqo::measurements::{PauliZProductInputWrapper, PauliZProductWrapper};
 use qoqo::operations::{convert_operation_to_pyobject, RotateXWrapper};
@@ -235,6 +235,152 @@ fn test_get_operation_types() {
     })
 }
 
+/// Test symbols and operations_by_symbol functions of Circuit
+#[test]
+fn test_symbols() {
+    let added_op1 = Operation::from(RotateX::new(0, CalculatorFloat::from("theta")));
+    let added_op2 = Operation::from(PauliX::new(0));
+    let added_op3 = Operation::from(RotateZ::new(0, CalculatorFloat::from("sin(theta) * phi")));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for operation in [added_op1, added_op2, added_op3] {
+            let operation = convert_operation_to_pyobject(operation, py).unwrap();
+            circuit.call_method1("add", (operation,)).unwrap();
+        }
+
+        let symbols =
+            HashSet::<String>::extract_bound(&circuit.call_method0("symbols").unwrap()).unwrap();
+        assert_eq!(
+            symbols,
+            HashSet::from(["theta".to_string(), "phi".to_string()])
+        );
+        let operations_by_symbol = HashMap::<String, Vec<usize>>::extract_bound(
+            &circuit.call_method0("operations_by_symbol").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(operations_by_symbol["theta"], vec![0, 2]);
+        assert_eq!(operations_by_symbol["phi"], vec![2]);
+    })
+}
+
+/// Test bind_parameters function of Circuit
+#[test]
+fn test_bind_parameters() {
+    let added_op = Operation::from(RotateX::new(
+        0,
+        CalculatorFloat::from("calibration * theta"),
+    ));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operation = convert_operation_to_pyobject(added_op, py).unwrap();
+        circuit.call_method1("add", (operation,)).unwrap();
+
+        let values: HashMap<String, f64> = HashMap::from([("calibration".to_string(), 0.5)]);
+        let bound = circuit
+            .call_method1("bind_parameters", (values,))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        let mut expected = roqoqo::Circuit::new();
+        expected += RotateX::new(0, CalculatorFloat::from("0.5 * theta"));
+        assert_eq!(bound.internal, expected);
+
+        let values: HashMap<String, f64> = HashMap::from([("theta".to_string(), 0.0)]);
+        let bound = circuit
+            .call_method1("bind_parameters", (values,))
+            .unwrap()
+            .call_method1("bind_parameters", (HashMap::<String, f64>::new(),))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        let mut expected = roqoqo::Circuit::new();
+        expected += RotateX::new(0, CalculatorFloat::from("calibration * 0.0"));
+        assert_eq!(bound.internal, expected);
+    })
+}
+
+/// Test rename_parameters and reparametrize functions of Circuit
+#[test]
+fn test_rename_parameters_reparametrize() {
+    let added_op = Operation::from(RotateX::new(0, CalculatorFloat::from("2 * theta")));
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let operation = convert_operation_to_pyobject(added_op, py).unwrap();
+        circuit.call_method1("add", (operation,)).unwrap();
+
+        let mapping: HashMap<String, String> =
+            HashMap::from([("theta".to_string(), "phi".to_string())]);
+        let renamed = circuit
+            .call_method1("rename_parameters", (mapping,))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        let mut expected = roqoqo::Circuit::new();
+        expected += RotateX::new(0, CalculatorFloat::from("2 * phi"));
+        assert_eq!(renamed.internal, expected);
+
+        let reparametrized = circuit
+            .call_method1("reparametrize", ("block_",))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        let mut expected = roqoqo::Circuit::new();
+        expected += RotateX::new(0, CalculatorFloat::from("2 * block_theta"));
+        assert_eq!(reparametrized.internal, expected);
+
+        let mapping: HashMap<String, String> =
+            HashMap::from([("theta".to_string(), "not valid".to_string())]);
+        let error = circuit.call_method1("rename_parameters", (mapping,));
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+        assert!(circuit.call_method1("reparametrize", ("0",)).is_err());
+    })
+}
+
+/// Test that adding CallDefinedGates not matching their GateDefinition raises a ValueError
+#[cfg(feature = "unstable_operation_definition")]
+#[test]
+fn test_add_mismatching_call() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut body = roqoqo::Circuit::new();
+        body += RotateX::new(0, "theta".into());
+        let definition = Operation::from(GateDefinition::new(
+            body,
+            "rotation".to_string(),
+            vec![0],
+            vec!["theta".to_string()],
+        ));
+        let call = Operation::from(CallDefinedGate::new(
+            "rotation".to_string(),
+            vec![0, 1],
+            vec![0.5.into()],
+        ));
+        let circuit = new_circuit(py);
+        circuit
+            .call_method1(
+                "add",
+                (convert_operation_to_pyobject(definition, py).unwrap(),),
+            )
+            .unwrap();
+        let call_py = convert_operation_to_pyobject(call.clone(), py).unwrap();
+        let error = circuit.call_method1("add", (call_py.clone(),));
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+        let error = circuit.call_method1("__iadd__", (call_py.clone(),));
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+        let error = circuit.call_method1("__add__", (call_py,));
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+
+        let other = new_circuit(py);
+        other.borrow_mut().internal += call;
+        let error = circuit.call_method1("__iadd__", (other,));
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+        assert_eq!(circuit.borrow().internal.len(), 1);
+    })
+}
+
 /// Test copy and deepcopy functions of Circuit
 #[test]
 fn test_copy_deepcopy() {
@@ -971,6 +1117,132 @@ fn test_number_of_qubits() {
     })
 }
 
+// Test is_clifford method
+#[test]
+fn test_is_clifford() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        let cnot = convert_operation_to_pyobject(Operation::from(CNOT::new(0, 1)), py).unwrap();
+        circuit.call_method1("add", (cnot,)).unwrap();
+        assert!(bool::extract_bound(&circuit.call_method0("is_clifford").unwrap()).unwrap());
+
+        populate_circuit_rotatex(py, &circuit, 1, 2);
+        assert!(!bool::extract_bound(&circuit.call_method0("is_clifford").unwrap()).unwrap());
+    })
+}
+
+// Test qubit_reuses method
+#[test]
+fn test_qubit_reuses() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for operation in [
+            Operation::from(MeasureQubitAndReset::new(0, "ro".to_string(), 0)),
+            Operation::from(CNOT::new(0, 1)),
+        ] {
+            let operation = convert_operation_to_pyobject(operation, py).unwrap();
+            circuit.call_method1("add", (operation,)).unwrap();
+        }
+        let reuses: HashMap<usize, usize> = circuit
+            .call_method0("qubit_reuses")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(reuses, HashMap::from([(0, 1), (1, 0)]));
+    })
+}
+
+// Test statistics method without and with a device
+#[test]
+#[cfg(feature = "circuitdag")]
+fn test_statistics() {
+    use qoqo::devices::GenericDeviceWrapper;
+    use qoqo::noise_models::ContinuousDecoherenceModelWrapper;
+    use roqoqo::devices::GenericDevice;
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for operation in [
+            Operation::from(TGate::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+            Operation::from(MeasureQubit::new(1, "ro".to_string(), 0)),
+        ] {
+            let operation = convert_operation_to_pyobject(operation, py).unwrap();
+            circuit.call_method1("add", (operation,)).unwrap();
+        }
+        let statistics = circuit.call_method0("statistics").unwrap();
+        let depth: usize = statistics.call_method0("depth").unwrap().extract().unwrap();
+        assert_eq!(depth, 3);
+        let gate_counts: HashMap<String, usize> = statistics
+            .call_method0("gate_counts")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            gate_counts,
+            HashMap::from([("TGate".to_string(), 1), ("CNOT".to_string(), 1)])
+        );
+        let t_count: usize = statistics
+            .call_method0("t_count")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(t_count, 1);
+        let runtime: Option<f64> = statistics
+            .call_method0("estimated_runtime")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(runtime, None);
+
+        let mut device = GenericDevice::new(2);
+        device.set_single_qubit_gate_time("TGate", 0, 1.0).unwrap();
+        device.set_two_qubit_gate_time("CNOT", 0, 1, 2.0).unwrap();
+        let device = Bound::new(py, GenericDeviceWrapper { internal: device }).unwrap();
+        let noise_model = py
+            .get_type::<ContinuousDecoherenceModelWrapper>()
+            .call0()
+            .unwrap()
+            .call_method1("add_damping_rate", (vec![0, 1], 0.1))
+            .unwrap();
+        let statistics = circuit
+            .call_method1("statistics", (device.clone(), noise_model))
+            .unwrap();
+        let runtime: Option<f64> = statistics
+            .call_method0("estimated_runtime")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(runtime, Some(3.0));
+        let fidelity: Option<f64> = statistics
+            .call_method0("estimated_fidelity")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!((fidelity.unwrap() - (-0.6_f64).exp()).abs() < 1e-10);
+
+        let json: String = statistics
+            .call_method0("to_json")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let statistics_type = py.get_type::<qoqo::CircuitStatisticsWrapper>();
+        let deserialized = statistics_type.call_method1("from_json", (json,)).unwrap();
+        assert!(deserialized.eq(&statistics).unwrap());
+
+        circuit
+            .call_method1(
+                "add",
+                (convert_operation_to_pyobject(PauliX::new(0).into(), py).unwrap(),),
+            )
+            .unwrap();
+        assert!(circuit.call_method1("statistics", (device,)).is_err());
+        assert!(circuit.call_method1("statistics", (0,)).is_err());
+    })
+}
+
 /// Test function overrotate() for Circuit
 #[test]
 #[cfg(feature = "overrotate")]
@@ -1017,3 +1289,34 @@ fn test_circuit_overrotate() {
         assert!(comparison);
     })
 }
+
+// Test drawing a circuit as ASCII diagram and SVG image
+#[test]
+fn test_draw() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = new_circuit(py);
+        for operation in [
+            Operation::from(Hadamard::new(0)),
+            Operation::from(CNOT::new(0, 1)),
+        ] {
+            let operation = convert_operation_to_pyobject(operation, py).unwrap();
+            circuit.call_method1("add", (operation,)).unwrap();
+        }
+        let ascii: String = circuit.call_method0("draw").unwrap().extract().unwrap();
+        assert_eq!(
+            ascii,
+            "q[0]:--[Hadamard]---*---\n                    |\nq[1]:--------------[X]--\n"
+        );
+        let svg: String = circuit
+            .call_method1("draw", (true, "svg"))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(">Hadamard</text>"));
+
+        let result = circuit.call_method1("draw", (false, "png"));
+        assert!(result.is_err());
+    })
+}
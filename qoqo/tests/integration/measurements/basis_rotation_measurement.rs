@@ -375,6 +375,59 @@ fn test_evaluate_symbolic(register: Vec<Vec<bool>>, constant: f64) {
     })
 }
 
+/// Test evaluate_statistics() function for PauliZProduct measurement
+#[test]
+fn test_py03_evaluate_statistics() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let input_type = py.get_type::<PauliZProductInputWrapper>();
+        let binding = input_type.call1((2, false)).unwrap();
+        let input = binding.downcast::<PauliZProductInputWrapper>().unwrap();
+        let _ = input
+            .call_method1("add_pauliz_product", ("ro", vec![0]))
+            .unwrap();
+        let _ = input
+            .call_method1("add_pauliz_product", ("ro", vec![1]))
+            .unwrap();
+        let mut linear_map: HashMap<usize, f64> = HashMap::new();
+        linear_map.insert(0, 1.0);
+        linear_map.insert(1, 1.0);
+        let _ = input
+            .call_method1("add_linear_exp_val", ("sum", linear_map))
+            .unwrap();
+
+        let br_type = py.get_type::<PauliZProductWrapper>();
+        let binding = br_type
+            .call1((None::<CircuitWrapper>, vec![CircuitWrapper::new()], input))
+            .unwrap();
+        let br = binding.downcast::<PauliZProductWrapper>().unwrap();
+
+        let mut measured_registers: HashMap<String, Vec<Vec<usize>>> = HashMap::new();
+        let _ = measured_registers.insert(
+            "ro".to_string(),
+            vec![vec![0, 0], vec![1, 1], vec![0, 1], vec![0, 0]],
+        );
+        let (expectation_values, shot_noise_variances, standard_errors): (
+            HashMap<String, f64>,
+            HashMap<String, f64>,
+            HashMap<String, f64>,
+        ) = br
+            .call_method1("evaluate_statistics", (measured_registers,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!((expectation_values.get("sum").unwrap() - 0.5).abs() < 1e-12);
+        assert!((shot_noise_variances.get("sum").unwrap() - 2.75).abs() < 1e-12);
+        assert!((standard_errors.get("sum").unwrap() - (2.75_f64 / 3.0).sqrt()).abs() < 1e-12);
+
+        let error = br.call_method1(
+            "evaluate_statistics",
+            (HashMap::<String, Vec<Vec<bool>>>::new(),),
+        );
+        assert!(error.is_err());
+    })
+}
+
 /// Test evaluate failure
 #[test]
 fn test_py03_evaluate_error0() {
@@ -71,6 +71,7 @@ fn new_circuit(py: Python) -> Bound<CircuitWrapper> {
 
 /// Test readout() input/function
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)); "MeasureQubit")]
+#[test_case(Operation::from(MeasureQubitAndReset::new(0, String::from("ro"), 1)); "MeasureQubitAndReset")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
@@ -197,6 +198,7 @@ fn test_pyo3_involved_qubits_all(input_definition: Operation) {
 
 /// Test involved_qubits function for pragmas with qubit 0
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)); "MeasureQubit")]
+#[test_case(Operation::from(MeasureQubitAndReset::new(0, String::from("ro"), 1)); "MeasureQubitAndReset")]
 #[test_case(Operation::from(PragmaGetPauliProduct::new(create_qubit_mapping(), String::from("ro"), create_circuit())); "PragmaGetPauliProduct")]
 fn test_pyo3_involved_qubits_0(input_definition: Operation) {
     pyo3::prepare_freethreaded_python();
@@ -212,6 +214,7 @@ fn test_pyo3_involved_qubits_0(input_definition: Operation) {
 
 /// Test format and repr functions
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)), "MeasureQubit { qubit: 0, readout: \"ro\", readout_index: 1 }"; "MeasureQubit")]
+#[test_case(Operation::from(MeasureQubitAndReset::new(0, String::from("ro"), 1)), "MeasureQubitAndReset { qubit: 0, readout: \"ro\", readout_index: 1 }"; "MeasureQubitAndReset")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), None)), "PragmaGetStateVector { readout: \"ro\", circuit: None }"; "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), None)), "PragmaGetDensityMatrix { readout: \"ro\", circuit: None }"; "PragmaGetDensityMatrix")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), None)), "PragmaGetOccupationProbability { readout: \"ro\", circuit: None }"; "PragmaGetOccupationProbability")]
@@ -232,6 +235,7 @@ fn test_pyo3_format_repr(input_measurement: Operation, format_repr: &str) {
 
 /// Test copy and deepcopy functions
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)); "MeasureQubit")]
+#[test_case(Operation::from(MeasureQubitAndReset::new(0, String::from("ro"), 1)); "MeasureQubitAndReset")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
@@ -298,6 +302,7 @@ fn test_pyo3_tags_measure_qubits() {
 
 /// Test hqslang function
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)), String::from("MeasureQubit"); "MeasureQubit")]
+#[test_case(Operation::from(MeasureQubitAndReset::new(0, String::from("ro"), 1)), String::from("MeasureQubitAndReset"); "MeasureQubitAndReset")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))), String::from("PragmaGetStateVector"); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))), String::from("PragmaGetDensityMatrix"); "PragmaGetDensityMatrix")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))), String::from("PragmaGetOccupationProbability"); "PragmaGetOccupationProbability")]
@@ -318,6 +323,7 @@ fn test_pyo3_hqslang(input_measurement: Operation, hqslang_param: String) {
 
 /// Test is_parametrized function
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 1)); "MeasureQubit")]
+#[test_case(Operation::from(MeasureQubitAndReset::new(0, String::from("ro"), 1)); "MeasureQubitAndReset")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))); "PragmaGetStateVector")]
 #[test_case(Operation::from(PragmaGetDensityMatrix::new(String::from("ro"), Some(create_circuit()))); "PragmaGetDensityMatrix")]
 #[test_case(Operation::from(PragmaGetOccupationProbability::new(String::from("ro"), Some(create_circuit()))); "PragmaGetOccupationProbability")]
@@ -391,6 +397,9 @@ fn test_pyo3_substitute_params_error(input_operation: Operation) {
 #[test_case(Operation::from(MeasureQubit::new(0, String::from("ro"), 0)),
             Operation::from(MeasureQubit::new(2, String::from("ro"), 0));
             "MeasureQubit")]
+#[test_case(Operation::from(MeasureQubitAndReset::new(0, String::from("ro"), 0)),
+            Operation::from(MeasureQubitAndReset::new(2, String::from("ro"), 0));
+            "MeasureQubitAndReset")]
 #[test_case(Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(create_circuit()))),
             Operation::from(PragmaGetStateVector::new(String::from("ro"), Some(circuit_remapped())));
             "PragmaGetStateVector")]
@@ -525,6 +534,24 @@ fn test_pyo3_new_set_number_of_measurements() {
     })
 }
 
+/// Test MeasureQubitAndReset new() function
+#[test]
+fn test_pyo3_new_measure_qubit_and_reset() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type::<MeasureQubitAndResetWrapper>();
+        let binding = operation.call1((0, "ro".to_string(), 1)).unwrap();
+        let new_op = binding.extract::<MeasureQubitAndResetWrapper>().unwrap();
+        assert_eq!(
+            new_op.internal,
+            MeasureQubitAndReset::new(0, String::from("ro"), 1)
+        );
+        let qubit: usize = binding.call_method0("qubit").unwrap().extract().unwrap();
+        assert_eq!(qubit, 0);
+        assert!(operation.call1(("ro".to_string(), 1)).is_err());
+    })
+}
+
 /// Test PragmaGetStateVector new() function
 #[test]
 fn test_pyo3_new_get_statevector() {
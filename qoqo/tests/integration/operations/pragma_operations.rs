@@ -83,6 +83,22 @@ fn create_circuit() -> Circuit {
     circuit
 }
 
+fn condition() -> BooleanExpression {
+    BooleanExpression::And(vec![
+        BooleanExpression::Bit("ro".to_string(), 0),
+        BooleanExpression::Not(Box::new(BooleanExpression::Bit("flag".to_string(), 1))),
+    ])
+}
+
+fn set_float() -> PragmaSetFloat {
+    PragmaSetFloat::new(
+        String::from("angles"),
+        0,
+        CalculatorFloat::from("2 * x"),
+        HashMap::from([(String::from("x"), (String::from("angles"), 1))]),
+    )
+}
+
 fn circuit_remapped() -> Circuit {
     let mut circuit = Circuit::new();
     circuit.add_operation(PauliX::new(2));
@@ -654,6 +670,8 @@ fn test_pyo3_involved_qubits_all(input_definition: Operation) {
 #[test_case(Operation::from(PragmaConditional::new(String::from("ro"), 1, create_circuit())); "PragmaConditional")]
 #[test_case(Operation::from(PragmaControlledCircuit::new(0, create_circuit())); "PragmaControlledCircuit")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())); "PragmaAnnotatedOp")]
+#[test_case(Operation::from(PragmaIfElse::new(condition(), create_circuit(), Circuit::new())); "PragmaIfElse")]
+#[test_case(Operation::from(PragmaWhileLoop::new(condition(), create_circuit(), 3)); "PragmaWhileLoop")]
 fn test_pyo3_involved_qubits_qubit(input_definition: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -769,6 +787,12 @@ fn test_pyo3_format_repr_overrotation(input_measurement: Operation, format_repr:
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, create_circuit())); "PragmaControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), Circuit::default())); "PragmaLoop")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())); "PragmaAnnotatedOp")]
+#[test_case(Operation::from(PragmaIfElse::new(condition(), create_circuit(), Circuit::new())); "PragmaIfElse")]
+#[test_case(Operation::from(PragmaWhileLoop::new(condition(), create_circuit(), 3)); "PragmaWhileLoop")]
+#[test_case(Operation::from(PragmaSetBit::new(String::from("out"), 1, condition())); "PragmaSetBit")]
+#[test_case(Operation::from(PragmaBitwise::new(BitwiseOperator::And, String::from("a"), 0, String::from("b"), 0, String::from("out"), 0, 2)); "PragmaBitwise")]
+#[test_case(Operation::from(PragmaParity::new(String::from("ro"), vec![0, 1], String::from("out"), 0)); "PragmaParity")]
+#[test_case(Operation::from(set_float()); "PragmaSetFloat")]
 fn test_pyo3_copy_deepcopy(input_measurement: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -989,6 +1013,12 @@ fn test_pyo3_tags_noise(input_measurement: Operation, tag_name: &str) {
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, create_circuit())), "PragmaControlledCircuit"; "PragmaControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from("number_t"), Circuit::default())), "PragmaLoop"; "PragmaLoop")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())), "PragmaAnnotatedOp"; "PragmaAnnotatedOp")]
+#[test_case(Operation::from(PragmaIfElse::new(condition(), create_circuit(), Circuit::new())), "PragmaIfElse"; "PragmaIfElse")]
+#[test_case(Operation::from(PragmaWhileLoop::new(condition(), create_circuit(), 3)), "PragmaWhileLoop"; "PragmaWhileLoop")]
+#[test_case(Operation::from(PragmaSetBit::new(String::from("out"), 1, condition())), "PragmaSetBit"; "PragmaSetBit")]
+#[test_case(Operation::from(PragmaBitwise::new(BitwiseOperator::And, String::from("a"), 0, String::from("b"), 0, String::from("out"), 0, 2)), "PragmaBitwise"; "PragmaBitwise")]
+#[test_case(Operation::from(PragmaParity::new(String::from("ro"), vec![0, 1], String::from("out"), 0)), "PragmaParity"; "PragmaParity")]
+#[test_case(Operation::from(set_float()), "PragmaSetFloat"; "PragmaSetFloat")]
 fn test_pyo3_hqslang(input_measurement: Operation, hqslang_param: &str) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -1037,6 +1067,12 @@ fn test_pyo3_hqslang_overrotation(input_measurement: Operation, hqslang_param: &
 #[test_case(Operation::from(PragmaControlledCircuit::new( 1, create_circuit())); "PragmaControlledCircuit")]
 #[test_case(Operation::from(PragmaLoop::new(CalculatorFloat::from(1.0), Circuit::default())); "PragmaLoop")]
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(PauliX::new(0)), "test".to_string())); "PragmaAnnotatedOp")]
+#[test_case(Operation::from(PragmaIfElse::new(condition(), create_circuit(), Circuit::new())); "PragmaIfElse")]
+#[test_case(Operation::from(PragmaWhileLoop::new(condition(), create_circuit(), 3)); "PragmaWhileLoop")]
+#[test_case(Operation::from(PragmaSetBit::new(String::from("out"), 1, condition())); "PragmaSetBit")]
+#[test_case(Operation::from(PragmaBitwise::new(BitwiseOperator::And, String::from("a"), 0, String::from("b"), 0, String::from("out"), 0, 2)); "PragmaBitwise")]
+#[test_case(Operation::from(PragmaParity::new(String::from("ro"), vec![0, 1], String::from("out"), 0)); "PragmaParity")]
+#[test_case(Operation::from(set_float()); "PragmaSetFloat")]
 fn test_pyo3_is_parametrized_false(input_pragma: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -1313,6 +1349,12 @@ fn test_pyo3_substituteparameters_error(input_operation: Operation) {
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(RotateX::new(0, CalculatorFloat::from("test"))), "test".to_string())),
             Operation::from(PragmaAnnotatedOp::new(Operation::from(RotateX::new(2, CalculatorFloat::from("test"))), "test".to_string()));
             "PragmaAnnotatedOp")]
+#[test_case(Operation::from(PragmaIfElse::new(condition(), create_circuit(), Circuit::new())),
+            Operation::from(PragmaIfElse::new(condition(), circuit_remapped(), Circuit::new()));
+            "PragmaIfElse")]
+#[test_case(Operation::from(PragmaWhileLoop::new(condition(), create_circuit(), 3)),
+            Operation::from(PragmaWhileLoop::new(condition(), circuit_remapped(), 3));
+            "PragmaWhileLoop")]
 fn test_pyo3_remap_qubits(first_op: Operation, second_op: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -1421,7 +1463,7 @@ fn test_pyo3_noise_superoperator_depolarising() {
     Python::with_gil(|py| {
         let operation = convert_operation_to_pyobject(noise_pragma, py).unwrap();
 
-        let superop_pre_exp: f64 = -1.0 * 0.005 * 0.02;
+        let superop_pre_exp: f64 = -0.005 * 0.02;
         let superop_prob: f64 = 0.75 * (1.0 - superop_pre_exp.exp());
         let superop_proba1: f64 = 1.0 - (2.0 / 3.0) * superop_prob;
         let superop_proba2: f64 = 1.0 - (4.0 / 3.0) * superop_prob;
@@ -1624,6 +1666,12 @@ fn test_pyo3_noise_powercf(first_op: Operation, second_op: Operation) {
 #[test_case(Operation::from(PragmaAnnotatedOp::new(Operation::from(RotateX::new(0, CalculatorFloat::from("test"))), "test".to_string())),
             Operation::from(PragmaAnnotatedOp::new(Operation::from(RotateX::new(2, CalculatorFloat::from("test"))), "test".to_string()));
             "PragmaAnnotatedOp")]
+#[test_case(Operation::from(PragmaIfElse::new(condition(), create_circuit(), Circuit::new())),
+            Operation::from(PragmaIfElse::new(condition(), circuit_remapped(), Circuit::new()));
+            "PragmaIfElse")]
+#[test_case(Operation::from(PragmaWhileLoop::new(condition(), create_circuit(), 3)),
+            Operation::from(PragmaWhileLoop::new(condition(), circuit_remapped(), 3));
+            "PragmaWhileLoop")]
 fn test_pyo3_richcmp(definition_1: Operation, definition_2: Operation) {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
@@ -2465,6 +2513,258 @@ fn test_pyo3_new_conditional() {
     })
 }
 
+/// Test BooleanExpression construction, operators and evaluation
+#[test]
+fn test_pyo3_boolean_expression() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let expression_type = py.get_type::<BooleanExpressionWrapper>();
+        let ro = expression_type.call_method1("bit", ("ro", 0)).unwrap();
+        let flag = expression_type.call_method1("bit", ("flag", 1)).unwrap();
+        let not_flag = expression_type
+            .call_method1("logical_not", (flag.clone(),))
+            .unwrap();
+        let combined = expression_type
+            .call_method1("logical_and", (vec![ro.clone(), not_flag],))
+            .unwrap();
+        let expression = combined.extract::<BooleanExpressionWrapper>().unwrap();
+        assert_eq!(expression.internal, condition());
+
+        // Python operators create the same expressions
+        let operator = ro
+            .call_method1("__and__", (flag.call_method0("__invert__").unwrap(),))
+            .unwrap();
+        assert!(bool::extract_bound(
+            &operator
+                .call_method1("__eq__", (combined.clone(),))
+                .unwrap()
+        )
+        .unwrap());
+        let xor = ro.call_method1("__xor__", (flag.clone(),)).unwrap();
+        assert_eq!(
+            xor.extract::<BooleanExpressionWrapper>().unwrap().internal,
+            BooleanExpression::Xor(vec![
+                BooleanExpression::Bit("ro".to_string(), 0),
+                BooleanExpression::Bit("flag".to_string(), 1)
+            ])
+        );
+
+        let registers: HashMap<String, Vec<bool>> = HashMap::from([
+            ("ro".to_string(), vec![true]),
+            ("flag".to_string(), vec![false, false]),
+        ]);
+        let value = combined.call_method1("evaluate", (registers,)).unwrap();
+        assert!(bool::extract_bound(&value).unwrap());
+        let missing: HashMap<String, Vec<bool>> = HashMap::new();
+        assert!(combined.call_method1("evaluate", (missing,)).is_err());
+
+        let involved: HashSet<(String, usize)> = combined
+            .call_method0("involved_bits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            involved,
+            HashSet::from([("ro".to_string(), 0), ("flag".to_string(), 1)])
+        );
+
+        let json = combined.call_method0("to_json").unwrap();
+        let deserialized = expression_type.call_method1("from_json", (json,)).unwrap();
+        assert!(bool::extract_bound(
+            &deserialized
+                .call_method1("__eq__", (combined.clone(),))
+                .unwrap()
+        )
+        .unwrap());
+        assert!(expression_type
+            .call_method1("from_json", ("not json",))
+            .is_err());
+        assert!(expression_type
+            .call_method1(
+                "logical_or",
+                (vec![ro.clone(), "ro".into_pyobject(py).unwrap().into_any()],)
+            )
+            .is_err());
+    })
+}
+
+/// Test PragmaIfElse, PragmaWhileLoop and PragmaSetBit new() functions
+#[test]
+fn test_pyo3_new_control_flow() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let expression = Py::new(
+            py,
+            BooleanExpressionWrapper {
+                internal: condition(),
+            },
+        )
+        .unwrap();
+
+        let operation = py.get_type::<PragmaIfElseWrapper>();
+        let binding = operation
+            .call1((expression.clone_ref(py), new_circuit(py), new_circuit(py)))
+            .unwrap();
+        let input_definition = Operation::from(PragmaIfElse::new(
+            condition(),
+            Circuit::new(),
+            Circuit::new(),
+        ));
+        let copy_param = convert_operation_to_pyobject(input_definition, py).unwrap();
+        assert!(
+            bool::extract_bound(&binding.call_method1("__eq__", (copy_param,)).unwrap()).unwrap()
+        );
+        let condition_py = binding.call_method0("condition").unwrap();
+        assert_eq!(
+            condition_py
+                .extract::<BooleanExpressionWrapper>()
+                .unwrap()
+                .internal,
+            condition()
+        );
+        assert!(operation
+            .call1((0, new_circuit(py), new_circuit(py)))
+            .is_err());
+
+        let operation = py.get_type::<PragmaWhileLoopWrapper>();
+        let binding = operation
+            .call1((expression.clone_ref(py), new_circuit(py), 5))
+            .unwrap();
+        let new_op = binding.extract::<PragmaWhileLoopWrapper>().unwrap();
+        assert_eq!(
+            new_op.internal,
+            PragmaWhileLoop::new(condition(), Circuit::new(), 5)
+        );
+        let max_iterations: usize = binding
+            .call_method0("max_iterations")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(max_iterations, 5);
+
+        let operation = py.get_type::<PragmaSetBitWrapper>();
+        let binding = operation.call1(("out", 1, expression)).unwrap();
+        let new_op = binding.extract::<PragmaSetBitWrapper>().unwrap();
+        assert_eq!(
+            new_op.internal,
+            PragmaSetBit::new("out".to_string(), 1, condition())
+        );
+        let involved: HashSet<usize> = binding
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(involved.is_empty());
+    })
+}
+
+/// Test classical register arithmetic PRAGMA new() functions
+#[test]
+fn test_pyo3_new_classical_arithmetic() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type::<PragmaBitwiseWrapper>();
+        for (name, operator) in [
+            ("AND", BitwiseOperator::And),
+            ("OR", BitwiseOperator::Or),
+            ("XOR", BitwiseOperator::Xor),
+        ] {
+            let binding = operation
+                .call1((name, "a", 0, "b", 1, "out", 2, 3))
+                .unwrap();
+            let new_op = binding.extract::<PragmaBitwiseWrapper>().unwrap();
+            assert_eq!(
+                new_op.internal,
+                PragmaBitwise::new(
+                    operator,
+                    "a".to_string(),
+                    0,
+                    "b".to_string(),
+                    1,
+                    "out".to_string(),
+                    2,
+                    3
+                )
+            );
+            let operator_py: String = binding.call_method0("operator").unwrap().extract().unwrap();
+            assert_eq!(operator_py, name);
+            let length: usize = binding.call_method0("length").unwrap().extract().unwrap();
+            assert_eq!(length, 3);
+        }
+        assert!(operation
+            .call1(("NAND", "a", 0, "b", 1, "out", 2, 3))
+            .is_err());
+        assert!(operation.call1(("AND", "a", 0, "b", 1, "out", 2)).is_err());
+
+        let operation = py.get_type::<PragmaParityWrapper>();
+        let binding = operation.call1(("ro", vec![0, 2], "out", 1)).unwrap();
+        let new_op = binding.extract::<PragmaParityWrapper>().unwrap();
+        assert_eq!(
+            new_op.internal,
+            PragmaParity::new("ro".to_string(), vec![0, 2], "out".to_string(), 1)
+        );
+        let indices: Vec<usize> = binding.call_method0("indices").unwrap().extract().unwrap();
+        assert_eq!(indices, vec![0, 2]);
+
+        let operation = py.get_type::<PragmaSetFloatWrapper>();
+        let variables: HashMap<String, (String, usize)> =
+            HashMap::from([("x".to_string(), ("angles".to_string(), 1))]);
+        let binding = operation
+            .call1(("angles", 0, "2 * x", variables.clone()))
+            .unwrap();
+        let new_op = binding.extract::<PragmaSetFloatWrapper>().unwrap();
+        assert_eq!(new_op.internal, set_float());
+        let variables_py: HashMap<String, (String, usize)> = binding
+            .call_method0("variables")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(variables_py, variables);
+        let involved: HashSet<usize> = binding
+            .call_method0("involved_qubits")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(involved.is_empty());
+    })
+}
+
+/// Test free parameters in the expression of PragmaSetFloat
+#[test]
+fn test_pyo3_set_float_free_parameters() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type::<PragmaSetFloatWrapper>();
+        let variables: HashMap<String, (String, usize)> =
+            HashMap::from([("x".to_string(), ("angles".to_string(), 1))]);
+        let binding = operation
+            .call1(("angles", 0, "theta * x", variables.clone()))
+            .unwrap();
+        let parametrized: bool = binding
+            .call_method0("is_parametrized")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(parametrized);
+
+        let substitution: HashMap<String, f64> = HashMap::from([("theta".to_string(), 2.0)]);
+        let substituted = binding
+            .call_method1("substitute_parameters", (substitution,))
+            .unwrap();
+        let new_op = substituted.extract::<PragmaSetFloatWrapper>().unwrap();
+        assert_eq!(
+            new_op.internal.expression(),
+            &CalculatorFloat::from("2.0 * x")
+        );
+        let parametrized: bool = substituted
+            .call_method0("is_parametrized")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(!parametrized);
+    })
+}
+
 /// Test PragmaControlledCircuit new() function
 #[test]
 fn test_pyo3_new_controlled_circuit() {
@@ -11,6 +11,7 @@
 // limitations under the License.
 
 use num_complex::Complex64;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use qoqo::measurements::{
     CheatedInputWrapper, CheatedPauliZProductInputWrapper, CheatedPauliZProductWrapper,
@@ -27,6 +28,7 @@ use roqoqo::measurements::{
 use roqoqo::operations::Operation;
 use roqoqo::operations::*;
 use roqoqo::{Circuit, QuantumProgram, ROQOQO_VERSION};
+use std::collections::{HashMap, HashSet};
 
 #[pyclass(name = "TestBackend", module = "qoqo")]
 #[derive(Debug, Clone, Copy)]
@@ -151,6 +153,152 @@ fn test_new_run_br() {
     })
 }
 
+/// Test symbols and validate_input_parameter_names functions of QuantumProgram
+#[test]
+fn test_symbols_validate_input_parameter_names() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circ1 = CircuitWrapper::new();
+        circ1.internal += RotateX::new(0, "theta".into());
+        let cr_type = py.get_type::<ClassicalRegisterWrapper>();
+        let binding = cr_type
+            .call1((Some(CircuitWrapper::new()), vec![circ1]))
+            .unwrap();
+        let measurement = binding.downcast::<ClassicalRegisterWrapper>().unwrap();
+        let symbols: HashSet<String> = measurement
+            .call_method0("symbols")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(symbols, HashSet::from(["theta".to_string()]));
+
+        let program_type = py.get_type::<QuantumProgramWrapper>();
+        let binding = program_type
+            .call1((measurement, vec!["theta".to_string()]))
+            .unwrap();
+        let program = binding.downcast::<QuantumProgramWrapper>().unwrap();
+        let symbols: HashSet<String> = program.call_method0("symbols").unwrap().extract().unwrap();
+        assert_eq!(symbols, HashSet::from(["theta".to_string()]));
+        assert!(program
+            .call_method0("validate_input_parameter_names")
+            .is_ok());
+
+        let binding = program_type
+            .call1((measurement, vec!["theta".to_string(), "phi".to_string()]))
+            .unwrap();
+        let program = binding.downcast::<QuantumProgramWrapper>().unwrap();
+        let error = program.call_method0("validate_input_parameter_names");
+        assert!(error.is_err());
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+    })
+}
+
+/// Test bind_parameters function of QuantumProgram
+#[test]
+fn test_bind_parameters() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circ1 = CircuitWrapper::new();
+        circ1.internal += RotateX::new(0, "calibration * theta".into());
+        let cr_type = py.get_type::<ClassicalRegisterWrapper>();
+        let binding = cr_type
+            .call1((None::<CircuitWrapper>, vec![circ1]))
+            .unwrap();
+        let measurement = binding.downcast::<ClassicalRegisterWrapper>().unwrap();
+
+        let program_type = py.get_type::<QuantumProgramWrapper>();
+        let binding = program_type
+            .call1((
+                measurement,
+                vec!["calibration".to_string(), "theta".to_string()],
+            ))
+            .unwrap();
+        let program = binding.downcast::<QuantumProgramWrapper>().unwrap();
+
+        let values: HashMap<String, f64> = HashMap::from([("calibration".to_string(), 2.0)]);
+        let bound = program
+            .call_method1("bind_parameters", (values.clone(),))
+            .unwrap()
+            .extract::<QuantumProgramWrapper>()
+            .unwrap();
+        let mut circ1 = Circuit::new();
+        circ1 += RotateX::new(0, "2.0 * theta".into());
+        let expected = QuantumProgram::ClassicalRegister {
+            measurement: ClassicalRegister {
+                constant_circuit: None,
+                circuits: vec![circ1.clone()],
+            },
+            input_parameter_names: vec!["theta".to_string()],
+        };
+        assert_eq!(bound.internal, expected);
+
+        let bound_measurement = measurement
+            .call_method1("bind_parameters", (values,))
+            .unwrap()
+            .extract::<ClassicalRegisterWrapper>()
+            .unwrap();
+        assert_eq!(bound_measurement.internal.circuits, vec![circ1]);
+    })
+}
+
+/// Test rename_parameters and reparametrize functions of QuantumProgram
+#[test]
+fn test_rename_parameters_reparametrize() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let mut circ1 = CircuitWrapper::new();
+        circ1.internal += RotateX::new(0, "theta".into());
+        let cr_type = py.get_type::<ClassicalRegisterWrapper>();
+        let binding = cr_type
+            .call1((None::<CircuitWrapper>, vec![circ1]))
+            .unwrap();
+        let measurement = binding.downcast::<ClassicalRegisterWrapper>().unwrap();
+
+        let program_type = py.get_type::<QuantumProgramWrapper>();
+        let binding = program_type
+            .call1((measurement, vec!["theta".to_string()]))
+            .unwrap();
+        let program = binding.downcast::<QuantumProgramWrapper>().unwrap();
+
+        let mapping: HashMap<String, String> =
+            HashMap::from([("theta".to_string(), "alpha".to_string())]);
+        let renamed = program
+            .call_method1("rename_parameters", (mapping.clone(),))
+            .unwrap()
+            .extract::<QuantumProgramWrapper>()
+            .unwrap();
+        let mut circ1 = Circuit::new();
+        circ1 += RotateX::new(0, "alpha".into());
+        let expected = QuantumProgram::ClassicalRegister {
+            measurement: ClassicalRegister {
+                constant_circuit: None,
+                circuits: vec![circ1.clone()],
+            },
+            input_parameter_names: vec!["alpha".to_string()],
+        };
+        assert_eq!(renamed.internal, expected);
+
+        let renamed_measurement = measurement
+            .call_method1("rename_parameters", (mapping,))
+            .unwrap()
+            .extract::<ClassicalRegisterWrapper>()
+            .unwrap();
+        assert_eq!(renamed_measurement.internal.circuits, vec![circ1]);
+
+        let reparametrized = program
+            .call_method1("reparametrize", ("first_",))
+            .unwrap()
+            .extract::<QuantumProgramWrapper>()
+            .unwrap();
+        assert_eq!(
+            reparametrized.internal.input_parameter_names(),
+            &["first_theta".to_string()]
+        );
+        let error = program.call_method1("reparametrize", ("first ",));
+        assert!(error.unwrap_err().is_instance_of::<PyValueError>(py));
+    })
+}
+
 /// Test new and run functions of QuantumProgram with all CheatedPauliZProduct measurement input
 #[test]
 fn test_new_run_cheated_br() {
@@ -73,11 +73,16 @@ fn operate_qubits_enum(de: DataEnum, ident: Ident) -> TokenStream {
             }
         }
     });
-    let is_parametrized_quotes = variants_with_type.map(|(vident, _, _)| {
+    let is_parametrized_quotes = variants_with_type.clone().map(|(vident, _, _)| {
         quote! {
             &#ident::#vident(ref inner) => {Operate::is_parametrized(&(*inner))},
         }
     });
+    let symbols_quotes = variants_with_type.map(|(vident, _, _)| {
+        quote! {
+            &#ident::#vident(ref inner) => {Operate::symbols(&(*inner))},
+        }
+    });
     let qtags = quote! {
         /// Returns tags classifying the type of the Operation.
         fn tags(&self) -> &'static [&'static str] {
@@ -105,6 +110,15 @@ fn operate_qubits_enum(de: DataEnum, ident: Ident) -> TokenStream {
             }
         }
     };
+    let qsymbols = quote! {
+        /// Returns the names of the symbols in the symbolic parameters of the Operation.
+        fn symbols(&self) -> std::collections::HashSet<String> {
+            match self{
+                #(#symbols_quotes)*
+                _ => panic!("Unexpectedly cannot match variant")
+            }
+        }
+    };
     quote! {
         #[automatically_derived]
         #[cfg_attr(feature = "dynamic", typetag::serde)]
@@ -113,6 +127,7 @@ fn operate_qubits_enum(de: DataEnum, ident: Ident) -> TokenStream {
             #qtags
             #qhqslang
             #qisparametrized
+            #qsymbols
         }
         #(#from_quotes)*
         #(#try_from_quotes)*
@@ -176,7 +191,76 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
             }
         });
 
+    let calculator_float_vec_fields = fields_with_type
+        .clone()
+        .filter(|(_, _, ty)| quote!(#ty).to_string().replace(' ', "") == "Vec<CalculatorFloat>")
+        .map(|(id, _, _)| {
+            quote! {
+                self.#id.iter().any(|x| !x.is_float())
+            }
+        });
+
+    let calculator_float_symbols = fields_with_type
+        .clone()
+        .filter(|(_, _, ty)| match &ty {
+            Type::Path(TypePath { path: p, .. }) => match p.get_ident() {
+                None => false,
+                Some(id) => *id == "CalculatorFloat",
+            },
+            _ => false,
+        })
+        .map(|(id, _, _)| {
+            quote! {
+                symbols.extend(crate::operations::symbols_in_parameter(&self.#id));
+            }
+        });
+    let calculator_float_vec_symbols = fields_with_type
+        .clone()
+        .filter(|(_, _, ty)| quote!(#ty).to_string().replace(' ', "") == "Vec<CalculatorFloat>")
+        .map(|(id, _, _)| {
+            quote! {
+                for parameter in self.#id.iter() {
+                    symbols.extend(crate::operations::symbols_in_parameter(parameter));
+                }
+            }
+        });
+    let circuit_symbols = fields_with_type
+        .clone()
+        .filter(|(_, type_string, _)| type_string == &Some("Circuit".to_string()))
+        .map(|(id, _, _)| {
+            quote! {
+                symbols.extend(self.#id.collect_symbols());
+            }
+        });
+    let circuit_symbols2 = fields_with_type
+        .clone()
+        .filter(|(_, type_string, _)| type_string == &Some("Option<Circuit>".to_string()))
+        .map(|(id, _, _)| {
+            quote! {
+                if let Some(x) = self.#id.as_ref() {
+                    symbols.extend(x.collect_symbols());
+                }
+            }
+        });
+    let struqture_symbols = fields_with_type
+        .clone()
+        .filter(|(_, type_string, _)| type_string == &Some("PauliHamiltonian".to_string()))
+        .map(|(id, _, _)| {
+            quote! {
+                for value in self.#id.values() {
+                    symbols.extend(crate::operations::symbols_in_parameter(value));
+                }
+            }
+        });
+    let symbols_fields: Vec<TokenStream> = calculator_float_symbols
+        .chain(calculator_float_vec_symbols)
+        .chain(circuit_symbols)
+        .chain(circuit_symbols2)
+        .chain(struqture_symbols)
+        .collect();
+
     let is_parametrized_fields = if calculator_float_fields.clone().next_back().is_none()
+        && calculator_float_vec_fields.clone().next_back().is_none()
         && circuit_fields.clone().next_back().is_none()
         && circuit_fields2.clone().next_back().is_none()
         && struqture_fields.clone().next_back().is_none()
@@ -184,6 +268,7 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
         vec![quote!(false)]
     } else {
         calculator_float_fields
+            .chain(calculator_float_vec_fields)
             .chain(circuit_fields)
             .chain(circuit_fields2)
             .chain(struqture_fields)
@@ -214,6 +299,7 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
 
             #[doc = #msg]
             #[inline]
+            #[allow(clippy::too_many_arguments)]
             pub fn new(#(#input_arguments),*) -> Self{
                 Self{#(#arguments),*}
             }
@@ -229,6 +315,14 @@ fn operate_qubits_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                     // Leading false is necessary for constant operations that are never parametrized
                     (#(#is_parametrized_fields)||*)
             }
+            /// Returns the names of the symbols in the symbolic parameters of the Operation.
+            #[inline]
+            #[allow(unused_mut)]
+            fn symbols(&self) -> std::collections::HashSet<String> {
+                let mut symbols = std::collections::HashSet::new();
+                #(#symbols_fields)*
+                symbols
+            }
             /// Returns tags classifying the type of the Operation.
             #[inline]
             fn tags(&self) -> &'static [&'static str]{
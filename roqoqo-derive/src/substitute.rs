@@ -34,6 +34,16 @@ fn substitute_enum(de: DataEnum, ident: Ident) -> TokenStream {
             &#ident::#vident(ref inner) => {Ok(#ident::#vident(Substitute::substitute_parameters(&(*inner), calculator)?))},
         }
     });
+    let bind_quotes = variants_with_type.clone().map(|(vident, _ ,_)| {
+        quote! {
+            &#ident::#vident(ref inner) => {Ok(#ident::#vident(Substitute::bind_parameters(&(*inner), values)?))},
+        }
+    });
+    let rename_quotes = variants_with_type.clone().map(|(vident, _ ,_)| {
+        quote! {
+            &#ident::#vident(ref inner) => {Ok(#ident::#vident(Substitute::rename_parameters(&(*inner), mapping)?))},
+        }
+    });
     let remap_quotes = variants_with_type.map(|(vident, _ ,_)| {
         quote! {
             &#ident::#vident(ref inner) => {Ok(#ident::#vident(Substitute::remap_qubits(&(*inner), mapping)?))},
@@ -58,12 +68,32 @@ fn substitute_enum(de: DataEnum, ident: Ident) -> TokenStream {
             }
         }
     };
+    let qbind = quote! {
+        /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+        fn bind_parameters(&self, values: &std::collections::HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+            match self{
+                #(#bind_quotes)*
+                _ => panic!("Unexpectedly cannot match variant")
+            }
+        }
+    };
+    let qrename = quote! {
+        /// Renames the given symbols in clone of the operation.
+        fn rename_parameters(&self, mapping: &std::collections::HashMap<String, String>) -> Result<Self, RoqoqoError> {
+            match self{
+                #(#rename_quotes)*
+                _ => panic!("Unexpectedly cannot match variant")
+            }
+        }
+    };
     quote! {
         /// Implements [Substitute] trait allowing to replace symbolic parameters and to perform qubit mappings.
         #[automatically_derived]
         impl Substitute for #ident{
             #qsubstitute
             #qremap
+            #qbind
+            #qrename
         }
     }
 }
@@ -84,6 +114,30 @@ fn substitute_struct(ds: DataStruct, ident: Ident) -> TokenStream {
             },
             _ => quote! {(self).#id.clone()},
         });
+    let bind_quote = fields_with_type
+        .clone()
+        .map(|(id, type_string, _)| match type_string {
+            Some(s) => match s.as_str() {
+                "CalculatorFloat" => {
+                    quote! {crate::operations::bind_parameter(&(self).#id, values)?}
+                }
+                "Circuit" => quote! {(self).#id.bind_parameters(values)?},
+                _ => quote! {(self).#id.clone()},
+            },
+            _ => quote! {(self).#id.clone()},
+        });
+    let rename_quote = fields_with_type
+        .clone()
+        .map(|(id, type_string, _)| match type_string {
+            Some(s) => match s.as_str() {
+                "CalculatorFloat" => {
+                    quote! {crate::operations::rename_parameter(&(self).#id, mapping)?}
+                }
+                "Circuit" => quote! {(self).#id.rename_parameters(mapping)?},
+                _ => quote! {(self).#id.clone()},
+            },
+            _ => quote! {(self).#id.clone()},
+        });
     let mut contains_qubits = false;
     let remap_quote = fields_with_type
         .clone()
@@ -126,6 +180,14 @@ fn substitute_struct(ds: DataStruct, ident: Ident) -> TokenStream {
                 #new_qubits_quote
                 Ok(Self::new(#(#remap_quote),*))
             }
+            /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+            fn bind_parameters(&self, values: &std::collections::HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+                Ok(Self::new(#(#bind_quote),*))
+            }
+            /// Renames the given symbols in clone of the operation.
+            fn rename_parameters(&self, mapping: &std::collections::HashMap<String, String>) -> Result<Self, RoqoqoError> {
+                Ok(Self::new(#(#rename_quote),*))
+            }
         }
     }
 }
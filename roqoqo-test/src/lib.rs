@@ -16,6 +16,11 @@
 //! operations should be tested.
 //! This crate provides helper functions that can be used to test the implementation
 //! of the interface of the
+//!
+//! It also provides generators for single- and two-qubit Clifford randomized benchmarking
+//! measurements and a fit of their results to an error per Clifford.
 
 mod stochastic_gate_test;
 pub use stochastic_gate_test::*;
+mod randomized_benchmarking;
+pub use randomized_benchmarking::*;
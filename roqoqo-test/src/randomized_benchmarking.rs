@@ -0,0 +1,507 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, VecDeque};
+
+use rand::distr::Uniform;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use roqoqo::measurements::ClassicalRegister;
+use roqoqo::operations::*;
+use roqoqo::registers::BitOutputRegister;
+use roqoqo::simulators::CliffordTableau;
+use roqoqo::{Circuit, RoqoqoError};
+
+/// Prefix of the readout registers written by randomized benchmarking circuits.
+///
+/// The register for sequences of length `m` is called `rb_length_m`.
+pub const RB_REGISTER_PREFIX: &str = "rb_length_";
+
+/// The Clifford group on one or two qubits.
+///
+/// Every element is stored as a shortest sequence of Hadamard, SGate and CNOT gates
+/// acting on the qubits `0..number_qubits`, found by a breadth-first search over the group.
+/// The elements are identified by their [roqoqo::simulators::CliffordTableau],
+/// so the group contains 24 elements for one qubit and 11520 elements for two qubits.
+#[derive(Debug, Clone)]
+pub struct CliffordGroup {
+    number_qubits: usize,
+    elements: Vec<Vec<Operation>>,
+    lookup: HashMap<CliffordTableau, usize>,
+}
+
+impl CliffordGroup {
+    /// Creates the Clifford group on one or two qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_qubits` - The number of qubits (1 or 2).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The Clifford group.
+    /// * `Err(RoqoqoError::GenericError)` - The number of qubits is not supported.
+    pub fn new(number_qubits: usize) -> Result<Self, RoqoqoError> {
+        if !(1..=2).contains(&number_qubits) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Clifford groups are only supported for one or two qubits, got {number_qubits}"
+                ),
+            });
+        }
+        let mut generators: Vec<Operation> = Vec::new();
+        for qubit in 0..number_qubits {
+            generators.push(Hadamard::new(qubit).into());
+            generators.push(SGate::new(qubit).into());
+        }
+        if number_qubits == 2 {
+            generators.push(CNOT::new(0, 1).into());
+        }
+
+        let identity = CliffordTableau::new(number_qubits);
+        let mut elements: Vec<Vec<Operation>> = vec![Vec::new()];
+        let mut tableaus: Vec<CliffordTableau> = vec![identity.clone()];
+        let mut lookup: HashMap<CliffordTableau, usize> = HashMap::new();
+        lookup.insert(identity, 0);
+        let mut queue: VecDeque<usize> = VecDeque::from([0]);
+        while let Some(index) = queue.pop_front() {
+            for generator in generators.iter() {
+                let mut tableau = tableaus[index].clone();
+                tableau
+                    .apply_gate(generator)
+                    .map_err(|err| RoqoqoError::GenericError {
+                        msg: format!("{err}"),
+                    })?;
+                if !lookup.contains_key(&tableau) {
+                    let mut word = elements[index].clone();
+                    word.push(generator.clone());
+                    lookup.insert(tableau.clone(), elements.len());
+                    queue.push_back(elements.len());
+                    elements.push(word);
+                    tableaus.push(tableau);
+                }
+            }
+        }
+        Ok(Self {
+            number_qubits,
+            elements,
+            lookup,
+        })
+    }
+
+    /// Returns the number of qubits the group acts on.
+    pub fn number_qubits(&self) -> usize {
+        self.number_qubits
+    }
+
+    /// Returns the number of elements of the group.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true when the group has no elements (never the case for a constructed group).
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the gate sequence of a group element.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&[Operation]>` - The gates of the element acting on qubits `0..number_qubits`.
+    pub fn element(&self, index: usize) -> Option<&[Operation]> {
+        self.elements.get(index).map(|word| word.as_slice())
+    }
+
+    /// Returns the index of the group element implementing a sequence of Clifford gates.
+    ///
+    /// # Arguments
+    ///
+    /// * `gates` - The Clifford gates acting on qubits `0..number_qubits`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The index of the element.
+    /// * `Err(RoqoqoError::GenericError)` - A gate is not a Clifford gate on the qubits of the group.
+    pub fn index_of(&self, gates: &[Operation]) -> Result<usize, RoqoqoError> {
+        let mut tableau = CliffordTableau::new(self.number_qubits);
+        for gate in gates {
+            tableau
+                .apply_gate(gate)
+                .map_err(|err| RoqoqoError::GenericError {
+                    msg: format!("{err}"),
+                })?;
+        }
+        self.lookup
+            .get(&tableau)
+            .copied()
+            .ok_or(RoqoqoError::GenericError {
+                msg: "Gates do not form an element of the Clifford group".to_string(),
+            })
+    }
+
+    /// Returns the index of the inverse of a group element.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The index of the inverse element.
+    /// * `Err(RoqoqoError::GenericError)` - The index is out of range.
+    pub fn inverse(&self, index: usize) -> Result<usize, RoqoqoError> {
+        let word = self.element(index).ok_or(RoqoqoError::GenericError {
+            msg: format!(
+                "Index {index} exceeds size {} of Clifford group",
+                self.len()
+            ),
+        })?;
+        self.index_of(&inverse_word(word))
+    }
+}
+
+// Inverts a sequence of Hadamard, SGate and CNOT gates
+fn inverse_word(word: &[Operation]) -> Vec<Operation> {
+    let mut inverse: Vec<Operation> = Vec::with_capacity(3 * word.len());
+    for gate in word.iter().rev() {
+        match gate {
+            Operation::SGate(_) => inverse.extend(vec![gate.clone(); 3]),
+            _ => inverse.push(gate.clone()),
+        }
+    }
+    inverse
+}
+
+// Maps the benchmarked qubits to 0..n and completes the mapping to a permutation
+fn local_mapping(qubits: &[usize]) -> HashMap<usize, usize> {
+    let mut mapping: HashMap<usize, usize> = qubits
+        .iter()
+        .enumerate()
+        .map(|(local, qubit)| (*qubit, local))
+        .collect();
+    let number_qubits = qubits.len();
+    let free_values: Vec<usize> = qubits
+        .iter()
+        .filter(|qubit| **qubit >= number_qubits)
+        .copied()
+        .collect();
+    let free_keys = (0..number_qubits).filter(|key| !qubits.contains(key));
+    for (key, value) in free_keys.zip(free_values) {
+        mapping.insert(key, value);
+    }
+    mapping
+}
+
+/// Creates a randomized benchmarking sequence of Clifford gates with the exact recovery gate.
+///
+/// The sequence consists of `sequence_length` random elements of the Clifford group,
+/// each optionally followed by the interleaved gate, and a final recovery Clifford that
+/// inverts the whole sequence. Without noise the sequence implements the identity.
+///
+/// # Arguments
+///
+/// * `group` - The Clifford group the random elements are drawn from.
+/// * `qubits` - The qubits the sequence acts on (one per qubit of the group).
+/// * `sequence_length` - The number of random Clifford gates.
+/// * `interleaved_gate` - Optional Clifford gate acting on `qubits` inserted after every random Clifford.
+/// * `seed` - Seed for the random number generator.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The benchmarking sequence.
+/// * `Err(RoqoqoError)` - The qubits are not distinct, do not match the group or the interleaved gate is not a Clifford gate on the qubits.
+pub fn randomized_benchmarking_sequence(
+    group: &CliffordGroup,
+    qubits: &[usize],
+    sequence_length: usize,
+    interleaved_gate: Option<&Operation>,
+    seed: u64,
+) -> Result<Circuit, RoqoqoError> {
+    if qubits.len() != group.number_qubits() {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "Number of qubits {} does not match Clifford group on {} qubits",
+                qubits.len(),
+                group.number_qubits()
+            ),
+        });
+    }
+    if (1..qubits.len()).any(|index| qubits[..index].contains(&qubits[index])) {
+        return Err(RoqoqoError::GenericError {
+            msg: format!("Benchmarked qubits {qubits:?} are not distinct"),
+        });
+    }
+    let to_local = local_mapping(qubits);
+    let to_global: HashMap<usize, usize> = to_local.iter().map(|(k, v)| (*v, *k)).collect();
+    let interleaved_word: Option<&[Operation]> = match interleaved_gate {
+        Some(gate) => {
+            let local_gate = gate.remap_qubits(&to_local)?;
+            let index = group.index_of(&[local_gate])?;
+            group.element(index)
+        }
+        None => None,
+    };
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let element_distribution =
+        Uniform::new(0, group.len()).map_err(|err| RoqoqoError::GenericError {
+            msg: format!("{err}"),
+        })?;
+    let mut circuit = Circuit::new();
+    let mut total_word: Vec<Operation> = Vec::new();
+    for _ in 0..sequence_length {
+        let element = group
+            .element(rng.sample(element_distribution))
+            .unwrap_or_default();
+        total_word.extend_from_slice(element);
+        for gate in element {
+            circuit.add_operation(gate.remap_qubits(&to_global)?);
+        }
+        if let (Some(word), Some(gate)) = (interleaved_word, interleaved_gate) {
+            total_word.extend_from_slice(word);
+            circuit.add_operation(gate.clone());
+        }
+    }
+    let recovery = group.index_of(&inverse_word(&total_word))?;
+    for gate in group.element(recovery).unwrap_or_default() {
+        circuit.add_operation(gate.remap_qubits(&to_global)?);
+    }
+    Ok(circuit)
+}
+
+/// Creates a randomized benchmarking measurement.
+///
+/// For every sequence length `number_sequences` random sequences are created with
+/// [randomized_benchmarking_sequence]. Each circuit measures the qubits `number_measurements` times
+/// into the bit register `rb_length_{sequence_length}` (see [RB_REGISTER_PREFIX]),
+/// so that the registers of all sequences of the same length are combined when the
+/// measurement is run with a backend.
+/// The seeds of the single sequences are derived from `seed`, making the measurement deterministic.
+///
+/// # Arguments
+///
+/// * `qubits` - The benchmarked qubits (one or two).
+/// * `sequence_lengths` - The numbers of random Clifford gates in the sequences.
+/// * `number_sequences` - The number of random sequences per length.
+/// * `number_measurements` - The number of projective measurements per sequence.
+/// * `interleaved_gate` - Optional Clifford gate for interleaved randomized benchmarking.
+/// * `seed` - Seed for the random number generator.
+///
+/// # Returns
+///
+/// * `Ok(ClassicalRegister)` - The randomized benchmarking measurement.
+/// * `Err(RoqoqoError)` - The sequences could not be created.
+pub fn randomized_benchmarking(
+    qubits: &[usize],
+    sequence_lengths: &[usize],
+    number_sequences: usize,
+    number_measurements: usize,
+    interleaved_gate: Option<&Operation>,
+    seed: u64,
+) -> Result<ClassicalRegister, RoqoqoError> {
+    let group = CliffordGroup::new(qubits.len())?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mapping: HashMap<usize, usize> = qubits
+        .iter()
+        .enumerate()
+        .map(|(index, qubit)| (*qubit, index))
+        .collect();
+    let mut circuits: Vec<Circuit> = Vec::with_capacity(sequence_lengths.len() * number_sequences);
+    for length in sequence_lengths {
+        let register = format!("{RB_REGISTER_PREFIX}{length}");
+        for _ in 0..number_sequences {
+            let mut circuit = Circuit::new();
+            circuit += DefinitionBit::new(register.clone(), qubits.len(), true);
+            circuit += randomized_benchmarking_sequence(
+                &group,
+                qubits,
+                *length,
+                interleaved_gate,
+                rng.random(),
+            )?;
+            circuit += PragmaRepeatedMeasurement::new(
+                register.clone(),
+                number_measurements,
+                Some(mapping.clone()),
+            );
+            circuits.push(circuit);
+        }
+    }
+    Ok(ClassicalRegister {
+        constant_circuit: None,
+        circuits,
+    })
+}
+
+/// Result of fitting randomized benchmarking data to `A p^m + B`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomizedBenchmarkingFit {
+    /// The amplitude A of the decay.
+    pub amplitude: f64,
+    /// The depolarizing parameter p.
+    pub decay: f64,
+    /// The offset B of the decay.
+    pub offset: f64,
+    /// The average error per Clifford gate `(1 - p) (d - 1) / d` with `d = 2^n`.
+    pub error_per_clifford: f64,
+}
+
+/// Returns the survival probabilities (probability to measure all qubits in |0>) per sequence length.
+///
+/// # Arguments
+///
+/// * `bit_registers` - The bit registers returned by running a [randomized_benchmarking] measurement.
+///
+/// # Returns
+///
+/// * `Vec<(usize, f64)>` - The sequence lengths and survival probabilities, sorted by length.
+pub fn survival_probabilities(
+    bit_registers: &HashMap<String, BitOutputRegister>,
+) -> Vec<(usize, f64)> {
+    let mut probabilities: Vec<(usize, f64)> = bit_registers
+        .iter()
+        .filter_map(|(name, register)| {
+            let length: usize = name.strip_prefix(RB_REGISTER_PREFIX)?.parse().ok()?;
+            if register.is_empty() {
+                return None;
+            }
+            let survived = register
+                .iter()
+                .filter(|row| row.iter().all(|bit| !bit))
+                .count();
+            Some((length, survived as f64 / register.len() as f64))
+        })
+        .collect();
+    probabilities.sort_by_key(|(length, _)| *length);
+    probabilities
+}
+
+// Linear least-squares fit of A and B for fixed p, returns (A, B, residual sum of squares)
+fn fit_linear_parameters(data: &[(usize, f64)], decay: f64) -> (f64, f64, f64) {
+    let number_points = data.len() as f64;
+    let xs: Vec<f64> = data.iter().map(|(m, _)| decay.powi(*m as i32)).collect();
+    let mean_x = xs.iter().sum::<f64>() / number_points;
+    let mean_y = data.iter().map(|(_, y)| y).sum::<f64>() / number_points;
+    let mut covariance = 0.0;
+    let mut variance = 0.0;
+    for (x, (_, y)) in xs.iter().zip(data.iter()) {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance += (x - mean_x).powi(2);
+    }
+    let amplitude = if variance > f64::EPSILON {
+        covariance / variance
+    } else {
+        0.0
+    };
+    let offset = mean_y - amplitude * mean_x;
+    let residual = xs
+        .iter()
+        .zip(data.iter())
+        .map(|(x, (_, y))| (amplitude * x + offset - y).powi(2))
+        .sum();
+    (amplitude, offset, residual)
+}
+
+/// Fits survival probabilities to the randomized benchmarking decay `A p^m + B`.
+///
+/// For fixed `p` the amplitude and offset are obtained by linear least squares,
+/// the decay `p` is optimized in `[0, 1]` by a grid search refined with a golden-section search.
+///
+/// # Arguments
+///
+/// * `survival_probabilities` - The sequence lengths and survival probabilities.
+/// * `number_qubits` - The number of benchmarked qubits.
+///
+/// # Returns
+///
+/// * `Ok(RandomizedBenchmarkingFit)` - The fitted parameters and the error per Clifford.
+/// * `Err(RoqoqoError::GenericError)` - Less than three distinct sequence lengths are provided.
+pub fn fit_randomized_benchmarking(
+    survival_probabilities: &[(usize, f64)],
+    number_qubits: usize,
+) -> Result<RandomizedBenchmarkingFit, RoqoqoError> {
+    let mut lengths: Vec<usize> = survival_probabilities.iter().map(|(m, _)| *m).collect();
+    lengths.sort_unstable();
+    lengths.dedup();
+    if lengths.len() < 3 {
+        return Err(RoqoqoError::GenericError {
+            msg: "At least three different sequence lengths are needed for the fit".to_string(),
+        });
+    }
+    let residual = |decay: f64| fit_linear_parameters(survival_probabilities, decay).2;
+    let number_grid_points = 1000;
+    let step = 1.0 / number_grid_points as f64;
+    // Scanning down from p = 1 resolves ties for flat data in favour of no decay
+    let best_grid_point = (0..=number_grid_points)
+        .rev()
+        .map(|i| i as f64 * step)
+        .min_by(|a, b| residual(*a).total_cmp(&residual(*b)))
+        .unwrap_or(1.0);
+    let golden_ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let mut lower = (best_grid_point - step).max(0.0);
+    let mut upper = (best_grid_point + step).min(1.0);
+    for _ in 0..100 {
+        let first = upper - golden_ratio * (upper - lower);
+        let second = lower + golden_ratio * (upper - lower);
+        if residual(first) < residual(second) {
+            upper = second;
+        } else {
+            lower = first;
+        }
+    }
+    let decay = (lower + upper) / 2.0;
+    let (amplitude, offset, _) = fit_linear_parameters(survival_probabilities, decay);
+    let dimension = 2_f64.powi(number_qubits as i32);
+    Ok(RandomizedBenchmarkingFit {
+        amplitude,
+        decay,
+        offset,
+        error_per_clifford: (1.0 - decay) * (dimension - 1.0) / dimension,
+    })
+}
+
+/// Returns the error of the interleaved gate from reference and interleaved randomized benchmarking.
+///
+/// The error is estimated as `(d - 1) (1 - p_interleaved / p_reference) / d` with `d = 2^n`.
+///
+/// # Arguments
+///
+/// * `reference` - The fit of the standard randomized benchmarking data.
+/// * `interleaved` - The fit of the interleaved randomized benchmarking data.
+/// * `number_qubits` - The number of benchmarked qubits.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The estimated error of the interleaved gate.
+/// * `Err(RoqoqoError::GenericError)` - The decay of the reference fit is zero or not finite.
+pub fn interleaved_gate_error(
+    reference: &RandomizedBenchmarkingFit,
+    interleaved: &RandomizedBenchmarkingFit,
+    number_qubits: usize,
+) -> Result<f64, RoqoqoError> {
+    if reference.decay == 0.0 || !reference.decay.is_finite() {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "Decay {} of the reference fit must be finite and non-zero",
+                reference.decay
+            ),
+        });
+    }
+    let dimension = 2_f64.powi(number_qubits as i32);
+    Ok((dimension - 1.0) * (1.0 - interleaved.decay / reference.decay) / dimension)
+}
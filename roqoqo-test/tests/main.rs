@@ -10,5 +10,7 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod randomized_benchmarking;
 #[cfg(test)]
 mod stochastic_gate_test;
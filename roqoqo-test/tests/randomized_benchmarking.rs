@@ -0,0 +1,132 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::operations::*;
+use roqoqo::simulators::{CliffordTableau, StabilizerBackend};
+use roqoqo_test::*;
+
+#[test]
+fn test_clifford_group() {
+    let group = CliffordGroup::new(1).unwrap();
+    assert_eq!(group.len(), 24);
+    assert!(!group.is_empty());
+    let group = CliffordGroup::new(2).unwrap();
+    assert_eq!(group.len(), 11520);
+    assert_eq!(group.number_qubits(), 2);
+    assert_eq!(group.element(0), Some([].as_slice()));
+    assert!(group.element(11520).is_none());
+
+    for index in [1, 100, 5000, 11519] {
+        let inverse = group.inverse(index).unwrap();
+        let mut gates = group.element(index).unwrap().to_vec();
+        gates.extend_from_slice(group.element(inverse).unwrap());
+        assert_eq!(group.index_of(&gates).unwrap(), 0);
+    }
+
+    assert!(CliffordGroup::new(0).is_err());
+    assert!(CliffordGroup::new(3).is_err());
+    assert!(group.index_of(&[TGate::new(0).into()]).is_err());
+    assert!(group.inverse(11520).is_err());
+}
+
+#[test]
+fn test_sequence_is_identity() {
+    let group = CliffordGroup::new(2).unwrap();
+    let interleaved: Operation = CNOT::new(5, 3).into();
+    for (seed, gate) in [(0, None), (1, None), (2, Some(&interleaved))] {
+        let circuit = randomized_benchmarking_sequence(&group, &[5, 3], 20, gate, seed).unwrap();
+        assert!(circuit.is_clifford());
+        let mut tableau = CliffordTableau::new(6);
+        for operation in circuit.iter() {
+            tableau.apply_gate(operation).unwrap();
+        }
+        assert_eq!(tableau, CliffordTableau::new(6));
+        if gate.is_some() {
+            let count = circuit.iter().filter(|op| *op == &interleaved).count();
+            assert!(count >= 20);
+        }
+    }
+}
+
+#[test]
+fn test_sequence_errors() {
+    let group = CliffordGroup::new(2).unwrap();
+    assert!(randomized_benchmarking_sequence(&group, &[0], 2, None, 0).is_err());
+    assert!(randomized_benchmarking_sequence(&group, &[1, 1], 2, None, 0).is_err());
+    let gate: Operation = TGate::new(0).into();
+    assert!(randomized_benchmarking_sequence(&group, &[0, 1], 2, Some(&gate), 0).is_err());
+    let gate: Operation = CNOT::new(0, 2).into();
+    assert!(randomized_benchmarking_sequence(&group, &[0, 1], 2, Some(&gate), 0).is_err());
+}
+
+#[test]
+fn test_measurement_is_deterministic() {
+    let first = randomized_benchmarking(&[0], &[1, 5, 10], 3, 10, None, 7).unwrap();
+    let second = randomized_benchmarking(&[0], &[1, 5, 10], 3, 10, None, 7).unwrap();
+    let other = randomized_benchmarking(&[0], &[1, 5, 10], 3, 10, None, 8).unwrap();
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+    assert_eq!(first.circuits.len(), 9);
+    assert!(first.constant_circuit.is_none());
+}
+
+#[test]
+fn test_noiseless_run() {
+    let interleaved: Operation = ControlledPauliZ::new(2, 1).into();
+    for gate in [None, Some(&interleaved)] {
+        let measurement = randomized_benchmarking(&[2, 1], &[1, 4, 8], 4, 20, gate, 3).unwrap();
+        let registers = StabilizerBackend::new(Some(11))
+            .run_measurement_registers(&measurement)
+            .unwrap()
+            .0;
+        assert_eq!(registers.len(), 3);
+        assert_eq!(registers.get("rb_length_4").unwrap().len(), 80);
+        let probabilities = survival_probabilities(&registers);
+        assert_eq!(probabilities, vec![(1, 1.0), (4, 1.0), (8, 1.0)]);
+        let fit = fit_randomized_benchmarking(&probabilities, 2).unwrap();
+        assert!(fit.error_per_clifford.abs() < 1e-6);
+        assert!((fit.amplitude + fit.offset - 1.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_fit() {
+    let (amplitude, decay, offset) = (0.7, 0.97, 0.25);
+    let data: Vec<(usize, f64)> = [1, 5, 10, 20, 50, 100]
+        .into_iter()
+        .map(|m| (m, amplitude * f64::powi(decay, m as i32) + offset))
+        .collect();
+    let fit = fit_randomized_benchmarking(&data, 2).unwrap();
+    assert!((fit.decay - decay).abs() < 1e-6);
+    assert!((fit.amplitude - amplitude).abs() < 1e-4);
+    assert!((fit.offset - offset).abs() < 1e-4);
+    assert!((fit.error_per_clifford - 0.75 * 0.03).abs() < 1e-6);
+
+    let interleaved_data: Vec<(usize, f64)> = [1, 5, 10, 20, 50, 100]
+        .into_iter()
+        .map(|m| (m, amplitude * f64::powi(decay * 0.99, m as i32) + offset))
+        .collect();
+    let interleaved = fit_randomized_benchmarking(&interleaved_data, 2).unwrap();
+    let error = interleaved_gate_error(&fit, &interleaved, 2).unwrap();
+    assert!((error - 0.75 * 0.01).abs() < 1e-6);
+
+    for reference_decay in [0.0, f64::NAN, f64::INFINITY] {
+        let reference = RandomizedBenchmarkingFit {
+            decay: reference_decay,
+            ..fit.clone()
+        };
+        assert!(interleaved_gate_error(&reference, &interleaved, 2).is_err());
+    }
+
+    assert!(fit_randomized_benchmarking(&data[..2], 2).is_err());
+}
@@ -11,7 +11,8 @@
 // limitations under the License.
 
 use crate::operations::{
-    Define, InvolveQubits, InvolvedQubits, Operate, Operation, Substitute, SupportedVersion,
+    Define, InputSymbolic, InvolveQubits, InvolvedQubits, Operate, OperateSingleQubit, Operation,
+    Substitute, SupportedVersion,
 };
 #[cfg(feature = "overrotate")]
 use crate::operations::{Rotate, Rotation};
@@ -19,7 +20,7 @@ use crate::RoqoqoError;
 use crate::RoqoqoVersion;
 #[cfg(feature = "serialize")]
 use crate::RoqoqoVersionSerializable;
-use qoqo_calculator::Calculator;
+use qoqo_calculator::{Calculator, CalculatorFloat};
 use std::collections::{HashMap, HashSet};
 #[cfg(feature = "overrotate")]
 use std::convert::TryFrom;
@@ -63,18 +64,28 @@ use std::{
 /// For Circuits the following functions are defined:
 /// * `new()`: creates an empty Circuit
 /// * `add_operation(operation)`: adds the specified operation to the Circuit
+/// * `try_add_operation(operation)`: adds the specified operation to the Circuit after checking calls of defined gates
 /// * `get(index)`: returns the operation at the specified index in the Circuit
 /// * `get_mut(index)`: returns mutable reference to the operation at the specified index in the Circuit
 /// * `iter()`: creates an iterator of the Circuit
 /// * `len()`: returns the length of the Circuit
 /// * `is_empty()`: returns a boolean of whether the Circuit contains any definitions and operations or not
+/// * `symbols()`: returns the names of the free symbols in the symbolic parameters of the Circuit
+/// * `operations_by_symbol()`: returns the indices of the operations depending on each free symbol
 /// * `involved_qubits()`: returns the qubits invovlved in the whole Circuit
 /// * `definitions()`: returns the definitions in the Circuit
 /// * `operations()`: returns the operations in the Circuit
 /// * `substitute_parameters(calculator)`: substitutes any symbolic parameters in (a copy of) the Circuit according to the specified Calculator
+/// * `bind_parameters(values)`: substitutes the specified symbols in (a copy of) the Circuit and keeps the other symbols symbolic
+/// * `rename_parameters(mapping)`: renames the specified symbols in (a copy of) the Circuit
+/// * `reparametrize(prefix)`: adds the specified prefix to the names of all symbols in (a copy of) the Circuit
 /// * `remap_qubits(mapping)`: remaps the qubits in (a copy of) the Circuit according to the specified mapping
 /// * `count_occurences(operations)`: returns the number of operations in the Circuit with the specified operation tags
 /// * `get_operation_types()`: returns a list of all of the operations in the Circuit (in hqslang)
+/// * `is_clifford()`: returns whether the Circuit can be simulated by a stabilizer simulator
+/// * `inline_definitions()`: replaces the calls of defined gates in (a copy of) the Circuit by their definitions
+/// * `draw_ascii(packed)`: draws the Circuit as an ASCII diagram
+/// * `draw_svg(packed)`: draws the Circuit as an SVG image
 /// * `from_iter(iterator)`: creates a Circuit from the items in the specified iterator
 /// * `extend(iterator)`: adds the operations in the specified iterator to the Circuit
 /// * `default()`: creates an empty Circuit
@@ -154,11 +165,24 @@ impl Circuit {
     /// # Arguments
     ///
     /// * `op` - The Operation to add to the Circuit.
+    ///
+    /// # Panics
+    ///
+    /// With the `unstable_operation_definition` feature, panics when a `CallDefinedGate`
+    /// or `GateDefinition` does not match the Circuit (see [Circuit::try_add_operation]).
     pub fn add_operation<T>(&mut self, op: T)
     where
         T: Into<Operation>,
     {
         let input: Operation = op.into();
+        if let Err(err) = self.check_defined_gates(&input) {
+            panic!("{err}")
+        }
+        self.push_operation(input);
+    }
+
+    // Adds an Operation to the definitions or operations of the Circuit without checks
+    fn push_operation(&mut self, input: Operation) {
         match &input {
             Operation::DefinitionBit(_) => self.definitions.push(input),
             Operation::DefinitionFloat(_) => {
@@ -181,6 +205,76 @@ impl Circuit {
         }
     }
 
+    /// Adds an Operation to Circuit (self) after checking that it is consistent with the Circuit.
+    ///
+    /// With the `unstable_operation_definition` feature, a `CallDefinedGate` has to match
+    /// the number of qubits and free parameters of the `GateDefinition` with the same name
+    /// in the Circuit and a GateDefinition has to match the CallDefinedGates in the Circuit calling it.
+    /// The calls in nested circuits and in the bodies of GateDefinitions are checked as well.
+    /// Calls of gates that are not defined yet are accepted, as the definition can be added later.
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The Operation to add to the Circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The Operation was added to the Circuit.
+    /// * `Err(RoqoqoError::GenericError)` - The Operation is not consistent with the Circuit and was not added.
+    pub fn try_add_operation<T>(&mut self, op: T) -> Result<(), RoqoqoError>
+    where
+        T: Into<Operation>,
+    {
+        let input: Operation = op.into();
+        self.check_defined_gates(&input)?;
+        self.push_operation(input);
+        Ok(())
+    }
+
+    // Checks that an added CallDefinedGate or GateDefinition matches the Circuit,
+    // including the calls in nested circuits and in the bodies of GateDefinitions
+    #[cfg(feature = "unstable_operation_definition")]
+    fn check_defined_gates(&self, input: &Operation) -> Result<(), RoqoqoError> {
+        let mut calls = Vec::new();
+        defined_gate_calls(input, &mut calls);
+        for call in calls {
+            for definition in self.definitions.iter() {
+                if let Operation::GateDefinition(definition) = definition {
+                    if definition.name() == call.gate_name() {
+                        crate::passes::check_call(call, definition)?;
+                    }
+                }
+            }
+        }
+        if let Operation::GateDefinition(definition) = input {
+            let mut calls = Vec::new();
+            for operation in self.iter() {
+                defined_gate_calls(operation, &mut calls);
+            }
+            for call in calls {
+                if definition.name() == call.gate_name() {
+                    crate::passes::check_call(call, definition)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Without defined gates there is nothing to check
+    #[cfg(not(feature = "unstable_operation_definition"))]
+    fn check_defined_gates(&self, _input: &Operation) -> Result<(), RoqoqoError> {
+        Ok(())
+    }
+
+    // Panics when an appended Circuit does not match the defined gates of the Circuit
+    fn check_appended_circuit(&self, other: &Circuit) {
+        for operation in other.iter() {
+            if let Err(err) = self.check_defined_gates(operation) {
+                panic!("{err}")
+            }
+        }
+    }
+
     /// Returns a reference to the element at index similar to std::Vec get function.
     ///
     /// Contrary to std::Vec get function not implemented for slices  .
@@ -240,6 +334,63 @@ impl Circuit {
             || self.definitions.iter().any(|o| o.is_parametrized())
     }
 
+    /// Returns the names of the free symbols in the symbolic parameters of the Circuit.
+    ///
+    /// Symbols set by an InputSymbolic definition and the free parameters of a GateDefinition
+    /// are bound inside the Circuit and therefore not free.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashSet<String>)` - The names of the symbols that need to be substituted.
+    /// * `Err(RoqoqoError::GenericError)` - A parametrized operation does not report its symbols.
+    pub fn symbols(&self) -> Result<HashSet<String>, RoqoqoError> {
+        check_reported_symbols(self)?;
+        Ok(self.collect_symbols())
+    }
+
+    // Collects the free symbols reported by the operations without checking that they are complete
+    pub(crate) fn collect_symbols(&self) -> HashSet<String> {
+        let mut symbols: HashSet<String> = HashSet::new();
+        for operation in self.iter() {
+            symbols.extend(operation_symbols(operation));
+        }
+        for name in self.input_symbolic_names() {
+            symbols.remove(name);
+        }
+        symbols
+    }
+
+    /// Returns the indices of the operations depending on each free symbol of the Circuit.
+    ///
+    /// The indices are the positions in [Circuit::iter], so they can be used with [Circuit::get].
+    /// Operations containing nested circuits depend on the symbols of their nested circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<String, Vec<usize>>` - The ordered indices of the operations depending on each symbol.
+    pub fn operations_by_symbol(&self) -> HashMap<String, Vec<usize>> {
+        let bound: HashSet<&String> = self.input_symbolic_names().collect();
+        let mut operations_by_symbol: HashMap<String, Vec<usize>> = HashMap::new();
+        for (index, operation) in self.iter().enumerate() {
+            for symbol in operation_symbols(operation) {
+                if !bound.contains(&symbol) {
+                    operations_by_symbol.entry(symbol).or_default().push(index);
+                }
+            }
+        }
+        operations_by_symbol
+    }
+
+    // Returns the names of the symbols set by InputSymbolic definitions
+    fn input_symbolic_names(&self) -> impl Iterator<Item = &String> {
+        self.definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Operation::InputSymbolic(input) => Some(input.name()),
+                _ => None,
+            })
+    }
+
     /// Returns the length of the Circuit.
     ///
     /// # Returns
@@ -280,6 +431,48 @@ impl Circuit {
         }
     }
 
+    /// Returns how often every qubit of the Circuit is reused after being reset.
+    ///
+    /// A qubit is reset by [crate::operations::MeasureQubitAndReset] or [crate::operations::PragmaActiveReset].
+    /// Every later operation acting on a qubit that has been reset since its last use counts as one reuse.
+    /// Operations acting on all qubits reuse all reset qubits. Nested circuits are not inspected separately.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<usize, usize>` - The number of reuses of every qubit the operations of the Circuit act on.
+    pub fn qubit_reuses(&self) -> HashMap<usize, usize> {
+        let mut reuses: HashMap<usize, usize> = HashMap::new();
+        let mut reset: HashSet<usize> = HashSet::new();
+        for op in self.operations.iter() {
+            match op.involved_qubits() {
+                InvolvedQubits::All => {
+                    for qubit in reset.drain() {
+                        *reuses.entry(qubit).or_insert(0) += 1;
+                    }
+                }
+                InvolvedQubits::None => (),
+                InvolvedQubits::Set(qubits) => {
+                    for qubit in qubits {
+                        let count = reuses.entry(qubit).or_insert(0);
+                        if reset.remove(&qubit) {
+                            *count += 1;
+                        }
+                    }
+                }
+            }
+            match op {
+                Operation::MeasureQubitAndReset(op) => {
+                    reset.insert(*op.qubit());
+                }
+                Operation::PragmaActiveReset(op) => {
+                    reset.insert(*op.qubit());
+                }
+                _ => (),
+            }
+        }
+        reuses
+    }
+
     /// Returns reference to the vector of definitions in Circuit.
     ///
     /// Definitions need to be unique.
@@ -332,6 +525,96 @@ impl Circuit {
             _roqoqo_version: RoqoqoVersion,
         })
     }
+    /// Substitutes the given symbols in a clone of Circuit and keeps the other symbols symbolic.
+    ///
+    /// Bracketed parts and function calls of the remaining symbolic expressions that no longer contain
+    /// symbols are evaluated. Symbols set by InputSymbolic definitions are substituted with the values
+    /// of the definitions, the free parameters of GateDefinitions are not substituted.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the symbols to substitute.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuit with the given symbols substituted.
+    /// * `Err(RoqoqoError)` - The substitution failed.
+    pub fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let mut values = values.clone();
+        for definition in self.definitions.iter() {
+            if let Operation::InputSymbolic(x) = definition {
+                values.insert(x.name().clone(), *x.input());
+            }
+        }
+        let mut tmp_def: Vec<Operation> = Vec::new();
+        for def in self.definitions.iter() {
+            tmp_def.push(bind_operation(def, &values)?);
+        }
+        let mut tmp_op: Vec<Operation> = Vec::new();
+        for op in self.operations.iter() {
+            tmp_op.push(bind_operation(op, &values)?);
+        }
+        Ok(Self {
+            definitions: tmp_def,
+            operations: tmp_op,
+            _roqoqo_version: RoqoqoVersion,
+        })
+    }
+
+    /// Renames the given symbols in a clone of Circuit.
+    ///
+    /// The symbols are renamed in all operations, nested circuits and GateDefinition bodies.
+    /// The names set by InputSymbolic definitions are renamed as well, the free parameters of
+    /// GateDefinitions are local to the definitions and not renamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The new names of the symbols to rename.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuit with the given symbols renamed.
+    /// * `Err(RoqoqoError)` - A new name is not a valid symbol name.
+    pub fn rename_parameters(
+        &self,
+        mapping: &HashMap<String, String>,
+    ) -> Result<Self, RoqoqoError> {
+        let mut tmp_def: Vec<Operation> = Vec::new();
+        for def in self.definitions.iter() {
+            tmp_def.push(rename_operation(def, mapping)?);
+        }
+        let mut tmp_op: Vec<Operation> = Vec::new();
+        for op in self.operations.iter() {
+            tmp_op.push(rename_operation(op, mapping)?);
+        }
+        Ok(Self {
+            definitions: tmp_def,
+            operations: tmp_op,
+            _roqoqo_version: RoqoqoVersion,
+        })
+    }
+
+    /// Prefixes the names of all symbols in a clone of Circuit.
+    ///
+    /// Renames every free symbol and every name set by an InputSymbolic definition to `{prefix}{name}`,
+    /// so that Circuits using the same symbol names can be combined without coupling their parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix added to the names of the symbols.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuit with the prefixed symbols.
+    /// * `Err(RoqoqoError)` - The prefixed names are not valid symbol names or a parametrized operation does not report its symbols.
+    pub fn reparametrize(&self, prefix: &str) -> Result<Self, RoqoqoError> {
+        let mut mapping: HashMap<String, String> = HashMap::new();
+        for name in self.symbols()?.iter().chain(self.input_symbolic_names()) {
+            mapping.insert(name.clone(), format!("{prefix}{name}"));
+        }
+        self.rename_parameters(&mapping)
+    }
+
     /// Remaps the qubits in operations in clone of Circuit.
     ///
     /// # Arguments
@@ -481,6 +764,23 @@ impl Circuit {
         Ok(return_circuit)
     }
 
+    /// Returns true when the circuit only contains Clifford operations.
+    ///
+    /// Clifford circuits can be simulated efficiently with [crate::simulators::StabilizerBackend].
+    /// Gates are classified by their action on Pauli operators, so rotations count as Clifford gates
+    /// when their angles are multiples of pi/2. Symbolic angles are evaluated when they do not depend on
+    /// free variables (e.g. `"3.141592653589793 / 2"`), otherwise the gate is not considered a Clifford gate.
+    /// Besides gates, definitions, measurements, [crate::operations::PragmaActiveReset],
+    /// pragmas without effect on the quantum state and loops or conditional operations
+    /// containing only Clifford operations are accepted.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - True if all operations of the Circuit are Clifford operations.
+    pub fn is_clifford(&self) -> bool {
+        self.iter().all(crate::simulators::is_clifford_operation)
+    }
+
     /// Returns the number of qubits in the circuit.
     ///
     /// # Returns
@@ -496,6 +796,168 @@ impl Circuit {
             .max()
             .unwrap_or_default()
     }
+
+    /// Returns a clone of the Circuit with every call of a defined gate replaced by the body of its definition.
+    ///
+    /// Calls are replaced recursively, see [crate::passes::inline_definitions].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Circuit)` - The Circuit without GateDefinitions and calls of defined gates.
+    /// * `Err(RoqoqoError)` - A call cannot be inlined or the definitions call each other cyclically.
+    #[cfg(feature = "unstable_operation_definition")]
+    pub fn inline_definitions(&self) -> Result<Self, RoqoqoError> {
+        crate::passes::inline_definitions(self)
+    }
+
+    /// Draws the Circuit as an ASCII diagram.
+    ///
+    /// Every qubit is drawn as a wire `-` and every classical register defined in the Circuit as a wire `=`.
+    /// Gates are drawn as labelled boxes, controls as `*` connected to their targets with `|`.
+    /// Measurements are connected to the entries of the classical registers they write to.
+    /// The Circuits of [crate::operations::PragmaLoop], [crate::operations::PragmaConditional],
+    /// [crate::operations::PragmaIfElse], [crate::operations::PragmaWhileLoop] and
+    /// [crate::operations::PragmaControlledCircuit] are drawn inside a labelled frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `packed` - Whether operations acting on different qubits are drawn in the same column,
+    ///   following the parallel blocks of `CircuitDag`. Without the `circuitdag` feature
+    ///   every operation is drawn in its own column.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The ASCII diagram of the Circuit.
+    pub fn draw_ascii(&self, packed: bool) -> String {
+        crate::circuit_drawer::draw_ascii(self, packed)
+    }
+
+    /// Draws the Circuit as an SVG image.
+    ///
+    /// The image uses the same layout as [Circuit::draw_ascii].
+    ///
+    /// # Arguments
+    ///
+    /// * `packed` - Whether operations acting on different qubits are drawn in the same column.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The SVG document of the Circuit.
+    pub fn draw_svg(&self, packed: bool) -> String {
+        crate::circuit_drawer::draw_svg(self, packed)
+    }
+}
+
+// Returns the free symbols of an operation, the free parameters of a GateDefinition are bound
+fn operation_symbols(operation: &Operation) -> HashSet<String> {
+    #[cfg(feature = "unstable_operation_definition")]
+    if let Operation::GateDefinition(definition) = operation {
+        let mut symbols = definition.circuit().collect_symbols();
+        for parameter in definition.free_parameters() {
+            symbols.remove(parameter);
+        }
+        return symbols;
+    }
+    operation.symbols()
+}
+
+// Checks that every parametrized operation reports the symbols it depends on,
+// operations with nested circuits are checked through the operations of the nested circuits
+// and the schedule symbols of ApplyTimeDependentPauliHamiltonian are bound by the operation itself
+fn check_reported_symbols(circuit: &Circuit) -> Result<(), RoqoqoError> {
+    for operation in circuit.iter() {
+        #[cfg(feature = "unstable_analog_operations")]
+        if let Operation::ApplyTimeDependentPauliHamiltonian(_) = operation {
+            continue;
+        }
+        let nested = crate::passes::nested_circuits(operation);
+        if nested.is_empty() {
+            if operation.is_parametrized() && operation.symbols().is_empty() {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Operation {} is parametrized but does not report its symbols",
+                        operation.hqslang()
+                    ),
+                });
+            }
+        } else {
+            for nested_circuit in nested {
+                check_reported_symbols(nested_circuit)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Collects the calls of defined gates in an operation, its nested circuits and the bodies of GateDefinitions,
+// calls of gates defined by a nested circuit itself are resolved there and skipped
+#[cfg(feature = "unstable_operation_definition")]
+fn defined_gate_calls<'a>(
+    operation: &'a Operation,
+    calls: &mut Vec<&'a crate::operations::CallDefinedGate>,
+) {
+    if let Operation::CallDefinedGate(call) = operation {
+        calls.push(call);
+    }
+    for circuit in crate::passes::nested_circuits(operation) {
+        let mut nested = Vec::new();
+        for inner in circuit.iter() {
+            defined_gate_calls(inner, &mut nested);
+        }
+        calls.extend(nested.into_iter().filter(|call| {
+            !circuit.definitions().iter().any(|definition| {
+                matches!(definition, Operation::GateDefinition(definition) if definition.name() == call.gate_name())
+            })
+        }));
+    }
+}
+
+// Binds the symbols of an operation, the free parameters of a GateDefinition are not bound
+fn bind_operation(
+    operation: &Operation,
+    values: &HashMap<String, f64>,
+) -> Result<Operation, RoqoqoError> {
+    #[cfg(feature = "unstable_operation_definition")]
+    if let Operation::GateDefinition(definition) = operation {
+        let mut values = values.clone();
+        for parameter in definition.free_parameters() {
+            values.remove(parameter);
+        }
+        return operation.bind_parameters(&values);
+    }
+    operation.bind_parameters(values)
+}
+
+// Renames the symbols of an operation, the free parameters of a GateDefinition are not renamed
+fn rename_operation(
+    operation: &Operation,
+    mapping: &HashMap<String, String>,
+) -> Result<Operation, RoqoqoError> {
+    if let Operation::InputSymbolic(input) = operation {
+        let name =
+            crate::operations::rename_parameter(&CalculatorFloat::from(input.name()), mapping)?
+                .to_string();
+        // The name set by an InputSymbolic cannot be replaced by an expression
+        if !crate::operations::is_symbol_name(&name) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "New name {} of InputSymbolic {} is not a valid symbol name",
+                    name,
+                    input.name()
+                ),
+            });
+        }
+        return Ok(InputSymbolic::new(name, *input.input()).into());
+    }
+    #[cfg(feature = "unstable_operation_definition")]
+    if let Operation::GateDefinition(definition) = operation {
+        let mut mapping = mapping.clone();
+        for parameter in definition.free_parameters() {
+            mapping.remove(parameter);
+        }
+        return operation.rename_parameters(&mapping);
+    }
+    operation.rename_parameters(mapping)
 }
 
 /// Implements Index Access for Circuit.
@@ -572,6 +1034,10 @@ where
     /// # Returns
     ///
     /// * `Self::IntoIter` - The Circuit in Circuit form.
+    ///
+    /// # Panics
+    ///
+    /// Panics when a CallDefinedGate does not match its GateDefinition, see [Circuit::add_operation].
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut circuit = Circuit::new();
         for op in iter {
@@ -590,6 +1056,10 @@ where
     /// # Arguments
     ///
     /// * `iter` - The iterator containing the operations by which to extend the Circuit.
+    ///
+    /// # Panics
+    ///
+    /// Panics when a CallDefinedGate does not match its GateDefinition, see [Circuit::add_operation].
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         for op in iter {
             self.add_operation(op.into());
@@ -736,6 +1206,10 @@ impl AsVec<std::ops::RangeFrom<usize>> for Circuit {
 /// # Arguments
 ///
 /// * `other` - Any type T that implements Into<Operation> trait.
+///
+/// # Panics
+///
+/// Panics when a CallDefinedGate does not match its GateDefinition, see [Circuit::add_operation].
 impl<T> ops::Add<T> for Circuit
 where
     T: Into<Operation>,
@@ -753,9 +1227,14 @@ where
 /// # Arguments
 ///
 /// * `other` - The Circuit to be added.
+///
+/// # Panics
+///
+/// Panics when a CallDefinedGate does not match its GateDefinition, see [Circuit::add_operation].
 impl ops::Add<Circuit> for Circuit {
     type Output = Self;
     fn add(self, other: Circuit) -> Self {
+        self.check_appended_circuit(&other);
         Self {
             definitions: self
                 .definitions
@@ -777,9 +1256,14 @@ impl ops::Add<Circuit> for Circuit {
 /// # Arguments
 ///
 /// * `other` - The Circuit reference to be added.
+///
+/// # Panics
+///
+/// Panics when a CallDefinedGate does not match its GateDefinition, see [Circuit::add_operation].
 impl ops::Add<&Circuit> for Circuit {
     type Output = Self;
     fn add(self, other: &Circuit) -> Self {
+        self.check_appended_circuit(other);
         Self {
             definitions: self
                 .definitions
@@ -801,6 +1285,10 @@ impl ops::Add<&Circuit> for Circuit {
 /// # Arguments
 ///
 /// * `other` - Any type T that implements Into<Operation> trait.
+///
+/// # Panics
+///
+/// Panics when a CallDefinedGate does not match its GateDefinition, see [Circuit::add_operation].
 impl<T> ops::AddAssign<T> for Circuit
 where
     T: Into<Operation>,
@@ -815,8 +1303,13 @@ where
 /// # Arguments
 ///
 /// * `other` - The Circuit to be appended.
+///
+/// # Panics
+///
+/// Panics when a CallDefinedGate does not match its GateDefinition, see [Circuit::add_operation].
 impl ops::AddAssign<Circuit> for Circuit {
     fn add_assign(&mut self, other: Circuit) {
+        self.check_appended_circuit(&other);
         self.definitions.extend(other.definitions);
         self.operations.extend(other.operations)
     }
@@ -827,8 +1320,13 @@ impl ops::AddAssign<Circuit> for Circuit {
 /// # Arguments
 ///
 /// * `other` - The Circuit to be appended.
+///
+/// # Panics
+///
+/// Panics when a CallDefinedGate does not match its GateDefinition, see [Circuit::add_operation].
 impl ops::AddAssign<&Circuit> for Circuit {
     fn add_assign(&mut self, other: &Circuit) {
+        self.check_appended_circuit(other);
         self.definitions.extend(other.definitions.iter().cloned());
         self.operations.extend(other.operations.iter().cloned())
     }
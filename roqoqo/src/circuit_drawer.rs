@@ -0,0 +1,786 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rendering of circuits as ASCII and SVG diagrams.
+//!
+//! Both renderers share the same layout: every qubit and every classical register that is written to
+//! is a horizontal wire, and the operations are placed in columns from left to right.
+//! The width of a column is measured in characters, the SVG renderer scales it to pixels.
+
+use crate::operations::*;
+use crate::Circuit;
+#[cfg(feature = "circuitdag")]
+use crate::CircuitDag;
+use qoqo_calculator::CalculatorFloat;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Width of one character of the layout in the SVG diagram.
+const SVG_CHAR_WIDTH: usize = 9;
+/// Distance between two wires in the SVG diagram.
+const SVG_WIRE_DISTANCE: usize = 40;
+
+// Content drawn on a single wire of a column
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    // A labelled box on a qubit wire
+    Box(String),
+    // The control dot of a controlled gate
+    Control,
+    // The entries of a classical register written to by a measurement or classical operation
+    Classical(String),
+}
+
+impl Cell {
+    fn text(&self) -> String {
+        match self {
+            Cell::Box(label) => format!("[{label}]"),
+            Cell::Control => "*".to_string(),
+            Cell::Classical(label) => label.clone(),
+        }
+    }
+}
+
+// A single operation drawn in a column: cells on wires joined by a vertical connector
+#[derive(Debug, Clone, PartialEq)]
+struct Item {
+    cells: Vec<(usize, Cell)>,
+}
+
+impl Item {
+    fn range(&self) -> (usize, usize) {
+        let wires = self.cells.iter().map(|(wire, _)| *wire);
+        (
+            wires.clone().min().unwrap_or_default(),
+            wires.max().unwrap_or_default(),
+        )
+    }
+}
+
+// An operation of the circuit prepared for the layout
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Item(Item),
+    Group {
+        label: String,
+        range: (usize, usize),
+        stages: Vec<Vec<Node>>,
+    },
+}
+
+// The kind of a column in the layout
+#[derive(Debug, Clone, PartialEq)]
+enum ColumnKind {
+    Items(Vec<Item>),
+    GroupStart(String, (usize, usize)),
+    GroupEnd((usize, usize)),
+}
+
+// A column of the layout together with the wire ranges of the groups enclosing it
+#[derive(Debug, Clone, PartialEq)]
+struct Column {
+    kind: ColumnKind,
+    groups: Vec<(usize, usize)>,
+}
+
+impl Column {
+    fn width(&self) -> usize {
+        match &self.kind {
+            ColumnKind::Items(items) => {
+                items
+                    .iter()
+                    .flat_map(|item| item.cells.iter())
+                    .map(|(_, cell)| cell.text().chars().count())
+                    .max()
+                    .unwrap_or(1)
+                    + 2
+            }
+            ColumnKind::GroupStart(label, _) => label.chars().count() + 1,
+            ColumnKind::GroupEnd(_) => 1,
+        }
+    }
+}
+
+// The complete layout of a circuit
+#[derive(Debug, Clone, PartialEq)]
+struct Layout {
+    number_qubits: usize,
+    registers: Vec<String>,
+    columns: Vec<Column>,
+}
+
+impl Layout {
+    fn new(circuit: &Circuit, packed: bool) -> Self {
+        let number_qubits = circuit.number_of_qubits();
+        let mut registers: Vec<String> = Vec::new();
+        collect_registers(circuit, &mut registers);
+        let builder = Builder {
+            number_qubits,
+            registers: &registers,
+            packed,
+        };
+        let stages = builder.stages(circuit);
+        let mut columns = Vec::new();
+        place_stages(&stages, &mut Vec::new(), &mut columns);
+        Layout {
+            number_qubits,
+            registers,
+            columns,
+        }
+    }
+
+    fn wire_labels(&self) -> Vec<String> {
+        (0..self.number_qubits)
+            .map(|qubit| format!("q[{qubit}]"))
+            .chain(self.registers.iter().cloned())
+            .collect()
+    }
+}
+
+// Converts the operations of circuits into nodes
+struct Builder<'a> {
+    number_qubits: usize,
+    registers: &'a [String],
+    // Without the circuitdag feature every operation is drawn in its own column
+    #[cfg_attr(not(feature = "circuitdag"), allow(dead_code))]
+    packed: bool,
+}
+
+impl Builder<'_> {
+    // Returns the operations of a circuit grouped in stages that can be drawn in parallel
+    fn stages(&self, circuit: &Circuit) -> Vec<Vec<Node>> {
+        self.operation_stages(circuit)
+            .into_iter()
+            .map(|stage| {
+                stage
+                    .iter()
+                    .flat_map(|operation| self.nodes(operation))
+                    .collect::<Vec<Node>>()
+            })
+            .filter(|stage| !stage.is_empty())
+            .collect()
+    }
+
+    #[cfg(feature = "circuitdag")]
+    fn operation_stages(&self, circuit: &Circuit) -> Vec<Vec<Operation>> {
+        if !self.packed {
+            return circuit
+                .iter()
+                .map(|operation| vec![operation.clone()])
+                .collect();
+        }
+        let dag = CircuitDag::from(circuit.clone());
+        dag.parallel_blocks()
+            .map(|mut block| {
+                block.sort_unstable();
+                block
+                    .into_iter()
+                    .filter_map(|node| dag.get(node).cloned())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[cfg(not(feature = "circuitdag"))]
+    fn operation_stages(&self, circuit: &Circuit) -> Vec<Vec<Operation>> {
+        circuit
+            .iter()
+            .map(|operation| vec![operation.clone()])
+            .collect()
+    }
+
+    fn qubits(&self, operation: &Operation) -> Vec<usize> {
+        match operation.involved_qubits() {
+            InvolvedQubits::All => (0..self.number_qubits).collect(),
+            InvolvedQubits::None => Vec::new(),
+            InvolvedQubits::Set(set) => {
+                let mut qubits: Vec<usize> = set.into_iter().collect();
+                qubits.sort_unstable();
+                qubits
+            }
+        }
+    }
+
+    fn register_wire(&self, register: &str) -> Option<usize> {
+        self.registers
+            .iter()
+            .position(|name| name == register)
+            .map(|index| self.number_qubits + index)
+    }
+
+    // Returns the cells on the classical wires written to by the operation
+    fn classical_cells(&self, operation: &Operation) -> Vec<(usize, Cell)> {
+        let entries: Vec<(String, String)> = match operation.involved_classical() {
+            InvolvedClassical::All(name) | InvolvedClassical::AllQubits(name) => {
+                vec![(name, "all".to_string())]
+            }
+            InvolvedClassical::None => Vec::new(),
+            InvolvedClassical::Set(set) => {
+                let mut names: Vec<String> = set.iter().map(|(name, _)| name.clone()).collect();
+                names.sort();
+                names.dedup();
+                names
+                    .into_iter()
+                    .map(|name| {
+                        let mut indices: Vec<usize> = set
+                            .iter()
+                            .filter(|(entry, _)| *entry == name)
+                            .map(|(_, index)| *index)
+                            .collect();
+                        indices.sort_unstable();
+                        let label = indices
+                            .iter()
+                            .map(|index| index.to_string())
+                            .collect::<Vec<String>>()
+                            .join(",");
+                        (name, label)
+                    })
+                    .collect()
+            }
+        };
+        entries
+            .into_iter()
+            .filter_map(|(name, label)| {
+                self.register_wire(&name)
+                    .map(|wire| (wire, Cell::Classical(label)))
+            })
+            .collect()
+    }
+
+    fn group(&self, label: String, operation: &Operation, circuit: &Circuit) -> Option<Node> {
+        let qubits = self.qubits(operation);
+        let range = (*qubits.first()?, *qubits.last()?);
+        Some(Node::Group {
+            label,
+            range,
+            stages: self.stages(circuit),
+        })
+    }
+
+    // Returns the nodes drawn for an operation
+    fn nodes(&self, operation: &Operation) -> Vec<Node> {
+        if operation.tags().contains(&"Definition") {
+            return Vec::new();
+        }
+        let group = match operation {
+            Operation::PragmaLoop(op) => self.group(
+                format!("Loop({})", parameter_label(op.repetitions())),
+                operation,
+                op.circuit(),
+            ),
+            Operation::PragmaConditional(op) => self.group(
+                format!("If({}[{}])", op.condition_register(), op.condition_index()),
+                operation,
+                op.circuit(),
+            ),
+            Operation::PragmaWhileLoop(op) => self.group(
+                format!("While({})", condition_label(op.condition())),
+                operation,
+                op.circuit(),
+            ),
+            Operation::PragmaControlledCircuit(op) => self.group(
+                format!("Controlled(q[{}])", op.controlling_qubit()),
+                operation,
+                op.circuit(),
+            ),
+            Operation::PragmaIfElse(op) => {
+                return self
+                    .group(
+                        format!("If({})", condition_label(op.condition())),
+                        operation,
+                        op.if_circuit(),
+                    )
+                    .into_iter()
+                    .chain(self.group("Else".to_string(), operation, op.else_circuit()))
+                    .collect();
+            }
+            _ => None,
+        };
+        if let Some(group) = group {
+            return vec![group];
+        }
+
+        let qubits = self.qubits(operation);
+        let mut cells: Vec<(usize, Cell)> = Vec::new();
+        if operation.tags().contains(&"Measurement") {
+            let label = match operation {
+                Operation::MeasureQubit(_) | Operation::PragmaRepeatedMeasurement(_) => {
+                    "M".to_string()
+                }
+                Operation::MeasureQubitAndReset(_) => "MR".to_string(),
+                _ => short_name(operation.hqslang()),
+            };
+            cells.extend(
+                qubits
+                    .iter()
+                    .map(|qubit| (*qubit, Cell::Box(label.clone()))),
+            );
+            cells.extend(self.classical_cells(operation));
+        } else if qubits.is_empty() {
+            cells.extend(
+                self.classical_cells(operation)
+                    .into_iter()
+                    .map(|(wire, _)| (wire, Cell::Box(short_name(operation.hqslang())))),
+            );
+        } else {
+            let (controls, targets, label) = gate_parts(operation, qubits);
+            cells.extend(controls.into_iter().map(|qubit| (qubit, Cell::Control)));
+            cells.extend(
+                targets
+                    .into_iter()
+                    .map(|qubit| (qubit, Cell::Box(label.clone()))),
+            );
+        }
+        if cells.is_empty() {
+            Vec::new()
+        } else {
+            vec![Node::Item(Item { cells })]
+        }
+    }
+}
+
+// Adds the classical registers of a circuit and its nested circuits in the order of their definition
+fn collect_registers(circuit: &Circuit, registers: &mut Vec<String>) {
+    for operation in circuit.iter() {
+        let name = match operation {
+            Operation::DefinitionBit(op) => Some(op.name()),
+            Operation::DefinitionFloat(op) => Some(op.name()),
+            Operation::DefinitionComplex(op) => Some(op.name()),
+            Operation::DefinitionUsize(op) => Some(op.name()),
+            _ => None,
+        };
+        if let Some(name) = name {
+            if !registers.contains(name) {
+                registers.push(name.clone());
+            }
+        }
+        let nested: Vec<&Circuit> = match operation {
+            Operation::PragmaLoop(op) => vec![op.circuit()],
+            Operation::PragmaConditional(op) => vec![op.circuit()],
+            Operation::PragmaWhileLoop(op) => vec![op.circuit()],
+            Operation::PragmaControlledCircuit(op) => vec![op.circuit()],
+            Operation::PragmaIfElse(op) => vec![op.if_circuit(), op.else_circuit()],
+            _ => Vec::new(),
+        };
+        for circuit in nested {
+            collect_registers(circuit, registers);
+        }
+    }
+}
+
+// Returns the controls, the targets and the label of the box of a gate or pragma acting on qubits
+fn gate_parts(operation: &Operation, qubits: Vec<usize>) -> (Vec<usize>, Vec<usize>, String) {
+    let hqslang = operation.hqslang();
+    let label = match Rotation::try_from(operation.clone()) {
+        Ok(rotation) => format!(
+            "{}({})",
+            short_name(hqslang),
+            parameter_label(rotation.theta())
+        ),
+        Err(_) => short_name(hqslang),
+    };
+    if let Ok(op) = TwoQubitGateOperation::try_from(operation.clone()) {
+        if hqslang == "CNOT" {
+            return (vec![*op.control()], vec![*op.target()], "X".to_string());
+        }
+        if let Some(controlled) = label.strip_prefix("Controlled") {
+            return (
+                vec![*op.control()],
+                vec![*op.target()],
+                controlled.to_string(),
+            );
+        }
+    }
+    if let Ok(op) = ThreeQubitGateOperation::try_from(operation.clone()) {
+        if hqslang == "Toffoli" {
+            return (
+                vec![*op.control_0(), *op.control_1()],
+                vec![*op.target()],
+                "X".to_string(),
+            );
+        }
+        if let Some(controlled) = label.strip_prefix("ControlledControlled") {
+            return (
+                vec![*op.control_0(), *op.control_1()],
+                vec![*op.target()],
+                controlled.to_string(),
+            );
+        }
+        if let Some(controlled) = label.strip_prefix("Controlled") {
+            return (
+                vec![*op.control_0()],
+                vec![*op.control_1(), *op.target()],
+                controlled.to_string(),
+            );
+        }
+    }
+    (Vec::new(), qubits, label)
+}
+
+// Returns the hqslang name without the Pragma prefix
+fn short_name(hqslang: &str) -> String {
+    hqslang
+        .strip_prefix("Pragma")
+        .unwrap_or(hqslang)
+        .to_string()
+}
+
+// Returns a short representation of a parameter
+fn parameter_label(parameter: &CalculatorFloat) -> String {
+    match parameter {
+        CalculatorFloat::Float(value) => {
+            let rounded = format!("{value:.3}");
+            rounded
+                .trim_end_matches('0')
+                .trim_end_matches('.')
+                .to_string()
+        }
+        CalculatorFloat::Str(expression) => expression.clone(),
+    }
+}
+
+// Returns a short representation of a condition on classical bits
+fn condition_label(condition: &BooleanExpression) -> String {
+    let join = |expressions: &Vec<BooleanExpression>, separator: &str| {
+        format!(
+            "({})",
+            expressions
+                .iter()
+                .map(condition_label)
+                .collect::<Vec<String>>()
+                .join(separator)
+        )
+    };
+    match condition {
+        BooleanExpression::Constant(value) => value.to_string(),
+        BooleanExpression::Bit(name, index) => format!("{name}[{index}]"),
+        BooleanExpression::Not(inner) => format!("!{}", condition_label(inner)),
+        BooleanExpression::And(expressions) => join(expressions, " & "),
+        BooleanExpression::Or(expressions) => join(expressions, " | "),
+        BooleanExpression::Xor(expressions) => join(expressions, " ^ "),
+    }
+}
+
+// Places the stages of nodes in columns
+//
+// The items of a stage share columns as long as their wire ranges do not overlap,
+// groups are placed after the items of their stage.
+fn place_stages(stages: &[Vec<Node>], groups: &mut Vec<(usize, usize)>, columns: &mut Vec<Column>) {
+    for stage in stages {
+        let mut stage_columns: Vec<Vec<Item>> = Vec::new();
+        for node in stage {
+            if let Node::Item(item) = node {
+                let (low, high) = item.range();
+                let free = stage_columns.iter_mut().find(|items| {
+                    items.iter().all(|other| {
+                        let (other_low, other_high) = other.range();
+                        high < other_low || other_high < low
+                    })
+                });
+                match free {
+                    Some(items) => items.push(item.clone()),
+                    None => stage_columns.push(vec![item.clone()]),
+                }
+            }
+        }
+        columns.extend(stage_columns.into_iter().map(|items| Column {
+            kind: ColumnKind::Items(items),
+            groups: groups.clone(),
+        }));
+        for node in stage {
+            if let Node::Group {
+                label,
+                range,
+                stages,
+            } = node
+            {
+                columns.push(Column {
+                    kind: ColumnKind::GroupStart(label.clone(), *range),
+                    groups: groups.clone(),
+                });
+                groups.push(*range);
+                place_stages(stages, groups, columns);
+                groups.pop();
+                columns.push(Column {
+                    kind: ColumnKind::GroupEnd(*range),
+                    groups: groups.clone(),
+                });
+            }
+        }
+    }
+}
+
+// Returns the text row of a wire, rows between the wires are used for connectors and group borders
+fn row(wire: usize) -> usize {
+    2 * wire + 1
+}
+
+/// Draws a circuit as an ASCII diagram.
+///
+/// See [crate::Circuit::draw_ascii].
+pub(crate) fn draw_ascii(circuit: &Circuit, packed: bool) -> String {
+    let layout = Layout::new(circuit, packed);
+    let labels = layout.wire_labels();
+    let number_rows = 2 * labels.len() + 1;
+    let margin = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    let base = |row: usize, wire_count: usize| -> char {
+        if row % 2 == 0 {
+            ' '
+        } else if (row - 1) / 2 < wire_count {
+            '-'
+        } else {
+            '='
+        }
+    };
+
+    let mut lines: Vec<Vec<char>> = (0..number_rows)
+        .map(|row| {
+            let mut line: Vec<char> = vec![' '; margin];
+            if row % 2 == 1 {
+                for (index, character) in labels[(row - 1) / 2].chars().enumerate() {
+                    line[index] = character;
+                }
+                line[margin - 2] = ':';
+                line[margin - 1] = base(row, layout.number_qubits);
+            }
+            line
+        })
+        .collect();
+
+    for column in layout.columns.iter() {
+        let width = column.width();
+        let mut block: Vec<Vec<char>> = (0..number_rows)
+            .map(|row| vec![base(row, layout.number_qubits); width])
+            .collect();
+        for (low, high) in column.groups.iter() {
+            block[row(*low) - 1] = vec!['-'; width];
+            block[row(*high) + 1] = vec!['-'; width];
+        }
+        match &column.kind {
+            ColumnKind::Items(items) => {
+                let center = width / 2;
+                for item in items {
+                    let (low, high) = item.range();
+                    let occupied: HashSet<usize> =
+                        item.cells.iter().map(|(wire, _)| row(*wire)).collect();
+                    for (row, line) in block.iter_mut().enumerate().take(row(high)).skip(row(low)) {
+                        if occupied.contains(&row) {
+                            continue;
+                        }
+                        line[center] = if row % 2 == 0 && line[center] == ' ' {
+                            '|'
+                        } else {
+                            '+'
+                        };
+                    }
+                    for (wire, cell) in item.cells.iter() {
+                        let text: Vec<char> = cell.text().chars().collect();
+                        let start = center - text.len() / 2;
+                        block[row(*wire)][start..start + text.len()].copy_from_slice(&text);
+                    }
+                }
+            }
+            ColumnKind::GroupStart(label, (low, high)) => {
+                block[row(*low) - 1] = format!("+{label}").chars().collect();
+                block[row(*high) + 1] = vec!['-'; width];
+                block[row(*high) + 1][0] = '+';
+                for line in block.iter_mut().take(row(*high) + 1).skip(row(*low)) {
+                    line[0] = '|';
+                }
+            }
+            ColumnKind::GroupEnd((low, high)) => {
+                block[row(*low) - 1][0] = '+';
+                block[row(*high) + 1][0] = '+';
+                for line in block.iter_mut().take(row(*high) + 1).skip(row(*low)) {
+                    line[0] = '|';
+                }
+            }
+        }
+        for (line, part) in lines.iter_mut().zip(block) {
+            line.extend(part);
+        }
+    }
+
+    let mut output = String::new();
+    for (row, line) in lines.iter().enumerate() {
+        let mut text: String = line.iter().collect();
+        if row % 2 == 1 {
+            text.push(base(row, layout.number_qubits));
+        }
+        let text = text.trim_end();
+        if !text.is_empty() || (row > 0 && row + 1 < number_rows) {
+            output.push_str(text);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// Draws a circuit as an SVG image.
+///
+/// See [crate::Circuit::draw_svg].
+pub(crate) fn draw_svg(circuit: &Circuit, packed: bool) -> String {
+    let layout = Layout::new(circuit, packed);
+    let labels = layout.wire_labels();
+    let margin = (labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 2)
+        * SVG_CHAR_WIDTH;
+    let total_width: usize = layout
+        .columns
+        .iter()
+        .map(|column| column.width())
+        .sum::<usize>()
+        + 1;
+    let width = margin + total_width * SVG_CHAR_WIDTH;
+    let height = (labels.len() + 1) * SVG_WIRE_DISTANCE;
+    // Wires are placed in the middle of their rows, the rows between wires have half the height
+    let y = |row: usize| row * SVG_WIRE_DISTANCE / 2 + SVG_WIRE_DISTANCE / 2;
+    let half_box = SVG_WIRE_DISTANCE * 3 / 8;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"14\">"
+    );
+    let _ = writeln!(
+        svg,
+        "<rect x=\"0\" y=\"0\" width=\"{width}\" height=\"{height}\" fill=\"white\"/>"
+    );
+    for (wire, label) in labels.iter().enumerate() {
+        let wire_y = y(row(wire));
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" dominant-baseline=\"middle\">{}</text>",
+            SVG_CHAR_WIDTH / 2,
+            wire_y,
+            escape(label)
+        );
+        if wire < layout.number_qubits {
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{margin}\" y1=\"{wire_y}\" x2=\"{width}\" y2=\"{wire_y}\" stroke=\"black\"/>"
+            );
+        } else {
+            for offset in [wire_y - 2, wire_y + 2] {
+                let _ = writeln!(
+                    svg,
+                    "<line x1=\"{margin}\" y1=\"{offset}\" x2=\"{width}\" y2=\"{offset}\" stroke=\"black\"/>"
+                );
+            }
+        }
+    }
+
+    let mut x = margin;
+    let mut open_groups: Vec<(usize, &String)> = Vec::new();
+    for column in layout.columns.iter() {
+        let column_width = column.width() * SVG_CHAR_WIDTH;
+        let center = x + column_width / 2;
+        match &column.kind {
+            ColumnKind::Items(items) => {
+                for item in items {
+                    let (low, high) = item.range();
+                    if low != high {
+                        let _ = writeln!(
+                            svg,
+                            "<line x1=\"{center}\" y1=\"{}\" x2=\"{center}\" y2=\"{}\" stroke=\"black\"/>",
+                            y(row(low)),
+                            y(row(high))
+                        );
+                    }
+                    for (wire, cell) in item.cells.iter() {
+                        let wire_y = y(row(*wire));
+                        match cell {
+                            Cell::Box(label) => {
+                                let box_width = (label.chars().count() + 1) * SVG_CHAR_WIDTH;
+                                let _ = writeln!(
+                                    svg,
+                                    "<rect x=\"{}\" y=\"{}\" width=\"{box_width}\" height=\"{}\" fill=\"white\" stroke=\"black\"/>",
+                                    center - box_width / 2,
+                                    wire_y - half_box,
+                                    2 * half_box
+                                );
+                                let _ = writeln!(
+                                    svg,
+                                    "<text x=\"{center}\" y=\"{wire_y}\" text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>",
+                                    escape(label)
+                                );
+                            }
+                            Cell::Control => {
+                                let _ = writeln!(
+                                    svg,
+                                    "<circle cx=\"{center}\" cy=\"{wire_y}\" r=\"4\" fill=\"black\"/>"
+                                );
+                            }
+                            Cell::Classical(label) => {
+                                let _ = writeln!(
+                                    svg,
+                                    "<circle cx=\"{center}\" cy=\"{wire_y}\" r=\"3\" fill=\"black\"/>"
+                                );
+                                let _ = writeln!(
+                                    svg,
+                                    "<text x=\"{}\" y=\"{}\" font-size=\"10\">{}</text>",
+                                    center + 4,
+                                    wire_y + 14,
+                                    escape(label)
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            ColumnKind::GroupStart(label, _) => open_groups.push((x, label)),
+            ColumnKind::GroupEnd(_) => (),
+        }
+        if let ColumnKind::GroupEnd((low, high)) = &column.kind {
+            let (start, label) = match open_groups.pop() {
+                Some((start, label)) => (start, Some(label)),
+                None => (margin, None),
+            };
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{start}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"gray\" stroke-dasharray=\"4\"/>",
+                y(row(*low) - 1),
+                x + column_width - start,
+                y(row(*high) + 1) - y(row(*low) - 1)
+            );
+            if let Some(label) = label {
+                let _ = writeln!(
+                    svg,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"10\" fill=\"gray\">{}</text>",
+                    start + 2,
+                    y(row(*low) - 1) - 2,
+                    escape(label)
+                );
+            }
+        }
+        x += column_width;
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// Escapes the characters with a special meaning in XML
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -200,6 +200,7 @@ impl CircuitDag {
         // InvolvedClassical: populate for the first time the classical register data
         // structure or start the update process
         if !self.is_definition_classical_populate(node.index(), operation.clone()) {
+            self.add_classical_edges_back(node.index(), &operation);
             self.update_classical_back(node.index(), operation);
         }
 
@@ -260,11 +261,10 @@ impl CircuitDag {
         // Update the first layer in case the qubit has never been seen before
         if qubit_presence.is_none() {
             // Update first_operation_involving_qubit depending on last_all
-            if self.last_all.is_none() {
-                self.first_operation_involving_qubit.insert(qubit, node);
+            if let Some(last_all) = self.last_all {
+                self.first_operation_involving_qubit.insert(qubit, last_all);
             } else {
-                self.first_operation_involving_qubit
-                    .insert(qubit, self.last_all.unwrap());
+                self.first_operation_involving_qubit.insert(qubit, node);
             }
         }
     }
@@ -342,6 +342,7 @@ impl CircuitDag {
         // InvolvedClassical: populate for the first time the classical register data
         // structure or start the update process
         if !self.is_definition_classical_populate(node.index(), operation.clone()) {
+            self.add_classical_edges_front(node.index(), &operation);
             self.update_classical_front(node.index(), operation);
         }
 
@@ -402,11 +403,10 @@ impl CircuitDag {
         // Update the last layer in case the qubit has never been seen before
         if qubit_presence.is_none() {
             // Update last_operation_involving_qubit depending on first_all
-            if self.first_all.is_none() {
-                self.last_operation_involving_qubit.insert(qubit, node);
+            if let Some(first_all) = self.first_all {
+                self.last_operation_involving_qubit.insert(qubit, first_all);
             } else {
-                self.last_operation_involving_qubit
-                    .insert(qubit, self.first_all.unwrap());
+                self.last_operation_involving_qubit.insert(qubit, node);
             }
         }
     }
@@ -508,6 +508,92 @@ impl CircuitDag {
         }
     }
 
+    /// Returns the nodes of the given layer that involve the same classical register entries as an Operation.
+    ///
+    /// Definitions are not returned as they are handled as commuting operations.
+    ///
+    /// # Arguments
+    ///
+    /// * 'involved' - The classical register entries involved in the Operation.
+    /// * 'layer' - Either first_operation_involving_classical or last_operation_involving_classical.
+    fn classical_neighbours(
+        &self,
+        involved: InvolvedClassical,
+        layer: &HashMap<(String, usize), NodeIndex<usize>>,
+    ) -> HashSet<NodeIndex<usize>> {
+        let neighbours: HashSet<NodeIndex<usize>> = match involved {
+            InvolvedClassical::Set(x) => {
+                x.iter().filter_map(|key| layer.get(key).copied()).collect()
+            }
+            InvolvedClassical::All(x) | InvolvedClassical::AllQubits(x) => layer
+                .iter()
+                .filter(|((name, _), _)| *name == x)
+                .map(|(_, node)| *node)
+                .collect(),
+            InvolvedClassical::None => HashSet::new(),
+        };
+        neighbours
+            .into_iter()
+            .filter(|node| !self.commuting_operations.contains(node))
+            .collect()
+    }
+
+    /// Adds the edges from the Operations last involving the same classical register entries
+    /// as a given Operation that was added to the back of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// * 'node' - The index of the node of the Operation that was added to the back of the graph.
+    /// * 'operation' - The Operation that was added to the back of the graph.
+    fn add_classical_edges_back(&mut self, node: NodeIndex<usize>, operation: &Operation) {
+        let involved = operation.involved_classical();
+        if involved == InvolvedClassical::None {
+            return;
+        }
+        // Operations acting on no qubits are only connected to the graph via classical registers
+        if operation.involved_qubits() == InvolvedQubits::None {
+            self.first_parallel_block.insert(node);
+            self.last_parallel_block.insert(node);
+        }
+        for predecessor in
+            self.classical_neighbours(involved, &self.last_operation_involving_classical)
+        {
+            if predecessor != node {
+                self.graph.update_edge(predecessor.into(), node.into(), ());
+                self.last_parallel_block.remove(&predecessor);
+                self.first_parallel_block.remove(&node);
+            }
+        }
+    }
+
+    /// Adds the edges to the Operations first involving the same classical register entries
+    /// as a given Operation that was added to the front of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// * 'node' - The index of the node of the Operation that was added to the front of the graph.
+    /// * 'operation' - The Operation that was added to the front of the graph.
+    fn add_classical_edges_front(&mut self, node: NodeIndex<usize>, operation: &Operation) {
+        let involved = operation.involved_classical();
+        if involved == InvolvedClassical::None {
+            return;
+        }
+        // Operations acting on no qubits are only connected to the graph via classical registers
+        if operation.involved_qubits() == InvolvedQubits::None {
+            self.first_parallel_block.insert(node);
+            self.last_parallel_block.insert(node);
+        }
+        for successor in
+            self.classical_neighbours(involved, &self.first_operation_involving_classical)
+        {
+            if successor != node {
+                self.graph.update_edge(node.into(), successor.into(), ());
+                self.first_parallel_block.remove(&successor);
+                self.last_parallel_block.remove(&node);
+            }
+        }
+    }
+
     /// Checks and updates the relevant classical registers attributes from a given Operation
     /// that was added to the back of the graph.
     ///
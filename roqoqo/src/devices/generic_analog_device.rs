@@ -0,0 +1,403 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use super::AnalogDevice;
+use crate::operations::{Operate, Operation};
+use crate::RoqoqoError;
+use crate::RoqoqoVersionSerializable;
+use qoqo_calculator::CalculatorFloat;
+use struqture::spins::{PauliHamiltonian, PauliProduct, SinglePauliOperator};
+use struqture::{OperateOnDensityMatrix, SpinIndex};
+
+/// A generic analog device with the supported Pauli terms and their coefficient bounds for every qubit and pair of qubits.
+///
+/// Two-qubit terms are stored with the smaller qubit first,
+/// the Pauli operators of a term are given in the order of the qubits, e.g. "XZ" for X on the smaller qubit.
+///
+/// # Note
+///
+/// GenericAnalogDevice uses nested HashMaps to represent the most general device connectivity.
+/// The memory usage will be inefficient for devices with large qubit numbers.
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(from = "GenericAnalogDeviceSerialize"))]
+#[cfg_attr(feature = "serialize", serde(into = "GenericAnalogDeviceSerialize"))]
+pub struct GenericAnalogDevice {
+    /// The number of qubits
+    pub number_qubits: usize,
+    /// Coefficient bounds of the supported single-qubit Pauli terms for all qubits
+    pub single_qubit_terms: HashMap<usize, TermBounds>,
+    /// Coefficient bounds of the supported two-qubit Pauli terms for all pairs of qubits
+    pub two_qubit_terms: HashMap<(usize, usize), TermBounds>,
+    /// The maximal evolution time
+    pub max_time: f64,
+    /// The time resolution of evolution times, zero for continuous times
+    pub time_resolution: f64,
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for GenericAnalogDevice {
+    fn schema_name() -> String {
+        "GenericAnalogDevice".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <GenericAnalogDeviceSerialize>::json_schema(gen)
+    }
+}
+
+type TermBounds = HashMap<String, (f64, f64)>;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json_schema", schemars(rename = "GenericAnalogDevice"))]
+struct GenericAnalogDeviceSerialize {
+    /// The number of qubits
+    number_qubits: usize,
+    /// Coefficient bounds of the supported single-qubit Pauli terms for all qubits
+    single_qubit_terms: Vec<(usize, TermBounds)>,
+    /// Coefficient bounds of the supported two-qubit Pauli terms for all pairs of qubits
+    two_qubit_terms: Vec<((usize, usize), TermBounds)>,
+    /// The maximal evolution time
+    max_time: f64,
+    /// The time resolution of evolution times, zero for continuous times
+    time_resolution: f64,
+    _roqoqo_version: RoqoqoVersionSerializable,
+}
+
+impl From<GenericAnalogDeviceSerialize> for GenericAnalogDevice {
+    fn from(value: GenericAnalogDeviceSerialize) -> Self {
+        GenericAnalogDevice {
+            number_qubits: value.number_qubits,
+            single_qubit_terms: value.single_qubit_terms.into_iter().collect(),
+            two_qubit_terms: value.two_qubit_terms.into_iter().collect(),
+            max_time: value.max_time,
+            time_resolution: value.time_resolution,
+        }
+    }
+}
+
+impl From<GenericAnalogDevice> for GenericAnalogDeviceSerialize {
+    fn from(value: GenericAnalogDevice) -> Self {
+        let current_version = RoqoqoVersionSerializable {
+            major_version: 1,
+            minor_version: 20,
+        };
+        GenericAnalogDeviceSerialize {
+            number_qubits: value.number_qubits,
+            single_qubit_terms: value.single_qubit_terms.into_iter().collect(),
+            two_qubit_terms: value.two_qubit_terms.into_iter().collect(),
+            max_time: value.max_time,
+            time_resolution: value.time_resolution,
+            _roqoqo_version: current_version,
+        }
+    }
+}
+
+impl GenericAnalogDevice {
+    /// Creates a new GenericAnalogDevice without supported terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_qubits` - The number of qubits in the device.
+    /// * `max_time` - The maximal evolution time.
+    /// * `time_resolution` - The time resolution of evolution times, zero for continuous times.
+    ///
+    pub fn new(number_qubits: usize, max_time: f64, time_resolution: f64) -> Self {
+        Self {
+            number_qubits,
+            single_qubit_terms: HashMap::new(),
+            two_qubit_terms: HashMap::new(),
+            max_time,
+            time_resolution,
+        }
+    }
+
+    /// Setting the coefficient bounds of a single-qubit Pauli term.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the term acts on.
+    /// * `pauli` - The Pauli operator of the term, "X", "Y" or "Z".
+    /// * `min` - The minimal coefficient of the term.
+    /// * `max` - The maximal coefficient of the term.
+    pub fn set_single_qubit_term(
+        &mut self,
+        qubit: usize,
+        pauli: &str,
+        min: f64,
+        max: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.check_qubit(qubit)?;
+        check_term(pauli, 1, min, max)?;
+        self.single_qubit_terms
+            .entry(qubit)
+            .or_default()
+            .insert(pauli.to_string(), (min, max));
+        Ok(())
+    }
+
+    /// Setting the coefficient bounds of a two-qubit Pauli interaction term.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit_0` - The first qubit the term acts on.
+    /// * `qubit_1` - The second qubit the term acts on.
+    /// * `paulis` - The Pauli operators acting on the first and the second qubit, e.g. "XZ".
+    /// * `min` - The minimal coefficient of the term.
+    /// * `max` - The maximal coefficient of the term.
+    pub fn set_two_qubit_term(
+        &mut self,
+        qubit_0: usize,
+        qubit_1: usize,
+        paulis: &str,
+        min: f64,
+        max: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.check_qubit(qubit_0)?;
+        self.check_qubit(qubit_1)?;
+        if qubit_0 == qubit_1 {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Two-qubit term {paulis} cannot act twice on qubit {qubit_0}"),
+            });
+        }
+        check_term(paulis, 2, min, max)?;
+        let (edge, paulis) = ordered_pair(qubit_0, qubit_1, paulis);
+        self.two_qubit_terms
+            .entry(edge)
+            .or_default()
+            .insert(paulis, (min, max));
+        Ok(())
+    }
+
+    fn check_qubit(&self, qubit: usize) -> Result<(), RoqoqoError> {
+        if qubit >= self.number_qubits {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Qubit {} larger than number qubits {}",
+                    qubit, self.number_qubits
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Implements AnalogDevice trait for GenericAnalogDevice.
+///
+/// The AnalogDevice trait defines standard functions available for roqoqo analog devices.
+///
+impl AnalogDevice for GenericAnalogDevice {
+    fn number_qubits(&self) -> usize {
+        self.number_qubits
+    }
+
+    fn single_qubit_term_bounds(&self, qubit: &usize, pauli: &str) -> Option<(f64, f64)> {
+        self.single_qubit_terms
+            .get(qubit)
+            .and_then(|terms| terms.get(pauli).copied())
+    }
+
+    fn two_qubit_term_bounds(
+        &self,
+        qubit_0: &usize,
+        qubit_1: &usize,
+        paulis: &str,
+    ) -> Option<(f64, f64)> {
+        let (edge, paulis) = ordered_pair(*qubit_0, *qubit_1, paulis);
+        self.two_qubit_terms
+            .get(&edge)
+            .and_then(|terms| terms.get(&paulis).copied())
+    }
+
+    fn max_time(&self) -> f64 {
+        self.max_time
+    }
+
+    fn time_resolution(&self) -> f64 {
+        self.time_resolution
+    }
+
+    fn to_generic_analog_device(&self) -> GenericAnalogDevice {
+        self.clone()
+    }
+}
+
+impl crate::operations::SupportedVersion for GenericAnalogDevice {}
+
+// Returns the pair of qubits with the smaller qubit first and the Pauli operators in the same order
+fn ordered_pair(qubit_0: usize, qubit_1: usize, paulis: &str) -> ((usize, usize), String) {
+    if qubit_0 <= qubit_1 {
+        ((qubit_0, qubit_1), paulis.to_string())
+    } else {
+        ((qubit_1, qubit_0), paulis.chars().rev().collect())
+    }
+}
+
+// Checks that a term consists of the given number of Pauli operators and that its bounds are ordered
+fn check_term(paulis: &str, number_paulis: usize, min: f64, max: f64) -> Result<(), RoqoqoError> {
+    if paulis.chars().count() != number_paulis
+        || !paulis.chars().all(|pauli| matches!(pauli, 'X' | 'Y' | 'Z'))
+    {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "Term {paulis} does not consist of {number_paulis} Pauli operators X, Y or Z"
+            ),
+        });
+    }
+    if min > max {
+        return Err(RoqoqoError::GenericError {
+            msg: format!("Lower bound {min} of term {paulis} is larger than upper bound {max}"),
+        });
+    }
+    Ok(())
+}
+
+// Checks an analog operation against an analog device, see [AnalogDevice::validate_analog_operation]
+pub(super) fn validate_analog_operation<T: AnalogDevice + ?Sized>(
+    device: &T,
+    operation: &Operation,
+) -> Result<(), RoqoqoError> {
+    match operation {
+        Operation::ApplyConstantPauliHamiltonian(op) => {
+            let time = match op.time {
+                CalculatorFloat::Float(time) => time,
+                _ => {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!("Evolution time {} is symbolic", op.time),
+                    })
+                }
+            };
+            check_time(device, time)?;
+            check_hamiltonian(device, &op.hamiltonian)
+        }
+        Operation::ApplyTimeDependentPauliHamiltonian(op) => {
+            op.validate_schedule()?;
+            for time in op.time().iter() {
+                check_time(device, *time)?;
+            }
+            for segment in op.piecewise_constant()? {
+                check_hamiltonian(device, &segment.hamiltonian)?;
+            }
+            Ok(())
+        }
+        _ => Err(RoqoqoError::GenericError {
+            msg: format!(
+                "Operation {} is not an analog Pauli Hamiltonian evolution",
+                operation.hqslang()
+            ),
+        }),
+    }
+}
+
+// Checks that an evolution time is a multiple of the time resolution not larger than the maximal time
+fn check_time<T: AnalogDevice + ?Sized>(device: &T, time: f64) -> Result<(), RoqoqoError> {
+    if time < 0.0 || time > device.max_time() {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "Evolution time {} is not between 0 and the maximal time {}",
+                time,
+                device.max_time()
+            ),
+        });
+    }
+    let resolution = device.time_resolution();
+    if resolution > 0.0 {
+        let steps = time / resolution;
+        // Relative tolerance for times accumulated from floating point steps
+        if (steps - steps.round()).abs() > 1e-9 * steps.max(1.0) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Evolution time {} is not a multiple of the time resolution {}",
+                    time, resolution
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+// Checks that every term of the Hamiltonian is supported with a coefficient within its bounds
+fn check_hamiltonian<T: AnalogDevice + ?Sized>(
+    device: &T,
+    hamiltonian: &PauliHamiltonian,
+) -> Result<(), RoqoqoError> {
+    for (product, coefficient) in hamiltonian.iter() {
+        let coefficient = match coefficient {
+            CalculatorFloat::Float(value) => *value,
+            _ => {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!("Coefficient {coefficient} of term {product} is symbolic"),
+                })
+            }
+        };
+        let bounds = term_bounds(device, product)?;
+        match bounds {
+            // Identity terms only contribute a global phase
+            None => continue,
+            Some((min, max)) => {
+                if coefficient < min || coefficient > max {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!(
+                            "Coefficient {coefficient} of term {product} is not between {min} and {max}"
+                        ),
+                    });
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// Returns the bounds of a Pauli product, None for the identity
+fn term_bounds<T: AnalogDevice + ?Sized>(
+    device: &T,
+    product: &PauliProduct,
+) -> Result<Option<(f64, f64)>, RoqoqoError> {
+    let mut paulis: Vec<(usize, char)> = Vec::new();
+    for (qubit, pauli) in product.iter() {
+        let label = match pauli {
+            SinglePauliOperator::Identity => continue,
+            SinglePauliOperator::X => 'X',
+            SinglePauliOperator::Y => 'Y',
+            SinglePauliOperator::Z => 'Z',
+        };
+        if *qubit >= device.number_qubits() {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Qubit {} larger than number qubits {}",
+                    qubit,
+                    device.number_qubits()
+                ),
+            });
+        }
+        paulis.push((*qubit, label));
+    }
+    paulis.sort_by_key(|(qubit, _)| *qubit);
+    let bounds = match paulis.as_slice() {
+        [] => return Ok(None),
+        [(qubit, pauli)] => device.single_qubit_term_bounds(qubit, &pauli.to_string()),
+        [(qubit_0, pauli_0), (qubit_1, pauli_1)] => {
+            device.two_qubit_term_bounds(qubit_0, qubit_1, &format!("{pauli_0}{pauli_1}"))
+        }
+        _ => None,
+    };
+    match bounds {
+        Some(bounds) => Ok(Some(bounds)),
+        None => Err(RoqoqoError::GenericError {
+            msg: format!("Term {product} is not supported by the device"),
+        }),
+    }
+}
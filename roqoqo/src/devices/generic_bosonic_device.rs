@@ -0,0 +1,298 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use super::BosonicDevice;
+use crate::RoqoqoError;
+use crate::RoqoqoVersionSerializable;
+
+/// A generic bosonic device with gate times, photon loss rates and detector efficiencies for every mode.
+///
+/// Two-mode gates are only available between the pairs of modes for which a gate time has been set,
+/// so the gate times of [crate::operations::BeamSplitter] define the connectivity of the device.
+///
+/// # Note
+///
+/// GenericBosonicDevice uses nested HashMaps to represent the most general device connectivity.
+/// The memory usage will be inefficient for devices with large mode numbers.
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(from = "GenericBosonicDeviceSerialize"))]
+#[cfg_attr(feature = "serialize", serde(into = "GenericBosonicDeviceSerialize"))]
+pub struct GenericBosonicDevice {
+    /// The number of modes
+    pub number_modes: usize,
+    /// Gate times for all single-mode gates
+    pub single_mode_gates: HashMap<String, HashMap<usize, f64>>,
+    /// Gate times for all two-mode gates
+    pub two_mode_gates: HashMap<String, TwoModeGates>,
+    /// Photon loss rates for all modes
+    pub loss_rates: HashMap<usize, f64>,
+    /// Photon detector efficiencies for all modes
+    pub detector_efficiencies: HashMap<usize, f64>,
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for GenericBosonicDevice {
+    fn schema_name() -> String {
+        "GenericBosonicDevice".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <GenericBosonicDeviceSerialize>::json_schema(gen)
+    }
+}
+
+type TwoModeGates = HashMap<(usize, usize), f64>;
+type TwoModeGatesVec = Vec<((usize, usize), f64)>;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json_schema", schemars(rename = "GenericBosonicDevice"))]
+struct GenericBosonicDeviceSerialize {
+    /// The number of modes
+    number_modes: usize,
+    /// Gate times for all single-mode gates
+    single_mode_gates: HashMap<String, Vec<(usize, f64)>>,
+    /// Gate times for all two-mode gates
+    two_mode_gates: HashMap<String, TwoModeGatesVec>,
+    /// Photon loss rates for all modes
+    loss_rates: Vec<(usize, f64)>,
+    /// Photon detector efficiencies for all modes
+    detector_efficiencies: Vec<(usize, f64)>,
+    _roqoqo_version: RoqoqoVersionSerializable,
+}
+
+impl From<GenericBosonicDeviceSerialize> for GenericBosonicDevice {
+    fn from(value: GenericBosonicDeviceSerialize) -> Self {
+        GenericBosonicDevice {
+            number_modes: value.number_modes,
+            single_mode_gates: value
+                .single_mode_gates
+                .into_iter()
+                .map(|(name, times)| (name, times.into_iter().collect()))
+                .collect(),
+            two_mode_gates: value
+                .two_mode_gates
+                .into_iter()
+                .map(|(name, times)| (name, times.into_iter().collect()))
+                .collect(),
+            loss_rates: value.loss_rates.into_iter().collect(),
+            detector_efficiencies: value.detector_efficiencies.into_iter().collect(),
+        }
+    }
+}
+
+impl From<GenericBosonicDevice> for GenericBosonicDeviceSerialize {
+    fn from(value: GenericBosonicDevice) -> Self {
+        let current_version = RoqoqoVersionSerializable {
+            major_version: 1,
+            minor_version: 20,
+        };
+        GenericBosonicDeviceSerialize {
+            number_modes: value.number_modes,
+            single_mode_gates: value
+                .single_mode_gates
+                .into_iter()
+                .map(|(name, times)| (name, times.into_iter().collect()))
+                .collect(),
+            two_mode_gates: value
+                .two_mode_gates
+                .into_iter()
+                .map(|(name, times)| (name, times.into_iter().collect()))
+                .collect(),
+            loss_rates: value.loss_rates.into_iter().collect(),
+            detector_efficiencies: value.detector_efficiencies.into_iter().collect(),
+            _roqoqo_version: current_version,
+        }
+    }
+}
+
+impl GenericBosonicDevice {
+    /// Creates a new GenericBosonicDevice.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of modes in the device.
+    ///
+    pub fn new(number_modes: usize) -> Self {
+        Self {
+            number_modes,
+            single_mode_gates: HashMap::new(),
+            two_mode_gates: HashMap::new(),
+            loss_rates: HashMap::new(),
+            detector_efficiencies: HashMap::new(),
+        }
+    }
+
+    /// Setting the gate time of a single-mode gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the single-mode gate.
+    /// * `mode` - The mode for which the gate time is set.
+    /// * `gate_time` - gate time for the given gate.
+    pub fn set_single_mode_gate_time(
+        &mut self,
+        gate: &str,
+        mode: usize,
+        gate_time: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.check_mode(mode)?;
+        self.single_mode_gates
+            .entry(gate.to_string())
+            .or_default()
+            .insert(mode, gate_time);
+        Ok(())
+    }
+
+    /// Setting the gate time of a two-mode gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - hqslang name of the two-mode gate.
+    /// * `mode_0` - The first mode for which the gate time is set.
+    /// * `mode_1` - The second mode for which the gate time is set.
+    /// * `gate_time` - gate time for the given gate.
+    pub fn set_two_mode_gate_time(
+        &mut self,
+        gate: &str,
+        mode_0: usize,
+        mode_1: usize,
+        gate_time: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.check_mode(mode_0)?;
+        self.check_mode(mode_1)?;
+        if mode_0 == mode_1 {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Two-mode gate {gate} cannot act twice on mode {mode_0}"),
+            });
+        }
+        self.two_mode_gates
+            .entry(gate.to_string())
+            .or_default()
+            .insert((mode_0, mode_1), gate_time);
+        Ok(())
+    }
+
+    /// Setting the photon loss rate of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode for which the loss rate is set.
+    /// * `loss_rate` - The non-negative photon loss rate.
+    pub fn set_mode_loss_rate(&mut self, mode: usize, loss_rate: f64) -> Result<(), RoqoqoError> {
+        self.check_mode(mode)?;
+        if loss_rate < 0.0 {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Loss rate {loss_rate} of mode {mode} is negative"),
+            });
+        }
+        self.loss_rates.insert(mode, loss_rate);
+        Ok(())
+    }
+
+    /// Setting the efficiency of the photon detector measuring a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode for which the detector efficiency is set.
+    /// * `efficiency` - The probability that a photon is detected, between 0 and 1.
+    pub fn set_detector_efficiency(
+        &mut self,
+        mode: usize,
+        efficiency: f64,
+    ) -> Result<(), RoqoqoError> {
+        self.check_mode(mode)?;
+        if !(0.0..=1.0).contains(&efficiency) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Detector efficiency {efficiency} of mode {mode} is not between 0 and 1"
+                ),
+            });
+        }
+        self.detector_efficiencies.insert(mode, efficiency);
+        Ok(())
+    }
+
+    fn check_mode(&self, mode: usize) -> Result<(), RoqoqoError> {
+        if mode >= self.number_modes {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Mode {} larger than number modes {}",
+                    mode, self.number_modes
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Implements BosonicDevice trait for GenericBosonicDevice.
+///
+/// The BosonicDevice trait defines standard functions available for roqoqo bosonic devices.
+///
+impl BosonicDevice for GenericBosonicDevice {
+    fn number_modes(&self) -> usize {
+        self.number_modes
+    }
+
+    fn single_mode_gate_time(&self, hqslang: &str, mode: &usize) -> Option<f64> {
+        self.single_mode_gates
+            .get(hqslang)
+            .and_then(|times| times.get(mode).copied())
+    }
+
+    fn two_mode_gate_time(&self, hqslang: &str, mode_0: &usize, mode_1: &usize) -> Option<f64> {
+        self.two_mode_gates
+            .get(hqslang)
+            .and_then(|times| times.get(&(*mode_0, *mode_1)).copied())
+    }
+
+    fn single_mode_gate_names(&self) -> Vec<String> {
+        self.single_mode_gates.keys().cloned().collect()
+    }
+
+    fn two_mode_gate_names(&self) -> Vec<String> {
+        self.two_mode_gates.keys().cloned().collect()
+    }
+
+    fn mode_loss_rate(&self, mode: &usize) -> Option<f64> {
+        self.loss_rates.get(mode).copied()
+    }
+
+    fn detector_efficiency(&self, mode: &usize) -> Option<f64> {
+        self.detector_efficiencies.get(mode).copied()
+    }
+
+    fn two_mode_edges(&self) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        if let Some(times) = self.two_mode_gates.get("BeamSplitter") {
+            for (mode_0, mode_1) in times.keys() {
+                let edge = (*mode_0.min(mode_1), *mode_0.max(mode_1));
+                if !edges.contains(&edge) {
+                    edges.push(edge);
+                }
+            }
+        }
+        edges.sort_unstable();
+        edges
+    }
+
+    fn to_generic_bosonic_device(&self) -> GenericBosonicDevice {
+        self.clone()
+    }
+}
+
+impl crate::operations::SupportedVersion for GenericBosonicDevice {}
@@ -46,6 +46,12 @@ mod all_to_all;
 pub use all_to_all::AllToAllDevice;
 mod square_lattice;
 pub use square_lattice::SquareLatticeDevice;
+mod generic_bosonic_device;
+pub use generic_bosonic_device::GenericBosonicDevice;
+#[cfg(feature = "unstable_analog_operations")]
+mod generic_analog_device;
+#[cfg(feature = "unstable_analog_operations")]
+pub use generic_analog_device::GenericAnalogDevice;
 // use crate::RoqoqoError;
 // use std::collections::HashMap;
 
@@ -237,6 +243,221 @@ pub trait Device {
     fn to_generic_device(&self) -> GenericDevice;
 }
 
+/// Trait for roqoqo devices operating on bosonic modes.
+///
+/// Defines the standard functions available for devices executing bosonic operations
+/// like [crate::operations::Squeezing] or [crate::operations::BeamSplitter].
+/// The noise model of a bosonic device consists of a continuous photon loss rate of every mode
+/// and the efficiency of the photon detector measuring a mode.
+///
+pub trait BosonicDevice {
+    /// Returns the number of bosonic modes of the device.
+    ///
+    /// # Returns
+    ///
+    /// The number of modes in the device.
+    ///
+    fn number_modes(&self) -> usize;
+
+    /// Returns the gate time of a single-mode operation if the operation is available on the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a single-mode gate.
+    /// * `mode` - The mode the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Some<f64>` - The gate time.
+    /// * `None` - The gate is not available on the device.
+    ///
+    fn single_mode_gate_time(&self, hqslang: &str, mode: &usize) -> Option<f64>;
+
+    /// Returns the gate time of a two-mode operation if the operation is available on the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a two-mode gate.
+    /// * `mode_0` - The first mode the gate acts on.
+    /// * `mode_1` - The second mode the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Some<f64>` - The gate time.
+    /// * `None` - The gate is not available on the device.
+    ///
+    fn two_mode_gate_time(&self, hqslang: &str, mode_0: &usize, mode_1: &usize) -> Option<f64>;
+
+    /// Returns the names of the single-mode operations available on the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The list of gate names.
+    ///
+    fn single_mode_gate_names(&self) -> Vec<String> {
+        self.to_generic_bosonic_device().single_mode_gate_names()
+    }
+
+    /// Returns the names of the two-mode operations available on the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The list of gate names.
+    ///
+    fn two_mode_gate_names(&self) -> Vec<String> {
+        self.to_generic_bosonic_device().two_mode_gate_names()
+    }
+
+    /// Returns the rate of photon loss of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode for which the loss rate is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Some<f64>` - The loss rate.
+    /// * `None` - No loss rate is set for the mode.
+    ///
+    fn mode_loss_rate(&self, mode: &usize) -> Option<f64>;
+
+    /// Returns the efficiency of the photon detector measuring a mode.
+    ///
+    /// The efficiency is the probability that a photon in the mode is detected.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode for which the detector efficiency is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Some<f64>` - The detector efficiency between 0 and 1.
+    /// * `None` - No detector efficiency is set for the mode.
+    ///
+    fn detector_efficiency(&self, mode: &usize) -> Option<f64>;
+
+    /// Returns the list of pairs of modes linked by a [crate::operations::BeamSplitter] in the device.
+    ///
+    /// Similar to [Device::two_qubit_edges], the returned vector is a graph-library independent
+    /// representation of the undirected connectivity graph of the modes of the device.
+    ///
+    /// # Returns
+    ///
+    /// A list (Vec) of pairs of modes linked by a beam splitter, with the smaller mode first.
+    ///
+    fn two_mode_edges(&self) -> Vec<(usize, usize)>;
+
+    /// Turns BosonicDevice into GenericBosonicDevice
+    ///
+    /// Can be used as a generic interface for bosonic devices when a boxed dyn trait object cannot be used
+    /// (for example when the interface needs to be serialized)
+    fn to_generic_bosonic_device(&self) -> GenericBosonicDevice;
+}
+
+#[cfg(feature = "unstable_analog_operations")]
+/// Trait for roqoqo devices evolving qubits under analog spin Hamiltonians.
+///
+/// Defines the standard functions available for devices executing analog operations
+/// like [crate::operations::ApplyConstantPauliHamiltonian].
+/// An analog device supports single-qubit Pauli terms and two-qubit Pauli interaction terms
+/// with coefficients between a lower and an upper bound.
+/// Evolution times are limited by a maximum time and have to be multiples of the time resolution.
+///
+/// Pauli terms are given by their Pauli operators, e.g. "X" for a single qubit or "ZZ" for a pair of qubits.
+///
+pub trait AnalogDevice {
+    /// Returns the number of qubits of the device.
+    ///
+    /// # Returns
+    ///
+    /// The number of qubits in the device.
+    ///
+    fn number_qubits(&self) -> usize;
+
+    /// Returns the coefficient bounds of a single-qubit Pauli term if the term is supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the term acts on.
+    /// * `pauli` - The Pauli operator of the term, "X", "Y" or "Z".
+    ///
+    /// # Returns
+    ///
+    /// * `Some<(f64, f64)>` - The minimal and maximal coefficient of the term.
+    /// * `None` - The term is not supported on the device.
+    ///
+    fn single_qubit_term_bounds(&self, qubit: &usize, pauli: &str) -> Option<(f64, f64)>;
+
+    /// Returns the coefficient bounds of a two-qubit Pauli interaction term if the term is supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit_0` - The first qubit the term acts on.
+    /// * `qubit_1` - The second qubit the term acts on.
+    /// * `paulis` - The Pauli operators acting on the first and the second qubit, e.g. "XZ".
+    ///
+    /// # Returns
+    ///
+    /// * `Some<(f64, f64)>` - The minimal and maximal coefficient of the term.
+    /// * `None` - The term is not supported on the device.
+    ///
+    fn two_qubit_term_bounds(
+        &self,
+        qubit_0: &usize,
+        qubit_1: &usize,
+        paulis: &str,
+    ) -> Option<(f64, f64)>;
+
+    /// Returns the maximal duration of an analog evolution on the device.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The maximal evolution time.
+    ///
+    fn max_time(&self) -> f64;
+
+    /// Returns the time resolution of analog evolutions on the device.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The time resolution, evolution times have to be multiples of it. Zero for continuous times.
+    ///
+    fn time_resolution(&self) -> f64;
+
+    /// Checks whether an analog operation can be executed on the device.
+    ///
+    /// For [crate::operations::ApplyConstantPauliHamiltonian] every term of the Hamiltonian has to be supported
+    /// with its coefficient within the bounds, and the evolution time has to be a multiple of the time resolution
+    /// not larger than the maximal time.
+    /// For [crate::operations::ApplyTimeDependentPauliHamiltonian] the schedule has to be valid,
+    /// every time of the range has to be a multiple of the time resolution not larger than the maximal time
+    /// and the terms of every piecewise-constant segment have to be supported within the bounds.
+    /// Identity terms only contribute a global phase and are always supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The analog operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The operation can be executed on the device.
+    /// * `Err(RoqoqoError::GenericError)` - The operation cannot be executed on the device.
+    ///
+    fn validate_analog_operation(
+        &self,
+        operation: &crate::operations::Operation,
+    ) -> Result<(), crate::RoqoqoError> {
+        generic_analog_device::validate_analog_operation(self, operation)
+    }
+
+    /// Returns a copy of the device as a GenericAnalogDevice.
+    ///
+    /// # Returns
+    ///
+    /// * `GenericAnalogDevice` - The device with the same supported terms, bounds and times.
+    ///
+    fn to_generic_analog_device(&self) -> GenericAnalogDevice;
+}
 #[cfg(feature = "unstable_qoqo_devices")]
 /// Trait for new qoqo devices.
 ///
@@ -434,6 +434,7 @@ mod circuit;
 pub use circuit::Circuit;
 #[doc(hidden)]
 pub use circuit::*;
+mod circuit_drawer;
 #[cfg(feature = "circuitdag")]
 mod circuitdag;
 #[cfg(feature = "circuitdag")]
@@ -449,3 +450,5 @@ pub mod registers;
 pub use quantum_program::QuantumProgram;
 
 pub mod noise_models;
+pub mod passes;
+pub mod simulators;
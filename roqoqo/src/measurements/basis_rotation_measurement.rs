@@ -11,7 +11,7 @@
 // limitations under the License.
 
 use super::*;
-use ndarray::{Array1, Array2};
+use ndarray::{Array1, Array2, Axis};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +28,29 @@ pub struct PauliZProduct {
     pub input: PauliZProductInput,
 }
 
+/// Expectation values of a [PauliZProduct] measurement together with their statistical uncertainties.
+///
+/// The shot-noise variance of an expectation value `H` is the variance of its estimator when every
+/// readout register is measured once, `sum_r Var(H_r)` with `H_r` the part of `H` measured in readout `r`.
+/// It is obtained from the covariances of the Pauli products measured in the same readout register.
+/// Pauli products from different readout registers are measured in separate circuits and
+/// their covariance is not accessible, it is treated as zero.
+/// The shot-noise variance therefore equals the variance `<H^2> - <H>^2` of the observable only
+/// when all Pauli products of `H` are measured in the same readout register.
+/// Expectation values defined by symbolic expressions are linearized around the measured Pauli products.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PauliZProductStatistics {
+    /// The measured expectation values.
+    pub expectation_values: HashMap<String, f64>,
+    /// The shot-noise variances of the estimators of the expectation values.
+    pub shot_noise_variances: HashMap<String, f64>,
+    /// The standard errors of the measured expectation values.
+    pub standard_errors: HashMap<String, f64>,
+    /// The single-shot covariance matrix of the Pauli products, indexed by the Pauli product indices.
+    /// Entries between Pauli products from different readout registers are zero.
+    pub pauli_product_covariances: Array2<f64>,
+}
+
 impl Measure for PauliZProduct {
     /// Returns the constant Circuit that is executed before each Circuit in circuits.
     ///
@@ -84,6 +107,58 @@ impl Measure for PauliZProduct {
             input: self.input.clone(),
         })
     }
+
+    /// Returns clone of Measurement with the given symbols replaced and the other symbols kept symbolic.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the symbols to substitute in the Circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuits with the given symbols substituted.
+    /// * `Err(RoqoqoError)` - The substitution failed.
+    fn bind_parameters(&self, values: HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let new_constant_circuit = match &self.constant_circuit {
+            None => None,
+            Some(c) => Some(c.bind_parameters(&values)?),
+        };
+        let mut new_circuits = Vec::new();
+        for circ in self.circuits.iter() {
+            new_circuits.push(circ.bind_parameters(&values)?)
+        }
+        Ok(Self {
+            constant_circuit: new_constant_circuit,
+            circuits: new_circuits,
+            input: self.input.clone(),
+        })
+    }
+
+    /// Returns clone of Measurement with the given symbols renamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The new names of the symbols to rename in the Circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuits with the given symbols renamed.
+    /// * `Err(RoqoqoError)` - The renaming failed.
+    fn rename_parameters(&self, mapping: HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let new_constant_circuit = match &self.constant_circuit {
+            None => None,
+            Some(c) => Some(c.rename_parameters(&mapping)?),
+        };
+        let mut new_circuits = Vec::new();
+        for circ in self.circuits.iter() {
+            new_circuits.push(circ.rename_parameters(&mapping)?)
+        }
+        Ok(Self {
+            constant_circuit: new_constant_circuit,
+            circuits: new_circuits,
+            input: self.input.clone(),
+        })
+    }
 }
 
 impl MeasureExpectationValues for PauliZProduct {
@@ -109,6 +184,163 @@ impl MeasureExpectationValues for PauliZProduct {
         float_registers: HashMap<String, FloatOutputRegister>,
         complex_registers: HashMap<String, ComplexOutputRegister>,
     ) -> Result<Option<HashMap<String, f64>>, RoqoqoError> {
+        let single_shot_dict = self.single_shot_pauli_products(&bit_registers)?;
+        let pauli_products = self.pauli_product_expectation_values(&single_shot_dict)?;
+        // Evaluating expectation values
+        let mut results: HashMap<String, f64> = HashMap::new();
+        for (name, evaluation) in self.input.measured_exp_vals.iter() {
+            results.insert(name.clone(), evaluate_exp_val(evaluation, &pauli_products)?);
+        }
+
+        Ok(Some(results))
+    }
+}
+
+impl PauliZProduct {
+    /// Evaluates the expectation values together with their shot-noise variances and standard errors.
+    ///
+    /// The expectation values are identical to the ones returned by [MeasureExpectationValues::evaluate].
+    /// See [PauliZProductStatistics] for the definition of the shot-noise variances.
+    /// The standard error of an expectation value is the square root of the variance of its estimator,
+    /// using the sample covariances of the Pauli products divided by the number of measurements
+    /// of the corresponding readout register.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The classical bit registers as a HashMap with the register name as key.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PauliZProductStatistics)` - The measurement has been evaluated successfully.
+    /// * `Err([RoqoqoError::PauliZProductMeasurementError])` - An error occured in PauliZ product measurement.
+    pub fn evaluate_statistics(
+        &self,
+        bit_registers: HashMap<String, BitOutputRegister>,
+    ) -> Result<PauliZProductStatistics, RoqoqoError> {
+        let number_pauli_products = self.input.number_pauli_products;
+        let single_shot_dict = self.single_shot_pauli_products(&bit_registers)?;
+        let pauli_products = self.pauli_product_expectation_values(&single_shot_dict)?;
+
+        let extensions: Vec<&str> = if self.input.use_flipped_measurement {
+            vec!["", "_flipped"]
+        } else {
+            vec![""]
+        };
+        let weight = 1.0 / extensions.len() as f64;
+        let mut covariances: Array2<f64> =
+            Array2::zeros((number_pauli_products, number_pauli_products));
+        let mut estimator_covariances: Array2<f64> =
+            Array2::zeros((number_pauli_products, number_pauli_products));
+        for register_name in self.input.pauli_product_qubit_masks.keys() {
+            if register_name.ends_with("flipped") {
+                continue;
+            }
+            for extension in extensions.iter() {
+                let single_shot_pauli_products = single_shot_dict
+                    .get(&format!("{register_name}{extension}"))
+                    .ok_or(RoqoqoError::PauliZProductMeasurementError {
+                        msg: format!("Register name {register_name}{extension} not found"),
+                    })?;
+                let number_shots = single_shot_pauli_products.nrows();
+                let means = single_shot_pauli_products.mean_axis(Axis(0)).ok_or(
+                    RoqoqoError::PauliZProductMeasurementError {
+                        msg: format!("Register {register_name}{extension} is empty"),
+                    },
+                )?;
+                let centered = single_shot_pauli_products - &means;
+                let covariance = centered.t().dot(&centered) / number_shots as f64;
+                covariances += &(&covariance * weight);
+                // Sample covariance divided by the number of shots
+                let normalization = (number_shots.max(2) - 1) as f64;
+                estimator_covariances += &(covariance * (weight * weight / normalization));
+            }
+        }
+
+        let mut statistics = PauliZProductStatistics {
+            expectation_values: HashMap::new(),
+            shot_noise_variances: HashMap::new(),
+            standard_errors: HashMap::new(),
+            pauli_product_covariances: covariances,
+        };
+        for (name, evaluation) in self.input.measured_exp_vals.iter() {
+            let gradient = exp_val_gradient(evaluation, &pauli_products)?;
+            let variance = gradient.dot(&statistics.pauli_product_covariances.dot(&gradient));
+            let estimator_variance = gradient.dot(&estimator_covariances.dot(&gradient));
+            statistics
+                .expectation_values
+                .insert(name.clone(), evaluate_exp_val(evaluation, &pauli_products)?);
+            statistics
+                .shot_noise_variances
+                .insert(name.clone(), variance.max(0.0));
+            statistics
+                .standard_errors
+                .insert(name.clone(), estimator_variance.max(0.0).sqrt());
+        }
+        Ok(statistics)
+    }
+
+    // Returns the expectation values of all Pauli products from their single-shot values.
+    //
+    // Averages between normal and flipped readout when flipped measurement is used.
+    fn pauli_product_expectation_values(
+        &self,
+        single_shot_dict: &HashMap<String, Array2<f64>>,
+    ) -> Result<Array1<f64>, RoqoqoError> {
+        let mut pauli_product_dict: HashMap<String, Array1<f64>> = HashMap::new();
+        for (register_name, single_shot_pauli_products) in single_shot_dict.iter() {
+            let mut pauli_products_tmp: Array1<f64> =
+                Array1::zeros(self.input.number_pauli_products);
+            for i in 0..self.input.number_pauli_products {
+                pauli_products_tmp[i] = single_shot_pauli_products.column(i).mean().ok_or(
+                    RoqoqoError::PauliZProductMeasurementError {
+                        msg: format!("Column {i} out of index for sinlge_shot_pauli_products"),
+                    },
+                )?;
+            }
+            pauli_product_dict.insert(register_name.clone(), pauli_products_tmp);
+        }
+
+        let mut pauli_products: Array1<f64> = Array1::zeros(self.input.number_pauli_products);
+        for (register_name, _) in self.input.pauli_product_qubit_masks.iter() {
+            if !register_name.ends_with("flipped") {
+                // Create temporary averaged vector of pauli_products
+                // Averaging between normal and flipped readout when flipped measurement is used
+                if self.input.use_flipped_measurement {
+                    let tmp_pauli_products = (&pauli_product_dict
+                        .get(register_name.as_str())
+                        .ok_or(RoqoqoError::PauliZProductMeasurementError {
+                            msg: format!("Register name {register_name} not fount"),
+                        })?
+                        .view()
+                        + &pauli_product_dict
+                            .get(format!("{register_name}_flipped").as_str())
+                            .ok_or(RoqoqoError::PauliZProductMeasurementError {
+                                msg: format!("Register name {register_name}_flipped not fount"),
+                            })?
+                            .view())
+                        / 2.0;
+                    // reinserting in dict of pauli products
+                    pauli_products += &tmp_pauli_products.view();
+                } else {
+                    pauli_products += &pauli_product_dict
+                        .get(register_name.as_str())
+                        .ok_or(RoqoqoError::PauliZProductMeasurementError {
+                            msg: format!("Register name {register_name} not fount"),
+                        })?
+                        .view()
+                }
+            }
+        }
+        Ok(pauli_products)
+    }
+
+    // Returns the single-shot values of the Pauli products for every readout register (including flipped registers).
+    //
+    // The columns of Pauli products that are not measured in a register are zero.
+    fn single_shot_pauli_products(
+        &self,
+        bit_registers: &HashMap<String, BitOutputRegister>,
+    ) -> Result<HashMap<String, Array2<f64>>, RoqoqoError> {
         // todo replace with actual input
         let measurement_fidelities = vec![1.0; self.input.number_qubits];
 
@@ -133,7 +365,7 @@ impl MeasureExpectationValues for PauliZProduct {
         } else {
             flipped_and_extension = vec![(false, "")];
         }
-        let mut pauli_product_dict: HashMap<String, Array1<f64>> = HashMap::new();
+        let mut single_shot_dict: HashMap<String, Array2<f64>> = HashMap::new();
         for (register_name, mask) in self.input.pauli_product_qubit_masks.iter() {
             for (flip_measurement, extension) in flipped_and_extension.iter() {
                 let register = bit_registers
@@ -172,79 +404,14 @@ impl MeasureExpectationValues for PauliZProduct {
                         }
                     }
                 }
-                let mut pauli_products_tmp: Array1<f64> =
-                    Array1::zeros(self.input.number_pauli_products);
-                for i in 0..self.input.number_pauli_products {
-                    pauli_products_tmp[i] = single_shot_pauli_products.column(i).mean().ok_or(
-                        RoqoqoError::PauliZProductMeasurementError {
-                            msg: format!("Column {i} out of index for sinlge_shot_pauli_products"),
-                        },
-                    )?;
-                }
-                pauli_product_dict.insert(
+                single_shot_dict.insert(
                     format!("{}{}", register_name.as_str(), extension),
-                    pauli_products_tmp,
+                    single_shot_pauli_products,
                 );
             }
         }
 
-        let mut pauli_products: Array1<f64> = Array1::zeros(self.input.number_pauli_products);
-        for (register_name, _) in self.input.pauli_product_qubit_masks.iter() {
-            if !register_name.ends_with("flipped") {
-                // Create temporary averaged vector of pauli_products
-                // Averaging between normal and flipped readout when flipped measurement is used
-                if self.input.use_flipped_measurement {
-                    let tmp_pauli_products = (&pauli_product_dict
-                        .get(register_name.as_str())
-                        .ok_or(RoqoqoError::PauliZProductMeasurementError {
-                            msg: format!("Register name {register_name} not fount"),
-                        })?
-                        .view()
-                        + &pauli_product_dict
-                            .get(format!("{register_name}_flipped").as_str())
-                            .ok_or(RoqoqoError::PauliZProductMeasurementError {
-                                msg: format!("Register name {register_name}_flipped not fount"),
-                            })?
-                            .view())
-                        / 2.0;
-                    // reinserting in dict of pauli products
-                    pauli_products += &tmp_pauli_products.view();
-                } else {
-                    pauli_products += &pauli_product_dict
-                        .get(register_name.as_str())
-                        .ok_or(RoqoqoError::PauliZProductMeasurementError {
-                            msg: format!("Register name {register_name} not fount"),
-                        })?
-                        .view()
-                }
-            }
-        }
-        // Evaluating expectation values
-        let mut results: HashMap<String, f64> = HashMap::new();
-
-        for (name, evaluation) in self.input.measured_exp_vals.iter() {
-            results.insert(
-                name.clone(),
-                match evaluation {
-                    PauliProductsToExpVal::Linear(hm) => {
-                        let mut value: f64 = 0.0;
-                        for (index, coefficient) in hm {
-                            value += pauli_products[*index] * coefficient;
-                        }
-                        value
-                    }
-                    PauliProductsToExpVal::Symbolic(x) => {
-                        let mut calculator = qoqo_calculator::Calculator::new();
-                        for (ind, p) in pauli_products.iter().enumerate() {
-                            calculator.set_variable(format!("pauli_product_{ind}").as_str(), *p);
-                        }
-                        calculator.parse_get(x.clone())?
-                    }
-                },
-            );
-        }
-
-        Ok(Some(results))
+        Ok(single_shot_dict)
     }
 }
 
@@ -264,3 +431,55 @@ impl crate::operations::SupportedVersion for PauliZProduct {
         current_minimum_version
     }
 }
+
+// Evaluates an expectation value from the expectation values of the Pauli products
+fn evaluate_exp_val(
+    evaluation: &PauliProductsToExpVal,
+    pauli_products: &Array1<f64>,
+) -> Result<f64, RoqoqoError> {
+    Ok(match evaluation {
+        PauliProductsToExpVal::Linear(hm) => {
+            let mut value: f64 = 0.0;
+            for (index, coefficient) in hm {
+                value += pauli_products[*index] * coefficient;
+            }
+            value
+        }
+        PauliProductsToExpVal::Symbolic(x) => {
+            let mut calculator = qoqo_calculator::Calculator::new();
+            for (ind, p) in pauli_products.iter().enumerate() {
+                calculator.set_variable(format!("pauli_product_{ind}").as_str(), *p);
+            }
+            calculator.parse_get(x.clone())?
+        }
+    })
+}
+
+// Returns the derivatives of an expectation value with respect to the Pauli product expectation values
+//
+// Symbolic expressions are differentiated numerically with central differences.
+fn exp_val_gradient(
+    evaluation: &PauliProductsToExpVal,
+    pauli_products: &Array1<f64>,
+) -> Result<Array1<f64>, RoqoqoError> {
+    let mut gradient: Array1<f64> = Array1::zeros(pauli_products.len());
+    match evaluation {
+        PauliProductsToExpVal::Linear(hm) => {
+            for (index, coefficient) in hm {
+                gradient[*index] += coefficient;
+            }
+        }
+        PauliProductsToExpVal::Symbolic(_) => {
+            let step = 1e-6;
+            for index in 0..pauli_products.len() {
+                let mut shifted = pauli_products.clone();
+                shifted[index] += step;
+                let upper = evaluate_exp_val(evaluation, &shifted)?;
+                shifted[index] -= 2.0 * step;
+                let lower = evaluate_exp_val(evaluation, &shifted)?;
+                gradient[index] = (upper - lower) / (2.0 * step);
+            }
+        }
+    }
+    Ok(gradient)
+}
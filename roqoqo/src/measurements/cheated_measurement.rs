@@ -91,6 +91,58 @@ impl Measure for Cheated {
             input: self.input.clone(),
         })
     }
+
+    /// Returns clone of Measurement with the given symbols replaced and the other symbols kept symbolic.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the symbols to substitute in the Circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuits with the given symbols substituted.
+    /// * `Err(RoqoqoError)` - The substitution failed.
+    fn bind_parameters(&self, values: HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let new_constant_circuit = match &self.constant_circuit {
+            None => None,
+            Some(c) => Some(c.bind_parameters(&values)?),
+        };
+        let mut new_circuits = Vec::new();
+        for circ in self.circuits.iter() {
+            new_circuits.push(circ.bind_parameters(&values)?)
+        }
+        Ok(Self {
+            constant_circuit: new_constant_circuit,
+            circuits: new_circuits,
+            input: self.input.clone(),
+        })
+    }
+
+    /// Returns clone of Measurement with the given symbols renamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The new names of the symbols to rename in the Circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuits with the given symbols renamed.
+    /// * `Err(RoqoqoError)` - The renaming failed.
+    fn rename_parameters(&self, mapping: HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let new_constant_circuit = match &self.constant_circuit {
+            None => None,
+            Some(c) => Some(c.rename_parameters(&mapping)?),
+        };
+        let mut new_circuits = Vec::new();
+        for circ in self.circuits.iter() {
+            new_circuits.push(circ.rename_parameters(&mapping)?)
+        }
+        Ok(Self {
+            constant_circuit: new_constant_circuit,
+            circuits: new_circuits,
+            input: self.input.clone(),
+        })
+    }
 }
 
 impl MeasureExpectationValues for Cheated {
@@ -0,0 +1,754 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::measurements::{
+    basis_measurement_circuit, check_distinct_qubits, Measure, MeasureExpectationValues,
+    MEASUREMENT_BASES,
+};
+use crate::operations::{Hadamard, InvSGate, MeasureQubit, PauliX, PauliZ, SGate};
+use crate::passes::CutCircuit;
+use crate::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
+use crate::Circuit;
+use crate::RoqoqoError;
+use std::collections::HashMap;
+
+/// The state a cut wire is prepared in at the start of a fragment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub enum CutPreparation {
+    /// The |0> eigenstate of Z.
+    Zero,
+    /// The |1> eigenstate of Z.
+    One,
+    /// The |+> eigenstate of X.
+    Plus,
+    /// The |-> eigenstate of X.
+    Minus,
+    /// The |+i> eigenstate of Y.
+    PlusI,
+    /// The |-i> eigenstate of Y.
+    MinusI,
+}
+
+// Preparations of the identity, X, Y and Z terms of the cut with the sign of their contribution
+const PREPARATIONS: [[(CutPreparation, f64); 2]; 4] = [
+    [(CutPreparation::Zero, 1.0), (CutPreparation::One, 1.0)],
+    [(CutPreparation::Plus, 1.0), (CutPreparation::Minus, -1.0)],
+    [(CutPreparation::PlusI, 1.0), (CutPreparation::MinusI, -1.0)],
+    [(CutPreparation::Zero, 1.0), (CutPreparation::One, -1.0)],
+];
+
+const ALL_PREPARATIONS: [CutPreparation; 6] = [
+    CutPreparation::Zero,
+    CutPreparation::One,
+    CutPreparation::Plus,
+    CutPreparation::Minus,
+    CutPreparation::PlusI,
+    CutPreparation::MinusI,
+];
+
+impl CutPreparation {
+    // Returns the circuit preparing the state from |0>
+    fn circuit(&self, qubit: usize) -> Circuit {
+        let mut circuit = Circuit::new();
+        if matches!(
+            self,
+            CutPreparation::One | CutPreparation::Minus | CutPreparation::MinusI
+        ) {
+            circuit += PauliX::new(qubit);
+        }
+        if !matches!(self, CutPreparation::Zero | CutPreparation::One) {
+            circuit += Hadamard::new(qubit);
+        }
+        if matches!(self, CutPreparation::PlusI | CutPreparation::MinusI) {
+            circuit += SGate::new(qubit);
+        }
+        circuit
+    }
+}
+
+/// The single-qubit operation replacing one side of a cut gate.
+///
+/// The cut rotation `exp(i θ Z⊗Z)` is replaced by a quasi-probability decomposition into operations
+/// on the (control, target) sides, where the measured eigenvalue of Z multiplies the result:
+/// `cos²θ` for (Identity, Identity), `sin²θ` for (PauliZ, PauliZ),
+/// `cosθ sinθ` for (MeasureZ, InvSGate) and (InvSGate, MeasureZ),
+/// `-cosθ sinθ` for (MeasureZ, SGate) and (SGate, MeasureZ).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub enum CutGateOperation {
+    /// No operation.
+    Identity,
+    /// The Pauli Z gate.
+    PauliZ,
+    /// A mid-circuit measurement of Z.
+    MeasureZ,
+    /// The S gate, `exp(-i π/4 Z)` up to a global phase.
+    SGate,
+    /// The inverse S gate, `exp(i π/4 Z)` up to a global phase.
+    InvSGate,
+}
+
+// Operations on the (control, target) sides of the terms of a gate cut
+const GATE_CUT_TERMS: [[CutGateOperation; 2]; 6] = [
+    [CutGateOperation::Identity, CutGateOperation::Identity],
+    [CutGateOperation::PauliZ, CutGateOperation::PauliZ],
+    [CutGateOperation::MeasureZ, CutGateOperation::InvSGate],
+    [CutGateOperation::MeasureZ, CutGateOperation::SGate],
+    [CutGateOperation::InvSGate, CutGateOperation::MeasureZ],
+    [CutGateOperation::SGate, CutGateOperation::MeasureZ],
+];
+
+const ALL_GATE_CUT_OPERATIONS: [CutGateOperation; 5] = [
+    CutGateOperation::Identity,
+    CutGateOperation::PauliZ,
+    CutGateOperation::MeasureZ,
+    CutGateOperation::SGate,
+    CutGateOperation::InvSGate,
+];
+
+// Coefficient of a term of the gate cut of exp(i angle Z⊗Z)
+fn gate_cut_coefficient(term: usize, angle: f64) -> f64 {
+    let (sin, cos) = angle.sin_cos();
+    match term {
+        0 => cos * cos,
+        1 => sin * sin,
+        2 | 4 => cos * sin,
+        _ => -cos * sin,
+    }
+}
+
+impl CutGateOperation {
+    // Returns the circuit of the operation, a measurement is written to bit `index` of the readout
+    fn circuit(&self, qubit: usize, readout: &str, index: usize) -> Circuit {
+        let mut circuit = Circuit::new();
+        match self {
+            CutGateOperation::Identity => (),
+            CutGateOperation::PauliZ => circuit += PauliZ::new(qubit),
+            CutGateOperation::MeasureZ => {
+                circuit += MeasureQubit::new(qubit, readout.to_string(), index)
+            }
+            CutGateOperation::SGate => circuit += SGate::new(qubit),
+            CutGateOperation::InvSGate => circuit += InvSGate::new(qubit),
+        }
+        circuit
+    }
+}
+
+/// A variant of a fragment with fixed preparations of its input cuts, measurement bases of its output cuts
+/// and operations on the sides of its cut gates.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct CutVariant {
+    /// The index of the fragment.
+    pub fragment: usize,
+    /// The prepared states in the order of the input cuts of the fragment.
+    pub preparations: Vec<CutPreparation>,
+    /// The measurement bases (X, Y or Z) in the order of the output cuts of the fragment.
+    pub bases: String,
+    /// The operations in the order of the cut gate sides of the fragment.
+    pub gate_operations: Vec<CutGateOperation>,
+}
+
+/// Circuit cutting measurement.
+///
+/// Measures the expectation values of products of Pauli Z operators on a circuit that has been cut
+/// into fragments by [crate::passes::cut_circuit].
+/// Every wire cut is replaced by the quasi-probability decomposition of the identity channel
+/// `ρ = 1/2 Σ_P Tr(P ρ) P` over the Pauli operators `P = I, X, Y, Z`:
+/// the wire is measured in the basis of `P` at the end of the fragment it leaves
+/// and the eigenstates of `P` are prepared at the start of the fragment it enters.
+/// Every cut gate is replaced by the quasi-probability decomposition of its rotation `exp(i θ Z⊗Z)`
+/// into six terms of single-qubit operations, see [CutGateOperation].
+/// The circuit with index `k` runs `variants[k]` and writes the measured values of all local qubits
+/// of the fragment into the readout register `{readout}_{k}`,
+/// followed by the mid-circuit measurements of the cut gate sides of the fragment.
+/// A backend running a measurement with cut gates therefore needs to support mid-circuit measurements.
+/// The expectation values of the original circuit are recombined by summing over the Pauli operators of all wire cuts
+/// and the terms of all gate cuts, so the number of terms grows exponentially with the number of cuts.
+///
+/// The measurement has no constant circuit, the fragments contain all operations of the original circuit.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct CircuitCutting {
+    /// Constant Circuit that is executed before each Circuit in circuits (always None).
+    pub constant_circuit: Option<Circuit>,
+    /// Collection of quantum circuits for the separate fragment variants.
+    pub circuits: Vec<Circuit>,
+    /// The fragments of the cut circuit.
+    pub cut_circuit: CutCircuit,
+    /// The prefix of the readout registers.
+    pub readout: String,
+    /// The fragment variant of every circuit.
+    pub variants: Vec<CutVariant>,
+    /// The measured products of Pauli Z operators given by the original qubits they act on.
+    pub pauli_products: Vec<Vec<usize>>,
+    /// The expectation values given by linear combinations of the measured Pauli products.
+    pub linear_exp_vals: HashMap<String, HashMap<usize, f64>>,
+}
+
+impl CircuitCutting {
+    /// Creates a new circuit cutting measurement.
+    ///
+    /// # Arguments
+    ///
+    /// * `cut_circuit` - The fragments of the cut circuit.
+    /// * `readout` - The prefix of the readout registers.
+    /// * `number_measurements` - The number of projective measurements per fragment variant.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new circuit cutting measurement.
+    pub fn new(cut_circuit: CutCircuit, readout: String, number_measurements: usize) -> Self {
+        let mut circuits = Vec::new();
+        let mut variants = Vec::new();
+        for (index, fragment) in cut_circuit.fragments.iter().enumerate() {
+            let measured_qubits: Vec<usize> = (0..fragment.number_qubits).collect();
+            let register_length = fragment.number_qubits + fragment.gate_cuts.len();
+            for preparations in product(&ALL_PREPARATIONS, fragment.input_cuts.len()) {
+                for bases in product(&MEASUREMENT_BASES, fragment.output_cuts.len()) {
+                    for gate_operations in
+                        product(&ALL_GATE_CUT_OPERATIONS, fragment.gate_cuts.len())
+                    {
+                        let name = format!("{readout}_{}", circuits.len());
+                        let mut circuit = Circuit::new();
+                        for ((_, qubit), preparation) in
+                            fragment.input_cuts.iter().zip(preparations.iter())
+                        {
+                            circuit += preparation.circuit(*qubit);
+                        }
+                        for (position, operation) in
+                            fragment.circuit.iter().map(Some).chain([None]).enumerate()
+                        {
+                            for (side_index, (side, gate_operation)) in fragment
+                                .gate_cuts
+                                .iter()
+                                .zip(gate_operations.iter())
+                                .enumerate()
+                                .filter(|(_, (side, _))| side.position == position)
+                            {
+                                circuit += gate_operation.circuit(
+                                    side.qubit,
+                                    &name,
+                                    fragment.number_qubits + side_index,
+                                );
+                            }
+                            if let Some(operation) = operation {
+                                circuit += operation.clone();
+                            }
+                        }
+                        circuit += basis_measurement_circuit(
+                            fragment
+                                .output_cuts
+                                .iter()
+                                .map(|(_, qubit)| *qubit)
+                                .zip(bases.iter().copied()),
+                            &measured_qubits,
+                            register_length,
+                            name,
+                            number_measurements,
+                        );
+                        circuits.push(circuit);
+                        variants.push(CutVariant {
+                            fragment: index,
+                            preparations: preparations.clone(),
+                            bases: bases.iter().collect(),
+                            gate_operations,
+                        });
+                    }
+                }
+            }
+        }
+        Self {
+            constant_circuit: None,
+            circuits,
+            cut_circuit,
+            readout,
+            variants,
+            pauli_products: Vec::new(),
+            linear_exp_vals: HashMap::new(),
+        }
+    }
+
+    /// Adds a measured product of Pauli Z operators and returns its index.
+    ///
+    /// When the Pauli product has already been added the function only returns its index.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits` - The original qubits the Pauli Z operators act on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The index of the Pauli product.
+    /// * `Err(RoqoqoError::GenericError)` - The qubits are not distinct.
+    pub fn add_pauliz_product(&mut self, mut qubits: Vec<usize>) -> Result<usize, RoqoqoError> {
+        qubits.sort_unstable();
+        check_distinct_qubits(&qubits, "Pauli product")?;
+        if let Some(index) = self
+            .pauli_products
+            .iter()
+            .position(|product| product == &qubits)
+        {
+            return Ok(index);
+        }
+        self.pauli_products.push(qubits);
+        Ok(self.pauli_products.len() - 1)
+    }
+
+    /// Adds an expectation value defined by a linear combination of Pauli products.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the expectation value.
+    /// * `linear` - The linear combination as a map between Pauli product index and coefficient.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The expectation value was added.
+    /// * `Err(RoqoqoError::ExpValUsedTwice)` - The name of the expectation value is already taken.
+    /// * `Err(RoqoqoError::GenericError)` - A Pauli product index has not been added.
+    pub fn add_linear_exp_val(
+        &mut self,
+        name: String,
+        linear: HashMap<usize, f64>,
+    ) -> Result<(), RoqoqoError> {
+        if let Some(index) = linear
+            .keys()
+            .find(|index| **index >= self.pauli_products.len())
+        {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Pauli product {index} has not been added"),
+            });
+        }
+        if self.linear_exp_vals.contains_key(&name) {
+            return Err(RoqoqoError::ExpValUsedTwice { name });
+        }
+        self.linear_exp_vals.insert(name, linear);
+        Ok(())
+    }
+
+    /// Recombines the expectation value of a product of Pauli Z operators on the original circuit.
+    ///
+    /// Qubits the original circuit does not act on are in the |0> state and contribute a factor of one.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The measured bit registers.
+    /// * `qubits` - The original qubits the Pauli Z operators act on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The recombined expectation value.
+    /// * `Err(RoqoqoError::GenericError)` - A readout register is missing or empty, or the variants do not match the fragments.
+    pub fn pauliz_product_expectation_value(
+        &self,
+        bit_registers: &HashMap<String, BitOutputRegister>,
+        qubits: &[usize],
+    ) -> Result<f64, RoqoqoError> {
+        #[allow(clippy::type_complexity)]
+        let variant_index: HashMap<
+            (usize, &[CutPreparation], &str, &[CutGateOperation]),
+            usize,
+        > = self
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(index, variant)| {
+                (
+                    (
+                        variant.fragment,
+                        variant.preparations.as_slice(),
+                        variant.bases.as_str(),
+                        variant.gate_operations.as_slice(),
+                    ),
+                    index,
+                )
+            })
+            .collect();
+        let observed: Vec<Vec<usize>> = self
+            .cut_circuit
+            .fragments
+            .iter()
+            .map(|fragment| {
+                qubits
+                    .iter()
+                    .filter_map(|qubit| fragment.output_qubits.get(qubit).copied())
+                    .collect()
+            })
+            .collect();
+        let number_cuts = self.cut_circuit.cut_qubits.len();
+        if let Some((cut, _)) = self
+            .cut_circuit
+            .fragments
+            .iter()
+            .flat_map(|fragment| {
+                fragment
+                    .input_cuts
+                    .iter()
+                    .chain(fragment.output_cuts.iter())
+            })
+            .find(|(cut, _)| *cut >= number_cuts)
+        {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Cut {cut} of a fragment is not one of the {number_cuts} cuts of the circuit"
+                ),
+            });
+        }
+        let number_gate_cuts = self.cut_circuit.cut_gates.len();
+        if let Some(side) = self
+            .cut_circuit
+            .fragments
+            .iter()
+            .flat_map(|fragment| fragment.gate_cuts.iter())
+            .find(|side| side.cut >= number_gate_cuts || side.side > 1)
+        {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Side {} of gate cut {} of a fragment is not a side of one of the {number_gate_cuts} cut gates of the circuit",
+                    side.side, side.cut
+                ),
+            });
+        }
+        let mut expectation_value = 0.0;
+        // Sum over the Pauli operators (I, X, Y, Z) of all wire cuts and the terms of all gate cuts
+        for (labels, terms) in product(&[0, 1, 2, 3], number_cuts)
+            .into_iter()
+            .flat_map(|labels| {
+                product(&[0, 1, 2, 3, 4, 5], number_gate_cuts)
+                    .into_iter()
+                    .map(move |terms| (labels.clone(), terms))
+            })
+        {
+            let mut term = 0.5_f64.powi(number_cuts as i32);
+            for (gate, term_index) in self.cut_circuit.cut_gates.iter().zip(terms.iter()) {
+                term *= gate_cut_coefficient(*term_index, gate.angle);
+            }
+            if term == 0.0 {
+                continue;
+            }
+            for (index, fragment) in self.cut_circuit.fragments.iter().enumerate() {
+                let bases: String = fragment
+                    .output_cuts
+                    .iter()
+                    .map(|(cut, _)| match labels[*cut] {
+                        1 => 'X',
+                        2 => 'Y',
+                        _ => 'Z',
+                    })
+                    .collect();
+                let mut measured_qubits = observed[index].clone();
+                measured_qubits.extend(
+                    fragment
+                        .output_cuts
+                        .iter()
+                        .filter(|(cut, _)| labels[*cut] != 0)
+                        .map(|(_, qubit)| *qubit),
+                );
+                let gate_operations: Vec<CutGateOperation> = fragment
+                    .gate_cuts
+                    .iter()
+                    .map(|side| GATE_CUT_TERMS[terms[side.cut]][side.side])
+                    .collect();
+                // The measured eigenvalues of the gate cut sides multiply the result
+                measured_qubits.extend(
+                    gate_operations
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, operation)| **operation == CutGateOperation::MeasureZ)
+                        .map(|(side_index, _)| fragment.number_qubits + side_index),
+                );
+                let mut fragment_value = 0.0;
+                for choice in product(&[0, 1], fragment.input_cuts.len()) {
+                    let mut sign = 1.0;
+                    let preparations: Vec<CutPreparation> = fragment
+                        .input_cuts
+                        .iter()
+                        .zip(choice.iter())
+                        .map(|((cut, _), eigenstate)| {
+                            let (preparation, eigenvalue) = PREPARATIONS[labels[*cut]][*eigenstate];
+                            sign *= eigenvalue;
+                            preparation
+                        })
+                        .collect();
+                    let variant = *variant_index
+                        .get(&(
+                            index,
+                            preparations.as_slice(),
+                            bases.as_str(),
+                            gate_operations.as_slice(),
+                        ))
+                        .ok_or_else(|| RoqoqoError::GenericError {
+                            msg: format!(
+                                "No variant of fragment {index} with preparations {preparations:?}, bases {bases} and gate operations {gate_operations:?}"
+                            ),
+                        })?;
+                    fragment_value +=
+                        sign * self.parity_mean(bit_registers, variant, &measured_qubits)?;
+                }
+                term *= fragment_value;
+                if term == 0.0 {
+                    break;
+                }
+            }
+            expectation_value += term;
+        }
+        Ok(expectation_value)
+    }
+
+    // Mean of (-1)^parity of the measured local qubits over all shots of a variant
+    fn parity_mean(
+        &self,
+        bit_registers: &HashMap<String, BitOutputRegister>,
+        variant: usize,
+        qubits: &[usize],
+    ) -> Result<f64, RoqoqoError> {
+        let name = format!("{}_{}", self.readout, variant);
+        let register = bit_registers
+            .get(&name)
+            .ok_or_else(|| RoqoqoError::GenericError {
+                msg: format!("Readout register {name} not found"),
+            })?;
+        if register.is_empty() {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Readout register {name} is empty"),
+            });
+        }
+        let sum: f64 = register
+            .iter()
+            .map(|shot| {
+                let parity = qubits
+                    .iter()
+                    .filter(|qubit| shot.get(**qubit).copied().unwrap_or(false))
+                    .count()
+                    % 2;
+                if parity == 0 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            })
+            .sum();
+        Ok(sum / register.len() as f64)
+    }
+}
+
+impl Measure for CircuitCutting {
+    /// Returns the constant Circuit that is executed before each Circuit in circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `&Option<Circuit` - The constant Circuit (None if not defined).
+    fn constant_circuit(&self) -> &Option<Circuit> {
+        &self.constant_circuit
+    }
+
+    /// Returns iterator over circuits for measurement.
+    ///
+    /// # Returns
+    ///
+    /// * `Box<dyn Iterator<Item = &'a Circuit> + 'a>` - The quantum circuits.
+    fn circuits<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Circuit> + 'a> {
+        Box::new(self.circuits.iter())
+    }
+
+    /// Returns clone of Measurement with symbolic parameters replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `substituted_parameters` - The HashMap containing the substitutions to use in the Circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuits with the parameters substituted.
+    /// * `Err(RoqoqoError)` - The substitution failed.
+    fn substitute_parameters(
+        &self,
+        substituted_parameters: HashMap<String, f64>,
+    ) -> Result<Self, RoqoqoError> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (name, val) in substituted_parameters.iter() {
+            calculator.set_variable(name, *val)
+        }
+        let new_constant_circuit = match &self.constant_circuit {
+            None => None,
+            Some(c) => Some(c.substitute_parameters(&calculator)?),
+        };
+        let mut new_circuits = Vec::new();
+        for circ in self.circuits.iter() {
+            new_circuits.push(circ.substitute_parameters(&calculator)?)
+        }
+        let mut new_cut_circuit = self.cut_circuit.clone();
+        for fragment in new_cut_circuit.fragments.iter_mut() {
+            fragment.circuit = fragment.circuit.substitute_parameters(&calculator)?;
+        }
+        Ok(Self {
+            constant_circuit: new_constant_circuit,
+            circuits: new_circuits,
+            cut_circuit: new_cut_circuit,
+            readout: self.readout.clone(),
+            variants: self.variants.clone(),
+            pauli_products: self.pauli_products.clone(),
+            linear_exp_vals: self.linear_exp_vals.clone(),
+        })
+    }
+
+    /// Returns clone of Measurement with the given symbols replaced and the other symbols kept symbolic.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the symbols to substitute in the Circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuits with the given symbols substituted.
+    /// * `Err(RoqoqoError)` - The substitution failed.
+    fn bind_parameters(&self, values: HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let new_constant_circuit = match &self.constant_circuit {
+            None => None,
+            Some(c) => Some(c.bind_parameters(&values)?),
+        };
+        let mut new_circuits = Vec::new();
+        for circ in self.circuits.iter() {
+            new_circuits.push(circ.bind_parameters(&values)?)
+        }
+        let mut new_cut_circuit = self.cut_circuit.clone();
+        for fragment in new_cut_circuit.fragments.iter_mut() {
+            fragment.circuit = fragment.circuit.bind_parameters(&values)?;
+        }
+        Ok(Self {
+            constant_circuit: new_constant_circuit,
+            circuits: new_circuits,
+            cut_circuit: new_cut_circuit,
+            readout: self.readout.clone(),
+            variants: self.variants.clone(),
+            pauli_products: self.pauli_products.clone(),
+            linear_exp_vals: self.linear_exp_vals.clone(),
+        })
+    }
+
+    /// Returns clone of Measurement with the given symbols renamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The new names of the symbols to rename in the Circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuits with the given symbols renamed.
+    /// * `Err(RoqoqoError)` - The renaming failed.
+    fn rename_parameters(&self, mapping: HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let new_constant_circuit = match &self.constant_circuit {
+            None => None,
+            Some(c) => Some(c.rename_parameters(&mapping)?),
+        };
+        let mut new_circuits = Vec::new();
+        for circ in self.circuits.iter() {
+            new_circuits.push(circ.rename_parameters(&mapping)?)
+        }
+        let mut new_cut_circuit = self.cut_circuit.clone();
+        for fragment in new_cut_circuit.fragments.iter_mut() {
+            fragment.circuit = fragment.circuit.rename_parameters(&mapping)?;
+        }
+        Ok(Self {
+            constant_circuit: new_constant_circuit,
+            circuits: new_circuits,
+            cut_circuit: new_cut_circuit,
+            readout: self.readout.clone(),
+            variants: self.variants.clone(),
+            pauli_products: self.pauli_products.clone(),
+            linear_exp_vals: self.linear_exp_vals.clone(),
+        })
+    }
+}
+
+impl MeasureExpectationValues for CircuitCutting {
+    /// Recombines the expectation values of the original circuit from the fragment measurements.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The classical bit registers with the register name as key.
+    /// * `_float_registers` - The classical float registers (unused).
+    /// * `_complex_registers` - The classical complex registers (unused).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(HashMap<String, f64>))` - The recombined expectation values.
+    /// * `Err(RoqoqoError)` - The recombination failed.
+    fn evaluate(
+        &self,
+        bit_registers: HashMap<String, BitOutputRegister>,
+        _float_registers: HashMap<String, FloatOutputRegister>,
+        _complex_registers: HashMap<String, ComplexOutputRegister>,
+    ) -> Result<Option<HashMap<String, f64>>, RoqoqoError> {
+        let pauli_products: Vec<f64> = self
+            .pauli_products
+            .iter()
+            .map(|qubits| self.pauliz_product_expectation_value(&bit_registers, qubits))
+            .collect::<Result<Vec<f64>, RoqoqoError>>()?;
+        let mut results: HashMap<String, f64> = HashMap::new();
+        for (name, linear) in self.linear_exp_vals.iter() {
+            let mut value = 0.0;
+            for (index, coefficient) in linear {
+                let pauli_product =
+                    pauli_products
+                        .get(*index)
+                        .ok_or_else(|| RoqoqoError::GenericError {
+                            msg: format!(
+                                "Pauli product {index} of expectation value {name} is not defined"
+                            ),
+                        })?;
+                value += coefficient * pauli_product;
+            }
+            results.insert(name.clone(), value);
+        }
+        Ok(Some(results))
+    }
+}
+
+impl crate::operations::SupportedVersion for CircuitCutting {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        let mut current_minimum_version = (1, 21, 0);
+        if let Some(circuit) = self.constant_circuit() {
+            let comparison_version = circuit.minimum_supported_roqoqo_version();
+            crate::update_roqoqo_version(&mut current_minimum_version, comparison_version);
+        }
+        for circuit in self.circuits.iter() {
+            let comparison_version = circuit.minimum_supported_roqoqo_version();
+            crate::update_roqoqo_version(&mut current_minimum_version, comparison_version);
+        }
+        current_minimum_version
+    }
+}
+
+// All sequences of the given length with entries from the choices, the last entry changing fastest
+fn product<T: Copy>(choices: &[T], length: usize) -> Vec<Vec<T>> {
+    let mut sequences: Vec<Vec<T>> = vec![Vec::with_capacity(length)];
+    for _ in 0..length {
+        sequences = sequences
+            .into_iter()
+            .flat_map(|sequence| {
+                choices.iter().map(move |choice| {
+                    let mut extended = sequence.clone();
+                    extended.push(*choice);
+                    extended
+                })
+            })
+            .collect();
+    }
+    sequences
+}
@@ -0,0 +1,336 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::measurements::{
+    basis_measurement_circuit, check_distinct_qubits, impl_measure, MEASUREMENT_BASES,
+};
+use crate::registers::BitOutputRegister;
+use crate::Circuit;
+use crate::RoqoqoError;
+use ndarray::Array1;
+use num_complex::Complex64;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+/// Classical shadows measurement.
+///
+/// Measures the state prepared by the constant circuit after random single-qubit Clifford
+/// basis changes on the chosen qubits, so that every qubit is measured in a random X, Y or Z basis.
+/// The chosen bases are recorded in the measurement, every measured bit string is one snapshot of the
+/// classical shadow. Expectation values of arbitrary Pauli observables and fidelities with pure states
+/// are estimated from the snapshots with median-of-means estimation.
+///
+/// The circuit with index `k` writes into the readout register `{readout}_{k}` and measures in `bases[k]`,
+/// which contains the basis of every qubit in the order of `qubits`.
+/// Pauli products are labelled by strings of I, X, Y and Z in the order of `qubits`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct ClassicalShadows {
+    /// Constant Circuit preparing the measured state, executed before each Circuit in circuits.
+    pub constant_circuit: Option<Circuit>,
+    /// Collection of quantum circuits for the separate random basis changes.
+    pub circuits: Vec<Circuit>,
+    /// The measured qubits.
+    pub qubits: Vec<usize>,
+    /// The prefix of the readout registers.
+    pub readout: String,
+    /// The measured bases of every circuit.
+    pub bases: Vec<String>,
+}
+
+impl ClassicalShadows {
+    /// Creates a new classical shadows measurement.
+    ///
+    /// # Arguments
+    ///
+    /// * `constant_circuit` - The Circuit preparing the state.
+    /// * `qubits` - The measured qubits.
+    /// * `readout` - The prefix of the readout registers.
+    /// * `number_bases` - The number of random basis changes.
+    /// * `number_measurements` - The number of projective measurements per basis change.
+    /// * `seed` - Seed for the random choice of the bases.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new classical shadows measurement.
+    /// * `Err(RoqoqoError::GenericError)` - The qubits are empty or not distinct.
+    pub fn new(
+        constant_circuit: Option<Circuit>,
+        qubits: Vec<usize>,
+        readout: String,
+        number_bases: usize,
+        number_measurements: usize,
+        seed: u64,
+    ) -> Result<Self, RoqoqoError> {
+        if qubits.is_empty() {
+            return Err(RoqoqoError::GenericError {
+                msg: "Classical shadows need at least one qubit".to_string(),
+            });
+        }
+        check_distinct_qubits(&qubits, "Classical shadow")?;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut circuits = Vec::with_capacity(number_bases);
+        let mut bases = Vec::with_capacity(number_bases);
+        for index in 0..number_bases {
+            let basis: String = qubits
+                .iter()
+                .map(|_| MEASUREMENT_BASES[rng.random_range(0..MEASUREMENT_BASES.len())])
+                .collect();
+            circuits.push(basis_measurement_circuit(
+                qubits.iter().copied().zip(basis.chars()),
+                &qubits,
+                qubits.len(),
+                format!("{readout}_{index}"),
+                number_measurements,
+            ));
+            bases.push(basis);
+        }
+        Ok(Self {
+            constant_circuit,
+            circuits,
+            qubits,
+            readout,
+            bases,
+        })
+    }
+
+    /// Estimates the expectation value of a Pauli product.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The measured bit registers.
+    /// * `pauli_product` - The Pauli product as a string of I, X, Y and Z in the order of `qubits`.
+    /// * `number_batches` - The number of batches of the median-of-means estimation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The estimated expectation value.
+    /// * `Err(RoqoqoError::GenericError)` - The Pauli product, the registers or the number of batches are invalid.
+    pub fn pauli_expectation_value(
+        &self,
+        bit_registers: &HashMap<String, BitOutputRegister>,
+        pauli_product: &str,
+        number_batches: usize,
+    ) -> Result<f64, RoqoqoError> {
+        self.expectation_value(
+            bit_registers,
+            &HashMap::from([(pauli_product.to_string(), 1.0)]),
+            number_batches,
+        )
+    }
+
+    /// Estimates the expectation value of an observable given as a linear combination of Pauli products.
+    ///
+    /// The observable is evaluated for every snapshot before the median-of-means estimation.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The measured bit registers.
+    /// * `observable` - The Pauli products (strings of I, X, Y and Z in the order of `qubits`) and their coefficients.
+    /// * `number_batches` - The number of batches of the median-of-means estimation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The estimated expectation value.
+    /// * `Err(RoqoqoError::GenericError)` - The Pauli products, the registers or the number of batches are invalid.
+    pub fn expectation_value(
+        &self,
+        bit_registers: &HashMap<String, BitOutputRegister>,
+        observable: &HashMap<String, f64>,
+        number_batches: usize,
+    ) -> Result<f64, RoqoqoError> {
+        for pauli_product in observable.keys() {
+            if pauli_product.chars().count() != self.qubits.len()
+                || pauli_product
+                    .chars()
+                    .any(|pauli| !matches!(pauli, 'I' | 'X' | 'Y' | 'Z'))
+            {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Pauli product {pauli_product} is not a string of I, X, Y and Z of length {}",
+                        self.qubits.len()
+                    ),
+                });
+            }
+        }
+        let estimates: Vec<f64> = self
+            .snapshots(bit_registers)?
+            .into_iter()
+            .map(|(basis, outcome)| {
+                observable
+                    .iter()
+                    .map(|(pauli_product, coefficient)| {
+                        coefficient * pauli_snapshot(pauli_product, basis, outcome)
+                    })
+                    .sum()
+            })
+            .collect();
+        median_of_means(&estimates, number_batches)
+    }
+
+    /// Estimates the fidelity of the measured state with a pure target state.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The measured bit registers.
+    /// * `target_state` - The state vector of the target state, `qubits[0]` corresponds to the least significant bit of the index.
+    /// * `number_batches` - The number of batches of the median-of-means estimation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The estimated fidelity.
+    /// * `Err(RoqoqoError::GenericError)` - The target state, the registers or the number of batches are invalid.
+    pub fn fidelity(
+        &self,
+        bit_registers: &HashMap<String, BitOutputRegister>,
+        target_state: &Array1<Complex64>,
+        number_batches: usize,
+    ) -> Result<f64, RoqoqoError> {
+        if target_state.len() != 1 << self.qubits.len() {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Target state has dimension {} instead of {}",
+                    target_state.len(),
+                    1 << self.qubits.len()
+                ),
+            });
+        }
+        let estimates: Vec<f64> = self
+            .snapshots(bit_registers)?
+            .into_iter()
+            .map(|(basis, outcome)| fidelity_snapshot(target_state, basis, outcome))
+            .collect();
+        median_of_means(&estimates, number_batches)
+    }
+
+    // Collects the measured bases and outcomes of all snapshots
+    fn snapshots<'a>(
+        &'a self,
+        bit_registers: &'a HashMap<String, BitOutputRegister>,
+    ) -> Result<Vec<(&'a str, &'a Vec<bool>)>, RoqoqoError> {
+        let mut snapshots = Vec::new();
+        for (index, basis) in self.bases.iter().enumerate() {
+            let name = format!("{}_{}", self.readout, index);
+            let register = bit_registers
+                .get(&name)
+                .ok_or_else(|| RoqoqoError::GenericError {
+                    msg: format!("Readout register {name} not found"),
+                })?;
+            for outcome in register.iter() {
+                if outcome.len() != self.qubits.len() {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!(
+                            "Readout register {name} has length {} instead of {}",
+                            outcome.len(),
+                            self.qubits.len()
+                        ),
+                    });
+                }
+                snapshots.push((basis.as_str(), outcome));
+            }
+        }
+        Ok(snapshots)
+    }
+}
+
+impl_measure!(ClassicalShadows, qubits, readout, bases);
+
+// Single-snapshot estimator of a Pauli product: 3^k (-1)^parity if all k non-trivial Paulis were measured, else 0
+fn pauli_snapshot(pauli_product: &str, basis: &str, outcome: &[bool]) -> f64 {
+    let mut estimate = 1.0;
+    for ((pauli, measured_basis), bit) in pauli_product.chars().zip(basis.chars()).zip(outcome) {
+        if pauli == 'I' {
+            continue;
+        }
+        if pauli != measured_basis {
+            return 0.0;
+        }
+        estimate *= if *bit { -3.0 } else { 3.0 };
+    }
+    estimate
+}
+
+// Single-snapshot estimator of the fidelity <psi| ⊗_i (3 |s_i><s_i| - 1) |psi>
+fn fidelity_snapshot(target_state: &Array1<Complex64>, basis: &str, outcome: &[bool]) -> f64 {
+    let mut state = target_state.clone();
+    for (qubit, (pauli, bit)) in basis.chars().zip(outcome).enumerate() {
+        // 3 |s><s| - 1 = (1 + 3 s P) / 2 for the eigenvalue s = ±1 of the Pauli operator P
+        let sign = if *bit { -1.5 } else { 1.5 };
+        let mask = 1 << qubit;
+        let mut new_state = state.mapv(|value| value * 0.5);
+        for index in 0..state.len() {
+            let flipped = index ^ mask;
+            let is_one = index & mask != 0;
+            let pauli_element = match pauli {
+                'X' => state[flipped],
+                'Y' => {
+                    if is_one {
+                        state[flipped] * Complex64::new(0.0, 1.0)
+                    } else {
+                        state[flipped] * Complex64::new(0.0, -1.0)
+                    }
+                }
+                _ => {
+                    if is_one {
+                        -state[index]
+                    } else {
+                        state[index]
+                    }
+                }
+            };
+            new_state[index] += pauli_element * sign;
+        }
+        state = new_state;
+    }
+    target_state
+        .iter()
+        .zip(state.iter())
+        .map(|(target, value)| target.conj() * value)
+        .sum::<Complex64>()
+        .re
+}
+
+// Median of the means of equally sized batches of the estimates
+//
+// The snapshots of one basis are consecutive, so the estimates are interleaved into the batches
+// instead of being split into consecutive chunks, which would group the batches by basis.
+fn median_of_means(estimates: &[f64], number_batches: usize) -> Result<f64, RoqoqoError> {
+    if number_batches == 0 || number_batches > estimates.len() {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "Number of batches {number_batches} must be between 1 and the number of snapshots {}",
+                estimates.len()
+            ),
+        });
+    }
+    let batch_size = estimates.len() / number_batches;
+    let mut means: Vec<f64> = (0..number_batches)
+        .map(|batch| {
+            estimates
+                .iter()
+                .skip(batch)
+                .step_by(number_batches)
+                .take(batch_size)
+                .sum::<f64>()
+                / batch_size as f64
+        })
+        .collect();
+    means.sort_by(|a, b| a.total_cmp(b));
+    let middle = means.len() / 2;
+    if means.len() % 2 == 0 {
+        Ok((means[middle - 1] + means[middle]) / 2.0)
+    } else {
+        Ok(means[middle])
+    }
+}
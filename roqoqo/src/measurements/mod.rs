@@ -21,7 +21,7 @@
 //! # Note
 //! The functionality to **perform** the actual measurement is provided by the measurement operations [crate::operations].
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[doc(hidden)]
 mod measurement_auxiliary_data_input;
@@ -38,12 +38,25 @@ pub use cheated_measurement::*;
 #[doc(hidden)]
 mod classical_register_measurement;
 pub use classical_register_measurement::*;
+#[doc(hidden)]
+mod circuit_cutting_measurement;
+pub use circuit_cutting_measurement::*;
+#[doc(hidden)]
+mod classical_shadows_measurement;
+pub use classical_shadows_measurement::*;
+#[doc(hidden)]
+mod tomography_measurement;
+pub use tomography_measurement::*;
 
+use crate::operations::{
+    DefinitionBit, Hadamard, MeasureQubit, PragmaSetNumberOfMeasurements, RotateX,
+};
 use crate::registers::BitOutputRegister;
 use crate::{
     registers::{ComplexOutputRegister, FloatOutputRegister},
     Circuit, RoqoqoError,
 };
+use std::f64::consts::FRAC_PI_2;
 
 #[cfg(feature = "async")]
 use crate::registers::Registers;
@@ -134,6 +147,63 @@ pub trait Measure: PartialEq + Clone {
         &self,
         substituted_parameters: HashMap<String, f64>,
     ) -> Result<Self, RoqoqoError>;
+
+    /// Returns clone of Measurement with the given symbols replaced and the other symbols kept symbolic.
+    ///
+    /// Allows binding the parameters of a Measurement in several steps,
+    /// for example calibration constants once and variational parameters later.
+    /// The default implementation falls back to [Measure::substitute_parameters]
+    /// and therefore fails when symbols are left unbound.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the symbols to substitute in the Circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuits with the given symbols substituted.
+    /// * `Err(RoqoqoError)` - The substitution failed.
+    fn bind_parameters(&self, values: HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        self.substitute_parameters(values)
+    }
+
+    /// Returns clone of Measurement with the given symbols renamed.
+    ///
+    /// Allows combining Measurements whose circuits use the same symbol names
+    /// for parameters that should stay independent.
+    /// The default implementation only supports Measurements without symbols, which are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The new names of the symbols to rename in the Circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` -  The Circuits with the given symbols renamed.
+    /// * `Err(RoqoqoError)` - The renaming failed.
+    fn rename_parameters(&self, _mapping: HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        self.substitute_parameters(HashMap::new())
+            .map_err(|_| RoqoqoError::GenericError {
+                msg: "Renaming symbols is not supported by the measurement".to_string(),
+            })
+    }
+
+    /// Returns the names of the free symbols in the circuits of the measurement.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashSet<String>)` - The free symbols of the constant Circuit and of all measurement circuits.
+    /// * `Err(RoqoqoError::GenericError)` - A parametrized operation does not report its symbols.
+    fn symbols(&self) -> Result<HashSet<String>, RoqoqoError> {
+        let mut symbols: HashSet<String> = HashSet::new();
+        if let Some(circuit) = self.constant_circuit() {
+            symbols.extend(circuit.symbols()?);
+        }
+        for circuit in self.circuits() {
+            symbols.extend(circuit.symbols()?);
+        }
+        Ok(symbols)
+    }
 }
 
 /// Allows generic interfacing with roqoqo measurements that evaluate expectation values.
@@ -240,3 +310,178 @@ pub trait MeasureExpectationValues: PartialEq + Clone + Measure {
             .unwrap())
     }
 }
+
+// Measurement bases of single qubits, the readout in basis P measures the Pauli operator P
+pub(crate) const MEASUREMENT_BASES: [char; 3] = ['X', 'Y', 'Z'];
+
+// Checks that the qubits are distinct, `description` names the qubits in the error message
+pub(crate) fn check_distinct_qubits(
+    qubits: &[usize],
+    description: &str,
+) -> Result<(), RoqoqoError> {
+    if (1..qubits.len()).any(|index| qubits[..index].contains(&qubits[index])) {
+        return Err(RoqoqoError::GenericError {
+            msg: format!("{description} qubits {qubits:?} are not distinct"),
+        });
+    }
+    Ok(())
+}
+
+// Rotates the qubits into their measurement bases and measures the measured qubits in order into the readout register
+//
+// The readout register has `register_length` bits, at least one for every measured qubit.
+pub(crate) fn basis_measurement_circuit(
+    bases: impl IntoIterator<Item = (usize, char)>,
+    measured_qubits: &[usize],
+    register_length: usize,
+    readout: String,
+    number_measurements: usize,
+) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new(readout.clone(), register_length, true);
+    for (qubit, basis) in bases {
+        match basis {
+            'X' => circuit += Hadamard::new(qubit),
+            'Y' => circuit += RotateX::new(qubit, FRAC_PI_2.into()),
+            _ => (),
+        }
+    }
+    for (index, qubit) in measured_qubits.iter().enumerate() {
+        circuit += MeasureQubit::new(*qubit, readout.clone(), index);
+    }
+    circuit += PragmaSetNumberOfMeasurements::new(number_measurements, readout);
+    circuit
+}
+
+// Implements Measure and SupportedVersion for measurements added in roqoqo 1.21 that only
+// store their circuits, the listed fields are cloned unchanged
+macro_rules! impl_measure {
+    ($name:ident, $($field:ident),*) => {
+        impl crate::measurements::Measure for $name {
+            /// Returns the constant Circuit that is executed before each Circuit in circuits.
+            ///
+            /// # Returns
+            ///
+            /// * `&Option<Circuit` - The constant Circuit (None if not defined).
+            fn constant_circuit(&self) -> &Option<crate::Circuit> {
+                &self.constant_circuit
+            }
+
+            /// Returns iterator over circuits for measurement.
+            ///
+            /// # Returns
+            ///
+            /// * `Box<dyn Iterator<Item = &'a Circuit> + 'a>` - The quantum circuits.
+            fn circuits<'a>(&'a self) -> Box<dyn Iterator<Item = &'a crate::Circuit> + 'a> {
+                Box::new(self.circuits.iter())
+            }
+
+            /// Returns clone of Measurement with symbolic parameters replaced.
+            ///
+            /// # Arguments
+            ///
+            /// * `substituted_parameters` - The HashMap containing the substitutions to use in the Circuit.
+            ///
+            /// # Returns
+            ///
+            /// * `Ok(Self)` -  The Circuits with the parameters substituted.
+            /// * `Err(RoqoqoError)` - The substitution failed.
+            fn substitute_parameters(
+                &self,
+                substituted_parameters: std::collections::HashMap<String, f64>,
+            ) -> Result<Self, crate::RoqoqoError> {
+                let mut calculator = qoqo_calculator::Calculator::new();
+                for (name, val) in substituted_parameters.iter() {
+                    calculator.set_variable(name, *val)
+                }
+                let new_constant_circuit = match &self.constant_circuit {
+                    None => None,
+                    Some(c) => Some(c.substitute_parameters(&calculator)?),
+                };
+                let mut new_circuits = Vec::new();
+                for circ in self.circuits.iter() {
+                    new_circuits.push(circ.substitute_parameters(&calculator)?)
+                }
+                Ok(Self {
+                    constant_circuit: new_constant_circuit,
+                    circuits: new_circuits,
+                    $($field: self.$field.clone(),)*
+                })
+            }
+
+            /// Returns clone of Measurement with the given symbols replaced and the other symbols kept symbolic.
+            ///
+            /// # Arguments
+            ///
+            /// * `values` - The values of the symbols to substitute in the Circuits.
+            ///
+            /// # Returns
+            ///
+            /// * `Ok(Self)` -  The Circuits with the given symbols substituted.
+            /// * `Err(RoqoqoError)` - The substitution failed.
+            fn bind_parameters(
+                &self,
+                values: std::collections::HashMap<String, f64>,
+            ) -> Result<Self, crate::RoqoqoError> {
+                let new_constant_circuit = match &self.constant_circuit {
+                    None => None,
+                    Some(c) => Some(c.bind_parameters(&values)?),
+                };
+                let mut new_circuits = Vec::new();
+                for circ in self.circuits.iter() {
+                    new_circuits.push(circ.bind_parameters(&values)?)
+                }
+                Ok(Self {
+                    constant_circuit: new_constant_circuit,
+                    circuits: new_circuits,
+                    $($field: self.$field.clone(),)*
+                })
+            }
+
+            /// Returns clone of Measurement with the given symbols renamed.
+            ///
+            /// # Arguments
+            ///
+            /// * `mapping` - The new names of the symbols to rename in the Circuits.
+            ///
+            /// # Returns
+            ///
+            /// * `Ok(Self)` -  The Circuits with the given symbols renamed.
+            /// * `Err(RoqoqoError)` - The renaming failed.
+            fn rename_parameters(
+                &self,
+                mapping: std::collections::HashMap<String, String>,
+            ) -> Result<Self, crate::RoqoqoError> {
+                let new_constant_circuit = match &self.constant_circuit {
+                    None => None,
+                    Some(c) => Some(c.rename_parameters(&mapping)?),
+                };
+                let mut new_circuits = Vec::new();
+                for circ in self.circuits.iter() {
+                    new_circuits.push(circ.rename_parameters(&mapping)?)
+                }
+                Ok(Self {
+                    constant_circuit: new_constant_circuit,
+                    circuits: new_circuits,
+                    $($field: self.$field.clone(),)*
+                })
+            }
+        }
+
+        impl crate::operations::SupportedVersion for $name {
+            fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+                let mut current_minimum_version = (1, 21, 0);
+                if let Some(circuit) = &self.constant_circuit {
+                    let comparison_version = circuit.minimum_supported_roqoqo_version();
+                    crate::update_roqoqo_version(&mut current_minimum_version, comparison_version);
+                }
+                for circuit in self.circuits.iter() {
+                    let comparison_version = circuit.minimum_supported_roqoqo_version();
+                    crate::update_roqoqo_version(&mut current_minimum_version, comparison_version);
+                }
+                current_minimum_version
+            }
+        }
+    };
+}
+pub(crate) use impl_measure;
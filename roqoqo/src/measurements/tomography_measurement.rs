@@ -0,0 +1,491 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::measurements::{
+    basis_measurement_circuit, check_distinct_qubits, impl_measure, MEASUREMENT_BASES,
+};
+use crate::operations::{Hadamard, PauliX, SGate};
+use crate::registers::BitOutputRegister;
+use crate::Circuit;
+use crate::RoqoqoError;
+use nalgebra::DMatrix;
+use ndarray::{array, Array2};
+use num_complex::Complex64;
+use std::collections::HashMap;
+
+// Prepared input states of the single qubits: |0>, |1>, |+> and |+i>
+const PREPARED_STATES: [char; 4] = ['0', '1', 'p', 'i'];
+// Convergence threshold of the maximum-likelihood iteration
+const MAXIMUM_LIKELIHOOD_TOLERANCE: f64 = 1e-12;
+
+/// Method used to reconstruct a density matrix from tomography measurement results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub enum TomographyMethod {
+    /// Linear inversion of the measured Pauli expectation values.
+    ///
+    /// The result is Hermitian with unit trace but not necessarily positive semi-definite.
+    LinearInversion,
+    /// Maximum-likelihood estimation with the iterative R rho R algorithm.
+    ///
+    /// The result is always a physical density matrix.
+    MaximumLikelihood {
+        /// The maximal number of iterations.
+        max_iterations: usize,
+    },
+}
+
+/// State tomography measurement.
+///
+/// Measures the state prepared by the constant circuit in all 3^n combinations of
+/// single-qubit X, Y and Z bases on the chosen qubits and reconstructs the density matrix
+/// of these qubits from the measured bit registers.
+///
+/// Each circuit writes into its own readout register `{readout}_{bases}`, where `bases` contains
+/// the basis (X, Y or Z) of every qubit in the order of `qubits`, e.g. `ro_XZ`.
+/// In the reconstructed density matrix `qubits[0]` corresponds to the least significant bit of the index.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct StateTomography {
+    /// Constant Circuit preparing the measured state, executed before each Circuit in circuits.
+    pub constant_circuit: Option<Circuit>,
+    /// Collection of quantum circuits for the separate basis rotations.
+    pub circuits: Vec<Circuit>,
+    /// The qubits whose state is reconstructed.
+    pub qubits: Vec<usize>,
+    /// The prefix of the readout registers.
+    pub readout: String,
+}
+
+impl StateTomography {
+    /// Creates a new state tomography measurement.
+    ///
+    /// # Arguments
+    ///
+    /// * `constant_circuit` - The Circuit preparing the state.
+    /// * `qubits` - The qubits whose state is reconstructed.
+    /// * `readout` - The prefix of the readout registers.
+    /// * `number_measurements` - The number of projective measurements per basis.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new state tomography measurement.
+    /// * `Err(RoqoqoError::GenericError)` - The qubits are empty or not distinct.
+    pub fn new(
+        constant_circuit: Option<Circuit>,
+        qubits: Vec<usize>,
+        readout: String,
+        number_measurements: usize,
+    ) -> Result<Self, RoqoqoError> {
+        check_qubits(&qubits)?;
+        let circuits = basis_labels(MEASUREMENT_BASES.as_slice(), qubits.len())
+            .iter()
+            .map(|bases| {
+                basis_measurement_circuit(
+                    qubits.iter().copied().zip(bases.chars()),
+                    &qubits,
+                    qubits.len(),
+                    format!("{readout}_{bases}"),
+                    number_measurements,
+                )
+            })
+            .collect();
+        Ok(Self {
+            constant_circuit,
+            circuits,
+            qubits,
+            readout,
+        })
+    }
+
+    /// Returns the expectation values of all Pauli products on the measured qubits.
+    ///
+    /// The Pauli products are labelled by strings of I, X, Y and Z in the order of `qubits`.
+    /// Each expectation value is averaged over all measured bases compatible with the Pauli product.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The measured bit registers.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, f64>)` - The Pauli product expectation values.
+    /// * `Err(RoqoqoError::GenericError)` - A readout register is missing or has the wrong length.
+    pub fn pauli_expectation_values(
+        &self,
+        bit_registers: &HashMap<String, BitOutputRegister>,
+    ) -> Result<HashMap<String, f64>, RoqoqoError> {
+        let frequencies = basis_frequencies(&self.readout, self.qubits.len(), bit_registers)?;
+        Ok(pauli_expectation_values(&frequencies, self.qubits.len()))
+    }
+
+    /// Reconstructs the density matrix of the measured qubits.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The measured bit registers.
+    /// * `method` - The reconstruction method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The reconstructed density matrix.
+    /// * `Err(RoqoqoError::GenericError)` - A readout register is missing or has the wrong length.
+    pub fn evaluate(
+        &self,
+        bit_registers: HashMap<String, BitOutputRegister>,
+        method: TomographyMethod,
+    ) -> Result<Array2<Complex64>, RoqoqoError> {
+        let frequencies = basis_frequencies(&self.readout, self.qubits.len(), &bit_registers)?;
+        Ok(reconstruct_state(&frequencies, self.qubits.len(), method))
+    }
+}
+
+/// Process tomography measurement.
+///
+/// Prepares every product of the single-qubit states |0>, |1>, |+> and |+i> on the chosen qubits,
+/// applies the process and measures the output state in all combinations of X, Y and Z bases.
+/// The process is reconstructed as a Choi matrix from the measured bit registers.
+///
+/// Each circuit writes into its own readout register `{readout}_{states}_{bases}`,
+/// where `states` contains the prepared state (0, 1, p for |+> or i for |+i>)
+/// and `bases` the measured basis of every qubit in the order of `qubits`, e.g. `ro_0p_XZ`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct ProcessTomography {
+    /// Constant Circuit that is executed before each Circuit in circuits.
+    pub constant_circuit: Option<Circuit>,
+    /// Collection of quantum circuits for the separate state preparations and basis rotations.
+    pub circuits: Vec<Circuit>,
+    /// The qubits the process acts on.
+    pub qubits: Vec<usize>,
+    /// The prefix of the readout registers.
+    pub readout: String,
+}
+
+impl ProcessTomography {
+    /// Creates a new process tomography measurement.
+    ///
+    /// # Arguments
+    ///
+    /// * `process` - The Circuit implementing the process.
+    /// * `qubits` - The qubits the process acts on.
+    /// * `readout` - The prefix of the readout registers.
+    /// * `number_measurements` - The number of projective measurements per preparation and basis.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new process tomography measurement.
+    /// * `Err(RoqoqoError::GenericError)` - The qubits are empty or not distinct.
+    pub fn new(
+        process: Circuit,
+        qubits: Vec<usize>,
+        readout: String,
+        number_measurements: usize,
+    ) -> Result<Self, RoqoqoError> {
+        check_qubits(&qubits)?;
+        let bases_labels = basis_labels(MEASUREMENT_BASES.as_slice(), qubits.len());
+        let mut circuits = Vec::new();
+        for states in basis_labels(PREPARED_STATES.as_slice(), qubits.len()) {
+            let mut preparation = Circuit::new();
+            for (qubit, state) in qubits.iter().zip(states.chars()) {
+                match state {
+                    '1' => preparation += PauliX::new(*qubit),
+                    'p' => preparation += Hadamard::new(*qubit),
+                    'i' => {
+                        preparation += Hadamard::new(*qubit);
+                        preparation += SGate::new(*qubit);
+                    }
+                    _ => (),
+                }
+            }
+            for bases in bases_labels.iter() {
+                let mut circuit = preparation.clone() + process.clone();
+                circuit += basis_measurement_circuit(
+                    qubits.iter().copied().zip(bases.chars()),
+                    &qubits,
+                    qubits.len(),
+                    format!("{readout}_{states}_{bases}"),
+                    number_measurements,
+                );
+                circuits.push(circuit);
+            }
+        }
+        Ok(Self {
+            constant_circuit: None,
+            circuits,
+            qubits,
+            readout,
+        })
+    }
+
+    /// Reconstructs the Choi matrix of the process.
+    ///
+    /// The output state of every prepared input state is reconstructed with the given method
+    /// and the process is obtained by linear inversion of the prepared input states.
+    /// The Choi matrix is `sum_{ij} |i><j| ⊗ E(|i><j|)`, the input index is the more significant part
+    /// of the row and column index. The trace of the Choi matrix is the dimension of the input space.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The measured bit registers.
+    /// * `method` - The reconstruction method for the output states.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The reconstructed Choi matrix.
+    /// * `Err(RoqoqoError::GenericError)` - A readout register is missing or has the wrong length.
+    pub fn evaluate(
+        &self,
+        bit_registers: HashMap<String, BitOutputRegister>,
+        method: TomographyMethod,
+    ) -> Result<Array2<Complex64>, RoqoqoError> {
+        let number_qubits = self.qubits.len();
+        let dimension = 1 << number_qubits;
+        let states = basis_labels(PREPARED_STATES.as_slice(), number_qubits);
+
+        // Columns of the matrix are the vectorized prepared input states
+        let mut inputs: DMatrix<Complex64> =
+            DMatrix::zeros(dimension * dimension, dimension * dimension);
+        let mut outputs: Vec<Array2<Complex64>> = Vec::with_capacity(states.len());
+        for (column, label) in states.iter().enumerate() {
+            let input = product_operator(label.chars().map(prepared_state).collect());
+            for ((row, col), value) in input.indexed_iter() {
+                inputs[(row * dimension + col, column)] = *value;
+            }
+            let frequencies = basis_frequencies(
+                &format!("{}_{}", self.readout, label),
+                number_qubits,
+                &bit_registers,
+            )?;
+            outputs.push(reconstruct_state(&frequencies, number_qubits, method));
+        }
+        let inverse = inputs
+            .try_inverse()
+            .ok_or_else(|| RoqoqoError::GenericError {
+                msg: "Prepared input states do not form a basis".to_string(),
+            })?;
+
+        let mut choi: Array2<Complex64> =
+            Array2::zeros((dimension * dimension, dimension * dimension));
+        for i in 0..dimension {
+            for j in 0..dimension {
+                // Expansion of |i><j| in the prepared input states
+                let mut output: Array2<Complex64> = Array2::zeros((dimension, dimension));
+                for (index, state_output) in outputs.iter().enumerate() {
+                    output = output + state_output * inverse[(index, i * dimension + j)];
+                }
+                for ((k, l), value) in output.indexed_iter() {
+                    choi[(i * dimension + k, j * dimension + l)] = *value;
+                }
+            }
+        }
+        Ok(choi)
+    }
+}
+
+impl_measure!(StateTomography, qubits, readout);
+impl_measure!(ProcessTomography, qubits, readout);
+
+// Checks that the tomography qubits are not empty and distinct
+fn check_qubits(qubits: &[usize]) -> Result<(), RoqoqoError> {
+    if qubits.is_empty() {
+        return Err(RoqoqoError::GenericError {
+            msg: "Tomography needs at least one qubit".to_string(),
+        });
+    }
+    check_distinct_qubits(qubits, "Tomography")
+}
+
+// Returns all labels of length `number_qubits` built from `symbols`, the first character varies fastest
+fn basis_labels(symbols: &[char], number_qubits: usize) -> Vec<String> {
+    let number_labels = symbols.len().pow(number_qubits as u32);
+    (0..number_labels)
+        .map(|mut index| {
+            (0..number_qubits)
+                .map(|_| {
+                    let symbol = symbols[index % symbols.len()];
+                    index /= symbols.len();
+                    symbol
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Returns the relative frequencies of the measured outcomes for every measurement basis
+fn basis_frequencies(
+    readout: &str,
+    number_qubits: usize,
+    bit_registers: &HashMap<String, BitOutputRegister>,
+) -> Result<HashMap<String, Vec<f64>>, RoqoqoError> {
+    let mut frequencies = HashMap::new();
+    for bases in basis_labels(MEASUREMENT_BASES.as_slice(), number_qubits) {
+        let name = format!("{readout}_{bases}");
+        let register = bit_registers
+            .get(&name)
+            .ok_or_else(|| RoqoqoError::GenericError {
+                msg: format!("Readout register {name} not found"),
+            })?;
+        let mut counts = vec![0.0; 1 << number_qubits];
+        for row in register.iter() {
+            if row.len() != number_qubits {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Readout register {name} has length {} instead of {number_qubits}",
+                        row.len()
+                    ),
+                });
+            }
+            let outcome = row.iter().enumerate().fold(0, |outcome, (index, bit)| {
+                outcome | (usize::from(*bit) << index)
+            });
+            counts[outcome] += 1.0;
+        }
+        if !register.is_empty() {
+            counts
+                .iter_mut()
+                .for_each(|count| *count /= register.len() as f64);
+        }
+        frequencies.insert(bases, counts);
+    }
+    Ok(frequencies)
+}
+
+// Estimates all Pauli product expectation values from the measured frequencies
+fn pauli_expectation_values(
+    frequencies: &HashMap<String, Vec<f64>>,
+    number_qubits: usize,
+) -> HashMap<String, f64> {
+    let mut expectation_values = HashMap::new();
+    for pauli in basis_labels(&['I', 'X', 'Y', 'Z'], number_qubits) {
+        let mut sum = 0.0;
+        let mut number_compatible = 0;
+        for (bases, probabilities) in frequencies.iter() {
+            if pauli
+                .chars()
+                .zip(bases.chars())
+                .any(|(p, b)| p != 'I' && p != b)
+            {
+                continue;
+            }
+            number_compatible += 1;
+            for (outcome, probability) in probabilities.iter().enumerate() {
+                let parity = pauli
+                    .chars()
+                    .enumerate()
+                    .filter(|(index, p)| *p != 'I' && (outcome >> index) & 1 == 1)
+                    .count();
+                let sign = if parity % 2 == 0 { 1.0 } else { -1.0 };
+                sum += sign * probability;
+            }
+        }
+        if number_compatible > 0 {
+            expectation_values.insert(pauli, sum / number_compatible as f64);
+        }
+    }
+    expectation_values
+}
+
+// Reconstructs a density matrix from the measured frequencies
+fn reconstruct_state(
+    frequencies: &HashMap<String, Vec<f64>>,
+    number_qubits: usize,
+    method: TomographyMethod,
+) -> Array2<Complex64> {
+    let dimension = 1 << number_qubits;
+    let mut density_matrix: Array2<Complex64> = Array2::zeros((dimension, dimension));
+    match method {
+        TomographyMethod::LinearInversion => {
+            for (pauli, value) in pauli_expectation_values(frequencies, number_qubits) {
+                let operator = product_operator(pauli.chars().map(pauli_matrix).collect());
+                density_matrix = density_matrix + operator * Complex64::new(value, 0.0);
+            }
+            density_matrix / Complex64::new(dimension as f64, 0.0)
+        }
+        TomographyMethod::MaximumLikelihood { max_iterations } => {
+            let mut projectors: Vec<(Array2<Complex64>, f64)> = Vec::new();
+            for (bases, probabilities) in frequencies.iter() {
+                for (outcome, probability) in probabilities.iter().enumerate() {
+                    let factors = bases
+                        .chars()
+                        .enumerate()
+                        .map(|(index, basis)| projector(basis, (outcome >> index) & 1 == 1))
+                        .collect();
+                    projectors.push((product_operator(factors), *probability));
+                }
+            }
+            density_matrix = Array2::eye(dimension) / Complex64::new(dimension as f64, 0.0);
+            for _ in 0..max_iterations {
+                let mut r_operator: Array2<Complex64> = Array2::zeros((dimension, dimension));
+                for (projector, frequency) in projectors.iter() {
+                    if *frequency == 0.0 {
+                        continue;
+                    }
+                    let probability = (density_matrix.dot(projector)).diag().sum().re.max(1e-15);
+                    r_operator =
+                        r_operator + projector * Complex64::new(frequency / probability, 0.0);
+                }
+                let mut new_density_matrix = r_operator.dot(&density_matrix).dot(&r_operator);
+                let trace = new_density_matrix.diag().sum();
+                new_density_matrix /= trace;
+                let change: f64 = (&new_density_matrix - &density_matrix)
+                    .iter()
+                    .map(|value| value.norm_sqr())
+                    .sum();
+                density_matrix = new_density_matrix;
+                if change < MAXIMUM_LIKELIHOOD_TOLERANCE {
+                    break;
+                }
+            }
+            density_matrix
+        }
+    }
+}
+
+// Returns the tensor product of single-qubit operators, the first operator acts on the least significant bit
+fn product_operator(factors: Vec<Array2<Complex64>>) -> Array2<Complex64> {
+    factors.into_iter().fold(Array2::eye(1), |product, factor| {
+        ndarray::linalg::kron(&factor, &product)
+    })
+}
+
+// Returns the single-qubit Pauli matrix
+fn pauli_matrix(pauli: char) -> Array2<Complex64> {
+    let zero = Complex64::new(0.0, 0.0);
+    let one = Complex64::new(1.0, 0.0);
+    let i = Complex64::new(0.0, 1.0);
+    match pauli {
+        'X' => array![[zero, one], [one, zero]],
+        'Y' => array![[zero, -i], [i, zero]],
+        'Z' => array![[one, zero], [zero, -one]],
+        _ => Array2::eye(2),
+    }
+}
+
+// Returns the projector on the eigenstate of the Pauli operator measured with outcome `bit`
+fn projector(basis: char, bit: bool) -> Array2<Complex64> {
+    let sign = if bit { -0.5 } else { 0.5 };
+    (Array2::eye(2) * Complex64::new(0.5, 0.0)) + pauli_matrix(basis) * Complex64::new(sign, 0.0)
+}
+
+// Returns the density matrix of a prepared single-qubit input state
+fn prepared_state(state: char) -> Array2<Complex64> {
+    match state {
+        '1' => projector('Z', true),
+        'p' => projector('X', false),
+        'i' => projector('Y', false),
+        _ => projector('Z', false),
+    }
+}
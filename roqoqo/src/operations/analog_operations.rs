@@ -15,7 +15,7 @@ use crate::operations::{
     SupportedVersion,
 };
 use crate::RoqoqoError;
-use qoqo_calculator::{Calculator, CalculatorFloat};
+use qoqo_calculator::{Calculator, CalculatorError, CalculatorFloat};
 use std::collections::{HashMap, HashSet};
 use struqture::spins::PauliHamiltonian;
 use struqture::OperateOnDensityMatrix;
@@ -95,10 +95,45 @@ impl Substitute for ApplyConstantPauliHamiltonian {
             new_time.into(),
         ))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let mut new_hamiltonian = self.hamiltonian.clone();
+        for (key, value) in &self.hamiltonian {
+            new_hamiltonian.set(
+                key.clone(),
+                crate::operations::bind_parameter(value, values)?,
+            )?;
+        }
+        let new_time = crate::operations::bind_parameter(&self.time, values)?;
+        Ok(ApplyConstantPauliHamiltonian::new(
+            new_hamiltonian,
+            new_time,
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let mut new_hamiltonian = self.hamiltonian.clone();
+        for (key, value) in &self.hamiltonian {
+            new_hamiltonian.set(
+                key.clone(),
+                crate::operations::rename_parameter(value, mapping)?,
+            )?;
+        }
+        let new_time = crate::operations::rename_parameter(&self.time, mapping)?;
+        Ok(ApplyConstantPauliHamiltonian::new(
+            new_hamiltonian,
+            new_time,
+        ))
+    }
 }
 
 /// Implements the continuous time, time-dependent spin Hamiltonian
-#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate)]
+///
+/// The symbols with an entry in `values` are driven by the schedule and are not free symbols
+/// of the operation, so they are not reported by [Operate::symbols] and not bound by [Substitute::bind_parameters].
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct ApplyTimeDependentPauliHamiltonian {
@@ -119,6 +154,27 @@ const TAGS_ApplyTimeDependentPauliHamiltonian: &[&str; 3] = &[
 
 impl ImplementedIn1point11 for ApplyTimeDependentPauliHamiltonian {}
 
+#[cfg_attr(feature = "dynamic", typetag::serde)]
+impl Operate for ApplyTimeDependentPauliHamiltonian {
+    fn tags(&self) -> &'static [&'static str] {
+        TAGS_ApplyTimeDependentPauliHamiltonian
+    }
+    fn hqslang(&self) -> &'static str {
+        "ApplyTimeDependentPauliHamiltonian"
+    }
+    fn is_parametrized(&self) -> bool {
+        self.hamiltonian.values().any(|x| !x.is_float())
+    }
+    fn symbols(&self) -> HashSet<String> {
+        let mut symbols: HashSet<String> = HashSet::new();
+        for value in self.hamiltonian.values() {
+            symbols.extend(crate::operations::symbols_in_parameter(value));
+        }
+        symbols.retain(|symbol| !self.values.contains_key(symbol));
+        symbols
+    }
+}
+
 impl OperateSpinsAnalog for ApplyTimeDependentPauliHamiltonian {
     fn spin(&self) -> Result<Vec<usize>, RoqoqoError> {
         let mut qubit_set = HashSet::new();
@@ -175,4 +231,196 @@ impl Substitute for ApplyTimeDependentPauliHamiltonian {
             self.values.clone(),
         ))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    ///
+    /// The symbols driven by the schedule are not bound.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let mut values = values.clone();
+        values.retain(|name, _| !self.values.contains_key(name));
+        let mut new_hamiltonian = self.hamiltonian.clone();
+        for (key, value) in &self.hamiltonian {
+            new_hamiltonian.set(
+                key.clone(),
+                crate::operations::bind_parameter(value, &values)?,
+            )?;
+        }
+        Ok(ApplyTimeDependentPauliHamiltonian::new(
+            new_hamiltonian,
+            self.time.clone(),
+            self.values.clone(),
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    ///
+    /// The symbols driven by the schedule are renamed in the Hamiltonian and in `values`.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let mut new_hamiltonian = self.hamiltonian.clone();
+        for (key, value) in &self.hamiltonian {
+            new_hamiltonian.set(
+                key.clone(),
+                crate::operations::rename_parameter(value, mapping)?,
+            )?;
+        }
+        let mut new_values: HashMap<String, Vec<f64>> = HashMap::new();
+        for (name, schedule) in &self.values {
+            let new_name = mapping.get(name).unwrap_or(name);
+            if !crate::operations::is_symbol_name(new_name) {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!("New name {new_name} of symbol {name} is not a valid symbol name"),
+                });
+            }
+            if new_values
+                .insert(new_name.clone(), schedule.clone())
+                .is_some()
+            {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Several symbols in values of ApplyTimeDependentPauliHamiltonian are renamed to {new_name}"
+                    ),
+                });
+            }
+        }
+        Ok(ApplyTimeDependentPauliHamiltonian::new(
+            new_hamiltonian,
+            self.time.clone(),
+            new_values,
+        ))
+    }
+}
+
+impl ApplyTimeDependentPauliHamiltonian {
+    /// Creates a new instance of `ApplyTimeDependentPauliHamiltonian`.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - Hamiltonian to be simulated.
+    /// * `time` - Range of time stored as a vector. The total duration of the simulations is given by the last value in the range.
+    /// * `values` - Values of time-dependent parameters, appearing in `hamiltonian`, at instances given by the vector `time`.
+    pub fn new(
+        hamiltonian: PauliHamiltonian,
+        time: Vec<f64>,
+        values: HashMap<String, Vec<f64>>,
+    ) -> Self {
+        Self {
+            hamiltonian,
+            time,
+            values,
+        }
+    }
+
+    /// Returns the value of the field `hamiltonian`.
+    pub fn hamiltonian(&self) -> &PauliHamiltonian {
+        &self.hamiltonian
+    }
+
+    /// Returns the value of the field `time`.
+    pub fn time(&self) -> &Vec<f64> {
+        &self.time
+    }
+
+    /// Returns the value of the field `values`.
+    pub fn values(&self) -> &HashMap<String, Vec<f64>> {
+        &self.values
+    }
+
+    /// Checks that the time schedule and the values of the time-dependent parameters are consistent.
+    ///
+    /// The time range has to be non-empty, non-negative and strictly increasing,
+    /// every entry of `values` has to contain one value per time in the range
+    /// and every symbol in the coefficients of the Hamiltonian has to be covered by `values`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The schedule is valid.
+    /// * `Err(RoqoqoError::GenericError)` - The schedule is not valid.
+    pub fn validate_schedule(&self) -> Result<(), RoqoqoError> {
+        if self.time.is_empty() {
+            return Err(RoqoqoError::GenericError {
+                msg: "Time range of ApplyTimeDependentPauliHamiltonian is empty".to_string(),
+            });
+        }
+        if self.time[0] < 0.0 || self.time.windows(2).any(|pair| pair[1] <= pair[0]) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Time range {:?} of ApplyTimeDependentPauliHamiltonian is not non-negative and strictly increasing",
+                    self.time
+                ),
+            });
+        }
+        for (name, values) in self.values.iter() {
+            if values.len() != self.time.len() {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Parameter {} has {} values but the time range has {} entries",
+                        name,
+                        values.len(),
+                        self.time.len()
+                    ),
+                });
+            }
+        }
+        self.hamiltonian_at(&self.calculator_at(0)?)?;
+        Ok(())
+    }
+
+    /// Samples the evolution into piecewise-constant segments.
+    ///
+    /// The coefficients are held constant between consecutive times of the range,
+    /// using the values of the time-dependent parameters at the start of every segment.
+    /// When the range does not start at zero, the first values are also used from zero to the first time.
+    /// The coefficients of every segment are evaluated with a [qoqo_calculator::Calculator]
+    /// containing the values of all time-dependent parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ApplyConstantPauliHamiltonian>)` - The evolutions of the segments in time order.
+    /// * `Err(RoqoqoError::GenericError)` - The schedule is not valid, see [ApplyTimeDependentPauliHamiltonian::validate_schedule].
+    /// * `Err(RoqoqoError::CalculatorError)` - A coefficient cannot be evaluated.
+    pub fn piecewise_constant(&self) -> Result<Vec<ApplyConstantPauliHamiltonian>, RoqoqoError> {
+        self.validate_schedule()?;
+        let mut segments: Vec<ApplyConstantPauliHamiltonian> = Vec::new();
+        let mut start = 0.0;
+        for index in 0..self.time.len() {
+            // The segment before the first time uses the first values, afterwards values are held
+            let value_index = index.saturating_sub(1);
+            let duration = self.time[index] - start;
+            if duration > 0.0 {
+                let hamiltonian = self.hamiltonian_at(&self.calculator_at(value_index)?)?;
+                segments.push(ApplyConstantPauliHamiltonian::new(
+                    hamiltonian,
+                    duration.into(),
+                ));
+            }
+            start = self.time[index];
+        }
+        Ok(segments)
+    }
+
+    // Returns a calculator containing the values of all time-dependent parameters at a time index
+    fn calculator_at(&self, index: usize) -> Result<Calculator, RoqoqoError> {
+        let mut calculator = Calculator::new();
+        for (name, values) in self.values.iter() {
+            calculator.set_variable(name, values[index]);
+        }
+        Ok(calculator)
+    }
+
+    // Returns the Hamiltonian with all coefficients evaluated by the calculator
+    fn hamiltonian_at(&self, calculator: &Calculator) -> Result<PauliHamiltonian, RoqoqoError> {
+        let mut hamiltonian = PauliHamiltonian::new();
+        for (key, value) in self.hamiltonian.iter() {
+            let evaluated = calculator.parse_get(value.clone()).map_err(|error| match error {
+                CalculatorError::VariableNotSet { name } => RoqoqoError::GenericError {
+                    msg: format!(
+                        "Symbol {name} in the Hamiltonian of ApplyTimeDependentPauliHamiltonian is not covered by values"
+                    ),
+                },
+                _ => error.into(),
+            })?;
+            hamiltonian.add_operator_product(key.clone(), evaluated.into())?;
+        }
+        Ok(hamiltonian)
+    }
 }
@@ -297,6 +297,31 @@ pub struct GateDefinition {
     free_parameters: Vec<String>,
 }
 
+#[cfg(feature = "unstable_operation_definition")]
+impl GateDefinition {
+    /// Returns the unitary matrix of the defined gate for the given values of the free parameters.
+    ///
+    /// The first qubit of the definition is the most significant qubit of the matrix.
+    /// The body may call gates defined in the body itself, these calls are inlined.
+    /// Besides gates, the body may only contain definitions and [crate::operations::PragmaGlobalPhase].
+    ///
+    /// # Arguments
+    ///
+    /// * `free_parameters` - The float values of the free parameters in the order of the definition.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix of the defined gate.
+    /// * `Err(RoqoqoError)` - The number of parameters does not match, a parameter is symbolic
+    ///   or the body contains an operation without unitary matrix.
+    pub fn unitary_matrix(
+        &self,
+        free_parameters: &[qoqo_calculator::CalculatorFloat],
+    ) -> Result<ndarray::Array2<num_complex::Complex64>, RoqoqoError> {
+        crate::passes::definition_unitary(self, free_parameters)
+    }
+}
+
 #[cfg(feature = "unstable_operation_definition")]
 impl super::ImplementedIn1point13 for GateDefinition {}
 
@@ -71,6 +71,58 @@ impl InvolveQubits for MeasureQubit {
 #[allow(non_upper_case_globals)]
 const TAGS_MeasureQubit: &[&str; 3] = &["Operation", "Measurement", "MeasureQubit"];
 
+/// Measurement gate operation that resets the measured qubit.
+///
+/// This Operation acts on one qubit writing the result of the measurement into a readout
+/// and afterwards resets the qubit to the |0> state, so that it can be reused in the rest of the circuit.
+/// In every shot the result is written into the same entry of the readout register.
+/// The classical register for the readout needs to be defined in advance by using a Definition operation.
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::Substitute,
+    roqoqo_derive::OperateSingleQubit,
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct MeasureQubitAndReset {
+    /// The measured qubit.
+    qubit: usize,
+    /// The register for the readout.
+    readout: String,
+    /// The index in the readout the result is saved to.
+    readout_index: usize,
+}
+
+impl super::ImplementedIn1point21 for MeasureQubitAndReset {}
+
+impl SupportedVersion for MeasureQubitAndReset {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 21, 0)
+    }
+}
+
+impl InvolveQubits for MeasureQubitAndReset {
+    fn involved_qubits(&self) -> InvolvedQubits {
+        let mut a: HashSet<usize> = HashSet::new();
+        a.insert(self.qubit);
+        InvolvedQubits::Set(a)
+    }
+
+    fn involved_classical(&self) -> super::InvolvedClassical {
+        let mut a: HashSet<(String, usize)> = HashSet::new();
+        a.insert((self.readout.clone(), self.readout_index));
+        InvolvedClassical::Set(a)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_MeasureQubitAndReset: &[&str; 3] = &["Operation", "Measurement", "MeasureQubitAndReset"];
+
 /// This PRAGMA measurement operation returns the statevector of a quantum register.
 ///
 #[derive(
@@ -117,6 +169,24 @@ impl Substitute for PragmaGetStateVector {
         };
         Ok(PragmaGetStateVector::new(self.readout.clone(), new_circuit))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.bind_parameters(values)?),
+            _ => None,
+        };
+        Ok(PragmaGetStateVector::new(self.readout.clone(), new_circuit))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.rename_parameters(mapping)?),
+            _ => None,
+        };
+        Ok(PragmaGetStateVector::new(self.readout.clone(), new_circuit))
+    }
 }
 
 // Implements the InvolveQubits trait for PragmaGetStateVector.
@@ -183,6 +253,30 @@ impl Substitute for PragmaGetDensityMatrix {
             new_circuit,
         ))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.bind_parameters(values)?),
+            _ => None,
+        };
+        Ok(PragmaGetDensityMatrix::new(
+            self.readout.clone(),
+            new_circuit,
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.rename_parameters(mapping)?),
+            _ => None,
+        };
+        Ok(PragmaGetDensityMatrix::new(
+            self.readout.clone(),
+            new_circuit,
+        ))
+    }
 }
 
 // Implements the InvolveQubits trait for PragmaGetDensityMatrix.
@@ -252,6 +346,30 @@ impl Substitute for PragmaGetOccupationProbability {
             new_circuit,
         ))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.bind_parameters(values)?),
+            _ => None,
+        };
+        Ok(PragmaGetOccupationProbability::new(
+            self.readout.clone(),
+            new_circuit,
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let new_circuit = match self.circuit.as_ref() {
+            Some(x) => Some(x.rename_parameters(mapping)?),
+            _ => None,
+        };
+        Ok(PragmaGetOccupationProbability::new(
+            self.readout.clone(),
+            new_circuit,
+        ))
+    }
 }
 
 // Implements the InvolveQubits trait for PragmaGetOccupationProbability.
@@ -322,13 +440,31 @@ impl Substitute for PragmaGetPauliProduct {
 
     /// Substitutes symbolic parameters in clone of the operation.
     fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
-        let new_circuit = self.circuit.substitute_parameters(calculator).unwrap();
+        let new_circuit = self.circuit.substitute_parameters(calculator)?;
         Ok(PragmaGetPauliProduct::new(
             self.qubit_paulis.clone(),
             self.readout.clone(),
             new_circuit,
         ))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaGetPauliProduct::new(
+            self.qubit_paulis.clone(),
+            self.readout.clone(),
+            self.circuit.bind_parameters(values)?,
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaGetPauliProduct::new(
+            self.qubit_paulis.clone(),
+            self.readout.clone(),
+            self.circuit.rename_parameters(mapping)?,
+        ))
+    }
 }
 
 // Implements the InvolveQubits trait for PragmaGetPauliProduct.
@@ -25,7 +25,7 @@ use crate::RoqoqoError;
 use dyn_clone::DynClone;
 use ndarray::Array2;
 use num_complex::Complex64;
-use qoqo_calculator::CalculatorFloat;
+use qoqo_calculator::{Calculator, CalculatorFloat};
 use roqoqo_derive::*;
 use std::collections::{HashMap, HashSet};
 /// Collection of roqoqo definition operations.
@@ -72,6 +72,10 @@ pub use spin_boson_operations::*;
 mod analog_operations;
 #[cfg(feature = "unstable_analog_operations")]
 pub use analog_operations::*;
+/// Symbols, binding and renaming of symbolic parameters.
+mod parameter_expressions;
+pub(crate) use parameter_expressions::is_symbol_name;
+pub use parameter_expressions::{bind_parameter, rename_parameter, symbols_in_parameter};
 
 include!(concat!(env!("OUT_DIR"), "/_auto_generated_operations.rs"));
 
@@ -129,6 +133,12 @@ pub trait Operate:
     fn hqslang(&self) -> &'static str;
     /// Returns true when operation has symbolic parameters.
     fn is_parametrized(&self) -> bool;
+    /// Returns the names of the symbols in the symbolic parameters of the operation.
+    ///
+    /// The default implementation returns no symbols.
+    fn symbols(&self) -> HashSet<String> {
+        HashSet::new()
+    }
 }
 
 #[cfg(not(feature = "dynamic"))]
@@ -160,6 +170,9 @@ pub trait Operate:
 /// // But it can also not be parametrized:
 /// let rotatez_not_param = RotateZ::new(0, CalculatorFloat::from(2.0));
 /// assert!(!rotatez_not_param.is_parametrized());
+/// // 4) The symbols a parametrized gate depends on can be listed
+/// let rotatez_expression = RotateZ::new(0, CalculatorFloat::from("2 * sin(theta) + phi"));
+/// assert_eq!(rotatez_expression.symbols(), ["theta".to_string(), "phi".to_string()].into());
 /// ```
 ///
 pub trait Operate:
@@ -175,6 +188,13 @@ pub trait Operate:
     fn hqslang(&self) -> &'static str;
     /// Returns `true` when operation has symbolic parameters.
     fn is_parametrized(&self) -> bool;
+    /// Returns the names of the symbols in the symbolic parameters of the operation.
+    ///
+    /// Function names in symbolic expressions (e.g. `sin` in `"sin(theta)"`) are not symbols.
+    /// The default implementation returns no symbols.
+    fn symbols(&self) -> HashSet<String> {
+        HashSet::new()
+    }
 }
 
 #[cfg(feature = "dynamic")]
@@ -237,6 +257,16 @@ pub trait InvolveQubits {
 /// qubit_mapping_test.insert(2, 0);
 /// let result = rotatez.remap_qubits(&qubit_mapping_test).unwrap();
 /// assert_eq!(result, RotateZ::new(2, CalculatorFloat::from(0.0)));
+/// // 3) The bind_parameters function substitutes only the given symbols and keeps the others symbolic
+/// let rotatez = RotateZ::new(0, CalculatorFloat::from("(2 * calibration) * theta"));
+/// let values: HashMap<String, f64> = [("calibration".to_string(), 0.5)].into();
+/// let result = rotatez.bind_parameters(&values).unwrap();
+/// assert_eq!(result, RotateZ::new(0, CalculatorFloat::from("1.0 * theta")));
+/// // 4) The rename_parameters function renames the given symbols
+/// let rotatez = RotateZ::new(0, CalculatorFloat::from("2 * theta"));
+/// let mapping: HashMap<String, String> = [("theta".to_string(), "phi".to_string())].into();
+/// let result = rotatez.rename_parameters(&mapping).unwrap();
+/// assert_eq!(result, RotateZ::new(0, CalculatorFloat::from("2 * phi")));
 /// ```
 ///
 pub trait Substitute
@@ -250,6 +280,28 @@ where
     ) -> Result<Self, RoqoqoError>;
     /// Remaps the qubits in clone of the operation.
     fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError>;
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    ///
+    /// The default implementation falls back to [Substitute::substitute_parameters].
+    /// It returns operations without symbols unchanged, but fails when symbols are left unbound,
+    /// so operations with symbolic parameters need to override it.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let mut calculator = Calculator::new();
+        for (name, value) in values {
+            calculator.set_variable(name, *value);
+        }
+        self.substitute_parameters(&calculator)
+    }
+    /// Renames the given symbols in clone of the operation.
+    ///
+    /// The default implementation only supports operations without symbols, which are returned unchanged,
+    /// so operations with symbolic parameters need to override it.
+    fn rename_parameters(&self, _mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        self.substitute_parameters(&Calculator::new())
+            .map_err(|_| RoqoqoError::GenericError {
+                msg: "Renaming symbols is not supported by the operation".to_string(),
+            })
+    }
 }
 
 #[cfg(feature = "dynamic")]
@@ -265,6 +317,16 @@ pub trait SubstituteDyn {
         &self,
         mapping: &HashMap<usize, usize>,
     ) -> Result<Box<dyn Operate>, RoqoqoError>;
+    /// Bind the given symbols in clone of operation.
+    fn bind_parameters_dyn(
+        &self,
+        values: &HashMap<String, f64>,
+    ) -> Result<Box<dyn Operate>, RoqoqoError>;
+    /// Rename the given symbols in clone of operation.
+    fn rename_parameters_dyn(
+        &self,
+        mapping: &HashMap<String, String>,
+    ) -> Result<Box<dyn Operate>, RoqoqoError>;
 }
 
 #[cfg(feature = "dynamic")]
@@ -288,6 +350,20 @@ where
     ) -> Result<Box<dyn Operate>, RoqoqoError> {
         Ok(Box::new(Substitute::remap_qubits(self, mapping)?))
     }
+    /// Bind the given symbols in boxed clone of operation.
+    fn bind_parameters_dyn(
+        &self,
+        values: &HashMap<String, f64>,
+    ) -> Result<Box<dyn Operate>, RoqoqoError> {
+        Ok(Box::new(Substitute::bind_parameters(self, values)?))
+    }
+    /// Rename the given symbols in boxed clone of operation.
+    fn rename_parameters_dyn(
+        &self,
+        mapping: &HashMap<String, String>,
+    ) -> Result<Box<dyn Operate>, RoqoqoError> {
+        Ok(Box::new(Substitute::rename_parameters(self, mapping)?))
+    }
 }
 
 /// Trait for operations acting on exactly one qubit.
@@ -935,6 +1011,9 @@ pub trait ImplementedIn1point19: Operate {}
 /// Marker trait to show that some operation has been implemented in roqoqo 1.20.0
 pub trait ImplementedIn1point20: Operate {}
 
+/// Marker trait to show that some operation has been implemented in roqoqo 1.21.0
+pub trait ImplementedIn1point21: Operate {}
+
 #[cfg(feature = "dynamic")]
 /// A wrapper for Operate trait objects.
 ///
@@ -957,6 +1036,9 @@ impl Operate for DynOperation {
     fn is_parametrized(&self) -> bool {
         self.0.is_parametrized()
     }
+    fn symbols(&self) -> HashSet<String> {
+        self.0.symbols()
+    }
 }
 
 #[cfg(feature = "dynamic")]
@@ -984,6 +1066,12 @@ impl Substitute for DynOperation {
     fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
         Ok(DynOperation(self.0.remap_qubits_dyn(mapping)?))
     }
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        Ok(DynOperation(self.0.bind_parameters_dyn(values)?))
+    }
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        Ok(DynOperation(self.0.rename_parameters_dyn(mapping)?))
+    }
 }
 #[cfg(feature = "dynamic")]
 impl PartialEq for DynOperation {
@@ -310,6 +310,72 @@ impl Substitute for CallDefinedGate {
             self.free_parameters.clone(),
         ))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(
+        &self,
+        values: &std::collections::HashMap<String, f64>,
+    ) -> Result<Self, RoqoqoError> {
+        let mut new_params: Vec<CalculatorFloat> = vec![];
+        for param in self.free_parameters.iter() {
+            new_params.push(crate::operations::bind_parameter(param, values)?);
+        }
+        Ok(CallDefinedGate::new(
+            self.gate_name.clone(),
+            self.qubits.clone(),
+            new_params,
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(
+        &self,
+        mapping: &std::collections::HashMap<String, String>,
+    ) -> Result<Self, RoqoqoError> {
+        let mut new_params: Vec<CalculatorFloat> = vec![];
+        for param in self.free_parameters.iter() {
+            new_params.push(crate::operations::rename_parameter(param, mapping)?);
+        }
+        Ok(CallDefinedGate::new(
+            self.gate_name.clone(),
+            self.qubits.clone(),
+            new_params,
+        ))
+    }
+}
+
+#[cfg(feature = "unstable_operation_definition")]
+impl CallDefinedGate {
+    /// Returns the unitary matrix of the called gate from its definition.
+    ///
+    /// The free parameters of the definition are replaced by the parameters of the call.
+    /// The i-th qubit of the call is the i-th qubit of the definition,
+    /// with the first qubit being the most significant qubit of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `definition` - The GateDefinition of the called gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The unitary matrix of the called gate.
+    /// * `Err(RoqoqoError)` - The definition does not match the call or its body has no unitary matrix.
+    pub fn unitary_matrix_from_definition(
+        &self,
+        definition: &operations::GateDefinition,
+    ) -> Result<Array2<Complex64>, RoqoqoError> {
+        if definition.name() != &self.gate_name {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "CallDefinedGate {} cannot use GateDefinition {}",
+                    self.gate_name,
+                    definition.name()
+                ),
+            });
+        }
+        crate::passes::check_call(self, definition)?;
+        definition.unitary_matrix(&self.free_parameters)
+    }
 }
 
 #[cfg(feature = "unstable_operation_definition")]
@@ -348,7 +414,6 @@ const TAGS_CallDefinedGate: &[&str; 3] =
 )]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
-#[allow(clippy::upper_case_acronyms)]
 pub struct QFT {
     /// The qubits involved in the QFT.
     qubits: Vec<usize>,
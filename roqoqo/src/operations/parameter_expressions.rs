@@ -0,0 +1,401 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::RoqoqoError;
+use qoqo_calculator::{Calculator, CalculatorFloat};
+use std::collections::{HashMap, HashSet};
+
+/// Returns the names of the symbols in a CalculatorFloat.
+///
+/// Float values do not contain symbols. String expressions are lexed like in the qoqo_calculator
+/// Calculator: every name that is not a function call (e.g. `sin` in `"sin(theta)"`) and is
+/// not assigned in the expression itself is returned.
+///
+/// # Arguments
+///
+/// * `parameter` - The CalculatorFloat to inspect.
+///
+/// # Returns
+///
+/// * `HashSet<String>` - The names of the symbols.
+pub fn symbols_in_parameter(parameter: &CalculatorFloat) -> HashSet<String> {
+    match parameter {
+        CalculatorFloat::Float(_) => HashSet::new(),
+        CalculatorFloat::Str(expression) => expression_symbols(expression),
+    }
+}
+
+/// Substitutes the given symbols in a CalculatorFloat and keeps the other symbols symbolic.
+///
+/// The values of the given symbols are inserted into the expression. Bracketed parts and function
+/// calls that no longer contain symbols are evaluated, and an expression without remaining
+/// symbols is evaluated to a float.
+///
+/// # Arguments
+///
+/// * `parameter` - The CalculatorFloat to bind.
+/// * `values` - The values of the symbols to substitute.
+///
+/// # Returns
+///
+/// * `Ok(CalculatorFloat)` - The parameter with the given symbols substituted.
+/// * `Err(RoqoqoError::GenericError)` - The value of a substituted symbol is not finite.
+/// * `Err(RoqoqoError::CalculatorError)` - The expression without remaining symbols cannot be evaluated.
+pub fn bind_parameter(
+    parameter: &CalculatorFloat,
+    values: &HashMap<String, f64>,
+) -> Result<CalculatorFloat, RoqoqoError> {
+    let expression = match parameter {
+        CalculatorFloat::Float(_) => return Ok(parameter.clone()),
+        CalculatorFloat::Str(expression) => expression,
+    };
+    let tokens = tokenize_expression(expression);
+    let assigned: HashSet<&String> = tokens
+        .iter()
+        .filter_map(|token| match token {
+            ExpressionToken::Assigned(name) => Some(name),
+            _ => None,
+        })
+        .collect();
+    let mut bound = String::new();
+    for token in tokens.iter() {
+        match token {
+            ExpressionToken::Variable(name) if !assigned.contains(name) => match values.get(name) {
+                Some(value) if !value.is_finite() => {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!("Value {value} of symbol {name} is not finite"),
+                    })
+                }
+                Some(value) => bound.push_str(&format_value(*value)),
+                None => bound.push_str(name),
+            },
+            ExpressionToken::Variable(text)
+            | ExpressionToken::Function(text)
+            | ExpressionToken::Assigned(text)
+            | ExpressionToken::Other(text) => bound.push_str(text),
+        }
+    }
+    let simplified = fold_constant_brackets(&bound);
+    if expression_symbols(&simplified).is_empty() {
+        let value = Calculator::new().parse_str(&simplified)?;
+        return Ok(CalculatorFloat::from(value));
+    }
+    Ok(CalculatorFloat::from(simplified))
+}
+
+/// Renames the given symbols in a CalculatorFloat.
+///
+/// Only names that are read as symbols are renamed, function calls and variables assigned in the
+/// expression itself are kept. A new name can also be an expression in brackets (e.g. `"(2 * phi)"`),
+/// which replaces the symbol by the expression.
+///
+/// # Arguments
+///
+/// * `parameter` - The CalculatorFloat to rename the symbols in.
+/// * `mapping` - The new names of the symbols to rename.
+///
+/// # Returns
+///
+/// * `Ok(CalculatorFloat)` - The parameter with the given symbols renamed.
+/// * `Err(RoqoqoError::GenericError)` - A new name is neither a valid symbol name nor an expression in brackets.
+pub fn rename_parameter(
+    parameter: &CalculatorFloat,
+    mapping: &HashMap<String, String>,
+) -> Result<CalculatorFloat, RoqoqoError> {
+    let expression = match parameter {
+        CalculatorFloat::Float(_) => return Ok(parameter.clone()),
+        CalculatorFloat::Str(expression) => expression,
+    };
+    let tokens = tokenize_expression(expression);
+    let assigned: HashSet<&String> = tokens
+        .iter()
+        .filter_map(|token| match token {
+            ExpressionToken::Assigned(name) => Some(name),
+            _ => None,
+        })
+        .collect();
+    let mut renamed = String::new();
+    for token in tokens.iter() {
+        match token {
+            ExpressionToken::Variable(name) if !assigned.contains(name) => {
+                match mapping.get(name) {
+                    Some(new_name) => {
+                        if !is_symbol_name(new_name) && !is_bracketed_expression(new_name) {
+                            return Err(RoqoqoError::GenericError {
+                                msg: format!(
+                                    "New name {new_name} of symbol {name} is not a valid symbol name"
+                                ),
+                            });
+                        }
+                        renamed.push_str(new_name)
+                    }
+                    None => renamed.push_str(name),
+                }
+            }
+            ExpressionToken::Variable(text)
+            | ExpressionToken::Function(text)
+            | ExpressionToken::Assigned(text)
+            | ExpressionToken::Other(text) => renamed.push_str(text),
+        }
+    }
+    Ok(CalculatorFloat::from(renamed))
+}
+
+/// Returns true when the name is a single symbol name that can be read in an expression.
+pub(crate) fn is_symbol_name(name: &str) -> bool {
+    matches!(
+        tokenize_expression(name).as_slice(),
+        [ExpressionToken::Variable(token_name)] if token_name == name
+    )
+}
+
+// Returns true for a single expression enclosed in one pair of brackets,
+// expressions with comments, assignments or several statements are not accepted
+fn is_bracketed_expression(expression: &str) -> bool {
+    if !expression.starts_with('(') || expression.contains(['#', '=', ';']) {
+        return false;
+    }
+    let mut depth = 0;
+    for (index, character) in expression.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+        if depth == 0 {
+            return index == expression.len() - 1 && index > 1;
+        }
+    }
+    false
+}
+
+// Part of a symbolic expression, keeping the original text to reassemble the expression
+#[derive(Debug, PartialEq)]
+enum ExpressionToken {
+    // Name of a variable that is read in the expression
+    Variable(String),
+    // Name of a called function
+    Function(String),
+    // Name of a variable that is assigned in the expression
+    Assigned(String),
+    // Numbers, operators, brackets, whitespace and comments
+    Other(String),
+}
+
+// Lexes an expression like the qoqo_calculator Calculator
+fn tokenize_expression(expression: &str) -> Vec<ExpressionToken> {
+    let characters: Vec<char> = expression.chars().collect();
+    let mut tokens: Vec<ExpressionToken> = Vec::new();
+    let mut position = 0;
+    while position < characters.len() {
+        let start = position;
+        let character = characters[position];
+        if character == '#' {
+            // Comments run until the end of the line
+            while position < characters.len() && characters[position] != '\n' {
+                position += 1;
+            }
+        } else if character.is_alphabetic() {
+            while position < characters.len()
+                && (characters[position].is_alphanumeric() || characters[position] == '_')
+            {
+                position += 1;
+            }
+            let name: String = characters[start..position].iter().collect();
+            let next = characters[position..].iter().find(|c| !c.is_whitespace());
+            tokens.push(match next {
+                Some('(') => ExpressionToken::Function(name),
+                Some('=') => ExpressionToken::Assigned(name),
+                _ => ExpressionToken::Variable(name),
+            });
+            continue;
+        } else if character.is_ascii_digit() || character == '.' {
+            // Numbers including an exponent in scientific notation, e.g. 1.5e-3
+            while position < characters.len()
+                && (characters[position].is_ascii_digit() || characters[position] == '.')
+            {
+                position += 1;
+            }
+            if position < characters.len() && matches!(characters[position], 'e' | 'E') {
+                position += 1;
+                if position < characters.len() && matches!(characters[position], '+' | '-') {
+                    position += 1;
+                }
+                while position < characters.len() && characters[position].is_ascii_digit() {
+                    position += 1;
+                }
+            }
+        } else {
+            position += 1;
+        }
+        tokens.push(ExpressionToken::Other(
+            characters[start..position].iter().collect(),
+        ));
+    }
+    tokens
+}
+
+// Returns the names of the variables read but not assigned in an expression
+fn expression_symbols(expression: &str) -> HashSet<String> {
+    let tokens = tokenize_expression(expression);
+    let mut symbols: HashSet<String> = HashSet::new();
+    let mut assigned: HashSet<String> = HashSet::new();
+    for token in tokens {
+        match token {
+            ExpressionToken::Variable(name) => {
+                symbols.insert(name);
+            }
+            ExpressionToken::Assigned(name) => {
+                assigned.insert(name);
+            }
+            _ => (),
+        }
+    }
+    symbols.retain(|name| !assigned.contains(name));
+    symbols
+}
+
+// Writes a value so that it can be inserted at the position of a variable in an expression
+fn format_value(value: f64) -> String {
+    if value.is_sign_negative() {
+        format!("({value:?})")
+    } else {
+        format!("{value:?}")
+    }
+}
+
+// Evaluates the bracketed parts and function calls of an expression that do not contain symbols,
+// expressions with comments, assignments or several statements are left unchanged
+fn fold_constant_brackets(expression: &str) -> String {
+    if expression.contains(['#', '=', ';']) {
+        return expression.to_string();
+    }
+    let characters: Vec<char> = expression.chars().collect();
+    let mut folded = String::new();
+    let mut position = 0;
+    while position < characters.len() {
+        if characters[position] != '(' {
+            folded.push(characters[position]);
+            position += 1;
+            continue;
+        }
+        let mut depth = 0;
+        let mut closing = None;
+        for (index, character) in characters.iter().enumerate().skip(position) {
+            match character {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => (),
+            }
+            if depth == 0 {
+                closing = Some(index);
+                break;
+            }
+        }
+        let closing = match closing {
+            Some(closing) => closing,
+            None => {
+                folded.extend(characters[position..].iter());
+                break;
+            }
+        };
+        let inner: String = characters[position + 1..closing].iter().collect();
+        let inner = fold_constant_brackets(&inner);
+        // A name directly in front of the bracket is the called function
+        let prefix = folded.trim_end();
+        let function_start = match tokenize_expression(prefix).last() {
+            Some(ExpressionToken::Variable(name)) => Some(prefix.len() - name.len()),
+            _ => None,
+        };
+        let (kept, call) = match function_start {
+            Some(index) => (&folded[..index], format!("{}({inner})", &prefix[index..])),
+            None => (folded.as_str(), format!("({inner})")),
+        };
+        // The Calculator without variables fails for every expression containing a symbol
+        let replacement = match Calculator::new().parse_str(&call) {
+            Ok(value) if value.is_finite() => format_value(value),
+            _ => call,
+        };
+        folded = format!("{kept}{replacement}");
+        position = closing + 1;
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    /// Test that numbers in scientific notation are single tokens and names are classified
+    #[test]
+    fn tokenize_scientific_notation() {
+        assert_eq!(
+            tokenize_expression("1.5e-3*sin(x)+y"),
+            vec![
+                ExpressionToken::Other("1.5e-3".to_string()),
+                ExpressionToken::Other("*".to_string()),
+                ExpressionToken::Function("sin".to_string()),
+                ExpressionToken::Other("(".to_string()),
+                ExpressionToken::Variable("x".to_string()),
+                ExpressionToken::Other(")".to_string()),
+                ExpressionToken::Other("+".to_string()),
+                ExpressionToken::Variable("y".to_string()),
+            ]
+        );
+        assert_eq!(
+            tokenize_expression("a = 2E+4"),
+            vec![
+                ExpressionToken::Assigned("a".to_string()),
+                ExpressionToken::Other(" ".to_string()),
+                ExpressionToken::Other("=".to_string()),
+                ExpressionToken::Other(" ".to_string()),
+                ExpressionToken::Other("2E+4".to_string()),
+            ]
+        );
+    }
+
+    /// Test that exponents, function names and assigned variables are not symbols
+    #[test_case("2e3 * theta", &["theta"]; "scientific notation")]
+    #[test_case("1E-3 + 4.5e+2", &[]; "only constants")]
+    #[test_case("cos(sin(theta) * phi)", &["theta", "phi"]; "nested functions")]
+    #[test_case("a = 2; a * b # comment c", &["b"]; "assignment and comment")]
+    fn symbols_of_expression(expression: &str, expected: &[&str]) {
+        let expected: HashSet<String> = expected.iter().map(|name| name.to_string()).collect();
+        assert_eq!(expression_symbols(expression), expected);
+    }
+
+    /// Test that negative values are bracketed and scientific notation is kept readable
+    #[test_case(2.0, "2.0"; "positive")]
+    #[test_case(-2.0, "(-2.0)"; "negative")]
+    #[test_case(1e-7, "1e-7"; "small")]
+    #[test_case(-2.5e20, "(-2.5e20)"; "large negative")]
+    fn format_values(value: f64, expected: &str) {
+        assert_eq!(format_value(value), expected);
+    }
+
+    /// Test folding of constant brackets including nested brackets and scientific notation
+    #[test_case("(1 + 2) * theta", "3.0 * theta"; "single bracket")]
+    #[test_case("((1 + 2) * (3 + theta))", "(3.0 * (3 + theta))"; "nested with symbol")]
+    #[test_case("((1 + 1) * (2 + 2)) * theta", "8.0 * theta"; "nested constant")]
+    #[test_case("(2.5e-1 * 4) * theta", "1.0 * theta"; "scientific notation")]
+    #[test_case("(1.5e-3 + theta) * 2e2", "(1.5e-3 + theta) * 2e2"; "scientific notation with symbol")]
+    #[test_case("(1 - 3) * theta", "(-2.0) * theta"; "negative result")]
+    #[test_case("sqrt(sqrt(16)) * theta", "2.0 * theta"; "nested functions")]
+    #[test_case("sin(theta * (1 + 1))", "sin(theta * 2.0)"; "function with symbol")]
+    #[test_case("(1 / 0) * theta", "(1 / 0) * theta"; "not finite")]
+    #[test_case("a = (1 + 1); a * theta", "a = (1 + 1); a * theta"; "assignment")]
+    #[test_case("((1 + 2) * theta", "((1 + 2) * theta"; "unbalanced")]
+    fn fold_brackets(expression: &str, expected: &str) {
+        assert_eq!(fold_constant_brackets(expression), expected);
+    }
+}
@@ -26,7 +26,7 @@ use bincode::serialize;
 use nalgebra::{matrix, Matrix4};
 use ndarray::{array, Array, Array1, Array2};
 use num_complex::Complex64;
-use qoqo_calculator::{Calculator, CalculatorFloat};
+use qoqo_calculator::{Calculator, CalculatorError, CalculatorFloat};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -1088,13 +1088,880 @@ impl Substitute for PragmaConditional {
 
     /// Substitutes symbolic parameters in clone of the operation.
     fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
-        let new_circuit = self.circuit.substitute_parameters(calculator).unwrap();
+        let new_circuit = self.circuit.substitute_parameters(calculator)?;
         Ok(PragmaConditional::new(
             self.condition_register.clone(),
             self.condition_index,
             new_circuit,
         ))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaConditional::new(
+            self.condition_register.clone(),
+            self.condition_index,
+            self.circuit.bind_parameters(values)?,
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaConditional::new(
+            self.condition_register.clone(),
+            self.condition_index,
+            self.circuit.rename_parameters(mapping)?,
+        ))
+    }
+}
+
+/// A boolean expression over entries of classical bit registers.
+///
+/// Used as the condition of [PragmaIfElse] and [PragmaWhileLoop] and as the assigned value of [PragmaSetBit].
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub enum BooleanExpression {
+    /// A constant bool value.
+    Constant(bool),
+    /// The entry of a [crate::registers::BitRegister] given by the register name and the index.
+    Bit(String, usize),
+    /// The negation of an expression.
+    Not(Box<BooleanExpression>),
+    /// True when all expressions are true (true for an empty list).
+    And(Vec<BooleanExpression>),
+    /// True when at least one expression is true (false for an empty list).
+    Or(Vec<BooleanExpression>),
+    /// True when an odd number of expressions is true (false for an empty list).
+    Xor(Vec<BooleanExpression>),
+}
+
+impl BooleanExpression {
+    /// Returns all bit register entries read by the expression.
+    ///
+    /// # Returns
+    ///
+    /// * `HashSet<(String, usize)>` - The (register name, index) pairs the expression depends on.
+    pub fn involved_bits(&self) -> HashSet<(String, usize)> {
+        let mut involved: HashSet<(String, usize)> = HashSet::new();
+        match self {
+            BooleanExpression::Constant(_) => (),
+            BooleanExpression::Bit(name, index) => {
+                involved.insert((name.clone(), *index));
+            }
+            BooleanExpression::Not(inner) => involved = inner.involved_bits(),
+            BooleanExpression::And(inner)
+            | BooleanExpression::Or(inner)
+            | BooleanExpression::Xor(inner) => {
+                for expression in inner.iter() {
+                    involved.extend(expression.involved_bits());
+                }
+            }
+        }
+        involved
+    }
+
+    /// Evaluates the expression for the given bit registers.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The bit registers the expression is evaluated on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - The value of the expression.
+    /// * `Err(RoqoqoError::GenericError)` - A register is missing or an index is out of range.
+    pub fn evaluate(
+        &self,
+        bit_registers: &HashMap<String, crate::registers::BitRegister>,
+    ) -> Result<bool, RoqoqoError> {
+        match self {
+            BooleanExpression::Constant(value) => Ok(*value),
+            BooleanExpression::Bit(name, index) => bit_registers
+                .get(name)
+                .ok_or_else(|| RoqoqoError::GenericError {
+                    msg: format!("Bit register {name} has not been defined"),
+                })?
+                .get(*index)
+                .copied()
+                .ok_or_else(|| RoqoqoError::GenericError {
+                    msg: format!("Index {index} exceeds length of bit register {name}"),
+                }),
+            BooleanExpression::Not(inner) => Ok(!inner.evaluate(bit_registers)?),
+            BooleanExpression::And(inner) => {
+                for expression in inner.iter() {
+                    if !expression.evaluate(bit_registers)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            BooleanExpression::Or(inner) => {
+                for expression in inner.iter() {
+                    if expression.evaluate(bit_registers)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            BooleanExpression::Xor(inner) => {
+                let mut parity = false;
+                for expression in inner.iter() {
+                    parity ^= expression.evaluate(bit_registers)?;
+                }
+                Ok(parity)
+            }
+        }
+    }
+}
+
+// Combines the classical register entries involved in a list of circuits with a set of read entries.
+fn involved_classical_with_circuits(
+    read: HashSet<(String, usize)>,
+    circuits: &[&Circuit],
+) -> InvolvedClassical {
+    let mut involved = read;
+    for op in circuits.iter().flat_map(|circuit| circuit.iter()) {
+        match op.involved_classical() {
+            InvolvedClassical::All(x) => return InvolvedClassical::All(x),
+            InvolvedClassical::AllQubits(x) => return InvolvedClassical::AllQubits(x),
+            InvolvedClassical::None => (),
+            InvolvedClassical::Set(x) => involved.extend(x),
+        }
+    }
+    if involved.is_empty() {
+        InvolvedClassical::None
+    } else {
+        InvolvedClassical::Set(involved)
+    }
+}
+
+// Returns the minimum roqoqo version of a control flow operation containing the given circuits.
+fn control_flow_version(circuits: &[&Circuit]) -> (u32, u32, u32) {
+    circuits
+        .iter()
+        .map(|circuit| circuit.minimum_supported_roqoqo_version())
+        .fold((1, 21, 0), |version, circuit_version| {
+            version.max(circuit_version)
+        })
+}
+
+/// The if-else PRAGMA operation.
+///
+/// This PRAGMA executes `if_circuit` when the condition evaluated on the classical bit registers is true
+/// and `else_circuit` otherwise.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaIfElse {
+    /// The boolean expression deciding which circuit is executed.
+    condition: BooleanExpression,
+    /// The circuit executed if the condition is true.
+    if_circuit: Circuit,
+    /// The circuit executed if the condition is false.
+    else_circuit: Circuit,
+}
+
+impl super::ImplementedIn1point21 for PragmaIfElse {}
+
+impl SupportedVersion for PragmaIfElse {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        control_flow_version(&[&self.if_circuit, &self.else_circuit])
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaIfElse: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaIfElse"];
+
+// Implementing the InvolveQubits trait for PragmaIfElse.
+impl InvolveQubits for PragmaIfElse {
+    /// Lists all involved qubits.
+    fn involved_qubits(&self) -> InvolvedQubits {
+        match (
+            self.if_circuit.involved_qubits(),
+            self.else_circuit.involved_qubits(),
+        ) {
+            (InvolvedQubits::All, _) | (_, InvolvedQubits::All) => InvolvedQubits::All,
+            (InvolvedQubits::None, other) | (other, InvolvedQubits::None) => other,
+            (InvolvedQubits::Set(mut first), InvolvedQubits::Set(second)) => {
+                first.extend(second);
+                InvolvedQubits::Set(first)
+            }
+        }
+    }
+
+    /// Lists the entries read by the condition and all entries involved in the two circuits.
+    fn involved_classical(&self) -> InvolvedClassical {
+        involved_classical_with_circuits(
+            self.condition.involved_bits(),
+            &[&self.if_circuit, &self.else_circuit],
+        )
+    }
+}
+
+/// Substitute trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaIfElse {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaIfElse::new(
+            self.condition.clone(),
+            self.if_circuit.remap_qubits(mapping)?,
+            self.else_circuit.remap_qubits(mapping)?,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        Ok(PragmaIfElse::new(
+            self.condition.clone(),
+            self.if_circuit.substitute_parameters(calculator)?,
+            self.else_circuit.substitute_parameters(calculator)?,
+        ))
+    }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaIfElse::new(
+            self.condition.clone(),
+            self.if_circuit.bind_parameters(values)?,
+            self.else_circuit.bind_parameters(values)?,
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaIfElse::new(
+            self.condition.clone(),
+            self.if_circuit.rename_parameters(mapping)?,
+            self.else_circuit.rename_parameters(mapping)?,
+        ))
+    }
+}
+
+/// The while-loop PRAGMA operation.
+///
+/// This PRAGMA repeatedly executes a circuit as long as the condition evaluated on the classical bit registers is true.
+/// The loop stops after `max_iterations` executions of the circuit even if the condition is still true.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaWhileLoop {
+    /// The boolean expression checked before every iteration.
+    condition: BooleanExpression,
+    /// The circuit executed while the condition is true.
+    circuit: Circuit,
+    /// The maximum number of times the circuit is executed.
+    max_iterations: usize,
+}
+
+impl super::ImplementedIn1point21 for PragmaWhileLoop {}
+
+impl SupportedVersion for PragmaWhileLoop {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        control_flow_version(&[&self.circuit])
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaWhileLoop: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaWhileLoop"];
+
+// Implementing the InvolveQubits trait for PragmaWhileLoop.
+impl InvolveQubits for PragmaWhileLoop {
+    /// Lists all involved qubits.
+    fn involved_qubits(&self) -> InvolvedQubits {
+        self.circuit.involved_qubits()
+    }
+
+    /// Lists the entries read by the condition and all entries involved in the circuit.
+    fn involved_classical(&self) -> InvolvedClassical {
+        involved_classical_with_circuits(self.condition.involved_bits(), &[&self.circuit])
+    }
+}
+
+/// Substitute trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaWhileLoop {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaWhileLoop::new(
+            self.condition.clone(),
+            self.circuit.remap_qubits(mapping)?,
+            self.max_iterations,
+        ))
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        Ok(PragmaWhileLoop::new(
+            self.condition.clone(),
+            self.circuit.substitute_parameters(calculator)?,
+            self.max_iterations,
+        ))
+    }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaWhileLoop::new(
+            self.condition.clone(),
+            self.circuit.bind_parameters(values)?,
+            self.max_iterations,
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaWhileLoop::new(
+            self.condition.clone(),
+            self.circuit.rename_parameters(mapping)?,
+            self.max_iterations,
+        ))
+    }
+}
+
+/// The classical assignment PRAGMA operation.
+///
+/// This PRAGMA sets an entry of a [crate::registers::BitRegister] to the value of a boolean expression
+/// evaluated on the classical bit registers.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaSetBit {
+    /// The name of the [crate::registers::BitRegister] that is written to.
+    register: String,
+    /// The index in the [crate::registers::BitRegister] that is written to.
+    index: usize,
+    /// The boolean expression whose value is assigned.
+    value: BooleanExpression,
+}
+
+impl super::ImplementedIn1point21 for PragmaSetBit {}
+
+impl SupportedVersion for PragmaSetBit {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 21, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaSetBit: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSetBit"];
+
+// Implementing the InvolveQubits trait for PragmaSetBit.
+impl InvolveQubits for PragmaSetBit {
+    /// Lists all involved qubits (here: none).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::None
+    }
+
+    /// Lists the written entry and all entries read by the assigned expression.
+    fn involved_classical(&self) -> InvolvedClassical {
+        let mut involved = self.value.involved_bits();
+        involved.insert((self.register.clone(), self.index));
+        InvolvedClassical::Set(involved)
+    }
+}
+
+/// Substitute trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaSetBit {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, _mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, _calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+// Returns the end of a range of register entries or an error when the end overflows.
+fn range_end(register: &str, start: usize, length: usize) -> Result<usize, RoqoqoError> {
+    start
+        .checked_add(length)
+        .ok_or_else(|| RoqoqoError::GenericError {
+            msg: format!(
+                "Range of {length} entries starting at {start} in register {register} overflows"
+            ),
+        })
+}
+
+// Returns a range of entries of a bit register or an error when the range is not available.
+fn bit_range<'a>(
+    bit_registers: &'a HashMap<String, crate::registers::BitRegister>,
+    register: &str,
+    start: usize,
+    length: usize,
+) -> Result<&'a [bool], RoqoqoError> {
+    let end = range_end(register, start, length)?;
+    bit_registers
+        .get(register)
+        .ok_or_else(|| RoqoqoError::GenericError {
+            msg: format!("Bit register {register} has not been defined"),
+        })?
+        .get(start..end)
+        .ok_or_else(|| RoqoqoError::GenericError {
+            msg: format!("Range {start}..{end} exceeds length of bit register {register}"),
+        })
+}
+
+// Collects the entries of several ranges of the same length in classical registers.
+// Overflowing ranges contain no entries, evaluating the operation fails for them.
+fn bitwise_involved_classical(
+    ranges: [(&String, usize); 3],
+    length: usize,
+) -> HashSet<(String, usize)> {
+    ranges
+        .iter()
+        .flat_map(|(register, start)| {
+            let end = range_end(register, *start, length).unwrap_or(*start);
+            (*start..end).map(|index| ((*register).clone(), index))
+        })
+        .collect()
+}
+
+/// The operator combining the entries of two bit register ranges in [PragmaBitwise].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub enum BitwiseOperator {
+    /// The logical AND.
+    And,
+    /// The logical OR.
+    Or,
+    /// The exclusive OR.
+    Xor,
+}
+
+impl BitwiseOperator {
+    /// Applies the operator to two bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The first bit.
+    /// * `second` - The second bit.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - The combined bit.
+    pub fn apply(&self, first: bool, second: bool) -> bool {
+        match self {
+            BitwiseOperator::And => first & second,
+            BitwiseOperator::Or => first | second,
+            BitwiseOperator::Xor => first ^ second,
+        }
+    }
+}
+
+impl std::fmt::Display for BitwiseOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitwiseOperator::And => write!(f, "AND"),
+            BitwiseOperator::Or => write!(f, "OR"),
+            BitwiseOperator::Xor => write!(f, "XOR"),
+        }
+    }
+}
+
+impl std::str::FromStr for BitwiseOperator {
+    type Err = RoqoqoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AND" => Ok(BitwiseOperator::And),
+            "OR" => Ok(BitwiseOperator::Or),
+            "XOR" => Ok(BitwiseOperator::Xor),
+            _ => Err(RoqoqoError::GenericError {
+                msg: format!("Unknown bitwise operator {s}, expected AND, OR or XOR"),
+            }),
+        }
+    }
+}
+
+/// The bitwise PRAGMA operation.
+///
+/// This PRAGMA sets the entries `target_start..target_start + length` of the target [crate::registers::BitRegister]
+/// to the corresponding entries of the two input ranges combined with the bitwise operator.
+///
+#[derive(Debug, Clone, PartialEq, Eq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaBitwise {
+    /// The operator combining the entries of the two input ranges.
+    operator: BitwiseOperator,
+    /// The name of the [crate::registers::BitRegister] containing the first input range.
+    first_register: String,
+    /// The index of the first entry of the first input range.
+    first_start: usize,
+    /// The name of the [crate::registers::BitRegister] containing the second input range.
+    second_register: String,
+    /// The index of the first entry of the second input range.
+    second_start: usize,
+    /// The name of the [crate::registers::BitRegister] that is written to.
+    target_register: String,
+    /// The index of the first entry of the target range.
+    target_start: usize,
+    /// The number of entries in each range.
+    length: usize,
+}
+
+impl super::ImplementedIn1point21 for PragmaBitwise {}
+
+impl SupportedVersion for PragmaBitwise {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 21, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaBitwise: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaBitwise"];
+
+impl PragmaBitwise {
+    /// Computes the values written to the target range.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The bit registers containing the input ranges.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<bool>)` - The values of the target range.
+    /// * `Err(RoqoqoError::GenericError)` - A register is missing or a range overflows or exceeds the length of its register.
+    pub fn evaluate(
+        &self,
+        bit_registers: &HashMap<String, crate::registers::BitRegister>,
+    ) -> Result<Vec<bool>, RoqoqoError> {
+        range_end(&self.target_register, self.target_start, self.length)?;
+        let first = bit_range(
+            bit_registers,
+            &self.first_register,
+            self.first_start,
+            self.length,
+        )?;
+        let second = bit_range(
+            bit_registers,
+            &self.second_register,
+            self.second_start,
+            self.length,
+        )?;
+        Ok(first
+            .iter()
+            .zip(second.iter())
+            .map(|(a, b)| self.operator.apply(*a, *b))
+            .collect())
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaBitwise.
+impl InvolveQubits for PragmaBitwise {
+    /// Lists all involved qubits (here: none).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::None
+    }
+
+    /// Lists all entries of the two input ranges and the target range.
+    fn involved_classical(&self) -> InvolvedClassical {
+        InvolvedClassical::Set(bitwise_involved_classical(
+            [
+                (&self.first_register, self.first_start),
+                (&self.second_register, self.second_start),
+                (&self.target_register, self.target_start),
+            ],
+            self.length,
+        ))
+    }
+}
+
+/// Substitute trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaBitwise {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, _mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, _calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+/// The parity PRAGMA operation.
+///
+/// This PRAGMA sets an entry of a [crate::registers::BitRegister] to the parity (XOR) of the given entries of a bit register,
+/// for example to compute a syndrome bit from the measurement results of stabilizer checks.
+///
+#[derive(Debug, Clone, PartialEq, Eq, roqoqo_derive::Operate, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaParity {
+    /// The name of the [crate::registers::BitRegister] containing the input entries.
+    register: String,
+    /// The indices of the input entries.
+    indices: Vec<usize>,
+    /// The name of the [crate::registers::BitRegister] that is written to.
+    target_register: String,
+    /// The index in the target [crate::registers::BitRegister] that is written to.
+    target_index: usize,
+}
+
+impl super::ImplementedIn1point21 for PragmaParity {}
+
+impl SupportedVersion for PragmaParity {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 21, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaParity: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaParity"];
+
+impl PragmaParity {
+    /// Computes the parity written to the target entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The bit registers containing the input entries.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - The parity of the input entries.
+    /// * `Err(RoqoqoError::GenericError)` - The register is missing or an index is out of range.
+    pub fn evaluate(
+        &self,
+        bit_registers: &HashMap<String, crate::registers::BitRegister>,
+    ) -> Result<bool, RoqoqoError> {
+        BooleanExpression::Xor(
+            self.indices
+                .iter()
+                .map(|index| BooleanExpression::Bit(self.register.clone(), *index))
+                .collect(),
+        )
+        .evaluate(bit_registers)
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaParity.
+impl InvolveQubits for PragmaParity {
+    /// Lists all involved qubits (here: none).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::None
+    }
+
+    /// Lists the input entries and the written entry.
+    fn involved_classical(&self) -> InvolvedClassical {
+        let mut involved: HashSet<(String, usize)> = self
+            .indices
+            .iter()
+            .map(|index| (self.register.clone(), *index))
+            .collect();
+        involved.insert((self.target_register.clone(), self.target_index));
+        InvolvedClassical::Set(involved)
+    }
+}
+
+/// Substitute trait allowing to replace symbolic parameters and to perform qubit mappings.
+impl Substitute for PragmaParity {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, _mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, _calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+/// The classical float arithmetic PRAGMA operation.
+///
+/// This PRAGMA sets an entry of a [crate::registers::FloatRegister] to the value of a symbolic expression.
+/// The symbols listed in `variables` stand for entries of float or bit registers read while the circuit
+/// runs (bits are read as 0.0 or 1.0). All other symbols are free parameters of the circuit and are
+/// substituted, bound and renamed like the symbolic parameters of other operations.
+///
+#[derive(Debug, Clone, PartialEq, roqoqo_derive::OperatePragma)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaSetFloat {
+    /// The name of the [crate::registers::FloatRegister] that is written to.
+    register: String,
+    /// The index in the [crate::registers::FloatRegister] that is written to.
+    index: usize,
+    /// The expression whose value is assigned.
+    expression: CalculatorFloat,
+    /// The register entries (register name, index) the symbols in the expression stand for.
+    variables: HashMap<String, (String, usize)>,
+}
+
+impl super::ImplementedIn1point21 for PragmaSetFloat {}
+
+impl SupportedVersion for PragmaSetFloat {
+    fn minimum_supported_roqoqo_version(&self) -> (u32, u32, u32) {
+        (1, 21, 0)
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaSetFloat: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSetFloat"];
+
+#[cfg_attr(feature = "dynamic", typetag::serde)]
+impl Operate for PragmaSetFloat {
+    fn tags(&self) -> &'static [&'static str] {
+        TAGS_PragmaSetFloat
+    }
+    fn hqslang(&self) -> &'static str {
+        "PragmaSetFloat"
+    }
+    fn is_parametrized(&self) -> bool {
+        !self.symbols().is_empty()
+    }
+    fn symbols(&self) -> HashSet<String> {
+        let mut symbols = crate::operations::symbols_in_parameter(&self.expression);
+        symbols.retain(|symbol| !self.variables.contains_key(symbol));
+        symbols
+    }
+}
+
+impl PragmaSetFloat {
+    /// Creates a new instance of `PragmaSetFloat`.
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - The name of the float register that is written to.
+    /// * `index` - The index in the float register that is written to.
+    /// * `expression` - The expression whose value is assigned.
+    /// * `variables` - The register entries (register name, index) the symbols in the expression stand for.
+    pub fn new(
+        register: String,
+        index: usize,
+        expression: CalculatorFloat,
+        variables: HashMap<String, (String, usize)>,
+    ) -> Self {
+        Self {
+            register,
+            index,
+            expression,
+            variables,
+        }
+    }
+
+    /// Returns the name of the float register that is written to.
+    pub fn register(&self) -> &String {
+        &self.register
+    }
+
+    /// Returns the index in the float register that is written to.
+    pub fn index(&self) -> &usize {
+        &self.index
+    }
+
+    /// Returns the expression whose value is assigned.
+    pub fn expression(&self) -> &CalculatorFloat {
+        &self.expression
+    }
+
+    /// Returns the register entries the symbols in the expression stand for.
+    pub fn variables(&self) -> &HashMap<String, (String, usize)> {
+        &self.variables
+    }
+
+    /// Computes the value written to the target entry.
+    ///
+    /// Variables are looked up in the float registers first and in the bit registers second.
+    /// The free parameters of the expression need to be substituted before.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_registers` - The bit registers the variables can refer to.
+    /// * `float_registers` - The float registers the variables can refer to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The value of the expression.
+    /// * `Err(RoqoqoError::GenericError)` - A register entry is missing.
+    /// * `Err(RoqoqoError::CalculatorError)` - The expression cannot be evaluated.
+    pub fn evaluate(
+        &self,
+        bit_registers: &HashMap<String, crate::registers::BitRegister>,
+        float_registers: &HashMap<String, crate::registers::FloatRegister>,
+    ) -> Result<f64, RoqoqoError> {
+        let mut calculator = Calculator::new();
+        for (symbol, (register, index)) in self.variables.iter() {
+            let value = match (float_registers.get(register), bit_registers.get(register)) {
+                (Some(floats), _) => floats.get(*index).copied(),
+                (None, Some(bits)) => bits.get(*index).map(|bit| if *bit { 1.0 } else { 0.0 }),
+                (None, None) => {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!("Register {register} has not been defined"),
+                    })
+                }
+            }
+            .ok_or_else(|| RoqoqoError::GenericError {
+                msg: format!("Index {index} exceeds length of register {register}"),
+            })?;
+            calculator.set_variable(symbol, value);
+        }
+        Ok(calculator.parse_get(self.expression.clone())?)
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaSetFloat.
+impl InvolveQubits for PragmaSetFloat {
+    /// Lists all involved qubits (here: none).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::None
+    }
+
+    /// Lists the entries read by the expression and the written entry.
+    fn involved_classical(&self) -> InvolvedClassical {
+        let mut involved: HashSet<(String, usize)> = self.variables.values().cloned().collect();
+        involved.insert((self.register.clone(), self.index));
+        InvolvedClassical::Set(involved)
+    }
+}
+
+/// Substitute trait allowing to replace symbolic parameters and to perform qubit mappings.
+///
+/// The symbols standing for register entries are kept.
+impl Substitute for PragmaSetFloat {
+    /// Remaps qubits in clone of the operation.
+    fn remap_qubits(&self, _mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+
+    /// Substitutes symbolic parameters in clone of the operation.
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        let new_expression = self.bind_parameters(&calculator.variables)?;
+        if let Some(name) = new_expression.symbols().into_iter().next() {
+            return Err(CalculatorError::VariableNotSet { name }.into());
+        }
+        Ok(new_expression)
+    }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let mut values = values.clone();
+        values.retain(|symbol, _| !self.variables.contains_key(symbol));
+        Ok(PragmaSetFloat::new(
+            self.register.clone(),
+            self.index,
+            crate::operations::bind_parameter(&self.expression, &values)?,
+            self.variables.clone(),
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let mut mapping = mapping.clone();
+        mapping.retain(|symbol, _| !self.variables.contains_key(symbol));
+        Ok(PragmaSetFloat::new(
+            self.register.clone(),
+            self.index,
+            crate::operations::rename_parameter(&self.expression, &mapping)?,
+            self.variables.clone(),
+        ))
+    }
 }
 
 /// A circuit controlled by a qubit.
@@ -1169,12 +2036,28 @@ impl Substitute for PragmaControlledCircuit {
 
     /// Substitutes symbolic parameters in clone of the operation.
     fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
-        let new_circuit = self.circuit.substitute_parameters(calculator).unwrap();
+        let new_circuit = self.circuit.substitute_parameters(calculator)?;
         Ok(PragmaControlledCircuit::new(
             self.controlling_qubit,
             new_circuit,
         ))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaControlledCircuit::new(
+            self.controlling_qubit,
+            self.circuit.bind_parameters(values)?,
+        ))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        Ok(PragmaControlledCircuit::new(
+            self.controlling_qubit,
+            self.circuit.rename_parameters(mapping)?,
+        ))
+    }
 }
 
 /// A wrapper around backend specific PRAGMA operations capable of changing a device.
@@ -1300,6 +2183,20 @@ impl Substitute for PragmaLoop {
         let new_circuit = self.circuit.substitute_parameters(calculator)?;
         Ok(PragmaLoop::new(new_repetitions.into(), new_circuit))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let new_repetitions = crate::operations::bind_parameter(&self.repetitions, values)?;
+        let new_circuit = self.circuit.bind_parameters(values)?;
+        Ok(PragmaLoop::new(new_repetitions, new_circuit))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let new_repetitions = crate::operations::rename_parameter(&self.repetitions, mapping)?;
+        let new_circuit = self.circuit.rename_parameters(mapping)?;
+        Ok(PragmaLoop::new(new_repetitions, new_circuit))
+    }
 }
 
 // Implements the InvolveQubits trait for PragmaLoop.
@@ -1364,6 +2261,9 @@ impl Operate for PragmaAnnotatedOp {
     fn is_parametrized(&self) -> bool {
         self.operation.is_parametrized()
     }
+    fn symbols(&self) -> HashSet<String> {
+        self.operation.symbols()
+    }
 }
 
 impl PragmaAnnotatedOp {
@@ -1405,6 +2305,18 @@ impl Substitute for PragmaAnnotatedOp {
         let new_op = self.operation.substitute_parameters(calculator)?;
         Ok(PragmaAnnotatedOp::new(new_op, self.annotation.clone()))
     }
+
+    /// Substitutes the given symbols in clone of the operation and keeps the other symbols symbolic.
+    fn bind_parameters(&self, values: &HashMap<String, f64>) -> Result<Self, RoqoqoError> {
+        let new_op = self.operation.bind_parameters(values)?;
+        Ok(PragmaAnnotatedOp::new(new_op, self.annotation.clone()))
+    }
+
+    /// Renames the given symbols in clone of the operation.
+    fn rename_parameters(&self, mapping: &HashMap<String, String>) -> Result<Self, RoqoqoError> {
+        let new_op = self.operation.rename_parameters(mapping)?;
+        Ok(PragmaAnnotatedOp::new(new_op, self.annotation.clone()))
+    }
 }
 
 // Implements the InvolveQubits trait for PragmaAnnotatedOp.
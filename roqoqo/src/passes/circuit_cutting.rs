@@ -0,0 +1,398 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::complete_mapping;
+use crate::devices::Device;
+use crate::operations::*;
+use crate::{Circuit, RoqoqoError};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+/// A subcircuit of a cut circuit.
+///
+/// The fragment acts on the local qubits `0..number_qubits`.
+/// Every local qubit holds one segment of a wire of the original circuit.
+/// A segment starting at a wire cut is prepared at the start of the fragment,
+/// a segment ending at a wire cut is measured at the end of the fragment.
+/// The sides of cut gates are replaced by local operations within the fragment circuit.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct CircuitFragment {
+    /// The operations of the fragment acting on the local qubits.
+    pub circuit: Circuit,
+    /// The number of local qubits of the fragment.
+    pub number_qubits: usize,
+    /// The wire cuts (cut index, local qubit) whose wire continues in the fragment.
+    pub input_cuts: Vec<(usize, usize)>,
+    /// The wire cuts (cut index, local qubit) whose wire ends in the fragment.
+    pub output_cuts: Vec<(usize, usize)>,
+    /// The local qubits holding the final state of the original qubits.
+    pub output_qubits: HashMap<usize, usize>,
+    /// The sides of cut gates acting on the fragment.
+    pub gate_cuts: Vec<CutGateSide>,
+}
+
+/// One side of a cut gate in a fragment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct CutGateSide {
+    /// The index of the gate cut.
+    pub cut: usize,
+    /// The side of the gate, 0 for the control and 1 for the target qubit.
+    pub side: usize,
+    /// The local qubit of the side.
+    pub qubit: usize,
+    /// The number of operations of the fragment circuit applied before the cut.
+    pub position: usize,
+}
+
+/// A two-qubit gate cut between two fragments.
+///
+/// The gate is decomposed into single-qubit operations and the rotation `exp(i angle Z⊗Z)`
+/// of its two qubits, only the rotation is cut.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct CutGate {
+    /// The original control qubit of the gate.
+    pub control: usize,
+    /// The original target qubit of the gate.
+    pub target: usize,
+    /// The angle of the cut rotation `exp(i angle Z⊗Z)`.
+    pub angle: f64,
+}
+
+/// A circuit cut into fragments at wire cuts and gate cuts.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct CutCircuit {
+    /// The fragments of the circuit.
+    pub fragments: Vec<CircuitFragment>,
+    /// The original qubit of every wire cut.
+    pub cut_qubits: Vec<usize>,
+    /// The cut gates.
+    pub cut_gates: Vec<CutGate>,
+}
+
+// An operation with the segments of its qubits, or the side of a cut gate (cut, side, segment)
+enum Assignment {
+    Operation(Operation, HashMap<usize, usize>),
+    GateCutSide(usize, usize, usize),
+}
+
+/// Cuts the wires and gates of a circuit so that every fragment fits on a device.
+///
+/// The operations are assigned to fragments in the order of the circuit.
+/// When an operation would join fragments acting on more than `device.number_qubits()` qubits,
+/// the operation is cut if it is a [crate::operations::CNOT], a [crate::operations::ControlledPauliZ]
+/// or a [crate::operations::ControlledPhaseShift] with a float angle between two fragments.
+/// Otherwise the wires of its qubits are cut, starting with the qubits in the largest fragments,
+/// so every operation has to fit on the device on its own.
+/// Every segment of a wire between two cuts takes up a qubit of its fragment.
+///
+/// Definitions and operations acting on no qubits (e.g. [crate::operations::PragmaGlobalPhase]) are dropped,
+/// since they do not change expectation values.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit that is cut.
+/// * `device` - The device the fragments need to fit on.
+///
+/// # Returns
+///
+/// * `Ok(CutCircuit)` - The fragments of the circuit.
+/// * `Err(RoqoqoError::GenericError)` - The circuit contains measurements, classical operations or operations on all qubits,
+///   or an operation acts on more qubits than the device has.
+pub fn cut_circuit<T>(circuit: &Circuit, device: &T) -> Result<CutCircuit, RoqoqoError>
+where
+    T: Device,
+{
+    let max_qubits = device.number_qubits();
+    // Segments of the wires, merged into fragments with a union-find structure
+    let mut segment_qubits: Vec<usize> = Vec::new();
+    let mut parents: Vec<usize> = Vec::new();
+    let mut current_segment: HashMap<usize, usize> = HashMap::new();
+    // Pairs of segments (ending, starting) separated by a cut
+    let mut cuts: Vec<(usize, usize)> = Vec::new();
+    let mut cut_gates: Vec<CutGate> = Vec::new();
+    let mut assigned: Vec<Assignment> = Vec::new();
+
+    'operations: for operation in circuit.iter() {
+        if operation.tags().contains(&"Measurement") {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Measurement operation {} cannot be part of a cut circuit",
+                    operation.hqslang()
+                ),
+            });
+        }
+        let mut qubits: Vec<usize> = match operation.involved_qubits() {
+            InvolvedQubits::All => {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Operation {} acting on all qubits cannot be part of a cut circuit",
+                        operation.hqslang()
+                    ),
+                })
+            }
+            InvolvedQubits::None => {
+                if operation.tags().contains(&"Definition")
+                    || operation.involved_classical() == InvolvedClassical::None
+                {
+                    continue;
+                }
+                return Err(RoqoqoError::GenericError {
+                    msg: format!(
+                        "Classical operation {} cannot be part of a cut circuit",
+                        operation.hqslang()
+                    ),
+                });
+            }
+            InvolvedQubits::Set(set) => set.into_iter().collect(),
+        };
+        qubits.sort_unstable();
+        if qubits.len() > max_qubits {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "Operation {} acts on {} qubits but the device only has {max_qubits} qubits",
+                    operation.hqslang(),
+                    qubits.len()
+                ),
+            });
+        }
+        for qubit in qubits.iter() {
+            current_segment.entry(*qubit).or_insert_with(|| {
+                segment_qubits.push(*qubit);
+                parents.push(parents.len());
+                parents.len() - 1
+            });
+        }
+        // Cut the gate when it would join two fragments that do not fit on the device together
+        if let Some((control, target, angle, before, after)) = zz_decomposition(operation) {
+            let widths = fragment_widths(&mut parents);
+            let control_root = find(&mut parents, current_segment[&control]);
+            let target_root = find(&mut parents, current_segment[&target]);
+            if control_root != target_root
+                && widths[&control_root] + widths[&target_root] > max_qubits
+            {
+                let single_qubit = |operation: Operation| {
+                    let segments = operation_segments(&operation, &current_segment);
+                    Assignment::Operation(operation, segments)
+                };
+                assigned.extend(before.into_iter().map(single_qubit));
+                for (side, qubit) in [control, target].into_iter().enumerate() {
+                    assigned.push(Assignment::GateCutSide(
+                        cut_gates.len(),
+                        side,
+                        current_segment[&qubit],
+                    ));
+                }
+                assigned.extend(after.into_iter().map(single_qubit));
+                cut_gates.push(CutGate {
+                    control,
+                    target,
+                    angle,
+                });
+                continue 'operations;
+            }
+        }
+        // Cut the wires in the largest fragments until the joined fragment fits on the device
+        loop {
+            let widths = fragment_widths(&mut parents);
+            let mut roots: Vec<usize> = qubits
+                .iter()
+                .map(|qubit| find(&mut parents, current_segment[qubit]))
+                .collect();
+            roots.sort_unstable();
+            roots.dedup();
+            let joined_width: usize = roots.iter().map(|root| widths[root]).sum();
+            if joined_width <= max_qubits {
+                break;
+            }
+            let mut largest = roots[0];
+            let mut largest_excess = 0;
+            for root in roots.iter() {
+                let own_segments = qubits
+                    .iter()
+                    .filter(|qubit| find(&mut parents, current_segment[qubit]) == *root)
+                    .count();
+                if widths[root] - own_segments > largest_excess {
+                    largest_excess = widths[root] - own_segments;
+                    largest = *root;
+                }
+            }
+            for qubit in qubits.iter() {
+                let segment = current_segment[qubit];
+                if find(&mut parents, segment) == largest {
+                    segment_qubits.push(*qubit);
+                    parents.push(parents.len());
+                    cuts.push((segment, parents.len() - 1));
+                    current_segment.insert(*qubit, parents.len() - 1);
+                }
+            }
+        }
+        for qubit in qubits.iter().skip(1) {
+            let root = find(&mut parents, current_segment[qubit]);
+            let first_root = find(&mut parents, current_segment[&qubits[0]]);
+            parents[root] = first_root;
+        }
+        assigned.push(Assignment::Operation(
+            operation.clone(),
+            operation_segments(operation, &current_segment),
+        ));
+    }
+
+    // Every fragment numbers its segments in the order they were created
+    let mut fragment_index: HashMap<usize, usize> = HashMap::new();
+    let mut local_qubit: Vec<usize> = Vec::with_capacity(parents.len());
+    let mut fragments: Vec<CircuitFragment> = Vec::new();
+    for segment in 0..parents.len() {
+        let root = find(&mut parents, segment);
+        let index = *fragment_index.entry(root).or_insert_with(|| {
+            fragments.push(CircuitFragment {
+                circuit: Circuit::new(),
+                number_qubits: 0,
+                input_cuts: Vec::new(),
+                output_cuts: Vec::new(),
+                output_qubits: HashMap::new(),
+                gate_cuts: Vec::new(),
+            });
+            fragments.len() - 1
+        });
+        local_qubit.push(fragments[index].number_qubits);
+        fragments[index].number_qubits += 1;
+    }
+    let fragment_of = |parents: &mut Vec<usize>, segment: usize| -> usize {
+        fragment_index[&find(parents, segment)]
+    };
+    for assignment in assigned {
+        match assignment {
+            Assignment::Operation(operation, segments) => {
+                let pairs: Vec<(usize, usize)> = segments
+                    .iter()
+                    .map(|(qubit, segment)| (*qubit, local_qubit[*segment]))
+                    .collect();
+                let mapping = complete_mapping(&pairs);
+                let segment = *segments.values().next().unwrap_or(&0);
+                let index = fragment_of(&mut parents, segment);
+                fragments[index]
+                    .circuit
+                    .add_operation(operation.remap_qubits(&mapping)?);
+            }
+            Assignment::GateCutSide(cut, side, segment) => {
+                let fragment = &mut fragments[fragment_of(&mut parents, segment)];
+                fragment.gate_cuts.push(CutGateSide {
+                    cut,
+                    side,
+                    qubit: local_qubit[segment],
+                    position: fragment.circuit.len(),
+                });
+            }
+        }
+    }
+    for (cut, (ending, starting)) in cuts.iter().enumerate() {
+        let index = fragment_of(&mut parents, *ending);
+        fragments[index]
+            .output_cuts
+            .push((cut, local_qubit[*ending]));
+        let index = fragment_of(&mut parents, *starting);
+        fragments[index]
+            .input_cuts
+            .push((cut, local_qubit[*starting]));
+    }
+    for (qubit, segment) in current_segment {
+        let index = fragment_of(&mut parents, segment);
+        fragments[index]
+            .output_qubits
+            .insert(qubit, local_qubit[segment]);
+    }
+    Ok(CutCircuit {
+        fragments,
+        cut_qubits: cuts
+            .iter()
+            .map(|(ending, _)| segment_qubits[*ending])
+            .collect(),
+        cut_gates,
+    })
+}
+
+// Decomposes a gate that can be cut into single-qubit operations and the rotation exp(i angle Z⊗Z)
+//
+// Returns the control and target qubits, the angle and the operations before and after the rotation.
+#[allow(clippy::type_complexity)]
+fn zz_decomposition(
+    operation: &Operation,
+) -> Option<(usize, usize, f64, Vec<Operation>, Vec<Operation>)> {
+    // A controlled phase shift by theta is exp(i theta/4 (I - Z_c - Z_t + Z_c Z_t))
+    let (control, target, theta, hadamard) = match operation {
+        Operation::CNOT(op) => (*op.control(), *op.target(), PI, true),
+        Operation::ControlledPauliZ(op) => (*op.control(), *op.target(), PI, false),
+        Operation::ControlledPhaseShift(op) => (
+            *op.control(),
+            *op.target(),
+            *op.theta().float().ok()?,
+            false,
+        ),
+        _ => return None,
+    };
+    let mut before: Vec<Operation> = Vec::new();
+    let mut after: Vec<Operation> = vec![
+        RotateZ::new(control, (theta / 2.0).into()).into(),
+        RotateZ::new(target, (theta / 2.0).into()).into(),
+    ];
+    if hadamard {
+        before.push(Hadamard::new(target).into());
+        after.push(Hadamard::new(target).into());
+    }
+    Some((control, target, theta / 4.0, before, after))
+}
+
+// Returns the current segments of the qubits of an operation
+fn operation_segments(
+    operation: &Operation,
+    current_segment: &HashMap<usize, usize>,
+) -> HashMap<usize, usize> {
+    match operation.involved_qubits() {
+        InvolvedQubits::Set(qubits) => qubits
+            .into_iter()
+            .map(|qubit| (qubit, current_segment[&qubit]))
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
+
+// Returns the number of segments of every fragment by its representative segment
+fn fragment_widths(parents: &mut [usize]) -> HashMap<usize, usize> {
+    let mut widths: HashMap<usize, usize> = HashMap::new();
+    for segment in 0..parents.len() {
+        *widths.entry(find(parents, segment)).or_insert(0) += 1;
+    }
+    widths
+}
+
+// Returns the representative segment of the fragment containing a segment
+fn find(parents: &mut [usize], segment: usize) -> usize {
+    let mut root = segment;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    let mut current = segment;
+    while parents[current] != root {
+        let next = parents[current];
+        parents[current] = root;
+        current = next;
+    }
+    root
+}
@@ -0,0 +1,212 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::estimate_fidelity;
+use crate::devices::Device;
+use crate::noise_models::NoiseModel;
+use crate::operations::*;
+use crate::{Circuit, CircuitDag, RoqoqoError};
+use petgraph::algo::toposort;
+use petgraph::Direction::Incoming;
+use std::collections::{BTreeSet, HashMap};
+use struqture::spins::PlusMinusLindbladNoiseOperator;
+use struqture::OperateOnDensityMatrix;
+
+/// Structural statistics and resource estimates of a circuit.
+///
+/// The structural statistics only depend on the circuit.
+/// The estimated runtime and fidelity are only available when the statistics are created
+/// for a device with [CircuitStatistics::with_device].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct CircuitStatistics {
+    /// The number of operations in the circuit.
+    pub number_operations: usize,
+    /// The length of the longest chain of dependent operations acting on qubits.
+    pub depth: usize,
+    /// The length of the longest chain of dependent two-qubit gates.
+    pub two_qubit_depth: usize,
+    /// The number of gates for every gate type (hqslang name).
+    pub gate_counts: HashMap<String, usize>,
+    /// The number of gates acting on every qubit.
+    pub qubit_gate_counts: HashMap<usize, usize>,
+    /// The number of [crate::operations::TGate] and [crate::operations::InvTGate] gates.
+    pub t_count: usize,
+    /// The number of measurement operations.
+    pub number_measurements: usize,
+    /// The number of operations with symbolic parameters.
+    pub number_parametric_operations: usize,
+    /// The length of every classical register defined in the circuit.
+    pub classical_registers: HashMap<String, usize>,
+    /// The estimated runtime of the circuit on the device.
+    pub estimated_runtime: Option<f64>,
+    /// The estimated fidelity of the circuit on the device.
+    pub estimated_fidelity: Option<f64>,
+}
+
+impl CircuitStatistics {
+    /// Creates the structural statistics of a circuit.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit` - The circuit that is analysed.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The statistics without runtime and fidelity estimates.
+    pub fn new(circuit: &Circuit) -> Self {
+        let mut gate_counts: HashMap<String, usize> = HashMap::new();
+        let mut qubit_gate_counts: HashMap<usize, usize> = HashMap::new();
+        let mut t_count = 0;
+        let mut number_measurements = 0;
+        let mut number_parametric_operations = 0;
+        let mut classical_registers: HashMap<String, usize> = HashMap::new();
+        for operation in circuit.iter() {
+            if is_gate(operation) {
+                *gate_counts
+                    .entry(operation.hqslang().to_string())
+                    .or_insert(0) += 1;
+                if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+                    for qubit in qubits {
+                        *qubit_gate_counts.entry(qubit).or_insert(0) += 1;
+                    }
+                }
+            }
+            if matches!(operation, Operation::TGate(_) | Operation::InvTGate(_)) {
+                t_count += 1;
+            }
+            if operation.tags().contains(&"Measurement") {
+                number_measurements += 1;
+            }
+            if operation.is_parametrized() {
+                number_parametric_operations += 1;
+            }
+            match operation {
+                Operation::DefinitionBit(op) => {
+                    classical_registers.insert(op.name().clone(), *op.length());
+                }
+                Operation::DefinitionFloat(op) => {
+                    classical_registers.insert(op.name().clone(), *op.length());
+                }
+                Operation::DefinitionComplex(op) => {
+                    classical_registers.insert(op.name().clone(), *op.length());
+                }
+                Operation::DefinitionUsize(op) => {
+                    classical_registers.insert(op.name().clone(), *op.length());
+                }
+                _ => (),
+            }
+        }
+        let dag = CircuitDag::from(circuit.clone());
+        Self {
+            number_operations: circuit.len(),
+            depth: longest_chain(&dag, |operation| {
+                operation.involved_qubits() != InvolvedQubits::None
+            }),
+            two_qubit_depth: longest_chain(&dag, |operation| {
+                is_gate(operation)
+                    && matches!(operation.involved_qubits(), InvolvedQubits::Set(qubits) if qubits.len() == 2)
+            }),
+            gate_counts,
+            qubit_gate_counts,
+            t_count,
+            number_measurements,
+            number_parametric_operations,
+            classical_registers,
+            estimated_runtime: None,
+            estimated_fidelity: None,
+        }
+    }
+
+    /// Creates the statistics of a circuit including runtime and fidelity estimates for a device.
+    ///
+    /// The runtime and fidelity are estimated with [super::estimate_fidelity],
+    /// using a [crate::noise_models::DecoherenceOnGateModel] as the additional noise on gates.
+    /// The rates of a [crate::noise_models::ContinuousDecoherenceModel] act on the qubits of the circuit
+    /// during the whole runtime, in addition to the rates of the device.
+    /// Other noise models do not change the estimated fidelity.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit` - The circuit that is analysed.
+    /// * `device` - The device providing the gate times and decoherence rates.
+    /// * `noise_model` - An optional noise model adding noise to the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The statistics with runtime and fidelity estimates.
+    /// * `Err(RoqoqoError::GenericError)` - A gate is not available on the device or a sleep time is symbolic.
+    pub fn with_device<T>(
+        circuit: &Circuit,
+        device: &T,
+        noise_model: Option<&NoiseModel>,
+    ) -> Result<Self, RoqoqoError>
+    where
+        T: Device,
+    {
+        let mut statistics = Self::new(circuit);
+        let gate_noise = match noise_model {
+            Some(NoiseModel::DecoherenceOnGateModel(model)) => Some(model),
+            _ => None,
+        };
+        let estimate = estimate_fidelity(circuit, device, gate_noise)?;
+        let runtime = estimate.runtime;
+        let mut fidelity = estimate.fidelity;
+        if let Some(NoiseModel::ContinuousDecoherenceModel(model)) = noise_model {
+            let qubits: BTreeSet<usize> = estimate.qubit_infidelities.keys().copied().collect();
+            fidelity *= (-continuous_rate(&model.lindblad_noise, &qubits) * runtime).exp();
+        }
+        statistics.estimated_runtime = Some(runtime);
+        statistics.estimated_fidelity = Some(fidelity);
+        Ok(statistics)
+    }
+}
+
+// Returns true for operations that are unitary gates
+fn is_gate(operation: &Operation) -> bool {
+    GateOperation::try_from(operation.clone()).is_ok()
+}
+
+// Returns the largest number of counted operations on a path through the CircuitDag
+fn longest_chain<F>(dag: &CircuitDag, counted: F) -> usize
+where
+    F: Fn(&Operation) -> bool,
+{
+    // The graph of a CircuitDag is acyclic by construction
+    let order = toposort(&dag.graph, None).unwrap_or_default();
+    let mut lengths: HashMap<usize, usize> = HashMap::new();
+    let mut longest = 0;
+    for node in order {
+        let previous = dag
+            .graph
+            .neighbors_directed(node, Incoming)
+            .map(|predecessor| lengths[&predecessor.index()])
+            .max()
+            .unwrap_or(0);
+        let length = previous + usize::from(counted(&dag.graph[node]));
+        longest = longest.max(length);
+        lengths.insert(node.index(), length);
+    }
+    longest
+}
+
+// Returns the summed rates of the diagonal terms of a noise operator acting only on the given qubits
+fn continuous_rate(noise: &PlusMinusLindbladNoiseOperator, qubits: &BTreeSet<usize>) -> f64 {
+    noise
+        .iter()
+        .filter(|((left, right), _)| {
+            left == right && left.iter().all(|(qubit, _)| qubits.contains(qubit))
+        })
+        .map(|(_, rate)| rate.re.float().copied().unwrap_or(0.0))
+        .sum()
+}
@@ -0,0 +1,164 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::schedule_circuit;
+use crate::devices::Device;
+use crate::operations::*;
+use crate::{Circuit, RoqoqoError};
+use std::collections::HashMap;
+
+/// Dynamical decoupling sequences that can be inserted into idle windows.
+///
+/// All sequences multiply to the identity (up to a global phase).
+/// The free evolution between the pulses is distributed symmetrically:
+/// for `n` pulses the window starts and ends with a sleep of `τ/2` and the pulses are separated by `τ`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecouplingSequence {
+    /// Two [crate::operations::PauliX] pulses.
+    XX,
+    /// Alternating [crate::operations::PauliX] and [crate::operations::PauliY] pulses (X-Y-X-Y).
+    XY4,
+    /// Carr-Purcell-Meiboom-Gill sequence of the given (even) number of [crate::operations::PauliY] pulses.
+    CPMG(usize),
+}
+
+impl DecouplingSequence {
+    /// Returns the pulses of the sequence acting on a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the pulses act on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Operation>)` - The pulses of the sequence.
+    /// * `Err(RoqoqoError::GenericError)` - The CPMG sequence does not have a positive even number of pulses.
+    pub fn pulses(&self, qubit: usize) -> Result<Vec<Operation>, RoqoqoError> {
+        match self {
+            DecouplingSequence::XX => Ok(vec![PauliX::new(qubit).into(); 2]),
+            DecouplingSequence::XY4 => Ok(vec![
+                PauliX::new(qubit).into(),
+                PauliY::new(qubit).into(),
+                PauliX::new(qubit).into(),
+                PauliY::new(qubit).into(),
+            ]),
+            DecouplingSequence::CPMG(number_pulses) => {
+                if *number_pulses == 0 || number_pulses % 2 != 0 {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!(
+                            "CPMG sequence needs a positive even number of pulses, got {number_pulses}"
+                        ),
+                    });
+                }
+                Ok(vec![PauliY::new(qubit).into(); *number_pulses])
+            }
+        }
+    }
+}
+
+/// Inserts dynamical decoupling sequences into the idle windows of a circuit.
+///
+/// The circuit is scheduled on the device with [super::schedule_circuit].
+/// Every period in which a qubit is idle between two operations acting on it
+/// is filled with the decoupling sequence, padded with [crate::operations::PragmaSleep].
+/// A sequence is only inserted when all its pulses are available on the qubit and
+/// the summed gate times of the pulses fit into the idle window.
+/// Idle periods before the first and after the last operation on a qubit are not filled.
+///
+/// The inserted operations exactly fill the idle windows,
+/// so the schedule of all original operations is unchanged.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit in which the sequences are inserted.
+/// * `device` - The device providing the gate times.
+/// * `sequence` - The decoupling sequence that is inserted.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The circuit with the decoupling sequences.
+/// * `Err(RoqoqoError)` - The circuit could not be scheduled or the sequence is invalid.
+pub fn dynamical_decoupling<T>(
+    circuit: &Circuit,
+    device: &T,
+    sequence: DecouplingSequence,
+) -> Result<Circuit, RoqoqoError>
+where
+    T: Device,
+{
+    let schedule = schedule_circuit(circuit, device)?;
+    // Operations inserted in front of the operation with the given index
+    let mut insertions: HashMap<usize, Vec<Operation>> = HashMap::new();
+    let mut last_end: HashMap<usize, f64> = HashMap::new();
+    for (index, scheduled) in schedule.iter().enumerate() {
+        for qubit in scheduled.qubits.iter() {
+            if let Some(end) = last_end.get(qubit) {
+                let window = scheduled.start_time - end;
+                if window > 0.0 {
+                    if let Some(ops) = fill_window(*qubit, window, device, &sequence)? {
+                        insertions.entry(index).or_default().extend(ops);
+                    }
+                }
+            }
+            last_end.insert(*qubit, scheduled.end_time);
+        }
+    }
+
+    let mut new_circuit = Circuit::new();
+    for (index, operation) in circuit.iter().enumerate() {
+        if let Some(ops) = insertions.remove(&index) {
+            for op in ops {
+                new_circuit.add_operation(op);
+            }
+        }
+        new_circuit.add_operation(operation.clone());
+    }
+    Ok(new_circuit)
+}
+
+// Returns the padded decoupling sequence for one idle window or None if it does not fit.
+fn fill_window<T>(
+    qubit: usize,
+    window: f64,
+    device: &T,
+    sequence: &DecouplingSequence,
+) -> Result<Option<Vec<Operation>>, RoqoqoError>
+where
+    T: Device,
+{
+    let pulses = sequence.pulses(qubit)?;
+    let mut pulse_time = 0.0;
+    for pulse in pulses.iter() {
+        match device.single_qubit_gate_time(pulse.hqslang(), &qubit) {
+            Some(time) => pulse_time += time,
+            None => return Ok(None),
+        }
+    }
+    let free_time = window - pulse_time;
+    if free_time < 0.0 {
+        return Ok(None);
+    }
+    let spacing = free_time / pulses.len() as f64;
+    let number_pulses = pulses.len();
+    let mut ops: Vec<Operation> = Vec::with_capacity(2 * number_pulses + 1);
+    for (index, pulse) in pulses.into_iter().enumerate() {
+        let sleep_time = if index == 0 { spacing / 2.0 } else { spacing };
+        if sleep_time > 0.0 {
+            ops.push(PragmaSleep::new(vec![qubit], sleep_time.into()).into());
+        }
+        ops.push(pulse);
+    }
+    if spacing > 0.0 {
+        ops.push(PragmaSleep::new(vec![qubit], (spacing / 2.0).into()).into());
+    }
+    Ok(Some(ops))
+}
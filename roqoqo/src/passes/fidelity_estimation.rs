@@ -0,0 +1,150 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::schedule_circuit;
+use crate::devices::Device;
+use crate::noise_models::DecoherenceOnGateModel;
+use crate::operations::*;
+use crate::{Circuit, RoqoqoError};
+use std::collections::{BTreeSet, HashMap};
+use struqture::spins::PlusMinusLindbladNoiseOperator;
+use struqture::OperateOnDensityMatrix;
+
+/// The estimated fidelity of a circuit on a device together with its contributions.
+///
+/// All infidelities are `1 - exp(-Σ rate · time)` of the respective contribution,
+/// the total fidelity is the product of the fidelities of all contributions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct FidelityEstimate {
+    /// The estimated fidelity of the whole circuit.
+    pub fidelity: f64,
+    /// The runtime of the scheduled circuit.
+    pub runtime: f64,
+    /// The infidelity caused by the decoherence of every qubit during the whole runtime.
+    pub qubit_infidelities: HashMap<usize, f64>,
+    /// The part of the decoherence infidelity of every qubit accumulated while the qubit is idle.
+    pub idle_infidelities: HashMap<usize, f64>,
+    /// The infidelity caused by the additional noise on gates.
+    pub gate_infidelity: f64,
+}
+
+/// Estimates the fidelity of a circuit on a device from its decoherence rates and gate errors.
+///
+/// The circuit is scheduled with [super::schedule_circuit].
+/// Every qubit the operations of the circuit act on decoheres from the start to the end of the schedule,
+/// both while operations act on it and while it is idle.
+/// The decoherence rate of a qubit is the trace of the rate matrix returned by
+/// [crate::devices::Device::qubit_decoherence_rates].
+/// When a [crate::noise_models::DecoherenceOnGateModel] is given, the noise of every gate it defines
+/// acts during the gate time, with the sum of the real parts of the diagonal terms as the rate.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit whose fidelity is estimated.
+/// * `device` - The device providing the gate times and decoherence rates.
+/// * `gate_noise` - The optional additional noise on gates.
+///
+/// # Returns
+///
+/// * `Ok(FidelityEstimate)` - The estimated fidelity and its contributions.
+/// * `Err(RoqoqoError::GenericError)` - A gate is not available on the device or a sleep time is symbolic.
+pub fn estimate_fidelity<T>(
+    circuit: &Circuit,
+    device: &T,
+    gate_noise: Option<&DecoherenceOnGateModel>,
+) -> Result<FidelityEstimate, RoqoqoError>
+where
+    T: Device,
+{
+    let schedule = schedule_circuit(circuit, device)?;
+    let runtime = schedule
+        .iter()
+        .map(|scheduled| scheduled.end_time)
+        .fold(0.0, f64::max);
+
+    let mut busy_times: HashMap<usize, f64> = HashMap::new();
+    let mut qubits: BTreeSet<usize> = BTreeSet::new();
+    let mut gate_exponent = 0.0;
+    for scheduled in schedule.iter() {
+        if let InvolvedQubits::Set(set) = scheduled.operation.involved_qubits() {
+            qubits.extend(set);
+        }
+        for qubit in scheduled.qubits.iter() {
+            *busy_times.entry(*qubit).or_insert(0.0) += scheduled.end_time - scheduled.start_time;
+        }
+        if let Some(noise) = gate_noise.and_then(|model| gate_error(model, &scheduled.operation)) {
+            gate_exponent += total_rate(noise) * (scheduled.end_time - scheduled.start_time);
+        }
+    }
+
+    let mut qubit_infidelities: HashMap<usize, f64> = HashMap::new();
+    let mut idle_infidelities: HashMap<usize, f64> = HashMap::new();
+    let mut fidelity = (-gate_exponent).exp();
+    for qubit in qubits {
+        let rate = device
+            .qubit_decoherence_rates(&qubit)
+            .map(|rates| rates.diag().sum())
+            .unwrap_or(0.0);
+        let idle_time = runtime - busy_times.get(&qubit).copied().unwrap_or(0.0);
+        qubit_infidelities.insert(qubit, 1.0 - (-rate * runtime).exp());
+        idle_infidelities.insert(qubit, 1.0 - (-rate * idle_time).exp());
+        fidelity *= (-rate * runtime).exp();
+    }
+    Ok(FidelityEstimate {
+        fidelity,
+        runtime,
+        qubit_infidelities,
+        idle_infidelities,
+        gate_infidelity: 1.0 - (-gate_exponent).exp(),
+    })
+}
+
+// Returns the noise the model adds when the gate is executed
+fn gate_error<'a>(
+    model: &'a DecoherenceOnGateModel,
+    operation: &Operation,
+) -> Option<&'a PlusMinusLindbladNoiseOperator> {
+    let hqslang = operation.hqslang();
+    if let Ok(op) = SingleQubitGateOperation::try_from(operation.clone()) {
+        model.get_single_qubit_gate_error(hqslang, *op.qubit())
+    } else if let Ok(op) = TwoQubitGateOperation::try_from(operation.clone()) {
+        model.get_two_qubit_gate_error(hqslang, *op.control(), *op.target())
+    } else if let Ok(op) = ThreeQubitGateOperation::try_from(operation.clone()) {
+        model.get_three_qubit_gate_error(hqslang, *op.control_0(), *op.control_1(), *op.target())
+    } else if let Ok(op) = FourQubitGateOperation::try_from(operation.clone()) {
+        // Four-qubit gates are scheduled with the multi-qubit gate times and use the matching errors
+        model.get_multi_qubit_gate_error(
+            hqslang,
+            vec![
+                *op.control_0(),
+                *op.control_1(),
+                *op.control_2(),
+                *op.target(),
+            ],
+        )
+    } else if let Ok(op) = MultiQubitGateOperation::try_from(operation.clone()) {
+        model.get_multi_qubit_gate_error(hqslang, op.qubits().clone())
+    } else {
+        None
+    }
+}
+
+// Returns the summed rates of the diagonal terms of a noise operator
+fn total_rate(noise: &PlusMinusLindbladNoiseOperator) -> f64 {
+    noise
+        .iter()
+        .filter(|((left, right), _)| left == right)
+        .map(|(_, rate)| rate.re.float().copied().unwrap_or(0.0))
+        .sum()
+}
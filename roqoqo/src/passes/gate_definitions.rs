@@ -0,0 +1,481 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::complete_mapping;
+use crate::operations::*;
+use crate::{Circuit, RoqoqoError};
+use ndarray::Array2;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use std::collections::HashMap;
+
+// Start index and qubits of an occurrence of a block
+type Occurrence = (usize, Vec<usize>);
+
+/// Replaces every [crate::operations::CallDefinedGate] by the body of its [crate::operations::GateDefinition].
+///
+/// The qubits of the definition are replaced by the qubits of the call and the free parameters
+/// by the parameters of the call. Calls inside the bodies of definitions and inside the circuits of
+/// [crate::operations::PragmaLoop], [crate::operations::PragmaConditional], [crate::operations::PragmaIfElse],
+/// [crate::operations::PragmaWhileLoop] and [crate::operations::PragmaControlledCircuit] are replaced recursively.
+/// A nested circuit can use the definitions of the enclosing circuits and its own definitions.
+/// The GateDefinitions are removed from the returned circuit.
+///
+/// Symbolic parameters of the calls are inserted as expressions, so symbols of the calls and symbols
+/// of the bodies that are not free parameters of the definitions stay symbolic.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit containing calls of defined gates.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The circuit without GateDefinitions and calls of defined gates.
+/// * `Err(RoqoqoError::GenericError)` - A called gate is not defined, a call does not match its definition,
+///   a call parameter is not finite or the definitions call each other cyclically.
+/// * `Err(RoqoqoError::CalculatorError)` - The parameters of a definition body cannot be substituted.
+pub fn inline_definitions(circuit: &Circuit) -> Result<Circuit, RoqoqoError> {
+    inline_circuit(circuit, &HashMap::new(), &mut Vec::new())
+}
+
+/// Extracts repeated blocks of gates into new [crate::operations::GateDefinition]s.
+///
+/// Blocks of `block_length` consecutive gate operations that are identical up to a relabelling of
+/// their qubits are replaced by a [crate::operations::CallDefinedGate] of a new GateDefinition.
+/// The qubits of a definition are numbered in the order in which they first appear in the block.
+/// The block with the most non-overlapping occurrences is extracted first, as long as it occurs at least
+/// `min_repetitions` times, and the search is repeated until no block occurs often enough.
+/// The new definitions are named `subcircuit_<n>` with the smallest n not used by an existing definition.
+///
+/// Only the operations of the top level circuit are compressed and parameters are not abstracted,
+/// so blocks with different rotation angles are different blocks.
+/// [inline_definitions] restores the original operations.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit to compress.
+/// * `block_length` - The number of operations in an extracted block, at least two.
+/// * `min_repetitions` - The minimal number of occurrences of an extracted block, at least two.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The circuit with repeated blocks replaced by calls of defined gates.
+/// * `Err(RoqoqoError::GenericError)` - The block length or the minimal number of repetitions is smaller than two.
+pub fn extract_definitions(
+    circuit: &Circuit,
+    block_length: usize,
+    min_repetitions: usize,
+) -> Result<Circuit, RoqoqoError> {
+    if block_length < 2 || min_repetitions < 2 {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "Extracted blocks need at least two operations and two repetitions, got {block_length} operations and {min_repetitions} repetitions"
+            ),
+        });
+    }
+    let mut compressed = circuit.clone();
+    loop {
+        let operations = compressed.operations();
+        // Occurrences of every block in order of first appearance, keyed by the debug string of the block
+        let mut keys: HashMap<String, usize> = HashMap::new();
+        let mut blocks: Vec<(Circuit, Vec<Occurrence>)> = Vec::new();
+        for start in 0..(operations.len() + 1).saturating_sub(block_length) {
+            if let Some((block, qubits)) =
+                canonical_block(&operations[start..start + block_length])?
+            {
+                let key = format!("{block:?}");
+                let index = *keys.entry(key).or_insert_with(|| {
+                    blocks.push((block, Vec::new()));
+                    blocks.len() - 1
+                });
+                blocks[index].1.push((start, qubits));
+            }
+        }
+        let mut best: Option<(Circuit, Vec<Occurrence>)> = None;
+        for (block, occurrences) in blocks.into_iter() {
+            let mut selected: Vec<Occurrence> = Vec::new();
+            for (start, qubits) in occurrences.into_iter() {
+                if selected
+                    .last()
+                    .map_or(true, |(previous, _)| start >= previous + block_length)
+                {
+                    selected.push((start, qubits));
+                }
+            }
+            if selected.len() >= min_repetitions
+                && best
+                    .as_ref()
+                    .map_or(true, |(_, occurrences)| selected.len() > occurrences.len())
+            {
+                best = Some((block, selected));
+            }
+        }
+        let (block, occurrences) = match best {
+            Some(best) => best,
+            None => return Ok(compressed),
+        };
+
+        let name = unused_definition_name(&compressed);
+        let number_qubits = occurrences[0].1.len();
+        let mut extracted = Circuit::new();
+        for definition in compressed.definitions().iter() {
+            extracted.add_operation(definition.clone());
+        }
+        extracted.add_operation(GateDefinition::new(
+            block,
+            name.clone(),
+            (0..number_qubits).collect(),
+            Vec::new(),
+        ));
+        let mut occurrences = occurrences.into_iter().peekable();
+        let mut index = 0;
+        while index < compressed.operations().len() {
+            match occurrences.next_if(|(start, _)| *start == index) {
+                Some((_, qubits)) => {
+                    extracted.add_operation(CallDefinedGate::new(name.clone(), qubits, Vec::new()));
+                    index += block_length;
+                }
+                None => {
+                    extracted.add_operation(compressed.operations()[index].clone());
+                    index += 1;
+                }
+            }
+        }
+        compressed = extracted;
+    }
+}
+
+/// Returns the circuit of a call of a defined gate.
+///
+/// The qubits of the definition are replaced by the qubits of the call
+/// and the free parameters by the parameters of the call.
+/// Calls of other defined gates in the body are not replaced.
+pub(crate) fn expand_call(
+    call: &CallDefinedGate,
+    definition: &GateDefinition,
+) -> Result<Circuit, RoqoqoError> {
+    let name = call.gate_name();
+    check_call(call, definition)?;
+    let body = definition.circuit();
+    if let InvolvedQubits::Set(qubits) = body.involved_qubits() {
+        if let Some(qubit) = qubits
+            .iter()
+            .find(|qubit| !definition.qubits().contains(qubit))
+        {
+            return Err(RoqoqoError::GenericError {
+                msg: format!(
+                    "GateDefinition {name} acts on qubit {qubit} which is not one of its qubits"
+                ),
+            });
+        }
+    }
+    let body = if definition.free_parameters().is_empty() {
+        body.clone()
+    } else {
+        // All free parameters are replaced at once by the bracketed parameters of the call,
+        // binding without values then evaluates the constant parts and keeps the other symbols
+        let mut mapping: HashMap<String, String> = HashMap::new();
+        for (parameter, value) in definition
+            .free_parameters()
+            .iter()
+            .zip(call.free_parameters().iter())
+        {
+            let expression = match value {
+                CalculatorFloat::Float(value) if !value.is_finite() => {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!("Parameter {value} of CallDefinedGate {name} is not finite"),
+                    })
+                }
+                CalculatorFloat::Float(value) => format!("({value:?})"),
+                CalculatorFloat::Str(expression) => format!("({expression})"),
+            };
+            mapping.insert(parameter.clone(), expression);
+        }
+        body.rename_parameters(&mapping)?
+            .bind_parameters(&HashMap::new())?
+    };
+    let pairs: Vec<(usize, usize)> = definition
+        .qubits()
+        .iter()
+        .copied()
+        .zip(call.qubits().iter().copied())
+        .collect();
+    body.remap_qubits(&complete_mapping(&pairs))
+}
+
+/// Checks that a call of a defined gate matches the number of qubits and free parameters of the definition
+/// and does not act twice on a qubit.
+pub(crate) fn check_call(
+    call: &CallDefinedGate,
+    definition: &GateDefinition,
+) -> Result<(), RoqoqoError> {
+    let name = call.gate_name();
+    if call.qubits().len() != definition.qubits().len() {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "CallDefinedGate {} acts on {} qubits but the GateDefinition has {} qubits",
+                name,
+                call.qubits().len(),
+                definition.qubits().len()
+            ),
+        });
+    }
+    if call.free_parameters().len() != definition.free_parameters().len() {
+        return Err(RoqoqoError::GenericError {
+            msg: format!(
+                "CallDefinedGate {} has {} parameters but the GateDefinition has {} free parameters",
+                name,
+                call.free_parameters().len(),
+                definition.free_parameters().len()
+            ),
+        });
+    }
+    for (index, qubit) in call.qubits().iter().enumerate() {
+        if call.qubits()[..index].contains(qubit) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("CallDefinedGate {name} acts twice on qubit {qubit}"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the unitary matrix of a defined gate for the given values of its free parameters.
+///
+/// The first qubit of the definition is the most significant qubit of the matrix.
+/// Calls of gates defined in the body of the definition are inlined.
+pub(crate) fn definition_unitary(
+    definition: &GateDefinition,
+    free_parameters: &[CalculatorFloat],
+) -> Result<Array2<Complex64>, RoqoqoError> {
+    let call = CallDefinedGate::new(
+        definition.name().clone(),
+        definition.qubits().clone(),
+        free_parameters.to_vec(),
+    );
+    let body = inline_definitions(&expand_call(&call, definition)?)?;
+    let number_qubits = definition.qubits().len();
+    let mut unitary: Array2<Complex64> = Array2::eye(1 << number_qubits);
+    for operation in body.operations().iter() {
+        if let Operation::PragmaGlobalPhase(op) = operation {
+            unitary *= Complex64::new(0.0, *op.phase().float()?).exp();
+            continue;
+        }
+        let no_unitary = || RoqoqoError::GenericError {
+            msg: format!(
+                "Operation {} in GateDefinition {} has no unitary matrix",
+                operation.hqslang(),
+                definition.name()
+            ),
+        };
+        let gate = GateOperation::try_from(operation.clone()).map_err(|_| no_unitary())?;
+        let qubits = gate_qubits(operation).ok_or_else(no_unitary)?;
+        // Bit positions of the gate qubits, the first qubit of the definition is the most significant bit
+        let positions: Vec<usize> = qubits
+            .iter()
+            .map(|qubit| {
+                let index = definition
+                    .qubits()
+                    .iter()
+                    .position(|defined| defined == qubit)
+                    .unwrap_or_default();
+                number_qubits - 1 - index
+            })
+            .collect();
+        unitary = embed_unitary(&gate.unitary_matrix()?, &positions, number_qubits).dot(&unitary);
+    }
+    Ok(unitary)
+}
+
+/// Applies a transformation to the circuits of the pragmas containing circuits.
+///
+/// The circuits of [crate::operations::PragmaLoop], [crate::operations::PragmaConditional],
+/// [crate::operations::PragmaIfElse], [crate::operations::PragmaWhileLoop] and
+/// [crate::operations::PragmaControlledCircuit] are transformed, other operations are returned unchanged.
+pub(crate) fn map_nested_circuits<F>(
+    operation: &Operation,
+    transform: &mut F,
+) -> Result<Operation, RoqoqoError>
+where
+    F: FnMut(&Circuit) -> Result<Circuit, RoqoqoError>,
+{
+    Ok(match operation {
+        Operation::PragmaLoop(op) => {
+            PragmaLoop::new(op.repetitions().clone(), transform(op.circuit())?).into()
+        }
+        Operation::PragmaConditional(op) => PragmaConditional::new(
+            op.condition_register().clone(),
+            *op.condition_index(),
+            transform(op.circuit())?,
+        )
+        .into(),
+        Operation::PragmaIfElse(op) => PragmaIfElse::new(
+            op.condition().clone(),
+            transform(op.if_circuit())?,
+            transform(op.else_circuit())?,
+        )
+        .into(),
+        Operation::PragmaWhileLoop(op) => PragmaWhileLoop::new(
+            op.condition().clone(),
+            transform(op.circuit())?,
+            *op.max_iterations(),
+        )
+        .into(),
+        Operation::PragmaControlledCircuit(op) => {
+            PragmaControlledCircuit::new(*op.controlling_qubit(), transform(op.circuit())?).into()
+        }
+        _ => operation.clone(),
+    })
+}
+
+// Inlines the calls in a circuit with the definitions of the enclosing circuits and the stack of calls being inlined
+fn inline_circuit(
+    circuit: &Circuit,
+    enclosing: &HashMap<String, GateDefinition>,
+    stack: &mut Vec<String>,
+) -> Result<Circuit, RoqoqoError> {
+    let mut scope = enclosing.clone();
+    let mut inlined = Circuit::new();
+    for definition in circuit.definitions().iter() {
+        match definition {
+            Operation::GateDefinition(op) => {
+                scope.insert(op.name().clone(), op.clone());
+            }
+            _ => inlined.add_operation(definition.clone()),
+        }
+    }
+    for operation in circuit.operations().iter() {
+        match operation {
+            Operation::CallDefinedGate(call) => {
+                let name = call.gate_name();
+                let definition = scope.get(name).ok_or_else(|| RoqoqoError::GenericError {
+                    msg: format!("GateDefinition {name} called by CallDefinedGate is not defined"),
+                })?;
+                if stack.contains(name) {
+                    return Err(RoqoqoError::GenericError {
+                        msg: format!(
+                            "GateDefinitions call each other cyclically: {} -> {}",
+                            stack.join(" -> "),
+                            name
+                        ),
+                    });
+                }
+                let body = expand_call(call, definition)?;
+                stack.push(name.clone());
+                inlined += inline_circuit(&body, &scope, stack)?;
+                stack.pop();
+            }
+            _ => inlined.add_operation(map_nested_circuits(operation, &mut |nested| {
+                inline_circuit(nested, &scope, stack)
+            })?),
+        }
+    }
+    Ok(inlined)
+}
+
+// Embeds the unitary of a gate acting on the given bit positions into the unitary of all qubits,
+// the first position is the most significant bit of the gate's unitary
+fn embed_unitary(
+    matrix: &Array2<Complex64>,
+    positions: &[usize],
+    number_qubits: usize,
+) -> Array2<Complex64> {
+    let dimension = 1 << number_qubits;
+    let number_local = positions.len();
+    let mask: usize = positions.iter().map(|position| 1 << position).sum();
+    let to_global = |local: usize| -> usize {
+        positions
+            .iter()
+            .enumerate()
+            .map(|(index, position)| ((local >> (number_local - 1 - index)) & 1) << position)
+            .sum()
+    };
+    let mut embedded: Array2<Complex64> = Array2::zeros((dimension, dimension));
+    for column in 0..dimension {
+        let rest = column & !mask;
+        let local_column: usize = positions
+            .iter()
+            .enumerate()
+            .map(|(index, position)| ((column >> position) & 1) << (number_local - 1 - index))
+            .sum();
+        for local_row in 0..1 << number_local {
+            embedded[[rest | to_global(local_row), column]] = matrix[[local_row, local_column]];
+        }
+    }
+    embedded
+}
+
+// Returns a block remapped to the qubits 0, 1, ... in order of appearance and the original qubits,
+// None when the block contains an operation that is not a gate
+fn canonical_block(operations: &[Operation]) -> Result<Option<(Circuit, Vec<usize>)>, RoqoqoError> {
+    let mut qubits: Vec<usize> = Vec::new();
+    for operation in operations.iter() {
+        if !operation.tags().contains(&"GateOperation") {
+            return Ok(None);
+        }
+        match gate_qubits(operation) {
+            Some(gate_qubits) => {
+                for qubit in gate_qubits.into_iter() {
+                    if !qubits.contains(&qubit) {
+                        qubits.push(qubit);
+                    }
+                }
+            }
+            None => return Ok(None),
+        }
+    }
+    let pairs: Vec<(usize, usize)> = qubits.iter().copied().zip(0..qubits.len()).collect();
+    let mapping = complete_mapping(&pairs);
+    let mut block = Circuit::new();
+    for operation in operations.iter() {
+        block.add_operation(operation.remap_qubits(&mapping)?);
+    }
+    Ok(Some((block, qubits)))
+}
+
+// Returns the qubits of a gate in the order of its arguments
+fn gate_qubits(operation: &Operation) -> Option<Vec<usize>> {
+    if let Ok(op) = SingleQubitGateOperation::try_from(operation.clone()) {
+        Some(vec![*op.qubit()])
+    } else if let Ok(op) = TwoQubitGateOperation::try_from(operation.clone()) {
+        Some(vec![*op.control(), *op.target()])
+    } else if let Ok(op) = ThreeQubitGateOperation::try_from(operation.clone()) {
+        Some(vec![*op.control_0(), *op.control_1(), *op.target()])
+    } else if let Ok(op) = FourQubitGateOperation::try_from(operation.clone()) {
+        Some(vec![
+            *op.control_0(),
+            *op.control_1(),
+            *op.control_2(),
+            *op.target(),
+        ])
+    } else if let Ok(op) = MultiQubitGateOperation::try_from(operation.clone()) {
+        Some(op.qubits().clone())
+    } else {
+        None
+    }
+}
+
+// Returns the first name subcircuit_<n> not used by a GateDefinition of the circuit
+fn unused_definition_name(circuit: &Circuit) -> String {
+    let used: Vec<&String> = circuit
+        .definitions()
+        .iter()
+        .filter_map(|definition| match definition {
+            Operation::GateDefinition(op) => Some(op.name()),
+            _ => None,
+        })
+        .collect();
+    (0..)
+        .map(|index| format!("subcircuit_{index}"))
+        .find(|name| !used.contains(&name))
+        .unwrap_or_default()
+}
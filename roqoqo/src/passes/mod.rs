@@ -0,0 +1,218 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transformation and analysis passes for roqoqo circuits.
+//!
+//! Passes take a [crate::Circuit] (and usually a [crate::devices::Device] describing the hardware)
+//! and either return a transformed Circuit or information extracted from the Circuit.
+//!
+//! Most time-based passes rely on the as-soon-as-possible schedule returned by [schedule_circuit].
+//! In this schedule every gate takes the gate time reported by the device,
+//! [crate::operations::PragmaSleep] takes its `sleep_time` and all other operations
+//! (definitions, measurements and other pragmas) are assumed to be instantaneous.
+//!
+
+use crate::devices::Device;
+use crate::operations::*;
+use crate::{Circuit, RoqoqoError};
+use std::collections::{BTreeSet, HashMap};
+
+mod circuit_cutting;
+pub use circuit_cutting::{cut_circuit, CircuitFragment, CutCircuit, CutGate, CutGateSide};
+#[cfg(feature = "circuitdag")]
+mod circuit_statistics;
+#[cfg(feature = "circuitdag")]
+pub use circuit_statistics::CircuitStatistics;
+mod dynamical_decoupling;
+pub use dynamical_decoupling::{dynamical_decoupling, DecouplingSequence};
+mod fidelity_estimation;
+pub use fidelity_estimation::{estimate_fidelity, FidelityEstimate};
+#[cfg(feature = "unstable_operation_definition")]
+mod gate_definitions;
+#[cfg(feature = "unstable_operation_definition")]
+pub(crate) use gate_definitions::{check_call, definition_unitary};
+#[cfg(feature = "unstable_operation_definition")]
+pub use gate_definitions::{extract_definitions, inline_definitions};
+mod qubit_reuse;
+pub use qubit_reuse::reuse_qubits;
+#[cfg(feature = "unstable_analog_operations")]
+mod trotterization;
+#[cfg(feature = "unstable_analog_operations")]
+pub use trotterization::{
+    trotterize, trotterize_circuit, trotterize_time_dependent, TrotterGates, TrotterOrder,
+};
+
+/// An operation of a Circuit together with the time window in which it is executed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledOperation {
+    /// The scheduled operation.
+    pub operation: Operation,
+    /// Time at which the operation starts.
+    pub start_time: f64,
+    /// Time at which the operation ends.
+    pub end_time: f64,
+    /// The qubits blocked by the operation during the time window.
+    pub qubits: BTreeSet<usize>,
+}
+
+/// Returns the as-soon-as-possible schedule of a circuit on a device.
+///
+/// Every operation is started as soon as all the qubits it involves are free.
+/// Operations acting on all qubits (e.g. [crate::operations::PragmaRepeatedMeasurement])
+/// block all qubits of the device and of the circuit.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit that is scheduled.
+/// * `device` - The device providing the gate times.
+///
+/// # Returns
+///
+/// * `Ok(Vec<ScheduledOperation>)` - The scheduled operations in the order of the circuit.
+/// * `Err(RoqoqoError::GenericError)` - A gate is not available on the device or a sleep time is symbolic.
+pub fn schedule_circuit<T>(
+    circuit: &Circuit,
+    device: &T,
+) -> Result<Vec<ScheduledOperation>, RoqoqoError>
+where
+    T: Device,
+{
+    let all_qubits: BTreeSet<usize> = match circuit.involved_qubits() {
+        InvolvedQubits::Set(set) => set.into_iter().collect(),
+        _ => BTreeSet::new(),
+    }
+    .union(&(0..device.number_qubits()).collect())
+    .cloned()
+    .collect();
+    let mut free_at: HashMap<usize, f64> = HashMap::new();
+    let mut schedule: Vec<ScheduledOperation> = Vec::with_capacity(circuit.len());
+    for operation in circuit.iter() {
+        let qubits: BTreeSet<usize> = match operation.involved_qubits() {
+            InvolvedQubits::None => BTreeSet::new(),
+            InvolvedQubits::All => all_qubits.clone(),
+            InvolvedQubits::Set(set) => set.into_iter().collect(),
+        };
+        let start_time = qubits
+            .iter()
+            .map(|qubit| free_at.get(qubit).copied().unwrap_or(0.0))
+            .fold(0.0, f64::max);
+        let end_time = start_time + operation_time(operation, device)?;
+        for qubit in qubits.iter() {
+            free_at.insert(*qubit, end_time);
+        }
+        schedule.push(ScheduledOperation {
+            operation: operation.clone(),
+            start_time,
+            end_time,
+            qubits,
+        });
+    }
+    Ok(schedule)
+}
+
+/// Returns the time needed to execute a single operation on a device.
+///
+/// # Arguments
+///
+/// * `operation` - The operation that is executed.
+/// * `device` - The device providing the gate times.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The execution time of the operation.
+/// * `Err(RoqoqoError::GenericError)` - The gate is not available on the device or the sleep time is symbolic.
+pub fn operation_time<T>(operation: &Operation, device: &T) -> Result<f64, RoqoqoError>
+where
+    T: Device,
+{
+    if let Operation::PragmaSleep(sleep) = operation {
+        return sleep
+            .sleep_time()
+            .float()
+            .copied()
+            .map_err(|_| RoqoqoError::GenericError {
+                msg: format!(
+                    "Sleep time {} of PragmaSleep is not a float",
+                    sleep.sleep_time()
+                ),
+            });
+    }
+    if GateOperation::try_from(operation.clone()).is_err() {
+        return Ok(0.0);
+    }
+    let hqslang = operation.hqslang();
+    let gate_time = if let Ok(op) = SingleQubitGateOperation::try_from(operation.clone()) {
+        device.single_qubit_gate_time(hqslang, op.qubit())
+    } else if let Ok(op) = TwoQubitGateOperation::try_from(operation.clone()) {
+        device.two_qubit_gate_time(hqslang, op.control(), op.target())
+    } else if let Ok(op) = ThreeQubitGateOperation::try_from(operation.clone()) {
+        device.three_qubit_gate_time(hqslang, op.control_0(), op.control_1(), op.target())
+    } else if let Ok(op) = FourQubitGateOperation::try_from(operation.clone()) {
+        device.multi_qubit_gate_time(
+            hqslang,
+            &[
+                *op.control_0(),
+                *op.control_1(),
+                *op.control_2(),
+                *op.target(),
+            ],
+        )
+    } else if let Ok(op) = MultiQubitGateOperation::try_from(operation.clone()) {
+        device.multi_qubit_gate_time(hqslang, op.qubits())
+    } else {
+        None
+    };
+    gate_time.ok_or(RoqoqoError::GenericError {
+        msg: format!("Gate time of {operation:?} is not defined on the device"),
+    })
+}
+
+/// Returns the circuits nested in an operation.
+///
+/// The circuits of [crate::operations::PragmaLoop], [crate::operations::PragmaConditional],
+/// [crate::operations::PragmaIfElse], [crate::operations::PragmaWhileLoop] and
+/// [crate::operations::PragmaControlledCircuit] and the body of a GateDefinition are returned,
+/// other operations have no nested circuits.
+pub(crate) fn nested_circuits(operation: &Operation) -> Vec<&Circuit> {
+    match operation {
+        #[cfg(feature = "unstable_operation_definition")]
+        Operation::GateDefinition(op) => vec![op.circuit()],
+        Operation::PragmaLoop(op) => vec![op.circuit()],
+        Operation::PragmaConditional(op) => vec![op.circuit()],
+        Operation::PragmaIfElse(op) => vec![op.if_circuit(), op.else_circuit()],
+        Operation::PragmaWhileLoop(op) => vec![op.circuit()],
+        Operation::PragmaControlledCircuit(op) => vec![op.circuit()],
+        _ => Vec::new(),
+    }
+}
+
+// Completes an injective map of qubits to a permutation as required by remap_qubits,
+// targets that are not mapped themselves are mapped to the sources that are not targets
+pub(super) fn complete_mapping(pairs: &[(usize, usize)]) -> HashMap<usize, usize> {
+    let mut mapping: HashMap<usize, usize> = pairs.iter().copied().collect();
+    let mut free_sources: Vec<usize> = pairs
+        .iter()
+        .map(|(source, _)| *source)
+        .filter(|source| !pairs.iter().any(|(_, target)| target == source))
+        .collect();
+    free_sources.sort_unstable();
+    let mut dangling_targets: Vec<usize> = pairs
+        .iter()
+        .map(|(_, target)| *target)
+        .filter(|target| !mapping.contains_key(target))
+        .collect();
+    dangling_targets.sort_unstable();
+    for (target, source) in dangling_targets.into_iter().zip(free_sources) {
+        mapping.insert(target, source);
+    }
+    mapping
+}
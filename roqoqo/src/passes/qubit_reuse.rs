@@ -0,0 +1,105 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::complete_mapping;
+use crate::operations::*;
+use crate::{Circuit, RoqoqoError};
+use std::collections::{BTreeSet, HashMap};
+
+/// Reduces the width of a circuit by reusing qubits that have been reset.
+///
+/// A qubit is freed after its last operation when this operation is a
+/// [crate::operations::MeasureQubitAndReset] or a [crate::operations::PragmaActiveReset].
+/// Every qubit is relabelled when it is used for the first time,
+/// taking the smallest qubit index that is currently not in use (either never used or freed).
+/// Operations acting on all qubits (e.g. [crate::operations::PragmaRepeatedMeasurement]) act on every qubit of the circuit,
+/// so no qubit is freed before and no qubit is first used after such an operation.
+///
+/// The connectivity of the device is not taken into account,
+/// the returned circuit may need to be routed again.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit whose qubits are reused.
+///
+/// # Returns
+///
+/// * `Ok((Circuit, HashMap<usize, usize>))` - The circuit acting on the reused qubits and the mapping from the original to the new qubits.
+/// * `Err(RoqoqoError)` - Remapping the qubits of an operation failed.
+pub fn reuse_qubits(circuit: &Circuit) -> Result<(Circuit, HashMap<usize, usize>), RoqoqoError> {
+    let operations: Vec<&Operation> = circuit.iter().collect();
+    let all_qubits: BTreeSet<usize> = operations
+        .iter()
+        .filter_map(|operation| match operation.involved_qubits() {
+            InvolvedQubits::Set(set) => Some(set),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    let touched: Vec<BTreeSet<usize>> = operations
+        .iter()
+        .map(|operation| match operation.involved_qubits() {
+            InvolvedQubits::All => all_qubits.clone(),
+            InvolvedQubits::None => BTreeSet::new(),
+            InvolvedQubits::Set(set) => set.into_iter().collect(),
+        })
+        .collect();
+
+    let mut first_use: HashMap<usize, usize> = HashMap::new();
+    let mut last_use: HashMap<usize, usize> = HashMap::new();
+    for (index, qubits) in touched.iter().enumerate() {
+        for qubit in qubits {
+            first_use.entry(*qubit).or_insert(index);
+            last_use.insert(*qubit, index);
+        }
+    }
+
+    let mut mapping: HashMap<usize, usize> = HashMap::new();
+    let mut in_use: BTreeSet<usize> = BTreeSet::new();
+    let mut new_circuit = Circuit::new();
+    for (index, (operation, qubits)) in operations.iter().zip(touched.iter()).enumerate() {
+        for qubit in qubits {
+            if first_use.get(qubit) == Some(&index) {
+                let new_qubit = (0..).find(|q| !in_use.contains(q)).unwrap_or_default();
+                in_use.insert(new_qubit);
+                mapping.insert(*qubit, new_qubit);
+            }
+        }
+        // Operations on unchanged qubits are kept as they are
+        if qubits.iter().all(|qubit| mapping.get(qubit) == Some(qubit)) {
+            new_circuit.add_operation((*operation).clone());
+        } else {
+            // The global mapping is not injective once qubits are reused,
+            // every operation is remapped with a permutation of its own qubits
+            let pairs: Vec<(usize, usize)> = qubits
+                .iter()
+                .map(|qubit| (*qubit, mapping[qubit]))
+                .collect();
+            new_circuit.add_operation(operation.remap_qubits(&complete_mapping(&pairs))?);
+        }
+        for qubit in qubits {
+            if last_use.get(qubit) == Some(&index) && frees_qubit(operation, *qubit) {
+                in_use.remove(&mapping[qubit]);
+            }
+        }
+    }
+    Ok((new_circuit, mapping))
+}
+
+// Returns true when the operation leaves the qubit in a reset state
+fn frees_qubit(operation: &Operation, qubit: usize) -> bool {
+    match operation {
+        Operation::MeasureQubitAndReset(op) => *op.qubit() == qubit,
+        Operation::PragmaActiveReset(op) => *op.qubit() == qubit,
+        _ => false,
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::operations::*;
+use crate::{Circuit, RoqoqoError};
+use qoqo_calculator::CalculatorFloat;
+use std::f64::consts::FRAC_PI_2;
+use struqture::spins::{PauliProduct, SinglePauliOperator};
+use struqture::{OperateOnDensityMatrix, SpinIndex};
+
+/// Order of the Trotter-Suzuki product formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrotterOrder {
+    /// First-order formula applying every term once per step.
+    First,
+    /// Symmetric second-order formula applying the terms forwards and backwards for half a step each.
+    Second,
+}
+
+/// Gates implementing the exponential of a Pauli product acting on more than one qubit.
+///
+/// Exponentials of single-qubit terms are always implemented with [crate::operations::RotateX],
+/// [crate::operations::RotateY] and [crate::operations::RotateZ].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrotterGates {
+    /// Basis changes into the Z basis, a ladder of [crate::operations::CNOT] gates and a [crate::operations::RotateZ].
+    CNOTLadder,
+    /// Basis changes into the Z basis and a [crate::operations::MultiQubitZZ].
+    MultiQubitZZ,
+    /// Basis changes into the X basis and a [crate::operations::MultiQubitMS].
+    MultiQubitMS,
+}
+
+/// Decomposes the evolution under a constant Pauli Hamiltonian into a circuit of gates.
+///
+/// The evolution exp(-i H t) with H = Σ_j c_j P_j is split into `number_steps` Trotter steps of
+/// duration t / `number_steps`. In every step the exponentials exp(-i c_j P_j dt) of the individual
+/// Pauli products are applied in the term order with the product formula of the given `order`.
+/// The identity term of the Hamiltonian is applied as a [crate::operations::PragmaGlobalPhase].
+///
+/// Without a term order the Pauli products are applied in their sorted order.
+/// Pauli products missing from a given term order are applied after the listed ones in sorted order.
+/// Symbolic coefficients and times are kept symbolic in the rotation angles.
+///
+/// # Arguments
+///
+/// * `operation` - The evolution under the constant Pauli Hamiltonian.
+/// * `number_steps` - The number of Trotter steps.
+/// * `order` - The order of the Trotter-Suzuki product formula.
+/// * `gates` - The gates implementing exponentials of multi-qubit Pauli products.
+/// * `term_order` - Optional order in which the Pauli products are applied in a Trotter step.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The Trotterized circuit.
+/// * `Err(RoqoqoError::GenericError)` - The number of steps is zero or the term order contains a Pauli product not in the Hamiltonian.
+pub fn trotterize(
+    operation: &ApplyConstantPauliHamiltonian,
+    number_steps: usize,
+    order: TrotterOrder,
+    gates: TrotterGates,
+    term_order: Option<&[PauliProduct]>,
+) -> Result<Circuit, RoqoqoError> {
+    if number_steps == 0 {
+        return Err(RoqoqoError::GenericError {
+            msg: "Trotterization needs at least one Trotter step".to_string(),
+        });
+    }
+    let hamiltonian = &operation.hamiltonian;
+    let mut terms: Vec<PauliProduct> = Vec::new();
+    for product in term_order.unwrap_or_default() {
+        // The identity term is applied as a global phase
+        if product.is_empty() {
+            continue;
+        }
+        if !hamiltonian.keys().any(|key| key == product) {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Pauli product {product} of the term order is not in the Hamiltonian"),
+            });
+        }
+        if !terms.contains(product) {
+            terms.push(product.clone());
+        }
+    }
+    let mut remaining: Vec<PauliProduct> = hamiltonian
+        .keys()
+        .filter(|key| !key.is_empty() && !terms.contains(key))
+        .cloned()
+        .collect();
+    remaining.sort();
+    terms.extend(remaining);
+
+    let step_time = operation.time.clone() / number_steps as f64;
+    let mut step = Circuit::new();
+    match order {
+        TrotterOrder::First => {
+            for product in terms.iter() {
+                step += pauli_exponential(product, hamiltonian.get(product) * &step_time, gates);
+            }
+        }
+        TrotterOrder::Second => {
+            let half_time = step_time.clone() / 2.0;
+            if let Some((last, rest)) = terms.split_last() {
+                for product in rest.iter() {
+                    step +=
+                        pauli_exponential(product, hamiltonian.get(product) * &half_time, gates);
+                }
+                step += pauli_exponential(last, hamiltonian.get(last) * &step_time, gates);
+                for product in rest.iter().rev() {
+                    step +=
+                        pauli_exponential(product, hamiltonian.get(product) * &half_time, gates);
+                }
+            }
+        }
+    }
+
+    let mut circuit = Circuit::new();
+    let identity = hamiltonian.get(&PauliProduct::new());
+    if identity != &CalculatorFloat::ZERO {
+        circuit += PragmaGlobalPhase::new(-(identity * &operation.time));
+    }
+    for _ in 0..number_steps {
+        circuit += step.clone();
+    }
+    Ok(circuit)
+}
+
+/// Decomposes the evolution under a time-dependent Pauli Hamiltonian into a circuit of gates.
+///
+/// The schedule is sampled into piecewise-constant segments with
+/// [crate::operations::ApplyTimeDependentPauliHamiltonian::piecewise_constant]
+/// and every segment is Trotterized with `steps_per_segment` Trotter steps, see [trotterize].
+///
+/// # Arguments
+///
+/// * `operation` - The evolution under the time-dependent Pauli Hamiltonian.
+/// * `steps_per_segment` - The number of Trotter steps of every piecewise-constant segment.
+/// * `order` - The order of the Trotter-Suzuki product formula.
+/// * `gates` - The gates implementing exponentials of multi-qubit Pauli products.
+/// * `term_order` - Optional order in which the Pauli products are applied in a Trotter step.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The Trotterized circuit.
+/// * `Err(RoqoqoError::GenericError)` - The schedule is not valid, the number of steps is zero or the term order contains a Pauli product not in the Hamiltonian.
+/// * `Err(RoqoqoError::CalculatorError)` - A coefficient cannot be evaluated.
+pub fn trotterize_time_dependent(
+    operation: &ApplyTimeDependentPauliHamiltonian,
+    steps_per_segment: usize,
+    order: TrotterOrder,
+    gates: TrotterGates,
+    term_order: Option<&[PauliProduct]>,
+) -> Result<Circuit, RoqoqoError> {
+    let mut circuit = Circuit::new();
+    for segment in operation.piecewise_constant()? {
+        // Terms vanishing in a segment are not part of its Hamiltonian
+        let segment_order: Option<Vec<PauliProduct>> = term_order.map(|products| {
+            products
+                .iter()
+                .filter(|product| segment.hamiltonian.keys().any(|key| key == *product))
+                .cloned()
+                .collect()
+        });
+        circuit += trotterize(
+            &segment,
+            steps_per_segment,
+            order,
+            gates,
+            segment_order.as_deref(),
+        )?;
+    }
+    Ok(circuit)
+}
+
+/// Replaces every analog Pauli Hamiltonian evolution in a circuit by its Trotterized circuit.
+///
+/// [crate::operations::ApplyConstantPauliHamiltonian] is replaced using [trotterize] and
+/// [crate::operations::ApplyTimeDependentPauliHamiltonian] using [trotterize_time_dependent]
+/// with `number_steps` steps per piecewise-constant segment.
+/// The Pauli products are applied in their sorted order.
+/// Operations in the circuits of pragmas are not replaced.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit containing analog operations.
+/// * `number_steps` - The number of Trotter steps of every analog operation or segment.
+/// * `order` - The order of the Trotter-Suzuki product formula.
+/// * `gates` - The gates implementing exponentials of multi-qubit Pauli products.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The circuit with the analog operations replaced by gates.
+/// * `Err(RoqoqoError::GenericError)` - The number of steps is zero or a time-dependent schedule is not valid.
+/// * `Err(RoqoqoError::CalculatorError)` - A time-dependent coefficient cannot be evaluated.
+pub fn trotterize_circuit(
+    circuit: &Circuit,
+    number_steps: usize,
+    order: TrotterOrder,
+    gates: TrotterGates,
+) -> Result<Circuit, RoqoqoError> {
+    let mut trotterized = Circuit::new();
+    for operation in circuit.iter() {
+        match operation {
+            Operation::ApplyConstantPauliHamiltonian(op) => {
+                trotterized += trotterize(op, number_steps, order, gates, None)?;
+            }
+            Operation::ApplyTimeDependentPauliHamiltonian(op) => {
+                trotterized += trotterize_time_dependent(op, number_steps, order, gates, None)?;
+            }
+            _ => trotterized.add_operation(operation.clone()),
+        }
+    }
+    Ok(trotterized)
+}
+
+// Returns the gates applying exp(-i angle P) for a non-identity Pauli product P
+fn pauli_exponential(
+    product: &PauliProduct,
+    angle: CalculatorFloat,
+    gates: TrotterGates,
+) -> Circuit {
+    let theta = angle * 2.0;
+    let mut paulis: Vec<(usize, SinglePauliOperator)> = product
+        .iter()
+        .filter(|(_, pauli)| *pauli != SinglePauliOperator::Identity)
+        .cloned()
+        .collect();
+    paulis.sort_by_key(|(qubit, _)| *qubit);
+    let mut circuit = Circuit::new();
+    if let [(qubit, pauli)] = paulis.as_slice() {
+        match pauli {
+            SinglePauliOperator::X => circuit += RotateX::new(*qubit, theta),
+            SinglePauliOperator::Y => circuit += RotateY::new(*qubit, theta),
+            _ => circuit += RotateZ::new(*qubit, theta),
+        }
+        return circuit;
+    }
+    let qubits: Vec<usize> = paulis.iter().map(|(qubit, _)| *qubit).collect();
+    let mut basis_change = Circuit::new();
+    let mut basis_change_back = Circuit::new();
+    for (qubit, pauli) in paulis.iter() {
+        // Rotations mapping the Pauli operator onto Z, or onto X for the Molmer-Sorensen gate
+        let (forward, backward): (Option<Operation>, Option<Operation>) = match (gates, pauli) {
+            (TrotterGates::MultiQubitMS, SinglePauliOperator::X)
+            | (TrotterGates::CNOTLadder | TrotterGates::MultiQubitZZ, SinglePauliOperator::Z) => {
+                (None, None)
+            }
+            (TrotterGates::MultiQubitMS, SinglePauliOperator::Y) => (
+                Some(RotateZ::new(*qubit, (-FRAC_PI_2).into()).into()),
+                Some(RotateZ::new(*qubit, FRAC_PI_2.into()).into()),
+            ),
+            (_, SinglePauliOperator::Y) => (
+                Some(RotateX::new(*qubit, FRAC_PI_2.into()).into()),
+                Some(RotateX::new(*qubit, (-FRAC_PI_2).into()).into()),
+            ),
+            _ => (
+                Some(Hadamard::new(*qubit).into()),
+                Some(Hadamard::new(*qubit).into()),
+            ),
+        };
+        if let (Some(forward), Some(backward)) = (forward, backward) {
+            basis_change.add_operation(forward);
+            basis_change_back.add_operation(backward);
+        }
+    }
+    circuit += basis_change;
+    match gates {
+        TrotterGates::CNOTLadder => {
+            for pair in qubits.windows(2) {
+                circuit += CNOT::new(pair[0], pair[1]);
+            }
+            circuit += RotateZ::new(qubits[qubits.len() - 1], theta);
+            for pair in qubits.windows(2).rev() {
+                circuit += CNOT::new(pair[0], pair[1]);
+            }
+        }
+        TrotterGates::MultiQubitZZ => circuit += MultiQubitZZ::new(qubits, theta),
+        TrotterGates::MultiQubitMS => circuit += MultiQubitMS::new(qubits, theta),
+    }
+    circuit += basis_change_back;
+    circuit
+}
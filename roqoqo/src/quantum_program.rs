@@ -13,14 +13,14 @@
 //! Represents a quantum program evaluating measurements based on a one or more free float parameters.
 #[cfg(feature = "unstable_parallel_run")]
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "async")]
 use crate::backends::AsyncEvaluatingBackend;
 use crate::backends::{EvaluatingBackend, RegisterResult};
 use crate::measurements;
 use crate::measurements::Measure;
-use crate::RoqoqoBackendError;
+use crate::{RoqoqoBackendError, RoqoqoError};
 use std::fmt::{Display, Formatter};
 
 /// Represents a quantum program evaluating measurements based on a one or more free float parameters.
@@ -329,6 +329,218 @@ impl QuantumProgram {
             _ => Err(RoqoqoBackendError::GenericError{msg: "A quantum programm returning expectation values cannot be executed by `run_registers` use `run` instead".to_string()})
         }
     }
+
+    /// Returns the input parameter names of the QuantumProgram.
+    ///
+    /// # Returns
+    ///
+    /// * `&[String]` - The free input parameters in the order they are passed when running the program.
+    pub fn input_parameter_names(&self) -> &[String] {
+        match self {
+            QuantumProgram::PauliZProduct {
+                input_parameter_names,
+                ..
+            }
+            | QuantumProgram::CheatedPauliZProduct {
+                input_parameter_names,
+                ..
+            }
+            | QuantumProgram::Cheated {
+                input_parameter_names,
+                ..
+            }
+            | QuantumProgram::ClassicalRegister {
+                input_parameter_names,
+                ..
+            } => input_parameter_names,
+        }
+    }
+
+    /// Returns the names of the free symbols in the circuits of the measurement of the QuantumProgram.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashSet<String>)` - The free symbols of all circuits of the measurement.
+    /// * `Err(RoqoqoError::GenericError)` - A parametrized operation does not report its symbols.
+    pub fn symbols(&self) -> Result<HashSet<String>, RoqoqoError> {
+        match self {
+            QuantumProgram::PauliZProduct { measurement, .. } => measurement.symbols(),
+            QuantumProgram::CheatedPauliZProduct { measurement, .. } => measurement.symbols(),
+            QuantumProgram::Cheated { measurement, .. } => measurement.symbols(),
+            QuantumProgram::ClassicalRegister { measurement, .. } => measurement.symbols(),
+        }
+    }
+
+    /// Substitutes the given symbols in the measurement and keeps the other symbols symbolic.
+    ///
+    /// The bound symbols are removed from the input parameter names, so the returned QuantumProgram
+    /// is run with the values of the remaining input parameters only.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the symbols to substitute.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QuantumProgram)` - The QuantumProgram with the given symbols substituted.
+    /// * `Err(RoqoqoError)` - The substitution failed.
+    pub fn bind_parameters(
+        &self,
+        values: &HashMap<String, f64>,
+    ) -> Result<QuantumProgram, RoqoqoError> {
+        let remaining_names = |input_parameter_names: &Vec<String>| -> Vec<String> {
+            input_parameter_names
+                .iter()
+                .filter(|name| !values.contains_key(*name))
+                .cloned()
+                .collect()
+        };
+        Ok(match self {
+            QuantumProgram::PauliZProduct {
+                measurement,
+                input_parameter_names,
+            } => QuantumProgram::PauliZProduct {
+                measurement: measurement.bind_parameters(values.clone())?,
+                input_parameter_names: remaining_names(input_parameter_names),
+            },
+            QuantumProgram::CheatedPauliZProduct {
+                measurement,
+                input_parameter_names,
+            } => QuantumProgram::CheatedPauliZProduct {
+                measurement: measurement.bind_parameters(values.clone())?,
+                input_parameter_names: remaining_names(input_parameter_names),
+            },
+            QuantumProgram::Cheated {
+                measurement,
+                input_parameter_names,
+            } => QuantumProgram::Cheated {
+                measurement: measurement.bind_parameters(values.clone())?,
+                input_parameter_names: remaining_names(input_parameter_names),
+            },
+            QuantumProgram::ClassicalRegister {
+                measurement,
+                input_parameter_names,
+            } => QuantumProgram::ClassicalRegister {
+                measurement: measurement.bind_parameters(values.clone())?,
+                input_parameter_names: remaining_names(input_parameter_names),
+            },
+        })
+    }
+
+    /// Renames the given symbols in the measurement and in the input parameter names.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The new names of the symbols to rename.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QuantumProgram)` - The QuantumProgram with the given symbols renamed.
+    /// * `Err(RoqoqoError)` - A new name is not a valid symbol name.
+    pub fn rename_parameters(
+        &self,
+        mapping: &HashMap<String, String>,
+    ) -> Result<QuantumProgram, RoqoqoError> {
+        let renamed_names = |input_parameter_names: &Vec<String>| -> Vec<String> {
+            input_parameter_names
+                .iter()
+                .map(|name| mapping.get(name).unwrap_or(name).clone())
+                .collect()
+        };
+        Ok(match self {
+            QuantumProgram::PauliZProduct {
+                measurement,
+                input_parameter_names,
+            } => QuantumProgram::PauliZProduct {
+                measurement: measurement.rename_parameters(mapping.clone())?,
+                input_parameter_names: renamed_names(input_parameter_names),
+            },
+            QuantumProgram::CheatedPauliZProduct {
+                measurement,
+                input_parameter_names,
+            } => QuantumProgram::CheatedPauliZProduct {
+                measurement: measurement.rename_parameters(mapping.clone())?,
+                input_parameter_names: renamed_names(input_parameter_names),
+            },
+            QuantumProgram::Cheated {
+                measurement,
+                input_parameter_names,
+            } => QuantumProgram::Cheated {
+                measurement: measurement.rename_parameters(mapping.clone())?,
+                input_parameter_names: renamed_names(input_parameter_names),
+            },
+            QuantumProgram::ClassicalRegister {
+                measurement,
+                input_parameter_names,
+            } => QuantumProgram::ClassicalRegister {
+                measurement: measurement.rename_parameters(mapping.clone())?,
+                input_parameter_names: renamed_names(input_parameter_names),
+            },
+        })
+    }
+
+    /// Prefixes the names of all input parameters and free symbols of the QuantumProgram.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix added to the names of the symbols.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QuantumProgram)` - The QuantumProgram with the prefixed symbols.
+    /// * `Err(RoqoqoError)` - The prefixed names are not valid symbol names or a parametrized operation does not report its symbols.
+    pub fn reparametrize(&self, prefix: &str) -> Result<QuantumProgram, RoqoqoError> {
+        let mut mapping: HashMap<String, String> = HashMap::new();
+        for name in self
+            .input_parameter_names()
+            .iter()
+            .chain(self.symbols()?.iter())
+        {
+            mapping.insert(name.clone(), format!("{prefix}{name}"));
+        }
+        self.rename_parameters(&mapping)
+    }
+
+    /// Validates that the input parameter names cover exactly the free symbols of the circuits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Every free symbol is set by exactly one input parameter and every input parameter is used.
+    /// * `Err(RoqoqoError::GenericError)` - An input parameter is repeated or unused, a symbol is not an input parameter
+    ///   or a parametrized operation does not report its symbols.
+    pub fn validate_input_parameter_names(&self) -> Result<(), RoqoqoError> {
+        let mut input_parameters: HashSet<&String> = HashSet::new();
+        for name in self.input_parameter_names() {
+            if !input_parameters.insert(name) {
+                return Err(RoqoqoError::GenericError {
+                    msg: format!("Input parameter {name} is listed more than once"),
+                });
+            }
+        }
+        let symbols = self.symbols()?;
+        let mut missing: Vec<&String> = symbols
+            .iter()
+            .filter(|symbol| !input_parameters.contains(symbol))
+            .collect();
+        missing.sort();
+        if !missing.is_empty() {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Symbols {missing:?} of the circuits are not input parameters"),
+            });
+        }
+        let mut unused: Vec<&String> = self
+            .input_parameter_names()
+            .iter()
+            .filter(|name| !symbols.contains(*name))
+            .collect();
+        unused.sort();
+        if !unused.is_empty() {
+            return Err(RoqoqoError::GenericError {
+                msg: format!("Input parameters {unused:?} are not used by the circuits"),
+            });
+        }
+        Ok(())
+    }
 }
 
 /// Implements the Display trait for QuantumProgram.
@@ -0,0 +1,89 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::RoqoqoBackendError;
+use nalgebra::DMatrix;
+use num_complex::Complex64;
+use struqture::spins::{PauliHamiltonian, SinglePauliOperator};
+use struqture::{OperateOnDensityMatrix, SpinIndex};
+
+/// Returns the dense matrix of a Pauli Hamiltonian.
+///
+/// Qubit k is bit k of the basis index, the same ordering as the qubits in the amplitudes of
+/// [super::QubitResonatorState].
+/// The dimension of the matrix is 2^`number_qubits`, so only small systems can be represented.
+///
+/// # Arguments
+///
+/// * `hamiltonian` - The Pauli Hamiltonian.
+/// * `number_qubits` - The number of qubits the matrix acts on.
+///
+/// # Returns
+///
+/// * `Ok(DMatrix<Complex64>)` - The dense matrix of the Hamiltonian.
+/// * `Err(RoqoqoBackendError::GenericError)` - The Hamiltonian acts on a qubit outside of the matrix.
+/// * `Err(RoqoqoBackendError::CalculatorError)` - A coefficient of the Hamiltonian is symbolic.
+pub fn pauli_hamiltonian_matrix(
+    hamiltonian: &PauliHamiltonian,
+    number_qubits: usize,
+) -> Result<DMatrix<Complex64>, RoqoqoBackendError> {
+    let qubits: Vec<usize> = (0..number_qubits).collect();
+    local_hamiltonian_matrix(hamiltonian, &qubits)
+}
+
+// Returns the dense matrix of a Pauli Hamiltonian acting on the given qubits,
+// qubits[k] is bit k of the local basis index
+pub(super) fn local_hamiltonian_matrix(
+    hamiltonian: &PauliHamiltonian,
+    qubits: &[usize],
+) -> Result<DMatrix<Complex64>, RoqoqoBackendError> {
+    let dimension = 1 << qubits.len();
+    let mut matrix = DMatrix::<Complex64>::zeros(dimension, dimension);
+    for (product, coefficient) in hamiltonian.iter() {
+        let coefficient = Complex64::from(*coefficient.float()?);
+        let mut flip_mask = 0;
+        let mut sign_mask = 0;
+        let mut number_y = 0;
+        for (qubit, pauli) in product.iter() {
+            let position = qubits.iter().position(|q| q == qubit).ok_or_else(|| {
+                RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Qubit {qubit} of the Hamiltonian is not part of a state with {} qubits",
+                        qubits.len()
+                    ),
+                }
+            })?;
+            let bit = 1 << position;
+            // Y = i X Z, so Y flips the bit with the sign of Z and an additional phase i
+            match pauli {
+                SinglePauliOperator::Identity => (),
+                SinglePauliOperator::X => flip_mask |= bit,
+                SinglePauliOperator::Y => {
+                    flip_mask |= bit;
+                    sign_mask |= bit;
+                    number_y += 1;
+                }
+                SinglePauliOperator::Z => sign_mask |= bit,
+            }
+        }
+        let phase = coefficient * Complex64::i().powu(number_y);
+        for column in 0..dimension {
+            let sign = if (column & sign_mask).count_ones() % 2 == 0 {
+                1.0
+            } else {
+                -1.0
+            };
+            matrix[(column ^ flip_mask, column)] += phase * sign;
+        }
+    }
+    Ok(matrix)
+}
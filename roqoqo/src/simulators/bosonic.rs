@@ -0,0 +1,848 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::stabilizer::get_bit;
+use crate::backends::{EvaluatingBackend, RegisterResult};
+use crate::operations::*;
+use crate::registers::{
+    BitOutputRegister, BitRegister, ComplexOutputRegister, FloatOutputRegister, FloatRegister,
+};
+use crate::{Circuit, RoqoqoBackendError};
+use nalgebra::{DMatrix, DVector, RowDVector};
+use num_complex::Complex64;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+const BACKEND_NAME: &str = "BosonicBackend";
+
+/// Pure Gaussian state of bosonic modes.
+///
+/// The state U|0> created from the vacuum by the Gaussian unitary U is represented by the
+/// Bogoliubov transformation of the annihilation operators in the Heisenberg picture,
+/// U† a U = M a + N a† + α.
+/// [crate::operations::Squeezing], [crate::operations::PhaseDisplacement],
+/// [crate::operations::PhaseShift] and [crate::operations::BeamSplitter] update the transformation exactly,
+/// so the state is not truncated and the cost of a gate only depends on the number of modes.
+///
+/// The gates follow the conventions
+/// * Squeezing: S(z) = exp(1/2 (z* a² - z a†²)) with z = squeezing * exp(i * phase)
+/// * PhaseDisplacement: D(α) = exp(α a† - α* a) with α = displacement * exp(i * phase)
+/// * PhaseShift: R(θ) = exp(i θ a† a)
+/// * BeamSplitter: B(θ, φ) = exp(θ (exp(i φ) a_0 b_1† - exp(-i φ) a_0† b_1))
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussianState {
+    number_modes: usize,
+    m: DMatrix<Complex64>,
+    n: DMatrix<Complex64>,
+    alpha: DVector<Complex64>,
+}
+
+impl GaussianState {
+    /// Creates the vacuum state of `number_modes` bosonic modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of bosonic modes of the state.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The vacuum state.
+    pub fn new(number_modes: usize) -> Self {
+        Self {
+            number_modes,
+            m: DMatrix::identity(number_modes, number_modes),
+            n: DMatrix::zeros(number_modes, number_modes),
+            alpha: DVector::zeros(number_modes),
+        }
+    }
+
+    /// Returns the number of bosonic modes of the state.
+    pub fn number_modes(&self) -> usize {
+        self.number_modes
+    }
+
+    /// Returns the expectation values <a_k> of the annihilation operators of all modes.
+    pub fn displacement(&self) -> Vec<Complex64> {
+        self.alpha.iter().copied().collect()
+    }
+
+    /// Returns the mean photon number <a_k† a_k> of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode of the photon number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The mean photon number.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The mode is not part of the state.
+    pub fn mean_photon_number(&self, mode: usize) -> Result<f64, RoqoqoBackendError> {
+        check_mode(mode, self.number_modes)?;
+        Ok(
+            self.alpha[mode].norm_sqr()
+                + self.n.row(mode).iter().map(|x| x.norm_sqr()).sum::<f64>(),
+        )
+    }
+
+    /// Applies a Gaussian bosonic gate operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The gate operation that is applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate was applied.
+    /// * `Err(RoqoqoBackendError::OperationNotInBackend)` - The operation is not a Gaussian bosonic gate.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The gate acts on a mode outside of the state.
+    /// * `Err(RoqoqoBackendError::CalculatorError)` - A parameter of the gate is symbolic.
+    pub fn apply_gate(&mut self, operation: &Operation) -> Result<(), RoqoqoBackendError> {
+        // Heisenberg action of the gate G† a G = m a + n a† + g on the modes of the gate
+        let (modes, m, n, g): (
+            Vec<usize>,
+            DMatrix<Complex64>,
+            DMatrix<Complex64>,
+            DVector<Complex64>,
+        ) = match operation {
+            Operation::Squeezing(op) => {
+                let r = *op.squeezing().float()?;
+                let phase = Complex64::from_polar(1.0, *op.phase().float()?);
+                (
+                    vec![*op.mode()],
+                    DMatrix::from_element(1, 1, Complex64::from(r.cosh())),
+                    DMatrix::from_element(1, 1, -phase * r.sinh()),
+                    DVector::zeros(1),
+                )
+            }
+            Operation::PhaseDisplacement(op) => (
+                vec![*op.mode()],
+                DMatrix::identity(1, 1),
+                DMatrix::zeros(1, 1),
+                DVector::from_element(
+                    1,
+                    Complex64::from_polar(*op.displacement().float()?, *op.phase().float()?),
+                ),
+            ),
+            Operation::PhaseShift(op) => (
+                vec![*op.mode()],
+                DMatrix::from_element(1, 1, Complex64::from_polar(1.0, *op.phase().float()?)),
+                DMatrix::zeros(1, 1),
+                DVector::zeros(1),
+            ),
+            Operation::BeamSplitter(op) => {
+                let theta = *op.theta().float()?;
+                let t = Complex64::from(theta.cos());
+                let r = Complex64::from_polar(theta.sin(), *op.phi().float()?);
+                (
+                    vec![*op.mode_0(), *op.mode_1()],
+                    DMatrix::from_row_slice(2, 2, &[t, -r.conj(), r, t]),
+                    DMatrix::zeros(2, 2),
+                    DVector::zeros(2),
+                )
+            }
+            _ => {
+                return Err(RoqoqoBackendError::OperationNotInBackend {
+                    backend: BACKEND_NAME,
+                    hqslang: operation.hqslang(),
+                })
+            }
+        };
+        for mode in modes.iter() {
+            check_mode(*mode, self.number_modes)?;
+        }
+        // U'† a U' = U† (G† a G) U, only the rows of the modes of the gate change
+        let rows_m: Vec<_> = modes
+            .iter()
+            .map(|mode| self.m.row(*mode).into_owned())
+            .collect();
+        let rows_n: Vec<_> = modes
+            .iter()
+            .map(|mode| self.n.row(*mode).into_owned())
+            .collect();
+        let alphas: Vec<Complex64> = modes.iter().map(|mode| self.alpha[*mode]).collect();
+        for (i, mode) in modes.iter().enumerate() {
+            let mut new_m = RowDVector::zeros(self.number_modes);
+            let mut new_n = RowDVector::zeros(self.number_modes);
+            let mut new_alpha = g[i];
+            for j in 0..modes.len() {
+                new_m += rows_m[j].clone() * m[(i, j)] + rows_n[j].map(|x| x.conj()) * n[(i, j)];
+                new_n += rows_n[j].clone() * m[(i, j)] + rows_m[j].map(|x| x.conj()) * n[(i, j)];
+                new_alpha += m[(i, j)] * alphas[j] + n[(i, j)] * alphas[j].conj();
+            }
+            self.m.set_row(*mode, &new_m);
+            self.n.set_row(*mode, &new_n);
+            self.alpha[*mode] = new_alpha;
+        }
+        Ok(())
+    }
+
+    /// Returns the Fock-space representation of the state truncated at `cutoff` photons per mode.
+    ///
+    /// The amplitudes are calculated exactly from the Gaussian state, amplitudes of states with
+    /// `cutoff` or more photons in any mode are dropped. The norm of the returned state is
+    /// therefore smaller than one when the state has a relevant population above the cutoff.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff` - The number of Fock states of every mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FockState)` - The truncated Fock-space representation.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The cutoff is zero or the state is too strongly squeezed.
+    pub fn to_fock(&self, cutoff: usize) -> Result<FockState, RoqoqoBackendError> {
+        let number_modes = self.number_modes;
+        let mut fock = FockState::new(number_modes, cutoff)?;
+        // The state is c exp(1/2 a† B a† + β a†)|0> with B = (M†)^-1 N^T and β = α - B α*
+        let b = self
+            .m
+            .adjoint()
+            .try_inverse()
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "Gaussian state cannot be converted to Fock space".to_string(),
+            })?
+            * self.n.transpose();
+        let beta = &self.alpha - &b * self.alpha.map(|x| x.conj());
+
+        // |c|² = sqrt(det(1 - B* B)) exp(-1/2 J^T K^-1 J) with K = [[-B, 1], [1, -B*]] and J = (β, β*)
+        let identity = DMatrix::<Complex64>::identity(number_modes, number_modes);
+        let mut k = DMatrix::<Complex64>::zeros(2 * number_modes, 2 * number_modes);
+        k.view_mut((0, 0), (number_modes, number_modes))
+            .copy_from(&(-&b));
+        k.view_mut((0, number_modes), (number_modes, number_modes))
+            .copy_from(&identity);
+        k.view_mut((number_modes, 0), (number_modes, number_modes))
+            .copy_from(&identity);
+        k.view_mut((number_modes, number_modes), (number_modes, number_modes))
+            .copy_from(&(-b.map(|x| x.conj())));
+        let j = DVector::from_iterator(
+            2 * number_modes,
+            beta.iter().copied().chain(beta.iter().map(|x| x.conj())),
+        );
+        let exponent = match k.try_inverse() {
+            Some(inverse) => (j.transpose() * inverse * &j)[(0, 0)].re,
+            None => {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: "Gaussian state cannot be converted to Fock space".to_string(),
+                })
+            }
+        };
+        let determinant = (&identity - b.map(|x| x.conj()) * &b).determinant().re;
+        fock.amplitudes[0] = Complex64::from((determinant.sqrt() * (-0.5 * exponent).exp()).sqrt());
+
+        // sqrt(n_k + 1) ψ(n + 1_k) = β_k ψ(n) + Σ_j B_kj sqrt(n_j) ψ(n - 1_j)
+        let strides = fock.strides.clone();
+        for index in 1..fock.amplitudes.len() {
+            let occupation = fock.occupation(index);
+            let mode = occupation.iter().position(|n| *n > 0).unwrap_or_default();
+            let previous = index - strides[mode];
+            let mut amplitude = beta[mode] * fock.amplitudes[previous];
+            for (other, n) in occupation.iter().enumerate() {
+                let n = if other == mode { n - 1 } else { *n };
+                if n > 0 {
+                    amplitude += b[(mode, other)]
+                        * (n as f64).sqrt()
+                        * fock.amplitudes[previous - strides[other]];
+                }
+            }
+            fock.amplitudes[index] = amplitude / (occupation[mode] as f64).sqrt();
+        }
+        Ok(fock)
+    }
+}
+
+/// State of bosonic modes in a Fock space truncated at `cutoff` photons per mode.
+///
+/// The amplitude of the Fock state |n_0, n_1, ...> is stored at the index Σ_k n_k * cutoff^k.
+/// Gates are applied as the matrix exponential of their generator restricted to the truncated space,
+/// which keeps the state normalized but is only accurate while the population near the cutoff is small.
+/// The gate conventions are the same as for [GaussianState].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FockState {
+    number_modes: usize,
+    cutoff: usize,
+    // The index offset cutoff^k of one photon in mode k
+    strides: Vec<usize>,
+    amplitudes: Vec<Complex64>,
+}
+
+impl FockState {
+    /// Creates the vacuum state of `number_modes` bosonic modes with `cutoff` Fock states per mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of bosonic modes of the state.
+    /// * `cutoff` - The number of Fock states of every mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The vacuum state.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The cutoff is zero or the Fock space is too large.
+    pub fn new(number_modes: usize, cutoff: usize) -> Result<Self, RoqoqoBackendError> {
+        if cutoff == 0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "The Fock space cutoff must be at least one".to_string(),
+            });
+        }
+        let dimension = u32::try_from(number_modes)
+            .ok()
+            .and_then(|exponent| cutoff.checked_pow(exponent))
+            .filter(|dimension| {
+                dimension
+                    .checked_mul(std::mem::size_of::<Complex64>())
+                    .is_some_and(|bytes| bytes <= isize::MAX as usize)
+            })
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "The Fock space of {number_modes} modes with cutoff {cutoff} is too large"
+                ),
+            })?;
+        // Every stride is at most the dimension and cannot overflow
+        let strides: Vec<usize> = (0..number_modes)
+            .map(|mode| cutoff.pow(mode as u32))
+            .collect();
+        let mut amplitudes = vec![Complex64::new(0.0, 0.0); dimension];
+        amplitudes[0] = Complex64::new(1.0, 0.0);
+        Ok(Self {
+            number_modes,
+            cutoff,
+            strides,
+            amplitudes,
+        })
+    }
+
+    /// Returns the number of bosonic modes of the state.
+    pub fn number_modes(&self) -> usize {
+        self.number_modes
+    }
+
+    /// Returns the number of Fock states of every mode.
+    pub fn cutoff(&self) -> usize {
+        self.cutoff
+    }
+
+    /// Returns the amplitudes of the state, the amplitude of |n_0, n_1, ...> is at the index Σ_k n_k * cutoff^k.
+    pub fn amplitudes(&self) -> &[Complex64] {
+        &self.amplitudes
+    }
+
+    /// Returns the norm of the state.
+    pub fn norm(&self) -> f64 {
+        self.amplitudes
+            .iter()
+            .map(|x| x.norm_sqr())
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Returns the probabilities to detect 0 to `cutoff - 1` photons in a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode of the photon number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<f64>)` - The photon number probabilities (not normalized when the norm of the state is not one).
+    /// * `Err(RoqoqoBackendError::GenericError)` - The mode is not part of the state.
+    pub fn photon_number_probabilities(&self, mode: usize) -> Result<Vec<f64>, RoqoqoBackendError> {
+        check_mode(mode, self.number_modes)?;
+        let mut probabilities = vec![0.0; self.cutoff];
+        for (index, amplitude) in self.amplitudes.iter().enumerate() {
+            probabilities[self.photons(index, mode)] += amplitude.norm_sqr();
+        }
+        Ok(probabilities)
+    }
+
+    /// Returns the mean photon number of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode of the photon number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The mean photon number.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The mode is not part of the state.
+    pub fn mean_photon_number(&self, mode: usize) -> Result<f64, RoqoqoBackendError> {
+        Ok(self
+            .photon_number_probabilities(mode)?
+            .iter()
+            .enumerate()
+            .map(|(n, p)| n as f64 * p)
+            .sum())
+    }
+
+    /// Applies a bosonic gate operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The gate operation that is applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate was applied.
+    /// * `Err(RoqoqoBackendError::OperationNotInBackend)` - The operation is not a bosonic gate.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The gate acts on a mode outside of the state.
+    /// * `Err(RoqoqoBackendError::CalculatorError)` - A parameter of the gate is symbolic.
+    pub fn apply_gate(&mut self, operation: &Operation) -> Result<(), RoqoqoBackendError> {
+        let (modes, unitary) = mode_gate_unitary(operation, &vec![self.cutoff; self.number_modes])?
+            .ok_or(RoqoqoBackendError::OperationNotInBackend {
+                backend: BACKEND_NAME,
+                hqslang: operation.hqslang(),
+            })?;
+        self.apply_unitary(&modes, &unitary);
+        Ok(())
+    }
+
+    /// Measures the photon number of a mode and collapses the state.
+    ///
+    /// The photon number is sampled from the normalized photon number probabilities of the mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The measured mode.
+    /// * `rng` - The random number generator used for the measurement outcome.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The measured photon number.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The mode is not part of the state or the state has no population.
+    pub fn measure<R: Rng>(
+        &mut self,
+        mode: usize,
+        rng: &mut R,
+    ) -> Result<usize, RoqoqoBackendError> {
+        let probabilities = self.photon_number_probabilities(mode)?;
+        let total: f64 = probabilities.iter().sum();
+        if total <= 0.0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "Fock state has no population below the cutoff".to_string(),
+            });
+        }
+        let mut sample = rng.random::<f64>() * total;
+        let mut result = probabilities.len() - 1;
+        for (n, p) in probabilities.iter().enumerate() {
+            if sample < *p {
+                result = n;
+                break;
+            }
+            sample -= p;
+        }
+        let normalization = probabilities[result].sqrt();
+        for index in 0..self.amplitudes.len() {
+            if self.photons(index, mode) == result {
+                self.amplitudes[index] /= normalization;
+            } else {
+                self.amplitudes[index] = Complex64::new(0.0, 0.0);
+            }
+        }
+        Ok(result)
+    }
+
+    // Returns the photon number of a mode in the Fock state at index
+    fn photons(&self, index: usize, mode: usize) -> usize {
+        (index / self.strides[mode]) % self.cutoff
+    }
+
+    // Returns the photon numbers of all modes in the Fock state at index
+    fn occupation(&self, index: usize) -> Vec<usize> {
+        (0..self.number_modes)
+            .map(|mode| self.photons(index, mode))
+            .collect()
+    }
+
+    // Applies a unitary acting on one or two modes, the local index of two modes is n_0 + cutoff * n_1
+    fn apply_unitary(&mut self, modes: &[usize], unitary: &DMatrix<Complex64>) {
+        let strides: Vec<usize> = modes.iter().map(|mode| self.strides[*mode]).collect();
+        let local_indices: Vec<usize> = (0..unitary.nrows())
+            .map(|local| {
+                strides
+                    .iter()
+                    .enumerate()
+                    .map(|(k, stride)| (local / self.strides[k]) % self.cutoff * stride)
+                    .sum()
+            })
+            .collect();
+        for base in 0..self.amplitudes.len() {
+            if modes.iter().any(|mode| self.photons(base, *mode) != 0) {
+                continue;
+            }
+            let local: Vec<Complex64> = local_indices
+                .iter()
+                .map(|offset| self.amplitudes[base + offset])
+                .collect();
+            for (row, offset) in local_indices.iter().enumerate() {
+                self.amplitudes[base + offset] = local
+                    .iter()
+                    .enumerate()
+                    .map(|(column, amplitude)| unitary[(row, column)] * amplitude)
+                    .sum();
+            }
+        }
+    }
+}
+
+/// Simulator backend for bosonic circuits.
+///
+/// The backend supports [crate::operations::Squeezing], [crate::operations::PhaseDisplacement],
+/// [crate::operations::PhaseShift], [crate::operations::BeamSplitter] and
+/// [crate::operations::PhotonDetection] as well as definitions, [crate::operations::PragmaSetNumberOfMeasurements],
+/// conditional operations and loops.
+///
+/// Every circuit run starts with a [GaussianState] that is evolved exactly by the Gaussian gates.
+/// The first photon detection converts the state to a [FockState] truncated at `cutoff` photons per mode,
+/// all later operations are simulated in the truncated Fock space.
+/// Circuits with all detections at the end are therefore only affected by the truncation of the final state.
+///
+/// A photon detection writes the photon number into a float register or, for a bit register,
+/// whether at least one photon has been detected.
+///
+/// When a seed is set, every call of `run_circuit` uses a random number generator initialized with that seed,
+/// so results are reproducible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BosonicBackend {
+    /// The number of Fock states of every mode used for photon detection.
+    pub cutoff: usize,
+    /// Optional seed of the random number generator used for measurements.
+    pub seed: Option<u64>,
+}
+
+impl BosonicBackend {
+    /// Creates a new BosonicBackend.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff` - The number of Fock states of every mode used for photon detection.
+    /// * `seed` - Optional seed of the random number generator used for measurements.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new backend.
+    pub fn new(cutoff: usize, seed: Option<u64>) -> Self {
+        Self { cutoff, seed }
+    }
+}
+
+// State of the modes during a single circuit run
+#[derive(Debug, Clone)]
+enum ModeState {
+    Gaussian(GaussianState),
+    Fock(FockState),
+}
+
+// Classical state of a single circuit run
+#[derive(Debug, Default)]
+struct RunRegisters {
+    bits: HashMap<String, BitRegister>,
+    floats: HashMap<String, FloatRegister>,
+}
+
+impl EvaluatingBackend for BosonicBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+    ) -> RegisterResult {
+        let operations: Vec<&Operation> = circuit.collect();
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+        if self.cutoff == 0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "The Fock space cutoff must be at least one".to_string(),
+            });
+        }
+
+        let mut output_bits: HashMap<String, BitOutputRegister> = HashMap::new();
+        let mut output_floats: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let output_complex: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        let mut number_modes = 0;
+        let mut repetitions = 1;
+        for operation in operations.iter() {
+            match operation {
+                Operation::DefinitionBit(def) if *def.is_output() => {
+                    output_bits.insert(def.name().clone(), Vec::new());
+                }
+                Operation::DefinitionFloat(def) if *def.is_output() => {
+                    output_floats.insert(def.name().clone(), Vec::new());
+                }
+                Operation::PragmaSetNumberOfMeasurements(op) => {
+                    repetitions = repetitions.max(*op.number_measurements());
+                }
+                _ => (),
+            }
+            number_modes = number_modes.max(number_of_modes(operation));
+        }
+
+        for _ in 0..repetitions {
+            let mut state = ModeState::Gaussian(GaussianState::new(number_modes));
+            let mut registers = RunRegisters::default();
+            for operation in operations.iter() {
+                self.run_operation(operation, &mut state, &mut registers, &mut rng)?;
+            }
+            for (name, output) in output_bits.iter_mut() {
+                output.push(registers.bits.remove(name).unwrap_or_default());
+            }
+            for (name, output) in output_floats.iter_mut() {
+                output.push(registers.floats.remove(name).unwrap_or_default());
+            }
+        }
+        Ok((output_bits, output_floats, output_complex))
+    }
+}
+
+impl BosonicBackend {
+    // Applies a single operation of a circuit run to the state of the modes and the classical registers
+    fn run_operation(
+        &self,
+        operation: &Operation,
+        state: &mut ModeState,
+        registers: &mut RunRegisters,
+        rng: &mut StdRng,
+    ) -> Result<(), RoqoqoBackendError> {
+        match operation {
+            Operation::DefinitionBit(def) => {
+                registers
+                    .bits
+                    .insert(def.name().clone(), vec![false; *def.length()]);
+            }
+            Operation::DefinitionFloat(def) => {
+                registers
+                    .floats
+                    .insert(def.name().clone(), vec![0.0; *def.length()]);
+            }
+            Operation::InputBit(op) => {
+                *get_bit(&mut registers.bits, op.name(), *op.index())? = *op.value();
+            }
+            Operation::Squeezing(_)
+            | Operation::PhaseDisplacement(_)
+            | Operation::PhaseShift(_)
+            | Operation::BeamSplitter(_) => match state {
+                ModeState::Gaussian(gaussian) => gaussian.apply_gate(operation)?,
+                ModeState::Fock(fock) => fock.apply_gate(operation)?,
+            },
+            Operation::PhotonDetection(op) => {
+                if let ModeState::Gaussian(gaussian) = state {
+                    *state = ModeState::Fock(gaussian.to_fock(self.cutoff)?);
+                }
+                let photons = match state {
+                    ModeState::Fock(fock) => fock.measure(*op.mode(), rng)?,
+                    ModeState::Gaussian(_) => unreachable!(),
+                };
+                if let Some(register) = registers.floats.get_mut(op.readout()) {
+                    *register.get_mut(*op.readout_index()).ok_or_else(|| {
+                        RoqoqoBackendError::GenericError {
+                            msg: format!(
+                                "Index {} exceeds length of float register {}",
+                                op.readout_index(),
+                                op.readout()
+                            ),
+                        }
+                    })? = photons as f64;
+                } else {
+                    *get_bit(&mut registers.bits, op.readout(), *op.readout_index())? = photons > 0;
+                }
+            }
+            Operation::PragmaConditional(op) => {
+                if *get_bit(
+                    &mut registers.bits,
+                    op.condition_register(),
+                    *op.condition_index(),
+                )? {
+                    self.run_circuit_operations(op.circuit(), state, registers, rng)?;
+                }
+            }
+            Operation::PragmaIfElse(op) => {
+                let circuit = if op.condition().evaluate(&registers.bits)? {
+                    op.if_circuit()
+                } else {
+                    op.else_circuit()
+                };
+                self.run_circuit_operations(circuit, state, registers, rng)?;
+            }
+            Operation::PragmaWhileLoop(op) => {
+                let mut iterations = 0;
+                while iterations < *op.max_iterations()
+                    && op.condition().evaluate(&registers.bits)?
+                {
+                    self.run_circuit_operations(op.circuit(), state, registers, rng)?;
+                    iterations += 1;
+                }
+            }
+            Operation::PragmaSetBit(op) => {
+                let value = op.value().evaluate(&registers.bits)?;
+                *get_bit(&mut registers.bits, op.register(), *op.index())? = value;
+            }
+            Operation::PragmaLoop(op) => {
+                let repetitions = *op.repetitions().float()?;
+                for _ in 0..(repetitions.max(0.0).floor() as usize) {
+                    self.run_circuit_operations(op.circuit(), state, registers, rng)?;
+                }
+            }
+            Operation::PragmaAnnotatedOp(op) => {
+                self.run_operation(&op.operation, state, registers, rng)?
+            }
+            Operation::InputSymbolic(_)
+            | Operation::DefinitionUsize(_)
+            | Operation::DefinitionComplex(_)
+            | Operation::PragmaSetNumberOfMeasurements(_)
+            | Operation::PragmaStopParallelBlock(_)
+            | Operation::PragmaGlobalPhase(_)
+            | Operation::PragmaStartDecompositionBlock(_)
+            | Operation::PragmaStopDecompositionBlock(_) => (),
+            _ => {
+                return Err(RoqoqoBackendError::OperationNotInBackend {
+                    backend: BACKEND_NAME,
+                    hqslang: operation.hqslang(),
+                })
+            }
+        }
+        Ok(())
+    }
+
+    fn run_circuit_operations(
+        &self,
+        circuit: &Circuit,
+        state: &mut ModeState,
+        registers: &mut RunRegisters,
+        rng: &mut StdRng,
+    ) -> Result<(), RoqoqoBackendError> {
+        for operation in circuit.iter() {
+            self.run_operation(operation, state, registers, rng)?;
+        }
+        Ok(())
+    }
+}
+
+// Returns the number of modes an operation and the operations in its circuits act on
+pub(super) fn number_of_modes(operation: &Operation) -> usize {
+    let circuit_modes = |circuit: &Circuit| circuit.iter().map(number_of_modes).max().unwrap_or(0);
+    match operation {
+        Operation::Squeezing(op) => op.mode() + 1,
+        Operation::PhaseDisplacement(op) => op.mode() + 1,
+        Operation::PhaseShift(op) => op.mode() + 1,
+        Operation::BeamSplitter(op) => op.mode_0().max(op.mode_1()) + 1,
+        Operation::PhotonDetection(op) => op.mode() + 1,
+        Operation::QuantumRabi(op) => op.mode() + 1,
+        Operation::LongitudinalCoupling(op) => op.mode() + 1,
+        Operation::JaynesCummings(op) => op.mode() + 1,
+        Operation::SingleExcitationStore(op) => op.mode() + 1,
+        Operation::SingleExcitationLoad(op) => op.mode() + 1,
+        Operation::CZQubitResonator(op) => op.mode() + 1,
+        Operation::PragmaConditional(op) => circuit_modes(op.circuit()),
+        Operation::PragmaIfElse(op) => {
+            circuit_modes(op.if_circuit()).max(circuit_modes(op.else_circuit()))
+        }
+        Operation::PragmaWhileLoop(op) => circuit_modes(op.circuit()),
+        Operation::PragmaLoop(op) => circuit_modes(op.circuit()),
+        Operation::PragmaAnnotatedOp(op) => number_of_modes(&op.operation),
+        _ => 0,
+    }
+}
+
+// The modes a gate acts on and its unitary
+type ModeGate = (Vec<usize>, DMatrix<Complex64>);
+
+// Returns the modes and the truncated unitary of a bosonic gate, None if the operation is not a bosonic gate.
+// The local index of the two modes of a beam splitter is n_0 + cutoff_0 * n_1.
+pub(super) fn mode_gate_unitary(
+    operation: &Operation,
+    cutoffs: &[usize],
+) -> Result<Option<ModeGate>, RoqoqoBackendError> {
+    let modes: Vec<usize> = match operation {
+        Operation::Squeezing(op) => vec![*op.mode()],
+        Operation::PhaseDisplacement(op) => vec![*op.mode()],
+        Operation::PhaseShift(op) => vec![*op.mode()],
+        Operation::BeamSplitter(op) => vec![*op.mode_0(), *op.mode_1()],
+        _ => return Ok(None),
+    };
+    for mode in modes.iter() {
+        check_mode(*mode, cutoffs.len())?;
+    }
+    if modes.len() == 2 && modes[0] == modes[1] {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!("{} acts twice on mode {}", operation.hqslang(), modes[0]),
+        });
+    }
+    let cutoff = cutoffs[modes[0]];
+    let a = annihilation(cutoff);
+    let a_dag = a.adjoint();
+    let generator: DMatrix<Complex64> = match operation {
+        Operation::Squeezing(op) => {
+            let z = Complex64::from_polar(*op.squeezing().float()?, *op.phase().float()?);
+            (&a * &a * z.conj() - &a_dag * &a_dag * z) * Complex64::from(0.5)
+        }
+        Operation::PhaseDisplacement(op) => {
+            let alpha = Complex64::from_polar(*op.displacement().float()?, *op.phase().float()?);
+            &a_dag * alpha - &a * alpha.conj()
+        }
+        Operation::PhaseShift(op) => &a_dag * &a * Complex64::new(0.0, *op.phase().float()?),
+        Operation::BeamSplitter(op) => {
+            let theta = *op.theta().float()?;
+            let phase = Complex64::from_polar(1.0, *op.phi().float()?);
+            let (cutoff_0, cutoff_1) = (cutoff, cutoffs[modes[1]]);
+            let dimension = cutoff_0 * cutoff_1;
+            // The generator moves one photon between the modes
+            let generator = DMatrix::from_fn(dimension, dimension, |row, column| {
+                let (row_0, row_1) = (row % cutoff_0, row / cutoff_0);
+                let (column_0, column_1) = (column % cutoff_0, column / cutoff_0);
+                if row_0 + 1 == column_0 && row_1 == column_1 + 1 {
+                    phase * theta * ((column_0 * row_1) as f64).sqrt()
+                } else if row_0 == column_0 + 1 && row_1 + 1 == column_1 {
+                    -phase.conj() * theta * ((row_0 * column_1) as f64).sqrt()
+                } else {
+                    Complex64::new(0.0, 0.0)
+                }
+            });
+            // The beam splitter conserves the total photon number of both modes,
+            // so the exponential is calculated separately for every total photon number
+            let mut unitary = DMatrix::<Complex64>::zeros(dimension, dimension);
+            for total in 0..(cutoff_0 + cutoff_1 - 1) {
+                let block: Vec<usize> = (0..dimension)
+                    .filter(|local| local % cutoff_0 + local / cutoff_0 == total)
+                    .collect();
+                let exponential = generator.select_rows(&block).select_columns(&block).exp();
+                for (row, row_index) in block.iter().enumerate() {
+                    for (column, column_index) in block.iter().enumerate() {
+                        unitary[(*row_index, *column_index)] = exponential[(row, column)];
+                    }
+                }
+            }
+            return Ok(Some((modes, unitary)));
+        }
+        _ => unreachable!(),
+    };
+    Ok(Some((modes, generator.exp())))
+}
+
+// Returns the truncated annihilation operator
+pub(super) fn annihilation(cutoff: usize) -> DMatrix<Complex64> {
+    DMatrix::from_fn(cutoff, cutoff, |row, column| {
+        if column == row + 1 {
+            Complex64::from((column as f64).sqrt())
+        } else {
+            Complex64::new(0.0, 0.0)
+        }
+    })
+}
+
+pub(super) fn check_mode(mode: usize, number_modes: usize) -> Result<(), RoqoqoBackendError> {
+    if mode < number_modes {
+        Ok(())
+    } else {
+        Err(RoqoqoBackendError::GenericError {
+            msg: format!("Mode {mode} is not part of a state with {number_modes} modes"),
+        })
+    }
+}
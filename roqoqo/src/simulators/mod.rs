@@ -0,0 +1,32 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reference simulators for restricted classes of roqoqo circuits.
+//!
+//! General purpose simulators and hardware backends are provided by separate crates.
+//! The simulators in this module implement [crate::backends::EvaluatingBackend]
+//! for circuits that can be simulated efficiently without a full state vector,
+//! for example Clifford circuits, circuits of bosonic modes or small circuits of qubits coupled to
+//! truncated bosonic modes.
+//!
+
+#[cfg(feature = "unstable_analog_operations")]
+mod analog;
+#[cfg(feature = "unstable_analog_operations")]
+pub use analog::pauli_hamiltonian_matrix;
+mod bosonic;
+pub use bosonic::{BosonicBackend, FockState, GaussianState};
+mod qubit_resonator;
+pub use qubit_resonator::{QubitResonatorBackend, QubitResonatorState};
+mod stabilizer;
+pub(crate) use stabilizer::is_clifford_operation;
+pub use stabilizer::{CliffordTableau, StabilizerBackend};
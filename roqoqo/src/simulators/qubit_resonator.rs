@@ -0,0 +1,848 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(feature = "unstable_analog_operations")]
+use super::analog::local_hamiltonian_matrix;
+use super::bosonic::{annihilation, check_mode, mode_gate_unitary, number_of_modes};
+use super::stabilizer::get_bit;
+use crate::backends::{EvaluatingBackend, RegisterResult};
+use crate::operations::*;
+use crate::registers::{
+    BitOutputRegister, BitRegister, ComplexOutputRegister, FloatOutputRegister, FloatRegister,
+};
+use crate::{Circuit, RoqoqoBackendError};
+use nalgebra::DMatrix;
+use ndarray::Array2;
+use num_complex::Complex64;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+#[cfg(feature = "unstable_analog_operations")]
+use struqture::spins::PauliHamiltonian;
+#[cfg(feature = "unstable_analog_operations")]
+use struqture::{OperateOnDensityMatrix, SpinIndex};
+
+const BACKEND_NAME: &str = "QubitResonatorBackend";
+
+// Populations below this threshold are treated as zero when checking the preconditions of operations
+const POPULATION_TOLERANCE: f64 = 1e-10;
+
+/// State vector of qubits coupled to bosonic modes truncated in the Fock basis.
+///
+/// Every mode has its own cutoff, a mode with cutoff c is restricted to the photon numbers 0 to c - 1.
+/// The amplitude of the basis state with qubit values q_k and photon numbers n_m is stored at the index
+/// Σ_k q_k 2^k + 2^(number of qubits) Σ_m n_m Π_(j<m) c_j.
+///
+/// The spin-boson gates act as
+/// * QuantumRabi: exp(-i θ X (b† + b))
+/// * LongitudinalCoupling: exp(-i θ Z (b† + b))
+/// * JaynesCummings: exp(-i θ (σ⁻ b† + σ⁺ b)) with σ⁻ = |0⟩⟨1|
+/// * SingleExcitationStore: |1⟩_Q |0⟩_B -> |0⟩_Q |1⟩_B, the mode has to be in the vacuum state
+/// * SingleExcitationLoad: |0⟩_Q |1⟩_B -> |1⟩_Q |0⟩_B, the mode has to be in the subspace of zero and one photons
+///   and can only contain a photon when the qubit is in |0⟩
+/// * CZQubitResonator: a phase of -1 for |1⟩_Q |1⟩_B
+///
+/// where the creation and annihilation operators are truncated at the cutoff of the mode.
+/// Bosonic gates follow the conventions of [super::FockState].
+///
+/// With the `unstable_analog_operations` feature, [crate::operations::ApplyConstantPauliHamiltonian]
+/// is applied exactly as exp(-i H t) with the dense matrix of the Hamiltonian on the qubits it acts on,
+/// and [crate::operations::ApplyTimeDependentPauliHamiltonian] as the product of the exact evolutions of its
+/// [crate::operations::ApplyTimeDependentPauliHamiltonian::piecewise_constant] segments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QubitResonatorState {
+    number_qubits: usize,
+    cutoffs: Vec<usize>,
+    amplitudes: Vec<Complex64>,
+}
+
+impl QubitResonatorState {
+    /// Creates a new QubitResonatorState with all qubits in |0⟩ and all modes in the vacuum state.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_qubits` - The number of qubits.
+    /// * `cutoffs` - The Fock space cutoff of every mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new state.
+    /// * `Err(RoqoqoBackendError::GenericError)` - A cutoff is zero.
+    pub fn new(number_qubits: usize, cutoffs: &[usize]) -> Result<Self, RoqoqoBackendError> {
+        if let Some(mode) = cutoffs.iter().position(|cutoff| *cutoff == 0) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("The Fock space cutoff of mode {mode} must be at least one"),
+            });
+        }
+        let dimension = (1 << number_qubits) * cutoffs.iter().product::<usize>();
+        let mut amplitudes = vec![Complex64::new(0.0, 0.0); dimension];
+        amplitudes[0] = Complex64::new(1.0, 0.0);
+        Ok(Self {
+            number_qubits,
+            cutoffs: cutoffs.to_vec(),
+            amplitudes,
+        })
+    }
+
+    /// Returns the number of qubits.
+    pub fn number_qubits(&self) -> usize {
+        self.number_qubits
+    }
+
+    /// Returns the Fock space cutoffs of the modes.
+    pub fn cutoffs(&self) -> &[usize] {
+        &self.cutoffs
+    }
+
+    /// Returns the amplitudes of the state.
+    pub fn amplitudes(&self) -> &[Complex64] {
+        &self.amplitudes
+    }
+
+    /// Returns the norm of the state.
+    pub fn norm(&self) -> f64 {
+        self.amplitudes
+            .iter()
+            .map(|amplitude| amplitude.norm_sqr())
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Returns the probability to find a qubit in the state |1⟩.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The probability of the qubit being excited.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The qubit is not part of the state.
+    pub fn excited_state_probability(&self, qubit: usize) -> Result<f64, RoqoqoBackendError> {
+        self.check_qubit(qubit)?;
+        Ok(self
+            .amplitudes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| (index >> qubit) & 1 == 1)
+            .map(|(_, amplitude)| amplitude.norm_sqr())
+            .sum())
+    }
+
+    /// Returns the probabilities of the photon numbers of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<f64>)` - The probability of every photon number below the cutoff of the mode.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The mode is not part of the state.
+    pub fn photon_number_probabilities(&self, mode: usize) -> Result<Vec<f64>, RoqoqoBackendError> {
+        check_mode(mode, self.cutoffs.len())?;
+        let mut probabilities = vec![0.0; self.cutoffs[mode]];
+        for (index, amplitude) in self.amplitudes.iter().enumerate() {
+            probabilities[self.photons(index, mode)] += amplitude.norm_sqr();
+        }
+        Ok(probabilities)
+    }
+
+    /// Returns the mean photon number of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The expectation value of the photon number operator.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The mode is not part of the state.
+    pub fn mean_photon_number(&self, mode: usize) -> Result<f64, RoqoqoBackendError> {
+        Ok(self
+            .photon_number_probabilities(mode)?
+            .iter()
+            .enumerate()
+            .map(|(n, p)| n as f64 * p)
+            .sum())
+    }
+
+    /// Applies a qubit gate, a spin-boson gate or a bosonic gate to the state.
+    ///
+    /// Qubit gates acting on more than two qubits are applied via their decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The gate operation that is applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate was applied.
+    /// * `Err(RoqoqoBackendError::OperationNotInBackend)` - The operation is not a supported gate.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The gate acts outside of the state or its precondition is violated.
+    /// * `Err(RoqoqoBackendError::CalculatorError)` - A parameter of the gate is symbolic.
+    pub fn apply_gate(&mut self, operation: &Operation) -> Result<(), RoqoqoBackendError> {
+        if let Some((modes, unitary)) = mode_gate_unitary(operation, &self.cutoffs)? {
+            // The local index of the bosonic gates has the first mode as the least significant digit
+            let subsystems: Vec<(usize, usize)> = modes
+                .iter()
+                .rev()
+                .map(|mode| self.mode_subsystem(*mode))
+                .collect();
+            self.apply_local_unitary(&subsystems, &unitary);
+            return Ok(());
+        }
+        match operation {
+            #[cfg(feature = "unstable_analog_operations")]
+            Operation::ApplyConstantPauliHamiltonian(op) => {
+                self.apply_pauli_evolution(&op.hamiltonian, *op.time.float()?)
+            }
+            #[cfg(feature = "unstable_analog_operations")]
+            Operation::ApplyTimeDependentPauliHamiltonian(op) => {
+                for segment in op.piecewise_constant()? {
+                    self.apply_pauli_evolution(&segment.hamiltonian, *segment.time.float()?)?;
+                }
+                Ok(())
+            }
+            Operation::QuantumRabi(op) => {
+                let pauli_x = [[0.0, 1.0], [1.0, 0.0]];
+                self.apply_coupling(*op.qubit(), *op.mode(), *op.theta().float()?, |cutoff| {
+                    let a = annihilation(cutoff);
+                    qubit_operator(pauli_x).kronecker(&(&a + a.adjoint()))
+                })
+            }
+            Operation::LongitudinalCoupling(op) => {
+                let pauli_z = [[1.0, 0.0], [0.0, -1.0]];
+                self.apply_coupling(*op.qubit(), *op.mode(), *op.theta().float()?, |cutoff| {
+                    let a = annihilation(cutoff);
+                    qubit_operator(pauli_z).kronecker(&(&a + a.adjoint()))
+                })
+            }
+            Operation::JaynesCummings(op) => {
+                let sigma_minus = [[0.0, 1.0], [0.0, 0.0]];
+                let sigma_plus = [[0.0, 0.0], [1.0, 0.0]];
+                self.apply_coupling(*op.qubit(), *op.mode(), *op.theta().float()?, |cutoff| {
+                    let a = annihilation(cutoff);
+                    qubit_operator(sigma_minus).kronecker(&a.adjoint())
+                        + qubit_operator(sigma_plus).kronecker(&a)
+                })
+            }
+            Operation::SingleExcitationStore(op) => {
+                let (qubit, mode) = (*op.qubit(), *op.mode());
+                self.check_qubit(qubit)?;
+                check_mode(mode, self.cutoffs.len())?;
+                if self.cutoffs[mode] < 2 {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "SingleExcitationStore needs a cutoff of at least two for mode {mode}"
+                        ),
+                    });
+                }
+                if self.population(|index| self.photons(index, mode) != 0) > POPULATION_TOLERANCE {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "SingleExcitationStore is only defined for mode {mode} in the vacuum state"
+                        ),
+                    });
+                }
+                let photon = self.mode_subsystem(mode).0;
+                for index in 0..self.amplitudes.len() {
+                    if (index >> qubit) & 1 == 1 && self.photons(index, mode) == 0 {
+                        self.amplitudes[index - (1 << qubit) + photon] = self.amplitudes[index];
+                        self.amplitudes[index] = Complex64::new(0.0, 0.0);
+                    }
+                }
+                Ok(())
+            }
+            Operation::SingleExcitationLoad(op) => {
+                let (qubit, mode) = (*op.qubit(), *op.mode());
+                self.check_qubit(qubit)?;
+                check_mode(mode, self.cutoffs.len())?;
+                let photon = self.mode_subsystem(mode).0;
+                let undefined = self.population(|index| {
+                    let photons = self.photons(index, mode);
+                    photons > 1 || (photons == 1 && (index >> qubit) & 1 == 1)
+                }) > POPULATION_TOLERANCE
+                    || (0..self.amplitudes.len()).any(|index| {
+                        (index >> qubit) & 1 == 0
+                            && self.photons(index, mode) == 1
+                            && self.amplitudes[index].norm_sqr() > POPULATION_TOLERANCE
+                            && self.amplitudes[index - photon + (1 << qubit)].norm_sqr()
+                                > POPULATION_TOLERANCE
+                    });
+                if undefined {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "SingleExcitationLoad is not defined for the state of qubit {qubit} and mode {mode}"
+                        ),
+                    });
+                }
+                for index in 0..self.amplitudes.len() {
+                    if (index >> qubit) & 1 == 0 && self.photons(index, mode) == 1 {
+                        let target = index - photon + (1 << qubit);
+                        let amplitude = self.amplitudes[index];
+                        self.amplitudes[target] += amplitude;
+                        self.amplitudes[index] = Complex64::new(0.0, 0.0);
+                    }
+                }
+                Ok(())
+            }
+            Operation::CZQubitResonator(op) => {
+                let (qubit, mode) = (*op.qubit(), *op.mode());
+                self.check_qubit(qubit)?;
+                check_mode(mode, self.cutoffs.len())?;
+                for index in 0..self.amplitudes.len() {
+                    if (index >> qubit) & 1 == 1 && self.photons(index, mode) == 1 {
+                        self.amplitudes[index] = -self.amplitudes[index];
+                    }
+                }
+                Ok(())
+            }
+            _ => self.apply_qubit_gate(operation),
+        }
+    }
+
+    /// Measures a qubit and collapses the state.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The measured qubit.
+    /// * `rng` - The random number generator used for the measurement outcome.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - The measured value of the qubit.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The qubit is not part of the state or the state has no population.
+    pub fn measure_qubit<R: Rng>(
+        &mut self,
+        qubit: usize,
+        rng: &mut R,
+    ) -> Result<bool, RoqoqoBackendError> {
+        let excited = self.excited_state_probability(qubit)?;
+        let total = self.norm().powi(2);
+        if total <= 0.0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "State has no population below the cutoffs".to_string(),
+            });
+        }
+        let result = rng.random::<f64>() * total < excited;
+        let normalization = if result { excited } else { total - excited }.sqrt();
+        for (index, amplitude) in self.amplitudes.iter_mut().enumerate() {
+            if ((index >> qubit) & 1 == 1) == result {
+                *amplitude /= normalization;
+            } else {
+                *amplitude = Complex64::new(0.0, 0.0);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Measures the photon number of a mode and collapses the state.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The measured mode.
+    /// * `rng` - The random number generator used for the measurement outcome.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The measured photon number.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The mode is not part of the state or the state has no population.
+    pub fn measure_mode<R: Rng>(
+        &mut self,
+        mode: usize,
+        rng: &mut R,
+    ) -> Result<usize, RoqoqoBackendError> {
+        let probabilities = self.photon_number_probabilities(mode)?;
+        let total: f64 = probabilities.iter().sum();
+        if total <= 0.0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "State has no population below the cutoffs".to_string(),
+            });
+        }
+        let mut sample = rng.random::<f64>() * total;
+        let mut result = probabilities.len() - 1;
+        for (n, p) in probabilities.iter().enumerate() {
+            if sample < *p {
+                result = n;
+                break;
+            }
+            sample -= p;
+        }
+        let normalization = probabilities[result].sqrt();
+        for index in 0..self.amplitudes.len() {
+            if self.photons(index, mode) == result {
+                self.amplitudes[index] /= normalization;
+            } else {
+                self.amplitudes[index] = Complex64::new(0.0, 0.0);
+            }
+        }
+        Ok(result)
+    }
+
+    // Applies a qubit gate, gates on more than two qubits are applied via their decomposition
+    fn apply_qubit_gate(&mut self, operation: &Operation) -> Result<(), RoqoqoBackendError> {
+        let (qubits, unitary) =
+            if let Ok(op) = SingleQubitGateOperation::try_from(operation.clone()) {
+                (vec![*op.qubit()], op.unitary_matrix()?)
+            } else if let Ok(op) = TwoQubitGateOperation::try_from(operation.clone()) {
+                (vec![*op.control(), *op.target()], op.unitary_matrix()?)
+            } else {
+                let circuit = if let Ok(op) = ThreeQubitGateOperation::try_from(operation.clone()) {
+                    op.circuit()
+                } else if let Ok(op) = FourQubitGateOperation::try_from(operation.clone()) {
+                    op.circuit()
+                } else if let Ok(op) = MultiQubitGateOperation::try_from(operation.clone()) {
+                    op.circuit()
+                } else {
+                    return Err(RoqoqoBackendError::OperationNotInBackend {
+                        backend: BACKEND_NAME,
+                        hqslang: operation.hqslang(),
+                    });
+                };
+                for inner in circuit.iter() {
+                    self.apply_gate(inner)?;
+                }
+                return Ok(());
+            };
+        for qubit in qubits.iter() {
+            self.check_qubit(*qubit)?;
+        }
+        if qubits.len() == 2 && qubits[0] == qubits[1] {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("{} acts twice on qubit {}", operation.hqslang(), qubits[0]),
+            });
+        }
+        // The first qubit of a gate is the most significant digit of the gate's unitary matrix
+        let subsystems: Vec<(usize, usize)> = qubits.iter().map(|qubit| (1 << qubit, 2)).collect();
+        self.apply_local_unitary(&subsystems, &to_dmatrix(&unitary));
+        Ok(())
+    }
+
+    // Applies exp(-i H t) for a Pauli Hamiltonian H on the qubits it acts on
+    #[cfg(feature = "unstable_analog_operations")]
+    fn apply_pauli_evolution(
+        &mut self,
+        hamiltonian: &PauliHamiltonian,
+        time: f64,
+    ) -> Result<(), RoqoqoBackendError> {
+        let mut qubits: Vec<usize> = hamiltonian
+            .keys()
+            .flat_map(|product| product.iter().map(|(qubit, _)| *qubit))
+            .collect();
+        qubits.sort_unstable();
+        qubits.dedup();
+        for qubit in qubits.iter() {
+            self.check_qubit(*qubit)?;
+        }
+        let generator = local_hamiltonian_matrix(hamiltonian, &qubits)?;
+        let unitary = (generator * Complex64::new(0.0, -time)).exp();
+        // The first qubit is the least significant digit of the local index
+        let subsystems: Vec<(usize, usize)> =
+            qubits.iter().rev().map(|qubit| (1 << qubit, 2)).collect();
+        self.apply_local_unitary(&subsystems, &unitary);
+        Ok(())
+    }
+
+    // Applies exp(-i θ H) for a generator H acting on a qubit and a mode with the local index q * cutoff + n
+    fn apply_coupling(
+        &mut self,
+        qubit: usize,
+        mode: usize,
+        theta: f64,
+        generator: impl Fn(usize) -> DMatrix<Complex64>,
+    ) -> Result<(), RoqoqoBackendError> {
+        self.check_qubit(qubit)?;
+        check_mode(mode, self.cutoffs.len())?;
+        let unitary = (generator(self.cutoffs[mode]) * Complex64::new(0.0, -theta)).exp();
+        let subsystems = [(1 << qubit, 2), self.mode_subsystem(mode)];
+        self.apply_local_unitary(&subsystems, &unitary);
+        Ok(())
+    }
+
+    // Applies a unitary to subsystems given by their stride and dimension,
+    // the first subsystem is the most significant digit of the local index
+    fn apply_local_unitary(&mut self, subsystems: &[(usize, usize)], unitary: &DMatrix<Complex64>) {
+        let offsets: Vec<usize> = (0..unitary.nrows())
+            .map(|local| {
+                let mut rest = local;
+                let mut offset = 0;
+                for (stride, dimension) in subsystems.iter().rev() {
+                    offset += (rest % dimension) * stride;
+                    rest /= dimension;
+                }
+                offset
+            })
+            .collect();
+        for base in 0..self.amplitudes.len() {
+            if subsystems
+                .iter()
+                .any(|(stride, dimension)| (base / stride) % dimension != 0)
+            {
+                continue;
+            }
+            let local: Vec<Complex64> = offsets
+                .iter()
+                .map(|offset| self.amplitudes[base + offset])
+                .collect();
+            for (row, offset) in offsets.iter().enumerate() {
+                self.amplitudes[base + offset] = local
+                    .iter()
+                    .enumerate()
+                    .map(|(column, amplitude)| unitary[(row, column)] * amplitude)
+                    .sum();
+            }
+        }
+    }
+
+    // Returns the stride and the cutoff of a mode
+    fn mode_subsystem(&self, mode: usize) -> (usize, usize) {
+        let stride = (1 << self.number_qubits) * self.cutoffs[..mode].iter().product::<usize>();
+        (stride, self.cutoffs[mode])
+    }
+
+    // Returns the photon number of a mode in the basis state at index
+    fn photons(&self, index: usize, mode: usize) -> usize {
+        let (stride, cutoff) = self.mode_subsystem(mode);
+        (index / stride) % cutoff
+    }
+
+    // Returns the total population of the basis states selected by the filter
+    fn population(&self, filter: impl Fn(usize) -> bool) -> f64 {
+        self.amplitudes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| filter(*index))
+            .map(|(_, amplitude)| amplitude.norm_sqr())
+            .sum()
+    }
+
+    fn check_qubit(&self, qubit: usize) -> Result<(), RoqoqoBackendError> {
+        if qubit < self.number_qubits {
+            Ok(())
+        } else {
+            Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Qubit {qubit} is not part of a state with {} qubits",
+                    self.number_qubits
+                ),
+            })
+        }
+    }
+}
+
+/// Simulator backend for circuits of qubits coupled to bosonic modes.
+///
+/// The backend simulates a [QubitResonatorState] and supports qubit gates,
+/// the spin-boson gates [crate::operations::QuantumRabi], [crate::operations::LongitudinalCoupling],
+/// [crate::operations::JaynesCummings], [crate::operations::SingleExcitationStore],
+/// [crate::operations::SingleExcitationLoad] and [crate::operations::CZQubitResonator],
+/// the bosonic gates [crate::operations::Squeezing], [crate::operations::PhaseDisplacement],
+/// [crate::operations::PhaseShift] and [crate::operations::BeamSplitter],
+/// the measurements [crate::operations::MeasureQubit], [crate::operations::PragmaRepeatedMeasurement]
+/// and [crate::operations::PhotonDetection] as well as definitions,
+/// [crate::operations::PragmaSetNumberOfMeasurements], conditional operations and loops.
+/// With the `unstable_analog_operations` feature, [crate::operations::ApplyConstantPauliHamiltonian]
+/// and [crate::operations::ApplyTimeDependentPauliHamiltonian] are evolved exactly,
+/// so small analog programs can be verified without Trotterization.
+///
+/// Every mode is truncated at `cutoff` photons unless a different cutoff has been set in `mode_cutoffs`.
+/// A photon detection writes the photon number into a float register or, for a bit register,
+/// whether at least one photon has been detected.
+///
+/// When a seed is set, every call of `run_circuit` uses a random number generator initialized with that seed,
+/// so results are reproducible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QubitResonatorBackend {
+    /// Fock space cutoff of the modes without an entry in `mode_cutoffs`.
+    pub cutoff: usize,
+    /// Fock space cutoffs of individual modes.
+    pub mode_cutoffs: HashMap<usize, usize>,
+    /// Optional seed of the random number generator used for measurements.
+    pub seed: Option<u64>,
+}
+
+impl QubitResonatorBackend {
+    /// Creates a new QubitResonatorBackend.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff` - The Fock space cutoff of every mode.
+    /// * `seed` - Optional seed of the random number generator used for measurements.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new backend.
+    pub fn new(cutoff: usize, seed: Option<u64>) -> Self {
+        Self {
+            cutoff,
+            mode_cutoffs: HashMap::new(),
+            seed,
+        }
+    }
+
+    /// Sets the Fock space cutoff of a single mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode.
+    /// * `cutoff` - The Fock space cutoff of the mode.
+    pub fn set_mode_cutoff(&mut self, mode: usize, cutoff: usize) {
+        self.mode_cutoffs.insert(mode, cutoff);
+    }
+}
+
+/// Classical state of a single circuit run.
+#[derive(Debug, Default)]
+struct RunRegisters {
+    bits: HashMap<String, BitRegister>,
+    floats: HashMap<String, FloatRegister>,
+    repeated: HashMap<String, BitOutputRegister>,
+}
+
+impl EvaluatingBackend for QubitResonatorBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+    ) -> RegisterResult {
+        let operations: Vec<&Operation> = circuit.collect();
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+
+        let mut register_lengths: HashMap<&str, usize> = HashMap::new();
+        let mut output_bits: HashMap<String, BitOutputRegister> = HashMap::new();
+        let mut output_floats: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let output_complex: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        let mut number_qubits = 0;
+        let mut number_modes = 0;
+        let mut repetitions = 1;
+        for operation in operations.iter() {
+            match operation {
+                Operation::DefinitionBit(def) => {
+                    register_lengths.insert(def.name(), *def.length());
+                    if *def.is_output() {
+                        output_bits.insert(def.name().clone(), Vec::new());
+                    }
+                }
+                Operation::DefinitionFloat(def) if *def.is_output() => {
+                    output_floats.insert(def.name().clone(), Vec::new());
+                }
+                Operation::PragmaSetNumberOfMeasurements(op) => {
+                    repetitions = repetitions.max(*op.number_measurements());
+                }
+                Operation::PragmaRepeatedMeasurement(op) => {
+                    number_qubits = number_qubits.max(match op.qubit_mapping() {
+                        Some(mapping) => mapping.keys().max().map(|q| q + 1).unwrap_or(0),
+                        None => register_lengths
+                            .get(op.readout().as_str())
+                            .copied()
+                            .unwrap_or(0),
+                    })
+                }
+                #[cfg(feature = "unstable_analog_operations")]
+                Operation::ApplyConstantPauliHamiltonian(op) => {
+                    number_qubits = number_qubits.max(op.spin()?.last().map_or(0, |q| q + 1));
+                }
+                #[cfg(feature = "unstable_analog_operations")]
+                Operation::ApplyTimeDependentPauliHamiltonian(op) => {
+                    number_qubits = number_qubits.max(op.spin()?.last().map_or(0, |q| q + 1));
+                }
+                _ => (),
+            }
+            if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+                number_qubits = number_qubits.max(qubits.into_iter().max().map_or(0, |q| q + 1));
+            }
+            number_modes = number_modes.max(number_of_modes(operation));
+        }
+        let cutoffs: Vec<usize> = (0..number_modes)
+            .map(|mode| *self.mode_cutoffs.get(&mode).unwrap_or(&self.cutoff))
+            .collect();
+        let initial_state = QubitResonatorState::new(number_qubits, &cutoffs)?;
+
+        for _ in 0..repetitions {
+            let mut state = initial_state.clone();
+            let mut registers = RunRegisters::default();
+            for operation in operations.iter() {
+                run_operation(operation, &mut state, &mut registers, &mut rng)?;
+            }
+            for (name, output) in output_bits.iter_mut() {
+                match registers.repeated.remove(name) {
+                    Some(mut rows) => output.append(&mut rows),
+                    None => output.push(registers.bits.remove(name).unwrap_or_default()),
+                }
+            }
+            for (name, output) in output_floats.iter_mut() {
+                output.push(registers.floats.remove(name).unwrap_or_default());
+            }
+        }
+        Ok((output_bits, output_floats, output_complex))
+    }
+}
+
+// Applies a single operation of a circuit run to the state and the classical registers
+fn run_operation(
+    operation: &Operation,
+    state: &mut QubitResonatorState,
+    registers: &mut RunRegisters,
+    rng: &mut StdRng,
+) -> Result<(), RoqoqoBackendError> {
+    match operation {
+        Operation::DefinitionBit(def) => {
+            registers
+                .bits
+                .insert(def.name().clone(), vec![false; *def.length()]);
+        }
+        Operation::DefinitionFloat(def) => {
+            registers
+                .floats
+                .insert(def.name().clone(), vec![0.0; *def.length()]);
+        }
+        Operation::InputBit(op) => {
+            *get_bit(&mut registers.bits, op.name(), *op.index())? = *op.value();
+        }
+        Operation::MeasureQubit(op) => {
+            let result = state.measure_qubit(*op.qubit(), rng)?;
+            *get_bit(&mut registers.bits, op.readout(), *op.readout_index())? = result;
+        }
+        Operation::PragmaRepeatedMeasurement(op) => {
+            let length = registers
+                .bits
+                .get(op.readout())
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!("Bit register {} has not been defined", op.readout()),
+                })?
+                .len();
+            let mapping: HashMap<usize, usize> = match op.qubit_mapping() {
+                Some(mapping) => mapping.clone(),
+                None => (0..length).map(|q| (q, q)).collect(),
+            };
+            if let Some(index) = mapping.values().find(|index| **index >= length) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Index {} exceeds length of bit register {}",
+                        index,
+                        op.readout()
+                    ),
+                });
+            }
+            let number_measurements = *op.number_measurements();
+            let mut rows: BitOutputRegister = Vec::with_capacity(number_measurements);
+            for measurement in 0..number_measurements {
+                // The last measurement collapses the state, all others are sampled from copies
+                let mut sample = if measurement + 1 == number_measurements {
+                    None
+                } else {
+                    Some(state.clone())
+                };
+                let mut row = vec![false; length];
+                for (qubit, index) in mapping.iter() {
+                    row[*index] = sample
+                        .as_mut()
+                        .unwrap_or(state)
+                        .measure_qubit(*qubit, rng)?;
+                }
+                rows.push(row);
+            }
+            registers
+                .repeated
+                .entry(op.readout().clone())
+                .or_default()
+                .extend(rows);
+        }
+        Operation::PhotonDetection(op) => {
+            let photons = state.measure_mode(*op.mode(), rng)?;
+            if let Some(register) = registers.floats.get_mut(op.readout()) {
+                *register.get_mut(*op.readout_index()).ok_or_else(|| {
+                    RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Index {} exceeds length of float register {}",
+                            op.readout_index(),
+                            op.readout()
+                        ),
+                    }
+                })? = photons as f64;
+            } else {
+                *get_bit(&mut registers.bits, op.readout(), *op.readout_index())? = photons > 0;
+            }
+        }
+        Operation::PragmaConditional(op) => {
+            if *get_bit(
+                &mut registers.bits,
+                op.condition_register(),
+                *op.condition_index(),
+            )? {
+                run_circuit_operations(op.circuit(), state, registers, rng)?;
+            }
+        }
+        Operation::PragmaIfElse(op) => {
+            let circuit = if op.condition().evaluate(&registers.bits)? {
+                op.if_circuit()
+            } else {
+                op.else_circuit()
+            };
+            run_circuit_operations(circuit, state, registers, rng)?;
+        }
+        Operation::PragmaWhileLoop(op) => {
+            let mut iterations = 0;
+            while iterations < *op.max_iterations() && op.condition().evaluate(&registers.bits)? {
+                run_circuit_operations(op.circuit(), state, registers, rng)?;
+                iterations += 1;
+            }
+        }
+        Operation::PragmaSetBit(op) => {
+            let value = op.value().evaluate(&registers.bits)?;
+            *get_bit(&mut registers.bits, op.register(), *op.index())? = value;
+        }
+        Operation::PragmaLoop(op) => {
+            let repetitions = *op.repetitions().float()?;
+            for _ in 0..(repetitions.max(0.0).floor() as usize) {
+                run_circuit_operations(op.circuit(), state, registers, rng)?;
+            }
+        }
+        Operation::PragmaAnnotatedOp(op) => run_operation(&op.operation, state, registers, rng)?,
+        Operation::InputSymbolic(_)
+        | Operation::DefinitionUsize(_)
+        | Operation::DefinitionComplex(_)
+        | Operation::PragmaSetNumberOfMeasurements(_)
+        | Operation::PragmaStopParallelBlock(_)
+        | Operation::PragmaGlobalPhase(_)
+        | Operation::PragmaStartDecompositionBlock(_)
+        | Operation::PragmaStopDecompositionBlock(_) => (),
+        _ => state.apply_gate(operation)?,
+    }
+    Ok(())
+}
+
+fn run_circuit_operations(
+    circuit: &Circuit,
+    state: &mut QubitResonatorState,
+    registers: &mut RunRegisters,
+    rng: &mut StdRng,
+) -> Result<(), RoqoqoBackendError> {
+    for operation in circuit.iter() {
+        run_operation(operation, state, registers, rng)?;
+    }
+    Ok(())
+}
+
+// Returns a real 2x2 qubit operator as a complex matrix
+fn qubit_operator(elements: [[f64; 2]; 2]) -> DMatrix<Complex64> {
+    DMatrix::from_fn(2, 2, |row, column| Complex64::from(elements[row][column]))
+}
+
+fn to_dmatrix(matrix: &Array2<Complex64>) -> DMatrix<Complex64> {
+    DMatrix::from_fn(matrix.nrows(), matrix.ncols(), |row, column| {
+        matrix[[row, column]]
+    })
+}
@@ -0,0 +1,850 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::backends::{EvaluatingBackend, RegisterResult};
+use crate::operations::*;
+use crate::registers::{
+    BitOutputRegister, BitRegister, ComplexOutputRegister, ComplexRegister, FloatOutputRegister,
+    FloatRegister,
+};
+use crate::{Circuit, RoqoqoBackendError};
+use ndarray::Array2;
+use num_complex::Complex64;
+use qoqo_calculator::Calculator;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+const BACKEND_NAME: &str = "StabilizerBackend";
+
+/// Pauli operator i^phase * X^x * Z^z acting on the qubits of a single gate.
+#[derive(Debug, Clone, PartialEq)]
+struct LocalPauli {
+    x: Vec<bool>,
+    z: Vec<bool>,
+    phase: u8,
+}
+
+impl LocalPauli {
+    fn identity(number_qubits: usize) -> Self {
+        Self {
+            x: vec![false; number_qubits],
+            z: vec![false; number_qubits],
+            phase: 0,
+        }
+    }
+
+    // (X^a Z^b)(X^c Z^d) = (-1)^(b c) X^(a+c) Z^(b+d) for every qubit
+    fn multiply(&mut self, other: &LocalPauli) {
+        let mut phase = self.phase + other.phase;
+        for (index, x) in self.x.iter_mut().enumerate() {
+            if self.z[index] && other.x[index] {
+                phase += 2;
+            }
+            *x ^= other.x[index];
+            self.z[index] ^= other.z[index];
+        }
+        self.phase = phase % 4;
+    }
+}
+
+/// Images of X and Z of every gate qubit under conjugation with the Clifford unitary.
+type CliffordImages = Vec<(LocalPauli, LocalPauli)>;
+
+/// A gate acting on a set of qubits represented by its action on Pauli operators.
+type CliffordAction = (Vec<usize>, CliffordImages);
+
+/// Stabilizer tableau of a Clifford state following Aaronson and Gottesman (PRA 70, 052328).
+///
+/// The tableau stores `number_qubits` destabilizer and `number_qubits` stabilizer generators
+/// as Pauli strings with a sign. Gates update the generators by conjugation,
+/// so every Clifford operation and every measurement costs polynomial time in the number of qubits.
+///
+/// Because the tableau uniquely identifies a Clifford unitary up to a global phase
+/// when starting from [CliffordTableau::new], tableaus can also be compared to identify Clifford gates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CliffordTableau {
+    number_qubits: usize,
+    x: Vec<Vec<bool>>,
+    z: Vec<Vec<bool>>,
+    r: Vec<bool>,
+}
+
+impl CliffordTableau {
+    /// Creates a new CliffordTableau for the state with all qubits in |0>.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_qubits` - The number of qubits of the state.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The tableau with destabilizers X_i and stabilizers Z_i.
+    pub fn new(number_qubits: usize) -> Self {
+        let mut x = vec![vec![false; number_qubits]; 2 * number_qubits];
+        let mut z = vec![vec![false; number_qubits]; 2 * number_qubits];
+        for qubit in 0..number_qubits {
+            x[qubit][qubit] = true;
+            z[qubit + number_qubits][qubit] = true;
+        }
+        Self {
+            number_qubits,
+            x,
+            z,
+            r: vec![false; 2 * number_qubits],
+        }
+    }
+
+    /// Returns the number of qubits of the tableau.
+    pub fn number_qubits(&self) -> usize {
+        self.number_qubits
+    }
+
+    /// Returns the stabilizer generators as signed Pauli strings.
+    ///
+    /// The i-th character after the sign corresponds to qubit i.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The stabilizer generators (for example `+XX` and `+ZZ` for a Bell state).
+    pub fn stabilizers(&self) -> Vec<String> {
+        (self.number_qubits..2 * self.number_qubits)
+            .map(|row| {
+                let mut pauli = String::from(if self.r[row] { "-" } else { "+" });
+                for qubit in 0..self.number_qubits {
+                    pauli.push(match (self.x[row][qubit], self.z[row][qubit]) {
+                        (false, false) => 'I',
+                        (true, false) => 'X',
+                        (true, true) => 'Y',
+                        (false, true) => 'Z',
+                    });
+                }
+                pauli
+            })
+            .collect()
+    }
+
+    /// Applies a Hadamard gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate was applied.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The qubit is outside of the tableau.
+    pub fn hadamard(&mut self, qubit: usize) -> Result<(), RoqoqoBackendError> {
+        self.check_qubit(qubit, "Hadamard")?;
+        for row in 0..2 * self.number_qubits {
+            self.r[row] ^= self.x[row][qubit] && self.z[row][qubit];
+            std::mem::swap(&mut self.x[row][qubit], &mut self.z[row][qubit]);
+        }
+        Ok(())
+    }
+
+    /// Applies an S gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate was applied.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The qubit is outside of the tableau.
+    pub fn s_gate(&mut self, qubit: usize) -> Result<(), RoqoqoBackendError> {
+        self.check_qubit(qubit, "SGate")?;
+        for row in 0..2 * self.number_qubits {
+            self.r[row] ^= self.x[row][qubit] && self.z[row][qubit];
+            self.z[row][qubit] ^= self.x[row][qubit];
+        }
+        Ok(())
+    }
+
+    /// Applies a CNOT gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `control` - The control qubit of the gate.
+    /// * `target` - The target qubit of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate was applied.
+    /// * `Err(RoqoqoBackendError::GenericError)` - A qubit is outside of the tableau.
+    pub fn cnot(&mut self, control: usize, target: usize) -> Result<(), RoqoqoBackendError> {
+        self.check_qubit(control, "CNOT")?;
+        self.check_qubit(target, "CNOT")?;
+        for row in 0..2 * self.number_qubits {
+            self.r[row] ^= self.x[row][control]
+                && self.z[row][target]
+                && !(self.x[row][target] ^ self.z[row][control]);
+            self.x[row][target] ^= self.x[row][control];
+            self.z[row][control] ^= self.z[row][target];
+        }
+        Ok(())
+    }
+
+    /// Applies a Clifford gate operation.
+    ///
+    /// Hadamard, SGate and CNOT are applied directly, all other gates are applied through the
+    /// action of their unitary matrix (or their decomposition for gates on more than two qubits) on Pauli operators.
+    /// Symbolic parameters that can be evaluated without free variables (like `"3.141592653589793 / 2"`) are supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The gate operation that is applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate was applied.
+    /// * `Err(RoqoqoBackendError::OperationNotInBackend)` - The operation is not a Clifford gate.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The gate acts on a qubit outside of the tableau.
+    pub fn apply_gate(&mut self, operation: &Operation) -> Result<(), RoqoqoBackendError> {
+        if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+            for qubit in qubits {
+                self.check_qubit(qubit, operation.hqslang())?;
+            }
+        }
+        match operation {
+            Operation::Hadamard(op) => self.hadamard(*op.qubit())?,
+            Operation::SGate(op) => self.s_gate(*op.qubit())?,
+            Operation::CNOT(op) => self.cnot(*op.control(), *op.target())?,
+            _ => {
+                for (qubits, images) in clifford_actions(operation)? {
+                    self.apply_images(&qubits, &images);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Measures a qubit in the Z basis and collapses the state.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The measured qubit.
+    /// * `rng` - The random number generator used for random measurement outcomes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - The measurement result (true for |1>).
+    /// * `Err(RoqoqoBackendError::GenericError)` - The qubit is outside of the tableau.
+    pub fn measure<R: Rng>(
+        &mut self,
+        qubit: usize,
+        rng: &mut R,
+    ) -> Result<bool, RoqoqoBackendError> {
+        self.check_qubit(qubit, "MeasureQubit")?;
+        let n = self.number_qubits;
+        Ok(match (n..2 * n).find(|row| self.x[*row][qubit]) {
+            Some(p) => {
+                for row in 0..2 * n {
+                    if row != p && self.x[row][qubit] {
+                        self.rowsum(row, p);
+                    }
+                }
+                self.x[p - n] = self.x[p].clone();
+                self.z[p - n] = self.z[p].clone();
+                self.r[p - n] = self.r[p];
+                self.x[p] = vec![false; n];
+                self.z[p] = vec![false; n];
+                self.z[p][qubit] = true;
+                let outcome: bool = rng.random();
+                self.r[p] = outcome;
+                outcome
+            }
+            None => {
+                let mut x = vec![false; n];
+                let mut z = vec![false; n];
+                let mut r = false;
+                for row in 0..n {
+                    if self.x[row][qubit] {
+                        let stabilizer = row + n;
+                        r = row_product_sign(
+                            (&self.x[stabilizer], &self.z[stabilizer], self.r[stabilizer]),
+                            (&x, &z, r),
+                        );
+                        for q in 0..n {
+                            x[q] ^= self.x[stabilizer][q];
+                            z[q] ^= self.z[stabilizer][q];
+                        }
+                    }
+                }
+                r
+            }
+        })
+    }
+
+    /// Resets a qubit to |0> by measuring it and flipping it when the result is |1>.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit that is reset.
+    /// * `rng` - The random number generator used for random measurement outcomes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The qubit was reset.
+    /// * `Err(RoqoqoBackendError::GenericError)` - The qubit is outside of the tableau.
+    pub fn reset<R: Rng>(&mut self, qubit: usize, rng: &mut R) -> Result<(), RoqoqoBackendError> {
+        if self.measure(qubit, rng)? {
+            self.hadamard(qubit)?;
+            self.s_gate(qubit)?;
+            self.s_gate(qubit)?;
+            self.hadamard(qubit)?;
+        }
+        Ok(())
+    }
+
+    // Returns an error when the qubit is not part of the tableau
+    fn check_qubit(&self, qubit: usize, hqslang: &str) -> Result<(), RoqoqoBackendError> {
+        if qubit >= self.number_qubits {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Qubit {} of {} exceeds number of qubits {} of the tableau",
+                    qubit, hqslang, self.number_qubits
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    // Replaces row h with the product of rows i and h
+    fn rowsum(&mut self, h: usize, i: usize) {
+        self.r[h] = row_product_sign(
+            (&self.x[i], &self.z[i], self.r[i]),
+            (&self.x[h], &self.z[h], self.r[h]),
+        );
+        for q in 0..self.number_qubits {
+            let (xi, zi) = (self.x[i][q], self.z[i][q]);
+            self.x[h][q] ^= xi;
+            self.z[h][q] ^= zi;
+        }
+    }
+
+    fn apply_images(&mut self, qubits: &[usize], images: &[(LocalPauli, LocalPauli)]) {
+        for row in 0..2 * self.number_qubits {
+            let mut product = LocalPauli::identity(qubits.len());
+            for (local, qubit) in qubits.iter().enumerate() {
+                let (x, z) = (self.x[row][*qubit], self.z[row][*qubit]);
+                // Y = i X Z
+                if x && z {
+                    product.phase += 1;
+                }
+                if x {
+                    product.multiply(&images[local].0);
+                }
+                if z {
+                    product.multiply(&images[local].1);
+                }
+            }
+            let mut phase = product.phase as usize;
+            for (local, qubit) in qubits.iter().enumerate() {
+                self.x[row][*qubit] = product.x[local];
+                self.z[row][*qubit] = product.z[local];
+                // X Z = -i Y
+                if product.x[local] && product.z[local] {
+                    phase += 3;
+                }
+            }
+            if phase % 4 == 2 {
+                self.r[row] = !self.r[row];
+            }
+        }
+    }
+}
+
+// Sign of the product of two signed Pauli strings (g function of Aaronson and Gottesman)
+fn row_product_sign(first: (&[bool], &[bool], bool), second: (&[bool], &[bool], bool)) -> bool {
+    let mut sum: i64 = 2 * first.2 as i64 + 2 * second.2 as i64;
+    for q in 0..first.0.len() {
+        let (x1, z1) = (first.0[q] as i64, first.1[q] as i64);
+        let (x2, z2) = (second.0[q] as i64, second.1[q] as i64);
+        sum += match (x1, z1) {
+            (0, 0) => 0,
+            (1, 1) => z2 - x2,
+            (1, 0) => z2 * (2 * x2 - 1),
+            _ => x2 * (1 - 2 * z2),
+        };
+    }
+    sum.rem_euclid(4) == 2
+}
+
+// Replaces symbolic parameters that do not depend on variables by their values
+fn resolve_parameters(operation: &Operation) -> Option<Operation> {
+    if operation.is_parametrized() {
+        operation.substitute_parameters(&Calculator::new()).ok()
+    } else {
+        Some(operation.clone())
+    }
+}
+
+// Returns the action of a Clifford gate on Pauli operators, decomposing gates on more than two qubits
+fn clifford_actions(operation: &Operation) -> Result<Vec<CliffordAction>, RoqoqoBackendError> {
+    let not_clifford = || RoqoqoBackendError::OperationNotInBackend {
+        backend: BACKEND_NAME,
+        hqslang: operation.hqslang(),
+    };
+    let resolved = resolve_parameters(operation).ok_or_else(not_clifford)?;
+    let (qubits, unitary) = if let Ok(op) = SingleQubitGateOperation::try_from(resolved.clone()) {
+        (vec![*op.qubit()], op.unitary_matrix()?)
+    } else if let Ok(op) = TwoQubitGateOperation::try_from(resolved.clone()) {
+        (vec![*op.control(), *op.target()], op.unitary_matrix()?)
+    } else {
+        let circuit = if let Ok(op) = ThreeQubitGateOperation::try_from(resolved.clone()) {
+            op.circuit()
+        } else if let Ok(op) = FourQubitGateOperation::try_from(resolved.clone()) {
+            op.circuit()
+        } else if let Ok(op) = MultiQubitGateOperation::try_from(resolved.clone()) {
+            op.circuit()
+        } else {
+            return Err(not_clifford());
+        };
+        let mut actions = Vec::new();
+        for op in circuit.iter() {
+            actions.extend(clifford_actions(op).map_err(|_| not_clifford())?);
+        }
+        return Ok(actions);
+    };
+    let images = clifford_images(&unitary).ok_or_else(not_clifford)?;
+    Ok(vec![(qubits, images)])
+}
+
+// Element (row, column) of X^x Z^z where bit j of x and z acts on the j-th most significant qubit
+fn pauli_element(x: usize, z: usize, row: usize, column: usize) -> f64 {
+    if row != column ^ x {
+        0.0
+    } else if (z & column).count_ones() % 2 == 0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+// Computes U X_j U^dagger and U Z_j U^dagger as Pauli operators, None if U is not a Clifford unitary
+fn clifford_images(unitary: &Array2<Complex64>) -> Option<CliffordImages> {
+    let dimension = unitary.nrows();
+    let number_qubits = dimension.trailing_zeros() as usize;
+    let adjoint = unitary.t().mapv(|c| c.conj());
+    let decompose = |x: usize, z: usize| -> Option<LocalPauli> {
+        let pauli = Array2::from_shape_fn((dimension, dimension), |(row, column)| {
+            Complex64::new(pauli_element(x, z, row, column), 0.0)
+        });
+        let conjugated = unitary.dot(&pauli).dot(&adjoint);
+        for image_x in 0..dimension {
+            for image_z in 0..dimension {
+                let mut overlap = Complex64::new(0.0, 0.0);
+                for column in 0..dimension {
+                    overlap += pauli_element(image_x, image_z, column ^ image_x, column)
+                        * conjugated[[column ^ image_x, column]];
+                }
+                overlap /= dimension as f64;
+                if (overlap.norm() - 1.0).abs() < 1e-6 {
+                    let phase = [
+                        Complex64::new(1.0, 0.0),
+                        Complex64::new(0.0, 1.0),
+                        Complex64::new(-1.0, 0.0),
+                        Complex64::new(0.0, -1.0),
+                    ]
+                    .iter()
+                    .position(|p| (p - overlap).norm() < 1e-6)?;
+                    let bits = |mask: usize| -> Vec<bool> {
+                        (0..number_qubits)
+                            .map(|j| (mask >> (number_qubits - 1 - j)) & 1 == 1)
+                            .collect()
+                    };
+                    return Some(LocalPauli {
+                        x: bits(image_x),
+                        z: bits(image_z),
+                        phase: phase as u8,
+                    });
+                }
+            }
+        }
+        None
+    };
+    (0..number_qubits)
+        .map(|j| {
+            let mask = 1 << (number_qubits - 1 - j);
+            Some((decompose(mask, 0)?, decompose(0, mask)?))
+        })
+        .collect()
+}
+
+/// Returns true when the operation can be simulated by a stabilizer simulator.
+///
+/// Clifford gates (also with symbolic parameters that evaluate to Clifford angles), definitions,
+/// measurements, active reset and pragmas without an effect on the quantum state are accepted.
+/// Loops and conditional operations are accepted when their circuits only contain such operations.
+pub(crate) fn is_clifford_operation(operation: &Operation) -> bool {
+    match operation {
+        Operation::PragmaLoop(op) => op.circuit().is_clifford(),
+        Operation::PragmaConditional(op) => op.circuit().is_clifford(),
+        Operation::PragmaIfElse(op) => {
+            op.if_circuit().is_clifford() && op.else_circuit().is_clifford()
+        }
+        Operation::PragmaWhileLoop(op) => op.circuit().is_clifford(),
+        Operation::PragmaSetBit(_)
+        | Operation::PragmaBitwise(_)
+        | Operation::PragmaParity(_)
+        | Operation::PragmaSetFloat(_) => true,
+        Operation::PragmaAnnotatedOp(op) => is_clifford_operation(&op.operation),
+        Operation::DefinitionBit(_)
+        | Operation::DefinitionFloat(_)
+        | Operation::DefinitionComplex(_)
+        | Operation::DefinitionUsize(_)
+        | Operation::InputSymbolic(_)
+        | Operation::InputBit(_)
+        | Operation::MeasureQubit(_)
+        | Operation::MeasureQubitAndReset(_)
+        | Operation::PragmaRepeatedMeasurement(_)
+        | Operation::PragmaSetNumberOfMeasurements(_)
+        | Operation::PragmaActiveReset(_)
+        | Operation::PragmaSleep(_)
+        | Operation::PragmaStopParallelBlock(_)
+        | Operation::PragmaGlobalPhase(_)
+        | Operation::PragmaStartDecompositionBlock(_)
+        | Operation::PragmaStopDecompositionBlock(_) => true,
+        _ => clifford_actions(operation).is_ok(),
+    }
+}
+
+/// Stabilizer simulator backend for Clifford circuits.
+///
+/// The backend simulates circuits with a [CliffordTableau] and supports Clifford gates,
+/// [crate::operations::MeasureQubit], [crate::operations::MeasureQubitAndReset],
+/// [crate::operations::PragmaRepeatedMeasurement],
+/// [crate::operations::PragmaActiveReset], [crate::operations::PragmaSetNumberOfMeasurements]
+/// as well as conditional operations and loops with Clifford bodies.
+/// [crate::Circuit::is_clifford] can be used to check whether a circuit can be run on the backend.
+///
+/// When a seed is set, every call of `run_circuit` uses a random number generator initialized with that seed,
+/// so results are reproducible.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StabilizerBackend {
+    /// Optional seed of the random number generator used for measurements.
+    pub seed: Option<u64>,
+}
+
+impl StabilizerBackend {
+    /// Creates a new StabilizerBackend.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Optional seed of the random number generator used for measurements.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new backend.
+    pub fn new(seed: Option<u64>) -> Self {
+        Self { seed }
+    }
+}
+
+/// Classical state of a single circuit run.
+#[derive(Debug, Default)]
+struct RunRegisters {
+    bits: HashMap<String, BitRegister>,
+    floats: HashMap<String, FloatRegister>,
+    complex: HashMap<String, ComplexRegister>,
+    repeated: HashMap<String, BitOutputRegister>,
+}
+
+impl EvaluatingBackend for StabilizerBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+    ) -> RegisterResult {
+        let operations: Vec<&Operation> = circuit.collect();
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+
+        let mut register_lengths: HashMap<&str, usize> = HashMap::new();
+        let mut output_bits: HashMap<String, BitOutputRegister> = HashMap::new();
+        let mut output_floats: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let mut output_complex: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        let mut number_qubits = 0;
+        let mut repetitions = 1;
+        for operation in operations.iter() {
+            match operation {
+                Operation::DefinitionBit(def) => {
+                    register_lengths.insert(def.name(), *def.length());
+                    if *def.is_output() {
+                        output_bits.insert(def.name().clone(), Vec::new());
+                    }
+                }
+                Operation::DefinitionFloat(def) if *def.is_output() => {
+                    output_floats.insert(def.name().clone(), Vec::new());
+                }
+                Operation::DefinitionComplex(def) if *def.is_output() => {
+                    output_complex.insert(def.name().clone(), Vec::new());
+                }
+                Operation::PragmaSetNumberOfMeasurements(op) => {
+                    repetitions = repetitions.max(*op.number_measurements());
+                }
+                _ => (),
+            }
+        }
+        for operation in operations.iter() {
+            number_qubits = number_qubits.max(number_of_qubits(operation, &register_lengths));
+        }
+
+        for _ in 0..repetitions {
+            let mut tableau = CliffordTableau::new(number_qubits);
+            let mut registers = RunRegisters::default();
+            for operation in operations.iter() {
+                run_operation(operation, &mut tableau, &mut registers, &mut rng)?;
+            }
+            for (name, output) in output_bits.iter_mut() {
+                match registers.repeated.remove(name) {
+                    Some(mut rows) => output.append(&mut rows),
+                    None => output.push(registers.bits.remove(name).unwrap_or_default()),
+                }
+            }
+            for (name, output) in output_floats.iter_mut() {
+                output.push(registers.floats.remove(name).unwrap_or_default());
+            }
+            for (name, output) in output_complex.iter_mut() {
+                output.push(registers.complex.remove(name).unwrap_or_default());
+            }
+        }
+        Ok((output_bits, output_floats, output_complex))
+    }
+}
+
+// Returns the number of qubits needed to run an operation, including the operations of nested circuits
+fn number_of_qubits(operation: &Operation, register_lengths: &HashMap<&str, usize>) -> usize {
+    let circuit_qubits = |circuit: &Circuit| {
+        circuit
+            .iter()
+            .map(|op| number_of_qubits(op, register_lengths))
+            .max()
+            .unwrap_or(0)
+    };
+    match operation {
+        Operation::PragmaRepeatedMeasurement(op) => match op.qubit_mapping() {
+            Some(mapping) => mapping.keys().max().map(|q| q + 1).unwrap_or(0),
+            None => register_lengths
+                .get(op.readout().as_str())
+                .copied()
+                .unwrap_or(0),
+        },
+        Operation::PragmaConditional(op) => circuit_qubits(op.circuit()),
+        Operation::PragmaIfElse(op) => {
+            circuit_qubits(op.if_circuit()).max(circuit_qubits(op.else_circuit()))
+        }
+        Operation::PragmaWhileLoop(op) => circuit_qubits(op.circuit()),
+        Operation::PragmaLoop(op) => circuit_qubits(op.circuit()),
+        Operation::PragmaAnnotatedOp(op) => number_of_qubits(&op.operation, register_lengths),
+        _ => match operation.involved_qubits() {
+            InvolvedQubits::Set(qubits) => qubits.into_iter().max().map_or(0, |q| q + 1),
+            _ => 0,
+        },
+    }
+}
+
+// Applies a single operation of a circuit run to the tableau and the classical registers
+fn run_operation(
+    operation: &Operation,
+    tableau: &mut CliffordTableau,
+    registers: &mut RunRegisters,
+    rng: &mut StdRng,
+) -> Result<(), RoqoqoBackendError> {
+    match operation {
+        Operation::DefinitionBit(def) => {
+            registers
+                .bits
+                .insert(def.name().clone(), vec![false; *def.length()]);
+        }
+        Operation::DefinitionFloat(def) => {
+            registers
+                .floats
+                .insert(def.name().clone(), vec![0.0; *def.length()]);
+        }
+        Operation::DefinitionComplex(def) => {
+            registers.complex.insert(
+                def.name().clone(),
+                vec![Complex64::new(0.0, 0.0); *def.length()],
+            );
+        }
+        Operation::InputBit(op) => {
+            *get_bit(&mut registers.bits, op.name(), *op.index())? = *op.value();
+        }
+        Operation::MeasureQubit(op) => {
+            let result = tableau.measure(*op.qubit(), rng)?;
+            *get_bit(&mut registers.bits, op.readout(), *op.readout_index())? = result;
+        }
+        Operation::MeasureQubitAndReset(op) => {
+            let result = tableau.measure(*op.qubit(), rng)?;
+            *get_bit(&mut registers.bits, op.readout(), *op.readout_index())? = result;
+            tableau.reset(*op.qubit(), rng)?;
+        }
+        Operation::PragmaRepeatedMeasurement(op) => {
+            let length = get_bit_register(&mut registers.bits, op.readout())?.len();
+            let mapping: HashMap<usize, usize> = match op.qubit_mapping() {
+                Some(mapping) => mapping.clone(),
+                None => (0..length).map(|q| (q, q)).collect(),
+            };
+            if let Some(index) = mapping.values().find(|index| **index >= length) {
+                return Err(index_error(op.readout(), *index));
+            }
+            let number_measurements = *op.number_measurements();
+            let mut rows: BitOutputRegister = Vec::with_capacity(number_measurements);
+            for measurement in 0..number_measurements {
+                // The last measurement collapses the state, all others are sampled from copies
+                let mut sample = if measurement + 1 == number_measurements {
+                    None
+                } else {
+                    Some(tableau.clone())
+                };
+                let mut row = vec![false; length];
+                for (qubit, index) in mapping.iter() {
+                    row[*index] = sample.as_mut().unwrap_or(tableau).measure(*qubit, rng)?;
+                }
+                rows.push(row);
+            }
+            registers
+                .repeated
+                .entry(op.readout().clone())
+                .or_default()
+                .extend(rows);
+        }
+        Operation::PragmaActiveReset(op) => tableau.reset(*op.qubit(), rng)?,
+        Operation::PragmaConditional(op) => {
+            if *get_bit(
+                &mut registers.bits,
+                op.condition_register(),
+                *op.condition_index(),
+            )? {
+                for inner in op.circuit().iter() {
+                    run_operation(inner, tableau, registers, rng)?;
+                }
+            }
+        }
+        Operation::PragmaIfElse(op) => {
+            let circuit = if op.condition().evaluate(&registers.bits)? {
+                op.if_circuit()
+            } else {
+                op.else_circuit()
+            };
+            for inner in circuit.iter() {
+                run_operation(inner, tableau, registers, rng)?;
+            }
+        }
+        Operation::PragmaWhileLoop(op) => {
+            let mut iterations = 0;
+            while iterations < *op.max_iterations() && op.condition().evaluate(&registers.bits)? {
+                for inner in op.circuit().iter() {
+                    run_operation(inner, tableau, registers, rng)?;
+                }
+                iterations += 1;
+            }
+        }
+        Operation::PragmaSetBit(op) => {
+            let value = op.value().evaluate(&registers.bits)?;
+            *get_bit(&mut registers.bits, op.register(), *op.index())? = value;
+        }
+        Operation::PragmaBitwise(op) => {
+            let values = op.evaluate(&registers.bits)?;
+            set_bit_range(registers, op.target_register(), *op.target_start(), values)?;
+        }
+        Operation::PragmaParity(op) => {
+            let value = op.evaluate(&registers.bits)?;
+            *get_bit(
+                &mut registers.bits,
+                op.target_register(),
+                *op.target_index(),
+            )? = value;
+        }
+        Operation::PragmaSetFloat(op) => {
+            let value = op.evaluate(&registers.bits, &registers.floats)?;
+            *registers
+                .floats
+                .get_mut(op.register())
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!("Float register {} has not been defined", op.register()),
+                })?
+                .get_mut(*op.index())
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Index {} exceeds length of float register {}",
+                        op.index(),
+                        op.register()
+                    ),
+                })? = value;
+        }
+        Operation::PragmaLoop(op) => {
+            let repetitions = *op.repetitions().float()?;
+            for _ in 0..(repetitions.max(0.0).floor() as usize) {
+                for inner in op.circuit().iter() {
+                    run_operation(inner, tableau, registers, rng)?;
+                }
+            }
+        }
+        Operation::PragmaAnnotatedOp(op) => run_operation(&op.operation, tableau, registers, rng)?,
+        Operation::InputSymbolic(_)
+        | Operation::DefinitionUsize(_)
+        | Operation::PragmaSetNumberOfMeasurements(_)
+        | Operation::PragmaSleep(_)
+        | Operation::PragmaStopParallelBlock(_)
+        | Operation::PragmaGlobalPhase(_)
+        | Operation::PragmaStartDecompositionBlock(_)
+        | Operation::PragmaStopDecompositionBlock(_) => (),
+        _ => tableau.apply_gate(operation)?,
+    }
+    Ok(())
+}
+
+fn get_bit_register<'a>(
+    bit_registers: &'a mut HashMap<String, BitRegister>,
+    name: &str,
+) -> Result<&'a mut BitRegister, RoqoqoBackendError> {
+    bit_registers
+        .get_mut(name)
+        .ok_or(RoqoqoBackendError::GenericError {
+            msg: format!("Bit register {name} has not been defined"),
+        })
+}
+
+// Returns the entry of a bit register or an error when the index is out of range
+pub(super) fn get_bit<'a>(
+    bit_registers: &'a mut HashMap<String, BitRegister>,
+    name: &str,
+    index: usize,
+) -> Result<&'a mut bool, RoqoqoBackendError> {
+    get_bit_register(bit_registers, name)?
+        .get_mut(index)
+        .ok_or_else(|| index_error(name, index))
+}
+
+// Writes consecutive entries of a bit register starting at `start`
+fn set_bit_range(
+    registers: &mut RunRegisters,
+    name: &str,
+    start: usize,
+    values: Vec<bool>,
+) -> Result<(), RoqoqoBackendError> {
+    for (offset, value) in values.into_iter().enumerate() {
+        *get_bit(&mut registers.bits, name, start + offset)? = value;
+    }
+    Ok(())
+}
+
+fn index_error(name: &str, index: usize) -> RoqoqoBackendError {
+    RoqoqoBackendError::GenericError {
+        msg: format!("Index {index} exceeds length of bit register {name}"),
+    }
+}
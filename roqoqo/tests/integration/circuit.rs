@@ -97,6 +97,361 @@ fn is_parametrized(operation: Operation, parametrized: bool) {
     }
 }
 
+/// Test symbols_in_parameter function
+#[test_case(CalculatorFloat::from(0.5), &[]; "float")]
+#[test_case(CalculatorFloat::from("theta"), &["theta"]; "symbol")]
+#[test_case(CalculatorFloat::from("2 * sin(theta_1) + phi / 1.5e-3"), &["theta_1", "phi"]; "expression")]
+#[test_case(CalculatorFloat::from("sqrt (x) + x"), &["x"]; "function with space")]
+#[test_case(CalculatorFloat::from("a = 3; a * b"), &["b"]; "assignment")]
+#[test_case(CalculatorFloat::from("2E+3 * t # comment with c"), &["t"]; "comment")]
+fn symbols_in_parameter(parameter: CalculatorFloat, expected: &[&str]) {
+    let expected: HashSet<String> = expected.iter().map(|s| s.to_string()).collect();
+    assert_eq!(
+        roqoqo::operations::symbols_in_parameter(&parameter),
+        expected
+    );
+}
+
+/// Test symbols and operations_by_symbol functions
+#[test]
+fn symbols() {
+    let mut inner = Circuit::new();
+    inner += RotateX::new(0, "theta".into());
+    let mut circuit = Circuit::new();
+    circuit += InputSymbolic::new("fixed".to_string(), 1.0);
+    circuit += RotateZ::new(0, "2 * theta + fixed".into());
+    circuit += PauliX::new(1);
+    circuit += PragmaLoop::new("repetitions".into(), inner);
+    circuit += RotateY::new(1, "cos(phi)".into());
+
+    let expected: HashSet<String> = ["theta", "repetitions", "phi"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(circuit.symbols().unwrap(), expected);
+    assert_eq!(
+        circuit.get(1).unwrap().symbols(),
+        ["theta".to_string(), "fixed".to_string()].into()
+    );
+
+    let operations_by_symbol = circuit.operations_by_symbol();
+    assert_eq!(operations_by_symbol.len(), 3);
+    assert_eq!(operations_by_symbol["theta"], vec![1, 3]);
+    assert_eq!(operations_by_symbol["repetitions"], vec![3]);
+    assert_eq!(operations_by_symbol["phi"], vec![4]);
+
+    assert!(Circuit::new().symbols().unwrap().is_empty());
+    assert!(Circuit::new().operations_by_symbol().is_empty());
+}
+
+/// Test that the free parameters of a GateDefinition are not free symbols of the Circuit
+#[cfg(feature = "unstable_operation_definition")]
+#[test]
+fn symbols_gate_definition() {
+    let mut body = Circuit::new();
+    body += RotateX::new(0, "angle * scale".into());
+    let mut circuit = Circuit::new();
+    circuit += GateDefinition::new(body, "rx".to_string(), vec![0], vec!["angle".to_string()]);
+    circuit += CallDefinedGate::new("rx".to_string(), vec![1], vec!["gamma".into()]);
+
+    let call = CallDefinedGate::new("rx".to_string(), vec![1], vec!["gamma".into()]);
+    assert!(call.is_parametrized());
+    assert_eq!(call.symbols(), ["gamma".to_string()].into());
+
+    let expected: HashSet<String> = ["scale", "gamma"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(circuit.symbols().unwrap(), expected);
+    let operations_by_symbol = circuit.operations_by_symbol();
+    assert_eq!(operations_by_symbol["scale"], vec![0]);
+    assert_eq!(operations_by_symbol["gamma"], vec![1]);
+}
+
+/// Test that parametrized operations whose symbols are all bound inside nested circuits are accepted
+#[test]
+fn symbols_bound_in_nested_circuit() {
+    let mut inner = Circuit::new();
+    inner += InputSymbolic::new("theta".to_string(), 0.5);
+    inner += RotateX::new(0, "theta".into());
+    let mut circuit = Circuit::new();
+    circuit += PragmaLoop::new(2.0.into(), inner);
+
+    assert!(circuit.is_parametrized());
+    assert!(circuit.get(0).unwrap().symbols().is_empty());
+    assert_eq!(circuit.symbols(), Ok(HashSet::new()));
+
+    #[cfg(feature = "unstable_operation_definition")]
+    {
+        let mut body = Circuit::new();
+        body += RotateX::new(0, "angle".into());
+        let mut circuit = Circuit::new();
+        circuit += GateDefinition::new(body, "rx".to_string(), vec![0], vec!["angle".to_string()]);
+        assert!(circuit.is_parametrized());
+        assert_eq!(circuit.symbols(), Ok(HashSet::new()));
+    }
+}
+
+/// Test bind_parameter function
+#[test_case(CalculatorFloat::from(0.5), CalculatorFloat::from(0.5); "float")]
+#[test_case(CalculatorFloat::from("theta"), CalculatorFloat::from("theta"); "unbound")]
+#[test_case(CalculatorFloat::from("2 * a + 1"), CalculatorFloat::from(2.0); "fully bound")]
+#[test_case(CalculatorFloat::from("a * theta"), CalculatorFloat::from("0.5 * theta"); "partially bound")]
+#[test_case(CalculatorFloat::from("theta / (2 * a)"), CalculatorFloat::from("theta / 1.0"); "bracket folded")]
+#[test_case(CalculatorFloat::from("sin(b) * cos(theta)"), CalculatorFloat::from(format!("({:?}) * cos(theta)", (-2.0_f64).sin())); "function folded")]
+#[test_case(CalculatorFloat::from("theta^b"), CalculatorFloat::from("theta^(-2.0)"); "negative value")]
+#[test_case(CalculatorFloat::from("atan2(theta, a)"), CalculatorFloat::from("atan2(theta, 0.5)"); "several arguments")]
+fn bind_parameter(parameter: CalculatorFloat, expected: CalculatorFloat) {
+    let values: HashMap<String, f64> = [("a".to_string(), 0.5), ("b".to_string(), -2.0)].into();
+    assert_eq!(
+        roqoqo::operations::bind_parameter(&parameter, &values).unwrap(),
+        expected
+    );
+}
+
+/// Test bind_parameter function with values that can not be bound
+#[test]
+fn bind_parameter_error() {
+    let values: HashMap<String, f64> = [("a".to_string(), f64::INFINITY)].into();
+    assert!(roqoqo::operations::bind_parameter(&CalculatorFloat::from("a * b"), &values).is_err());
+    let values: HashMap<String, f64> = [("a".to_string(), 0.0)].into();
+    assert!(roqoqo::operations::bind_parameter(&CalculatorFloat::from("1 / a"), &values).is_err());
+}
+
+/// Test bind_parameters function of Circuit
+#[test]
+fn bind_parameters() {
+    let mut inner = Circuit::new();
+    inner += RotateX::new(0, "calibration * theta".into());
+    let mut circuit = Circuit::new();
+    circuit += InputSymbolic::new("fixed".to_string(), 2.0);
+    circuit += RotateZ::new(0, "fixed * calibration".into());
+    circuit += PragmaLoop::new("repetitions".into(), inner);
+    circuit += PragmaGetStateVector::new(
+        "ro".to_string(),
+        Some(Circuit::new() + RotateY::new(0, "calibration".into())),
+    );
+
+    let values: HashMap<String, f64> = [("calibration".to_string(), 0.5)].into();
+    let bound = circuit.bind_parameters(&values).unwrap();
+
+    let mut inner = Circuit::new();
+    inner += RotateX::new(0, "0.5 * theta".into());
+    let mut expected = Circuit::new();
+    expected += InputSymbolic::new("fixed".to_string(), 2.0);
+    expected += RotateZ::new(0, 1.0.into());
+    expected += PragmaLoop::new("repetitions".into(), inner);
+    expected += PragmaGetStateVector::new(
+        "ro".to_string(),
+        Some(Circuit::new() + RotateY::new(0, 0.5.into())),
+    );
+    assert_eq!(bound, expected);
+    assert_eq!(
+        bound.symbols().unwrap(),
+        ["theta".to_string(), "repetitions".to_string()].into()
+    );
+
+    let values: HashMap<String, f64> =
+        [("theta".to_string(), 2.0), ("repetitions".to_string(), 3.0)].into();
+    let fully_bound = bound.bind_parameters(&values).unwrap();
+    assert!(!fully_bound.is_parametrized());
+    let mut calculator = Calculator::new();
+    calculator.set_variable("calibration", 0.5);
+    calculator.set_variable("theta", 2.0);
+    calculator.set_variable("repetitions", 3.0);
+    assert_eq!(
+        fully_bound,
+        circuit.substitute_parameters(&calculator).unwrap()
+    );
+}
+
+/// Test that bind_parameters does not bind the free parameters of a GateDefinition
+#[cfg(feature = "unstable_operation_definition")]
+#[test]
+fn bind_parameters_gate_definition() {
+    let mut body = Circuit::new();
+    body += RotateX::new(0, "angle * scale".into());
+    let mut circuit = Circuit::new();
+    circuit += GateDefinition::new(body, "rx".to_string(), vec![0], vec!["angle".to_string()]);
+    circuit += CallDefinedGate::new("rx".to_string(), vec![1], vec!["angle".into()]);
+
+    let values: HashMap<String, f64> =
+        [("angle".to_string(), 0.5), ("scale".to_string(), 2.0)].into();
+    let bound = circuit.bind_parameters(&values).unwrap();
+
+    let mut body = Circuit::new();
+    body += RotateX::new(0, "angle * 2.0".into());
+    let mut expected = Circuit::new();
+    expected += GateDefinition::new(body, "rx".to_string(), vec![0], vec!["angle".to_string()]);
+    expected += CallDefinedGate::new("rx".to_string(), vec![1], vec![0.5.into()]);
+    assert_eq!(bound, expected);
+}
+
+/// Test rename_parameter function
+#[test_case(CalculatorFloat::from(1.0), CalculatorFloat::from(1.0); "float")]
+#[test_case(CalculatorFloat::from("a * theta"), CalculatorFloat::from("alpha * theta"); "variable renamed")]
+#[test_case(CalculatorFloat::from("sin(a) + b"), CalculatorFloat::from("sin(alpha) + a"); "swapped names")]
+#[test_case(CalculatorFloat::from("a = 2; a * b"), CalculatorFloat::from("a = 2; a * a"); "assigned kept")]
+#[test_case(CalculatorFloat::from("sin(theta)"), CalculatorFloat::from("sin(theta)"); "function kept")]
+fn rename_parameter(parameter: CalculatorFloat, expected: CalculatorFloat) {
+    let mapping: HashMap<String, String> = [
+        ("a".to_string(), "alpha".to_string()),
+        ("b".to_string(), "a".to_string()),
+        ("sin".to_string(), "cos".to_string()),
+    ]
+    .into();
+    assert_eq!(
+        roqoqo::operations::rename_parameter(&parameter, &mapping).unwrap(),
+        expected
+    );
+}
+
+/// Test rename_parameter function replacing symbols by expressions in brackets
+#[test]
+fn rename_parameter_expression() {
+    let mapping: HashMap<String, String> = [
+        ("a".to_string(), "(2 * b)".to_string()),
+        ("b".to_string(), "(sin(a) + 1)".to_string()),
+    ]
+    .into();
+    assert_eq!(
+        roqoqo::operations::rename_parameter(&CalculatorFloat::from("a * b"), &mapping).unwrap(),
+        CalculatorFloat::from("(2 * b) * (sin(a) + 1)")
+    );
+
+    // The name set by an InputSymbolic cannot be replaced by an expression
+    let mut circuit = Circuit::new();
+    circuit += InputSymbolic::new("a".to_string(), 1.0);
+    assert!(circuit.rename_parameters(&mapping).is_err());
+}
+
+/// Test rename_parameter function with new names that are not valid symbol names
+#[test_case("1a"; "leading digit")]
+#[test_case("a b"; "whitespace")]
+#[test_case("a + b"; "expression")]
+#[test_case("(a) + (b)"; "several brackets")]
+#[test_case("()"; "empty brackets")]
+#[test_case("(a = 1)"; "assignment")]
+#[test_case(""; "empty")]
+fn rename_parameter_error(new_name: &str) {
+    let mapping: HashMap<String, String> = [("a".to_string(), new_name.to_string())].into();
+    assert!(
+        roqoqo::operations::rename_parameter(&CalculatorFloat::from("a * b"), &mapping).is_err()
+    );
+}
+
+/// Test rename_parameters function of Circuit
+#[test]
+fn rename_parameters() {
+    let mut circuit = Circuit::new();
+    circuit += InputSymbolic::new("fixed".to_string(), 2.0);
+    circuit += RotateZ::new(0, "fixed * theta".into());
+    circuit += PragmaLoop::new(
+        "repetitions".into(),
+        Circuit::new() + RotateX::new(0, "theta".into()),
+    );
+    circuit += PragmaConditional::new(
+        "ro".to_string(),
+        0,
+        Circuit::new() + RotateY::new(0, "phi".into()),
+    );
+    circuit += PragmaGetPauliProduct::new(
+        HashMap::from([(0, 3)]),
+        "ro".to_string(),
+        Circuit::new() + RotateX::new(0, "2 * theta".into()),
+    );
+
+    let mapping: HashMap<String, String> = [
+        ("theta".to_string(), "theta_1".to_string()),
+        ("fixed".to_string(), "fixed_1".to_string()),
+        ("repetitions".to_string(), "n".to_string()),
+    ]
+    .into();
+    let renamed = circuit.rename_parameters(&mapping).unwrap();
+
+    let mut expected = Circuit::new();
+    expected += InputSymbolic::new("fixed_1".to_string(), 2.0);
+    expected += RotateZ::new(0, "fixed_1 * theta_1".into());
+    expected += PragmaLoop::new(
+        "n".into(),
+        Circuit::new() + RotateX::new(0, "theta_1".into()),
+    );
+    expected += PragmaConditional::new(
+        "ro".to_string(),
+        0,
+        Circuit::new() + RotateY::new(0, "phi".into()),
+    );
+    expected += PragmaGetPauliProduct::new(
+        HashMap::from([(0, 3)]),
+        "ro".to_string(),
+        Circuit::new() + RotateX::new(0, "2 * theta_1".into()),
+    );
+    assert_eq!(renamed, expected);
+    assert_eq!(
+        renamed.symbols().unwrap(),
+        ["theta_1".to_string(), "n".to_string(), "phi".to_string()].into()
+    );
+
+    let mapping: HashMap<String, String> = [("theta".to_string(), "2theta".to_string())].into();
+    assert!(circuit.rename_parameters(&mapping).is_err());
+}
+
+/// Test that reparametrize decouples the symbols of combined Circuits
+#[test]
+fn reparametrize() {
+    let mut ansatz = Circuit::new();
+    ansatz += InputSymbolic::new("offset".to_string(), 0.5);
+    ansatz += RotateX::new(0, "theta_0 + offset".into());
+    ansatz += PragmaLoop::new(
+        "layers".into(),
+        Circuit::new() + RotateZ::new(0, "theta_1".into()),
+    );
+
+    let first = ansatz.reparametrize("a_").unwrap();
+    let second = ansatz.reparametrize("b_").unwrap();
+
+    let mut expected = Circuit::new();
+    expected += InputSymbolic::new("a_offset".to_string(), 0.5);
+    expected += RotateX::new(0, "a_theta_0 + a_offset".into());
+    expected += PragmaLoop::new(
+        "a_layers".into(),
+        Circuit::new() + RotateZ::new(0, "a_theta_1".into()),
+    );
+    assert_eq!(first, expected);
+
+    let combined = first + second;
+    assert_eq!(
+        combined.symbols().unwrap(),
+        [
+            "a_theta_0".to_string(),
+            "a_theta_1".to_string(),
+            "a_layers".to_string(),
+            "b_theta_0".to_string(),
+            "b_theta_1".to_string(),
+            "b_layers".to_string()
+        ]
+        .into()
+    );
+    assert!(ansatz.reparametrize("1").is_err());
+}
+
+/// Test that rename_parameters does not rename the free parameters of a GateDefinition
+#[cfg(feature = "unstable_operation_definition")]
+#[test]
+fn rename_parameters_gate_definition() {
+    let mut body = Circuit::new();
+    body += RotateX::new(0, "angle * scale".into());
+    let mut circuit = Circuit::new();
+    circuit += GateDefinition::new(body, "rx".to_string(), vec![0], vec!["angle".to_string()]);
+    circuit += CallDefinedGate::new("rx".to_string(), vec![1], vec!["angle".into()]);
+
+    let renamed = circuit.reparametrize("p_").unwrap();
+
+    let mut body = Circuit::new();
+    body += RotateX::new(0, "angle * p_scale".into());
+    let mut expected = Circuit::new();
+    expected += GateDefinition::new(body, "rx".to_string(), vec![0], vec!["angle".to_string()]);
+    expected += CallDefinedGate::new("rx".to_string(), vec![1], vec!["p_angle".into()]);
+    assert_eq!(renamed, expected);
+}
+
 /// Test len function
 #[test]
 fn length() {
@@ -320,7 +675,7 @@ fn as_vec() {
     circuit.add_operation(pauliz0.clone());
     circuit.add_operation(paulix1.clone());
 
-    let vec_ops = vec![
+    let vec_ops = [
         Operation::from(definition),
         Operation::from(rotatez0),
         Operation::from(rotatex1),
@@ -470,6 +825,114 @@ fn test_overrotate() {
     assert_ne!(t.theta(), &2.0.into());
 }
 
+/// Test drawing a circuit as an ASCII diagram
+#[test]
+fn test_draw_ascii() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += RotateZ::new(1, "theta".into());
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+
+    let expected = "q[0]:--[Hadamard]---*--------------------------\n                    |\nq[1]:--------------[X]--[RotateZ(theta)]--[M]--\n                                           |\nro  :======================================1===\n";
+    assert_eq!(circuit.draw_ascii(false), expected);
+    assert_eq!(Circuit::new().draw_ascii(false), "");
+}
+
+/// Test that packed diagrams draw independent operations in the same column
+#[cfg(feature = "circuitdag")]
+#[test]
+fn test_draw_ascii_packed() {
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(0);
+    circuit += PauliY::new(1);
+    circuit += ControlledPauliZ::new(1, 0);
+
+    let unpacked = "q[0]:--[PauliX]------------[PauliZ]--\n                               |\nq[1]:------------[PauliY]------*-----\n";
+    let packed =
+        "q[0]:--[PauliX]--[PauliZ]--\n                     |\nq[1]:--[PauliY]------*-----\n";
+    assert_eq!(circuit.draw_ascii(false), unpacked);
+    assert_eq!(circuit.draw_ascii(true), packed);
+}
+
+/// Test drawing the circuits of pragmas as groups
+#[test]
+fn test_draw_ascii_groups() {
+    let mut inner = Circuit::new();
+    inner += PauliX::new(1);
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += PragmaLoop::new(2.into(), inner.clone());
+    circuit += PragmaConditional::new("ro".to_string(), 0, inner.clone());
+    circuit += PragmaControlledCircuit::new(0, inner.clone());
+    circuit += PragmaIfElse::new(
+        BooleanExpression::Not(Box::new(BooleanExpression::Bit("ro".to_string(), 0))),
+        inner,
+        Circuit::new(),
+    );
+
+    let drawing = circuit.draw_ascii(false);
+    assert!(drawing.contains("+Loop(2)"));
+    assert!(drawing.contains("+If(ro[0])"));
+    assert!(drawing.contains("+Controlled(q[0])"));
+    assert!(drawing.contains("+If(!ro[0])"));
+    assert!(drawing.contains("+Else"));
+    assert_eq!(drawing.matches("[PauliX]").count(), 4);
+    assert!(drawing
+        .lines()
+        .all(|line| line.len() == line.trim_end().len()));
+}
+
+/// Test drawing operations with controls and measurements
+#[test]
+fn test_draw_ascii_controls_measurements() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += Toffoli::new(0, 1, 2);
+    circuit += ControlledSWAP::new(0, 1, 2);
+    circuit += ControlledPhaseShift::new(0, 1, 0.5.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+
+    let drawing = circuit.draw_ascii(false);
+    let lines: Vec<&str> = drawing.lines().collect();
+    assert_eq!(lines[0].matches('*').count(), 3);
+    assert!(lines[2].contains("[SWAP]"));
+    assert!(lines[2].contains("[PhaseShift(0.5)]"));
+    assert!(lines[4].contains("[X]"));
+    assert_eq!(lines[4].matches("[SWAP]").count(), 1);
+    assert_eq!(drawing.matches("[M]").count(), 3);
+    assert!(lines[6].starts_with("ro  :="));
+    assert!(lines[6].contains("all"));
+}
+
+/// Test drawing a circuit as an SVG image
+#[test]
+fn test_draw_svg() {
+    let mut inner = Circuit::new();
+    inner += RotateX::new(0, "a<b".into());
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += CNOT::new(0, 1);
+    circuit += PragmaLoop::new(2.into(), inner);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+
+    let svg = circuit.draw_svg(false);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    // Two qubit wires and a doubled classical wire
+    assert!(svg.contains(">q[1]</text>"));
+    assert!(svg.contains(">ro</text>"));
+    // The control of the CNOT and the measured entry of the register
+    assert_eq!(svg.matches("<circle").count(), 2);
+    assert!(svg.contains(">X</text>"));
+    assert!(svg.contains(">M</text>"));
+    // Escaped symbolic parameter and the group frame
+    assert!(svg.contains(">RotateX(a&lt;b)</text>"));
+    assert!(svg.contains("stroke-dasharray"));
+    assert!(svg.contains(">Loop(2)</text>"));
+}
+
 /// Test JsonSchema trait
 #[cfg(feature = "json_schema")]
 #[test]
@@ -10,7 +10,8 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashSet;
+use qoqo_calculator::CalculatorFloat;
+use std::collections::{HashMap, HashSet};
 
 use roqoqo::{
     operations::{self, *},
@@ -389,6 +390,137 @@ fn test_pragma_conditional(op_vec: Vec<Operation>) {
     assert!(!dag.last_operation_involving_qubit().is_empty());
 }
 
+#[test]
+fn test_control_flow_classical_dependencies() {
+    let mut dag: CircuitDag = CircuitDag::with_capacity(DEFAULT_NODE_NUMBER, DEFAULT_EDGE_NUMBER);
+
+    let a = dag
+        .add_to_back(Operation::from(MeasureQubit::new(0, "ro".to_string(), 0)))
+        .unwrap();
+    let b = dag
+        .add_to_back(Operation::from(MeasureQubit::new(1, "ro".to_string(), 1)))
+        .unwrap();
+    let c = dag
+        .add_to_back(Operation::from(PragmaSetBit::new(
+            "parity".to_string(),
+            0,
+            BooleanExpression::Xor(vec![
+                BooleanExpression::Bit("ro".to_string(), 0),
+                BooleanExpression::Bit("ro".to_string(), 1),
+            ]),
+        )))
+        .unwrap();
+    let mut correction = Circuit::new();
+    correction += PauliX::new(2);
+    let d = dag
+        .add_to_back(Operation::from(PragmaIfElse::new(
+            BooleanExpression::Bit("parity".to_string(), 0),
+            correction,
+            Circuit::new(),
+        )))
+        .unwrap();
+    let e = dag
+        .add_to_back(Operation::from(PragmaWhileLoop::new(
+            BooleanExpression::Not(Box::new(BooleanExpression::Bit("ro".to_string(), 0))),
+            Circuit::from_iter(vec![Operation::from(MeasureQubit::new(
+                3,
+                "ro".to_string(),
+                0,
+            ))]),
+            5,
+        )))
+        .unwrap();
+
+    assert_eq!(dag.execution_blocked(&[], &c), vec![a, b]);
+    assert_eq!(dag.execution_blocked(&[], &d), vec![a, b, c]);
+    assert!(dag.execution_blocked(&[a, b, c, d], &e).is_empty());
+    assert_eq!(dag.execution_blocked(&[b, c, d], &e), vec![a]);
+    let blocks: Vec<Vec<usize>> = dag.parallel_blocks().collect();
+    assert_eq!(blocks.concat().len(), 5);
+    assert_eq!(blocks[1], vec![c]);
+
+    // Adding the same operations to the front creates the same dependencies
+    let mut front_dag: CircuitDag =
+        CircuitDag::with_capacity(DEFAULT_NODE_NUMBER, DEFAULT_EDGE_NUMBER);
+    let operations: Vec<Operation> = [a, b, c, d]
+        .iter()
+        .map(|node| dag.get(*node).unwrap().clone())
+        .collect();
+    let nodes: Vec<usize> = operations
+        .into_iter()
+        .rev()
+        .map(|operation| front_dag.add_to_front(operation).unwrap())
+        .collect();
+    assert_eq!(
+        front_dag.execution_blocked(&[], &nodes[0]),
+        vec![nodes[1], nodes[2], nodes[3]]
+    );
+    assert_eq!(
+        dag.last_operation_involving_classical()
+            .get(&("parity".to_string(), 0)),
+        Some(&d)
+    );
+    assert_eq!(
+        dag.last_operation_involving_classical()
+            .get(&("ro".to_string(), 0)),
+        Some(&e)
+    );
+}
+
+#[test]
+fn test_classical_arithmetic_dependencies() {
+    let mut dag: CircuitDag = CircuitDag::with_capacity(DEFAULT_NODE_NUMBER, DEFAULT_EDGE_NUMBER);
+
+    let a = dag
+        .add_to_back(Operation::from(MeasureQubit::new(0, "ro".to_string(), 0)))
+        .unwrap();
+    let b = dag
+        .add_to_back(Operation::from(MeasureQubit::new(1, "ro".to_string(), 1)))
+        .unwrap();
+    let c = dag
+        .add_to_back(Operation::from(PragmaParity::new(
+            "ro".to_string(),
+            vec![0, 1],
+            "syndrome".to_string(),
+            0,
+        )))
+        .unwrap();
+    let d = dag
+        .add_to_back(Operation::from(PragmaBitwise::new(
+            BitwiseOperator::Or,
+            "syndrome".to_string(),
+            0,
+            "ro".to_string(),
+            2,
+            "flags".to_string(),
+            0,
+            1,
+        )))
+        .unwrap();
+    let e = dag
+        .add_to_back(Operation::from(PragmaSetFloat::new(
+            "angles".to_string(),
+            0,
+            CalculatorFloat::from("3 * x"),
+            HashMap::from([("x".to_string(), ("flags".to_string(), 0))]),
+        )))
+        .unwrap();
+    let f = dag
+        .add_to_back(Operation::from(MeasureQubit::new(2, "ro".to_string(), 2)))
+        .unwrap();
+
+    assert_eq!(dag.execution_blocked(&[], &c), vec![a, b]);
+    assert_eq!(dag.execution_blocked(&[a, b], &d), vec![c]);
+    assert_eq!(dag.execution_blocked(&[a, b, c], &e), vec![d]);
+    // The measurement writing ro[2] has to wait for the bitwise operation reading it
+    assert_eq!(dag.execution_blocked(&[a, b, c], &f), vec![d]);
+    assert_eq!(
+        dag.last_operation_involving_classical()
+            .get(&("angles".to_string(), 0)),
+        Some(&e)
+    );
+}
+
 #[test_case(Operation::from(PauliX::new(0)))]
 #[test_case(Operation::from(PauliZ::new(1)))]
 #[test_case(Operation::from(CNOT::new(0, 1)))]
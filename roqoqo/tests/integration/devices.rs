@@ -13,12 +13,28 @@
 #[cfg(feature = "json_schema")]
 use jsonschema::{Draft, Validator};
 use ndarray::array;
+#[cfg(feature = "unstable_analog_operations")]
+use qoqo_calculator::CalculatorFloat;
 use roqoqo::{
-    devices::{AllToAllDevice, Device, GenericDevice, SquareLatticeDevice},
+    devices::{
+        AllToAllDevice, BosonicDevice, Device, GenericBosonicDevice, GenericDevice,
+        SquareLatticeDevice,
+    },
     RoqoqoError,
 };
+#[cfg(feature = "unstable_analog_operations")]
+use roqoqo::{
+    devices::{AnalogDevice, GenericAnalogDevice},
+    operations::{ApplyConstantPauliHamiltonian, ApplyTimeDependentPauliHamiltonian, PauliX},
+};
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
+#[cfg(feature = "unstable_analog_operations")]
+use std::collections::HashMap;
+#[cfg(feature = "unstable_analog_operations")]
+use struqture::prelude::*;
+#[cfg(feature = "unstable_analog_operations")]
+use struqture::spins::{PauliHamiltonian, PauliProduct};
 // use test_case::test_case;
 
 #[test]
@@ -494,6 +510,322 @@ fn test_square_lattice() {
         .contains("The `change_device()` method has not been implemented."));
 }
 
+#[test]
+fn generic_bosonic_device_works() {
+    let mut device = GenericBosonicDevice::new(3);
+    assert_eq!(device.number_modes(), 3);
+    assert_eq!(device.two_mode_edges(), Vec::<(usize, usize)>::new());
+
+    device
+        .set_single_mode_gate_time("PhaseShift", 0, 0.5)
+        .unwrap();
+    device
+        .set_single_mode_gate_time("Squeezing", 2, 1.0)
+        .unwrap();
+    device
+        .set_two_mode_gate_time("BeamSplitter", 1, 0, 2.0)
+        .unwrap();
+    device
+        .set_two_mode_gate_time("BeamSplitter", 0, 1, 2.0)
+        .unwrap();
+    device
+        .set_two_mode_gate_time("BeamSplitter", 2, 1, 3.0)
+        .unwrap();
+    device.set_mode_loss_rate(1, 0.01).unwrap();
+    device.set_detector_efficiency(2, 0.9).unwrap();
+
+    assert_eq!(device.single_mode_gate_time("PhaseShift", &0), Some(0.5));
+    assert_eq!(device.single_mode_gate_time("PhaseShift", &1), None);
+    assert_eq!(device.single_mode_gate_time("Squeezing", &2), Some(1.0));
+    assert_eq!(device.two_mode_gate_time("BeamSplitter", &1, &0), Some(2.0));
+    assert_eq!(device.two_mode_gate_time("BeamSplitter", &1, &2), None);
+    assert_eq!(device.two_mode_gate_time("BeamSplitter", &2, &1), Some(3.0));
+    assert_eq!(device.mode_loss_rate(&1), Some(0.01));
+    assert_eq!(device.mode_loss_rate(&0), None);
+    assert_eq!(device.detector_efficiency(&2), Some(0.9));
+    assert_eq!(device.detector_efficiency(&1), None);
+    assert_eq!(device.two_mode_edges(), vec![(0, 1), (1, 2)]);
+
+    let mut single_mode_gate_names = device.single_mode_gate_names();
+    single_mode_gate_names.sort();
+    assert_eq!(
+        single_mode_gate_names,
+        vec!["PhaseShift".to_string(), "Squeezing".to_string()]
+    );
+    assert_eq!(
+        device.two_mode_gate_names(),
+        vec!["BeamSplitter".to_string()]
+    );
+    assert_eq!(device.to_generic_bosonic_device(), device);
+}
+
+#[test]
+fn generic_bosonic_device_errors() {
+    let mut device = GenericBosonicDevice::new(2);
+    assert_eq!(
+        device.set_single_mode_gate_time("PhaseShift", 2, 1.0),
+        Err(RoqoqoError::GenericError {
+            msg: "Mode 2 larger than number modes 2".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_two_mode_gate_time("BeamSplitter", 0, 3, 1.0),
+        Err(RoqoqoError::GenericError {
+            msg: "Mode 3 larger than number modes 2".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_two_mode_gate_time("BeamSplitter", 1, 1, 1.0),
+        Err(RoqoqoError::GenericError {
+            msg: "Two-mode gate BeamSplitter cannot act twice on mode 1".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_mode_loss_rate(0, -0.1),
+        Err(RoqoqoError::GenericError {
+            msg: "Loss rate -0.1 of mode 0 is negative".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_detector_efficiency(1, 1.5),
+        Err(RoqoqoError::GenericError {
+            msg: "Detector efficiency 1.5 of mode 1 is not between 0 and 1".to_string()
+        })
+    );
+    assert_eq!(device, GenericBosonicDevice::new(2));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn generic_bosonic_device_serde() {
+    let mut device = GenericBosonicDevice::new(2);
+    device
+        .set_single_mode_gate_time("PhaseShift", 0, 0.5)
+        .unwrap();
+    device
+        .set_two_mode_gate_time("BeamSplitter", 0, 1, 2.0)
+        .unwrap();
+    device.set_mode_loss_rate(1, 0.01).unwrap();
+    device.set_detector_efficiency(0, 0.9).unwrap();
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: GenericBosonicDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, device);
+
+    let encoded = bincode::serialize(&device).unwrap();
+    let decoded: GenericBosonicDevice = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, device);
+}
+
+#[cfg(feature = "unstable_analog_operations")]
+fn analog_device() -> GenericAnalogDevice {
+    let mut device = GenericAnalogDevice::new(3, 10.0, 0.5);
+    device.set_single_qubit_term(0, "X", -1.0, 1.0).unwrap();
+    device.set_single_qubit_term(1, "Z", 0.0, 2.0).unwrap();
+    device.set_two_qubit_term(1, 0, "XZ", -0.5, 0.5).unwrap();
+    device
+}
+
+#[cfg(feature = "unstable_analog_operations")]
+fn analog_hamiltonian(terms: &[(PauliProduct, CalculatorFloat)]) -> PauliHamiltonian {
+    let mut hamiltonian = PauliHamiltonian::new();
+    for (product, coefficient) in terms.iter() {
+        hamiltonian
+            .add_operator_product(product.clone(), coefficient.clone())
+            .unwrap();
+    }
+    hamiltonian
+}
+
+#[cfg(feature = "unstable_analog_operations")]
+#[test]
+fn generic_analog_device_works() {
+    let device = analog_device();
+    assert_eq!(device.number_qubits(), 3);
+    assert_eq!(device.max_time(), 10.0);
+    assert_eq!(device.time_resolution(), 0.5);
+    assert_eq!(device.single_qubit_term_bounds(&0, "X"), Some((-1.0, 1.0)));
+    assert_eq!(device.single_qubit_term_bounds(&0, "Z"), None);
+    assert_eq!(device.single_qubit_term_bounds(&2, "X"), None);
+    // Two-qubit terms are stored with the smaller qubit first
+    assert_eq!(
+        device.two_qubit_terms[&(0, 1)].get("ZX"),
+        Some(&(-0.5, 0.5))
+    );
+    assert_eq!(
+        device.two_qubit_term_bounds(&0, &1, "ZX"),
+        Some((-0.5, 0.5))
+    );
+    assert_eq!(
+        device.two_qubit_term_bounds(&1, &0, "XZ"),
+        Some((-0.5, 0.5))
+    );
+    assert_eq!(device.two_qubit_term_bounds(&0, &1, "XZ"), None);
+    assert_eq!(device.to_generic_analog_device(), device);
+}
+
+#[cfg(feature = "unstable_analog_operations")]
+#[test]
+fn generic_analog_device_errors() {
+    let mut device = GenericAnalogDevice::new(2, 1.0, 0.0);
+    assert_eq!(
+        device.set_single_qubit_term(2, "X", 0.0, 1.0),
+        Err(RoqoqoError::GenericError {
+            msg: "Qubit 2 larger than number qubits 2".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_single_qubit_term(0, "XX", 0.0, 1.0),
+        Err(RoqoqoError::GenericError {
+            msg: "Term XX does not consist of 1 Pauli operators X, Y or Z".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_two_qubit_term(0, 1, "XI", 0.0, 1.0),
+        Err(RoqoqoError::GenericError {
+            msg: "Term XI does not consist of 2 Pauli operators X, Y or Z".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_two_qubit_term(1, 1, "ZZ", 0.0, 1.0),
+        Err(RoqoqoError::GenericError {
+            msg: "Two-qubit term ZZ cannot act twice on qubit 1".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_two_qubit_term(0, 1, "ZZ", 1.0, 0.0),
+        Err(RoqoqoError::GenericError {
+            msg: "Lower bound 1 of term ZZ is larger than upper bound 0".to_string()
+        })
+    );
+    assert_eq!(device, GenericAnalogDevice::new(2, 1.0, 0.0));
+}
+
+#[cfg(feature = "unstable_analog_operations")]
+#[test]
+fn generic_analog_device_validate_constant() {
+    let device = analog_device();
+    let valid = analog_hamiltonian(&[
+        (PauliProduct::new(), 3.0.into()),
+        (PauliProduct::new().x(0), 0.5.into()),
+        (PauliProduct::new().z(0).x(1), (-0.5).into()),
+    ]);
+    assert!(device
+        .validate_analog_operation(
+            &ApplyConstantPauliHamiltonian::new(valid.clone(), 2.5.into()).into()
+        )
+        .is_ok());
+
+    let error = |msg: &str| {
+        Err(RoqoqoError::GenericError {
+            msg: msg.to_string(),
+        })
+    };
+    let cases: Vec<(PauliHamiltonian, CalculatorFloat, &str)> = vec![
+        (
+            valid.clone(),
+            10.5.into(),
+            "Evolution time 10.5 is not between 0 and the maximal time 10",
+        ),
+        (
+            valid.clone(),
+            1.25.into(),
+            "Evolution time 1.25 is not a multiple of the time resolution 0.5",
+        ),
+        (valid.clone(), "t".into(), "Evolution time t is symbolic"),
+        (
+            analog_hamiltonian(&[(PauliProduct::new().x(0), 1.5.into())]),
+            1.0.into(),
+            "Coefficient 1.5 of term 0X is not between -1 and 1",
+        ),
+        (
+            analog_hamiltonian(&[(PauliProduct::new().x(0), "a".into())]),
+            1.0.into(),
+            "Coefficient a of term 0X is symbolic",
+        ),
+        (
+            analog_hamiltonian(&[(PauliProduct::new().y(0), 0.5.into())]),
+            1.0.into(),
+            "Term 0Y is not supported by the device",
+        ),
+        (
+            analog_hamiltonian(&[(PauliProduct::new().z(0).z(1).z(2), 0.5.into())]),
+            1.0.into(),
+            "Term 0Z1Z2Z is not supported by the device",
+        ),
+        (
+            analog_hamiltonian(&[(PauliProduct::new().x(3), 0.5.into())]),
+            1.0.into(),
+            "Qubit 3 larger than number qubits 3",
+        ),
+    ];
+    for (hamiltonian, time, msg) in cases {
+        assert_eq!(
+            device.validate_analog_operation(
+                &ApplyConstantPauliHamiltonian::new(hamiltonian, time).into()
+            ),
+            error(msg)
+        );
+    }
+    assert_eq!(
+        device.validate_analog_operation(&PauliX::new(0).into()),
+        error("Operation PauliX is not an analog Pauli Hamiltonian evolution")
+    );
+}
+
+#[cfg(feature = "unstable_analog_operations")]
+#[test]
+fn generic_analog_device_validate_time_dependent() {
+    let device = analog_device();
+    let hamiltonian = analog_hamiltonian(&[
+        (PauliProduct::new().x(0), "omega".into()),
+        (PauliProduct::new().z(1), 1.0.into()),
+    ]);
+    let values = HashMap::from([("omega".to_string(), vec![0.0, 0.5, 1.0])]);
+    let operation =
+        ApplyTimeDependentPauliHamiltonian::new(hamiltonian.clone(), vec![0.5, 1.0, 1.5], values);
+    assert!(device.validate_analog_operation(&operation.into()).is_ok());
+
+    // Only the values at the start of the segments are applied
+    let values = HashMap::from([("omega".to_string(), vec![0.0, 2.0, 3.0])]);
+    let operation =
+        ApplyTimeDependentPauliHamiltonian::new(hamiltonian.clone(), vec![0.5, 1.0, 1.5], values);
+    assert_eq!(
+        device.validate_analog_operation(&operation.into()),
+        Err(RoqoqoError::GenericError {
+            msg: "Coefficient 2 of term 0X is not between -1 and 1".to_string()
+        })
+    );
+
+    let values = HashMap::from([("omega".to_string(), vec![0.0, 0.5])]);
+    let operation =
+        ApplyTimeDependentPauliHamiltonian::new(hamiltonian.clone(), vec![0.5, 0.75], values);
+    assert_eq!(
+        device.validate_analog_operation(&operation.into()),
+        Err(RoqoqoError::GenericError {
+            msg: "Evolution time 0.75 is not a multiple of the time resolution 0.5".to_string()
+        })
+    );
+
+    let operation =
+        ApplyTimeDependentPauliHamiltonian::new(hamiltonian, vec![0.5, 1.0], HashMap::new());
+    assert!(device.validate_analog_operation(&operation.into()).is_err());
+}
+
+#[cfg(all(feature = "unstable_analog_operations", feature = "serialize"))]
+#[test]
+fn generic_analog_device_serde() {
+    let device = analog_device();
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: GenericAnalogDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, device);
+
+    let encoded = bincode::serialize(&device).unwrap();
+    let decoded: GenericAnalogDevice = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, device);
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_json_schema() {
@@ -502,6 +834,11 @@ fn test_json_schema() {
         SquareLatticeDevice::new(2, 2, &["RotateX".to_string()], &["CNOT".to_string()], 0.1);
     let all_to_all_device =
         AllToAllDevice::new(3, &["RotateX".to_string()], &["CNOT".to_string()], 0.1);
+    let mut bosonic_device = GenericBosonicDevice::new(2);
+    bosonic_device
+        .set_two_mode_gate_time("BeamSplitter", 0, 1, 2.0)
+        .unwrap();
+    bosonic_device.set_mode_loss_rate(1, 0.01).unwrap();
 
     // Serialize
     let test_json_generic = serde_json::to_string(&generic_device).unwrap();
@@ -543,4 +880,25 @@ fn test_json_schema() {
     assert!(validation_result_generic.is_ok());
     assert!(validation_result_squared.is_ok());
     assert!(validation_result_all_to_all.is_ok());
+
+    let test_value_bosonic = serde_json::to_value(&bosonic_device).unwrap();
+    let schema_value_bosonic = serde_json::to_value(schema_for!(GenericBosonicDevice)).unwrap();
+    let compiled_schema_bosonic = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value_bosonic)
+        .unwrap();
+    assert!(compiled_schema_bosonic
+        .validate(&test_value_bosonic)
+        .is_ok());
+
+    #[cfg(feature = "unstable_analog_operations")]
+    {
+        let test_value_analog = serde_json::to_value(analog_device()).unwrap();
+        let schema_value_analog = serde_json::to_value(schema_for!(GenericAnalogDevice)).unwrap();
+        let compiled_schema_analog = Validator::options()
+            .with_draft(Draft::Draft7)
+            .build(&schema_value_analog)
+            .unwrap();
+        assert!(compiled_schema_analog.validate(&test_value_analog).is_ok());
+    }
 }
@@ -28,3 +28,9 @@ mod quantum_program;
 #[cfg(test)]
 #[cfg(feature = "circuitdag")]
 mod circuitdag;
+
+#[cfg(test)]
+mod passes;
+
+#[cfg(test)]
+mod simulators;
@@ -365,6 +365,126 @@ fn test_evaluate_symbolic(register: Vec<Vec<bool>>, constant: f64) {
     assert!((result.get("constant").unwrap() - constant).abs() < f64::EPSILON);
 }
 
+fn statistics_input(use_flipped_measurement: bool) -> PauliZProductInput {
+    let mut bri = PauliZProductInput::new(2, use_flipped_measurement);
+    let _ = bri.add_pauliz_product("ro".to_string(), vec![0]);
+    let _ = bri.add_pauliz_product("ro".to_string(), vec![1]);
+    let _ = bri.add_pauliz_product("ro".to_string(), vec![0, 1]);
+    let _ = bri.add_pauliz_product("ri".to_string(), vec![0]);
+    bri.add_linear_exp_val(
+        "single_readout".to_string(),
+        HashMap::from([(0, 1.0), (1, 1.0)]),
+    )
+    .unwrap();
+    bri.add_linear_exp_val(
+        "two_readouts".to_string(),
+        HashMap::from([(0, 1.0), (3, 1.0)]),
+    )
+    .unwrap();
+    bri.add_symbolic_exp_val(
+        "product".to_string(),
+        "pauli_product_0 * pauli_product_1".into(),
+    )
+    .unwrap();
+    bri
+}
+
+fn statistics_registers() -> HashMap<String, BitOutputRegister> {
+    HashMap::from([
+        (
+            "ro".to_string(),
+            vec![
+                vec![false, false],
+                vec![true, true],
+                vec![false, true],
+                vec![false, false],
+            ],
+        ),
+        (
+            "ri".to_string(),
+            vec![vec![true, false], vec![false, false]],
+        ),
+    ])
+}
+
+#[test]
+fn test_evaluate_statistics() {
+    let br = PauliZProduct {
+        constant_circuit: None,
+        circuits: vec![Circuit::new()],
+        input: statistics_input(false),
+    };
+    let statistics = br.evaluate_statistics(statistics_registers()).unwrap();
+    let expectation_values = br
+        .evaluate(statistics_registers(), HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap();
+    assert_eq!(statistics.expectation_values, expectation_values);
+
+    // Covariances within the same readout, zero between readouts
+    let covariances = &statistics.pauli_product_covariances;
+    assert!((covariances[(0, 0)] - 0.75).abs() < 1e-12);
+    assert!((covariances[(1, 1)] - 1.0).abs() < 1e-12);
+    assert!((covariances[(0, 1)] - 0.5).abs() < 1e-12);
+    assert!((covariances[(1, 0)] - 0.5).abs() < 1e-12);
+    assert!((covariances[(3, 3)] - 1.0).abs() < 1e-12);
+    assert_eq!(covariances[(0, 3)], 0.0);
+
+    // <H^2> - <H>^2 for H = Z0 + Z1
+    let variance = statistics
+        .shot_noise_variances
+        .get("single_readout")
+        .unwrap();
+    assert!((variance - 2.75).abs() < 1e-12);
+    let standard_error = statistics.standard_errors.get("single_readout").unwrap();
+    assert!((standard_error - (2.75_f64 / 3.0).sqrt()).abs() < 1e-12);
+
+    // Var(Z0) + Var(Z3), the covariance between the readouts is not included
+    let variance = statistics.shot_noise_variances.get("two_readouts").unwrap();
+    assert!((variance - 1.75).abs() < 1e-12);
+    let standard_error = statistics.standard_errors.get("two_readouts").unwrap();
+    assert!((standard_error - 1.25_f64.sqrt()).abs() < 1e-12);
+
+    // Linearized around <Z0> = 0.5 and <Z1> = 0
+    let variance = statistics.shot_noise_variances.get("product").unwrap();
+    assert!((variance - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn test_evaluate_statistics_flipped() {
+    let br = PauliZProduct {
+        constant_circuit: None,
+        circuits: vec![Circuit::new()],
+        input: statistics_input(true),
+    };
+    let mut registers = statistics_registers();
+    for name in ["ro", "ri"] {
+        let flipped: BitOutputRegister = registers
+            .get(name)
+            .unwrap()
+            .iter()
+            .map(|row| row.iter().map(|bit| !bit).collect())
+            .collect();
+        registers.insert(format!("{name}_flipped"), flipped);
+    }
+    let statistics = br.evaluate_statistics(registers.clone()).unwrap();
+    let expectation_values = br
+        .evaluate(registers.clone(), HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap();
+    assert_eq!(statistics.expectation_values, expectation_values);
+    let variance = statistics
+        .shot_noise_variances
+        .get("single_readout")
+        .unwrap();
+    assert!((variance - 2.75).abs() < 1e-12);
+    let standard_error = statistics.standard_errors.get("single_readout").unwrap();
+    assert!((standard_error - (2.75_f64 / 6.0).sqrt()).abs() < 1e-12);
+
+    registers.remove("ri_flipped");
+    assert!(br.evaluate_statistics(registers).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_json_schema() {
@@ -0,0 +1,322 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of circuit cutting measurement
+
+use std::collections::HashMap;
+
+#[cfg(feature = "json_schema")]
+use jsonschema::{Draft, Validator};
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::devices::GenericDevice;
+use roqoqo::measurements::{CircuitCutting, CutGateOperation, CutPreparation};
+use roqoqo::operations::*;
+use roqoqo::passes::cut_circuit;
+use roqoqo::prelude::*;
+use roqoqo::registers::BitOutputRegister;
+use roqoqo::simulators::{QubitResonatorBackend, StabilizerBackend};
+use roqoqo::Circuit;
+#[cfg(feature = "json_schema")]
+use schemars::schema_for;
+use std::f64::consts::PI;
+use test_case::test_case;
+
+// Prepares the GHZ state (|000> + |111>) / sqrt(2), which needs one gate cut on two qubits
+fn ghz_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += CNOT::new(1, 2);
+    circuit
+}
+
+// Prepares the state (|000> + i|101>) / sqrt(2), which needs one wire cut on two qubits
+fn iswap_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += ISwap::new(1, 2);
+    circuit
+}
+
+fn ghz_measurement() -> CircuitCutting {
+    cut_measurement(ghz_circuit())
+}
+
+fn cut_measurement(circuit: Circuit) -> CircuitCutting {
+    let cut = cut_circuit(&circuit, &GenericDevice::new(2)).unwrap();
+    let mut measurement = CircuitCutting::new(cut, "ro".to_string(), 2000);
+    for (name, qubits) in [
+        ("z0", vec![0]),
+        ("z0z2", vec![0, 2]),
+        ("z1z2", vec![2, 1]),
+        ("z0z1z2", vec![0, 1, 2]),
+    ] {
+        let index = measurement.add_pauliz_product(qubits).unwrap();
+        measurement
+            .add_linear_exp_val(name.to_string(), HashMap::from([(index, 1.0)]))
+            .unwrap();
+    }
+    measurement
+}
+
+// Runs every circuit with a different seed so that the variants are independent
+fn registers(measurement: &CircuitCutting) -> HashMap<String, BitOutputRegister> {
+    let mut registers = HashMap::new();
+    for (index, circuit) in measurement.circuits().enumerate() {
+        let (bits, _, _) = StabilizerBackend::new(Some(index as u64))
+            .run_circuit(circuit)
+            .unwrap();
+        registers.extend(bits);
+    }
+    registers
+}
+
+#[test]
+fn test_circuits_and_variants() {
+    let measurement = cut_measurement(iswap_circuit());
+    assert!(measurement.constant_circuit().is_none());
+    // The first fragment measures the cut in three bases, the second prepares six states
+    assert_eq!(measurement.circuits.len(), 9);
+    assert_eq!(measurement.variants.len(), 9);
+    assert_eq!(measurement.variants[1].fragment, 0);
+    assert_eq!(measurement.variants[1].bases, "Y".to_string());
+    assert_eq!(
+        measurement.variants[8].preparations,
+        vec![CutPreparation::MinusI]
+    );
+    for circuit in measurement.circuits() {
+        assert!(circuit.number_of_qubits() <= 2);
+        assert!(circuit.is_clifford());
+    }
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro_8".to_string(), 2, true);
+    expected += PauliX::new(1);
+    expected += Hadamard::new(1);
+    expected += SGate::new(1);
+    expected += ISwap::new(1, 0);
+    expected += MeasureQubit::new(0, "ro_8".to_string(), 0);
+    expected += MeasureQubit::new(1, "ro_8".to_string(), 1);
+    expected += PragmaSetNumberOfMeasurements::new(2000, "ro_8".to_string());
+    assert_eq!(measurement.circuits[8], expected);
+}
+
+#[test]
+fn test_gate_cut_circuits_and_variants() {
+    let measurement = ghz_measurement();
+    // Both fragments run the five operations on their side of the cut gate
+    assert_eq!(measurement.circuits.len(), 10);
+    assert_eq!(measurement.variants[2].fragment, 0);
+    assert_eq!(
+        measurement.variants[2].gate_operations,
+        vec![CutGateOperation::MeasureZ]
+    );
+    assert_eq!(measurement.variants[9].fragment, 1);
+    assert_eq!(
+        measurement.variants[9].gate_operations,
+        vec![CutGateOperation::InvSGate]
+    );
+    for circuit in measurement.circuits() {
+        assert!(circuit.number_of_qubits() <= 2);
+        assert!(circuit.is_clifford());
+    }
+    // The mid-circuit measurement is written behind the measured local qubits
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro_2".to_string(), 3, true);
+    expected += Hadamard::new(0);
+    expected += CNOT::new(0, 1);
+    expected += MeasureQubit::new(1, "ro_2".to_string(), 2);
+    expected += RotateZ::new(1, (PI / 2.0).into());
+    expected += MeasureQubit::new(0, "ro_2".to_string(), 0);
+    expected += MeasureQubit::new(1, "ro_2".to_string(), 1);
+    expected += PragmaSetNumberOfMeasurements::new(2000, "ro_2".to_string());
+    assert_eq!(measurement.circuits[2], expected);
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro_9".to_string(), 2, true);
+    expected += Hadamard::new(0);
+    expected += InvSGate::new(0);
+    expected += RotateZ::new(0, (PI / 2.0).into());
+    expected += Hadamard::new(0);
+    expected += MeasureQubit::new(0, "ro_9".to_string(), 0);
+    expected += PragmaSetNumberOfMeasurements::new(2000, "ro_9".to_string());
+    assert_eq!(measurement.circuits[9], expected);
+}
+
+#[test_case(ghz_circuit(), "z0", 0.0; "gate cut z0")]
+#[test_case(ghz_circuit(), "z0z2", 1.0; "gate cut z0z2")]
+#[test_case(ghz_circuit(), "z1z2", 1.0; "gate cut z1z2")]
+#[test_case(ghz_circuit(), "z0z1z2", 0.0; "gate cut z0z1z2")]
+#[test_case(iswap_circuit(), "z0", 0.0; "wire cut z0")]
+#[test_case(iswap_circuit(), "z0z2", 1.0; "wire cut z0z2")]
+#[test_case(iswap_circuit(), "z1z2", 0.0; "wire cut z1z2")]
+#[test_case(iswap_circuit(), "z0z1z2", 1.0; "wire cut z0z1z2")]
+fn test_recombined_expectation_values(circuit: Circuit, name: &str, expected: f64) {
+    let measurement = cut_measurement(circuit);
+    let result = measurement
+        .evaluate(registers(&measurement), HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap();
+    assert!((result[name] - expected).abs() < 0.15);
+}
+
+#[test]
+fn test_run_measurement_on_backend() {
+    // Without cuts the recombination reproduces the measured values exactly
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(1);
+    circuit += CNOT::new(1, 0);
+    let cut = cut_circuit(&circuit, &GenericDevice::new(2)).unwrap();
+    assert!(cut.cut_qubits.is_empty());
+    let mut measurement = CircuitCutting::new(cut, "ro".to_string(), 10);
+    let z0 = measurement.add_pauliz_product(vec![0]).unwrap();
+    let z3 = measurement.add_pauliz_product(vec![3]).unwrap();
+    measurement
+        .add_linear_exp_val("energy".to_string(), HashMap::from([(z0, 2.0), (z3, 0.5)]))
+        .unwrap();
+    let result = StabilizerBackend::new(Some(0))
+        .run_measurement(&measurement)
+        .unwrap()
+        .unwrap();
+    assert_eq!(result, HashMap::from([("energy".to_string(), -1.5)]));
+}
+
+#[test]
+fn test_errors() {
+    let mut measurement = ghz_measurement();
+    assert_eq!(measurement.add_pauliz_product(vec![2, 0]), Ok(1));
+    assert!(measurement.add_pauliz_product(vec![1, 1]).is_err());
+    assert!(measurement
+        .add_linear_exp_val("z0".to_string(), HashMap::from([(0, 1.0)]))
+        .is_err());
+    assert!(measurement
+        .add_linear_exp_val("new".to_string(), HashMap::from([(10, 1.0)]))
+        .is_err());
+    assert!(measurement
+        .evaluate(HashMap::new(), HashMap::new(), HashMap::new())
+        .is_err());
+}
+
+#[test_case(1, 2; "control in first fragment")]
+#[test_case(2, 1; "control in second fragment")]
+fn test_recombined_controlled_phase_shift(control: usize, target: usize) {
+    // The phase shift by π/2 leaves <X2> = <0|ρ1|0> = cos²(π/6), measured after a Hadamard
+    let mut circuit = Circuit::new();
+    circuit += RotateY::new(1, (PI / 3.0).into());
+    circuit += CNOT::new(1, 0);
+    circuit += Hadamard::new(2);
+    circuit += ControlledPhaseShift::new(control, target, (PI / 2.0).into());
+    circuit += Hadamard::new(2);
+    let cut = cut_circuit(&circuit, &GenericDevice::new(2)).unwrap();
+    assert!(cut.cut_qubits.is_empty());
+    assert_eq!(cut.cut_gates.len(), 1);
+    let mut measurement = CircuitCutting::new(cut, "ro".to_string(), 2000);
+    let index = measurement.add_pauliz_product(vec![2]).unwrap();
+    measurement
+        .add_linear_exp_val("x2".to_string(), HashMap::from([(index, 1.0)]))
+        .unwrap();
+    let mut registers = HashMap::new();
+    for (index, circuit) in measurement.circuits().enumerate() {
+        let (bits, _, _) = QubitResonatorBackend::new(1, Some(index as u64))
+            .run_circuit(circuit)
+            .unwrap();
+        registers.extend(bits);
+    }
+    let result = measurement
+        .evaluate(registers, HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap();
+    assert!((result["x2"] - 0.75).abs() < 0.15);
+}
+
+#[test]
+fn test_inconsistent_measurement() {
+    let measurement = cut_measurement(iswap_circuit());
+    let registers = registers(&measurement);
+
+    let mut missing_variant = measurement.clone();
+    missing_variant.variants.pop();
+    assert!(missing_variant
+        .pauliz_product_expectation_value(&registers, &[0, 1, 2])
+        .is_err());
+
+    let mut invalid_cut = measurement.clone();
+    invalid_cut.cut_circuit.fragments[0].output_cuts[0].0 = 5;
+    assert!(invalid_cut
+        .pauliz_product_expectation_value(&registers, &[0])
+        .is_err());
+
+    let gate_cut = ghz_measurement();
+    let gate_registers = self::registers(&gate_cut);
+    let mut invalid_gate_cut = gate_cut.clone();
+    invalid_gate_cut.cut_circuit.fragments[1].gate_cuts[0].cut = 1;
+    assert!(invalid_gate_cut
+        .pauliz_product_expectation_value(&gate_registers, &[0])
+        .is_err());
+    let mut invalid_side = gate_cut;
+    invalid_side.cut_circuit.fragments[1].gate_cuts[0].side = 2;
+    assert!(invalid_side
+        .pauliz_product_expectation_value(&gate_registers, &[0])
+        .is_err());
+
+    let mut invalid_product = measurement;
+    invalid_product
+        .linear_exp_vals
+        .insert("invalid".to_string(), HashMap::from([(10, 1.0)]));
+    assert!(invalid_product
+        .evaluate(registers, HashMap::new(), HashMap::new())
+        .is_err());
+}
+
+#[test]
+fn test_substitute_parameters() {
+    let mut circuit = ghz_circuit();
+    circuit += RotateZ::new(2, "theta".into());
+    let cut = cut_circuit(&circuit, &GenericDevice::new(2)).unwrap();
+    let measurement = CircuitCutting::new(cut, "ro".to_string(), 10);
+    let substituted = measurement
+        .substitute_parameters(HashMap::from([("theta".to_string(), 0.5)]))
+        .unwrap();
+    assert!(substituted
+        .circuits()
+        .all(|circuit| !circuit.is_parametrized()));
+    assert!(substituted
+        .cut_circuit
+        .fragments
+        .iter()
+        .all(|fragment| !fragment.circuit.is_parametrized()));
+    assert!(measurement.substitute_parameters(HashMap::new()).is_err());
+    assert_eq!(measurement.minimum_supported_roqoqo_version(), (1, 21, 0));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_serde() {
+    let measurement = ghz_measurement();
+    let serialized = serde_json::to_string(&measurement).unwrap();
+    let deserialized: CircuitCutting = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, measurement);
+}
+
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_json_schema() {
+    let measurement = ghz_measurement();
+    let test_value = serde_json::to_value(&measurement).unwrap();
+    let test_schema = schema_for!(CircuitCutting);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+    assert!(compiled_schema.validate(&test_value).is_ok());
+}
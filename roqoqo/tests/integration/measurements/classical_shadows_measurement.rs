@@ -0,0 +1,269 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of classical shadows measurement
+
+use std::collections::HashMap;
+
+#[cfg(feature = "json_schema")]
+use jsonschema::{Draft, Validator};
+use ndarray::{array, Array1};
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::measurements::ClassicalShadows;
+use roqoqo::operations::*;
+use roqoqo::prelude::*;
+use roqoqo::registers::BitOutputRegister;
+use roqoqo::simulators::StabilizerBackend;
+use roqoqo::Circuit;
+#[cfg(feature = "json_schema")]
+use schemars::schema_for;
+use test_case::test_case;
+
+// Prepares the Bell state (|00> + i|11>) / sqrt(2) on qubits 0 and 2
+fn bell_preparation() -> Circuit {
+    let mut preparation = Circuit::new();
+    preparation += Hadamard::new(2);
+    preparation += CNOT::new(2, 0);
+    preparation += SGate::new(0);
+    preparation
+}
+
+// Runs every circuit with a different seed so that the snapshots are independent
+fn bell_registers(measurement: &ClassicalShadows) -> HashMap<String, BitOutputRegister> {
+    let mut registers = HashMap::new();
+    for (index, circuit) in measurement.circuits().enumerate() {
+        let circuit = measurement.constant_circuit().clone().unwrap() + circuit;
+        let (bits, _, _) = StabilizerBackend::new(Some(index as u64))
+            .run_circuit(&circuit)
+            .unwrap();
+        registers.extend(bits);
+    }
+    registers
+}
+
+#[test]
+fn test_circuits_and_bases() {
+    let measurement = ClassicalShadows::new(
+        Some(bell_preparation()),
+        vec![0, 2],
+        "ro".to_string(),
+        50,
+        2,
+        7,
+    )
+    .unwrap();
+    assert_eq!(measurement.circuits().count(), 50);
+    assert_eq!(measurement.bases.len(), 50);
+    assert_eq!(measurement.constant_circuit(), &Some(bell_preparation()));
+    assert!(measurement
+        .bases
+        .iter()
+        .all(|basis| basis.len() == 2 && basis.chars().all(|p| "XYZ".contains(p))));
+    assert!(measurement.circuits().all(|circuit| circuit.is_clifford()));
+
+    // The recorded bases match the basis changes in the circuits
+    for (circuit, basis) in measurement.circuits().zip(measurement.bases.iter()) {
+        let number_hadamards = circuit
+            .iter()
+            .filter(|op| matches!(op, Operation::Hadamard(_)))
+            .count();
+        let number_rotations = circuit
+            .iter()
+            .filter(|op| matches!(op, Operation::RotateX(_)))
+            .count();
+        assert_eq!(number_hadamards, basis.matches('X').count());
+        assert_eq!(number_rotations, basis.matches('Y').count());
+    }
+
+    // Deterministic for a fixed seed
+    let same = ClassicalShadows::new(
+        Some(bell_preparation()),
+        vec![0, 2],
+        "ro".to_string(),
+        50,
+        2,
+        7,
+    )
+    .unwrap();
+    assert_eq!(measurement, same);
+    let other = ClassicalShadows::new(
+        Some(bell_preparation()),
+        vec![0, 2],
+        "ro".to_string(),
+        50,
+        2,
+        8,
+    )
+    .unwrap();
+    assert_ne!(measurement.bases, other.bases);
+
+    assert!(ClassicalShadows::new(None, vec![], "ro".to_string(), 1, 1, 0).is_err());
+    assert!(ClassicalShadows::new(None, vec![1, 1], "ro".to_string(), 1, 1, 0).is_err());
+}
+
+#[test]
+fn test_substitute_parameters() {
+    let mut preparation = Circuit::new();
+    preparation += RotateX::new(0, "theta".into());
+    let measurement =
+        ClassicalShadows::new(Some(preparation), vec![0], "ro".to_string(), 3, 1, 0).unwrap();
+    let substituted = measurement
+        .substitute_parameters(HashMap::from([("theta".to_string(), 0.5)]))
+        .unwrap();
+    assert!(!substituted
+        .constant_circuit()
+        .as_ref()
+        .unwrap()
+        .is_parametrized());
+    assert_eq!(substituted.bases, measurement.bases);
+    assert_eq!(substituted.minimum_supported_roqoqo_version(), (1, 21, 0));
+}
+
+#[test_case("ZZ", 1.0; "zz")]
+#[test_case("XY", 1.0; "xy")]
+#[test_case("YX", 1.0; "yx")]
+#[test_case("XX", 0.0; "xx")]
+#[test_case("ZI", 0.0; "zi")]
+#[test_case("II", 1.0; "identity")]
+fn test_pauli_expectation_values(pauli_product: &str, expected: f64) {
+    let measurement = ClassicalShadows::new(
+        Some(bell_preparation()),
+        vec![0, 2],
+        "ro".to_string(),
+        600,
+        5,
+        1,
+    )
+    .unwrap();
+    let registers = bell_registers(&measurement);
+    let value = measurement
+        .pauli_expectation_value(&registers, pauli_product, 10)
+        .unwrap();
+    assert!((value - expected).abs() < 0.25, "{pauli_product}: {value}");
+}
+
+#[test]
+fn test_observable_and_fidelity() {
+    let measurement = ClassicalShadows::new(
+        Some(bell_preparation()),
+        vec![0, 2],
+        "ro".to_string(),
+        600,
+        5,
+        1,
+    )
+    .unwrap();
+    let registers = bell_registers(&measurement);
+    let observable = HashMap::from([("ZZ".to_string(), 0.5), ("II".to_string(), 2.0)]);
+    let value = measurement
+        .expectation_value(&registers, &observable, 10)
+        .unwrap();
+    assert!((value - 2.5).abs() < 0.2);
+
+    let amplitude = std::f64::consts::FRAC_1_SQRT_2;
+    let target: Array1<Complex64> = array![
+        Complex64::new(amplitude, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.0, amplitude)
+    ];
+    let fidelity = measurement.fidelity(&registers, &target, 10).unwrap();
+    assert!((fidelity - 1.0).abs() < 0.2, "{fidelity}");
+
+    let orthogonal: Array1<Complex64> = array![
+        Complex64::new(0.0, 0.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.0, 0.0)
+    ];
+    let fidelity = measurement.fidelity(&registers, &orthogonal, 10).unwrap();
+    assert!(fidelity.abs() < 0.2, "{fidelity}");
+}
+
+#[test]
+fn test_batches_mix_bases() {
+    // The Z snapshots estimate <Z> = 3, the X snapshots estimate 0
+    let measurement = ClassicalShadows {
+        constant_circuit: None,
+        circuits: vec![Circuit::new(), Circuit::new()],
+        qubits: vec![0],
+        readout: "ro".to_string(),
+        bases: vec!["Z".to_string(), "X".to_string()],
+    };
+    let registers = HashMap::from([
+        ("ro_0".to_string(), vec![vec![false]; 4]),
+        ("ro_1".to_string(), vec![vec![false]; 2]),
+    ]);
+    // Consecutive batches would be grouped by basis with the means 3, 3 and 0
+    let value = measurement
+        .pauli_expectation_value(&registers, "Z", 3)
+        .unwrap();
+    assert!((value - 1.5).abs() < 1e-10, "{value}");
+}
+
+#[test]
+fn test_evaluation_errors() {
+    let measurement = ClassicalShadows::new(
+        Some(bell_preparation()),
+        vec![0, 2],
+        "ro".to_string(),
+        4,
+        1,
+        1,
+    )
+    .unwrap();
+    let registers = bell_registers(&measurement);
+    assert!(measurement
+        .pauli_expectation_value(&registers, "Z", 1)
+        .is_err());
+    assert!(measurement
+        .pauli_expectation_value(&registers, "ZA", 1)
+        .is_err());
+    assert!(measurement
+        .pauli_expectation_value(&registers, "ZZ", 0)
+        .is_err());
+    assert!(measurement
+        .pauli_expectation_value(&registers, "ZZ", 5)
+        .is_err());
+    assert!(measurement
+        .pauli_expectation_value(&HashMap::new(), "ZZ", 1)
+        .is_err());
+    assert!(measurement
+        .fidelity(&registers, &Array1::zeros(2), 1)
+        .is_err());
+}
+
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_json_schema() {
+    let measurement = ClassicalShadows::new(
+        Some(bell_preparation()),
+        vec![0, 2],
+        "ro".to_string(),
+        4,
+        1,
+        1,
+    )
+    .unwrap();
+    let test_json = serde_json::to_string(&measurement).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    let test_schema = schema_for!(ClassicalShadows);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+    assert!(compiled_schema.validate(&test_value).is_ok());
+}
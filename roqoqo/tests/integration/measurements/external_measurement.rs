@@ -0,0 +1,87 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for the default methods of measurements implemented outside of roqoqo
+
+use roqoqo::measurements::Measure;
+use roqoqo::operations::RotateX;
+use roqoqo::{Circuit, RoqoqoError};
+use std::collections::HashMap;
+
+// Measurement implemented by hand outside of roqoqo, relying on the default trait methods
+#[derive(Debug, Clone, PartialEq)]
+struct ExternalMeasurement {
+    constant_circuit: Option<Circuit>,
+    circuits: Vec<Circuit>,
+}
+
+impl Measure for ExternalMeasurement {
+    fn circuits<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Circuit> + 'a> {
+        Box::new(self.circuits.iter())
+    }
+
+    fn constant_circuit(&self) -> &Option<Circuit> {
+        &self.constant_circuit
+    }
+
+    fn substitute_parameters(
+        &self,
+        substituted_parameters: HashMap<String, f64>,
+    ) -> Result<Self, RoqoqoError> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (name, value) in substituted_parameters {
+            calculator.set_variable(&name, value);
+        }
+        let mut circuits = Vec::new();
+        for circuit in self.circuits.iter() {
+            circuits.push(circuit.substitute_parameters(&calculator)?);
+        }
+        Ok(Self {
+            constant_circuit: self.constant_circuit.clone(),
+            circuits,
+        })
+    }
+}
+
+fn external_measurement() -> ExternalMeasurement {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, "2 * theta".into());
+    ExternalMeasurement {
+        constant_circuit: None,
+        circuits: vec![circuit],
+    }
+}
+
+// Test the default bind_parameters falling back to substitute_parameters
+#[test]
+fn test_default_bind_parameters() {
+    let measurement = external_measurement();
+    let values: HashMap<String, f64> = [("theta".to_string(), 0.5)].into();
+    let bound = measurement.bind_parameters(values).unwrap();
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, 1.0.into());
+    assert_eq!(bound.circuits, vec![circuit]);
+    assert!(bound.symbols().unwrap().is_empty());
+    assert!(measurement.bind_parameters(HashMap::new()).is_err());
+}
+
+// Test the default rename_parameters only supporting measurements without symbols
+#[test]
+fn test_default_rename_parameters() {
+    let mapping: HashMap<String, String> = [("theta".to_string(), "phi".to_string())].into();
+    let measurement = external_measurement();
+    assert!(measurement.rename_parameters(mapping.clone()).is_err());
+    let bound = measurement
+        .bind_parameters([("theta".to_string(), 0.5)].into())
+        .unwrap();
+    assert_eq!(bound.rename_parameters(mapping).unwrap(), bound);
+}
@@ -13,5 +13,9 @@
 mod basis_rotation_measurement;
 mod cheated_basis_rotation_measurement;
 mod cheated_measurement;
+mod circuit_cutting_measurement;
 mod classical_register_measurement;
+mod classical_shadows_measurement;
+mod external_measurement;
 mod measurement_auxiliary_data_input;
+mod tomography_measurement;
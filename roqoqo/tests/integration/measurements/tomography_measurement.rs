@@ -0,0 +1,201 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of state and process tomography measurements
+
+use std::collections::HashMap;
+
+#[cfg(feature = "json_schema")]
+use jsonschema::{Draft, Validator};
+use ndarray::{array, Array2};
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::measurements::{ProcessTomography, StateTomography, TomographyMethod};
+use roqoqo::operations::*;
+use roqoqo::prelude::*;
+use roqoqo::registers::BitOutputRegister;
+use roqoqo::simulators::StabilizerBackend;
+use roqoqo::Circuit;
+#[cfg(feature = "json_schema")]
+use schemars::schema_for;
+use test_case::test_case;
+
+fn max_difference(first: &Array2<Complex64>, second: &Array2<Complex64>) -> f64 {
+    (first - second)
+        .iter()
+        .map(|value| value.norm())
+        .fold(0.0, f64::max)
+}
+
+// Exact measurement record of the single-qubit state |0>
+fn zero_state_registers() -> HashMap<String, BitOutputRegister> {
+    let balanced = vec![vec![false], vec![true], vec![false], vec![true]];
+    HashMap::from([
+        ("ro_X".to_string(), balanced.clone()),
+        ("ro_Y".to_string(), balanced),
+        ("ro_Z".to_string(), vec![vec![false]; 4]),
+    ])
+}
+
+#[test]
+fn test_state_tomography_circuits() {
+    let mut preparation = Circuit::new();
+    preparation += RotateX::new(3, "theta".into());
+    let measurement =
+        StateTomography::new(Some(preparation.clone()), vec![3, 1], "ro".to_string(), 100).unwrap();
+    assert_eq!(measurement.circuits().count(), 9);
+    assert_eq!(measurement.constant_circuit(), &Some(preparation));
+
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro_YZ".to_string(), 2, true);
+    expected += RotateX::new(3, std::f64::consts::FRAC_PI_2.into());
+    expected += MeasureQubit::new(3, "ro_YZ".to_string(), 0);
+    expected += MeasureQubit::new(1, "ro_YZ".to_string(), 1);
+    expected += PragmaSetNumberOfMeasurements::new(100, "ro_YZ".to_string());
+    assert_eq!(measurement.circuits[7], expected);
+
+    let substituted = measurement
+        .substitute_parameters(HashMap::from([("theta".to_string(), 0.5)]))
+        .unwrap();
+    assert!(!substituted
+        .constant_circuit()
+        .as_ref()
+        .unwrap()
+        .is_parametrized());
+    assert_eq!(substituted.minimum_supported_roqoqo_version(), (1, 21, 0));
+
+    assert!(StateTomography::new(None, vec![], "ro".to_string(), 1).is_err());
+    assert!(StateTomography::new(None, vec![0, 0], "ro".to_string(), 1).is_err());
+}
+
+#[test_case(TomographyMethod::LinearInversion; "linear_inversion")]
+#[test_case(TomographyMethod::MaximumLikelihood { max_iterations: 1000 }; "maximum_likelihood")]
+fn test_state_tomography_exact(method: TomographyMethod) {
+    let measurement = StateTomography::new(None, vec![0], "ro".to_string(), 4).unwrap();
+    let density_matrix = measurement
+        .evaluate(zero_state_registers(), method)
+        .unwrap();
+    let expected: Array2<Complex64> = array![
+        [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+        [Complex64::new(0.0, 0.0), Complex64::new(0.0, 0.0)]
+    ];
+    assert!(max_difference(&density_matrix, &expected) < 1e-4);
+
+    let expectation_values = measurement
+        .pauli_expectation_values(&zero_state_registers())
+        .unwrap();
+    assert_eq!(expectation_values.len(), 4);
+    assert_eq!(expectation_values.get("I"), Some(&1.0));
+    assert_eq!(expectation_values.get("X"), Some(&0.0));
+    assert_eq!(expectation_values.get("Z"), Some(&1.0));
+}
+
+#[test]
+fn test_state_tomography_missing_register() {
+    let measurement = StateTomography::new(None, vec![0], "ro".to_string(), 4).unwrap();
+    let mut registers = zero_state_registers();
+    registers.remove("ro_Y");
+    assert!(measurement
+        .evaluate(registers, TomographyMethod::LinearInversion)
+        .is_err());
+    let mut registers = zero_state_registers();
+    registers.insert("ro_Z".to_string(), vec![vec![false, false]]);
+    assert!(measurement.pauli_expectation_values(&registers).is_err());
+}
+
+#[test_case(TomographyMethod::LinearInversion; "linear_inversion")]
+#[test_case(TomographyMethod::MaximumLikelihood { max_iterations: 200 }; "maximum_likelihood")]
+fn test_state_tomography_bell_state(method: TomographyMethod) {
+    let mut preparation = Circuit::new();
+    preparation += Hadamard::new(2);
+    preparation += CNOT::new(2, 0);
+    preparation += SGate::new(0);
+    let measurement =
+        StateTomography::new(Some(preparation), vec![0, 2], "ro".to_string(), 2000).unwrap();
+    let (registers, _, _) = StabilizerBackend::new(Some(5))
+        .run_measurement_registers(&measurement)
+        .unwrap();
+    let density_matrix = measurement.evaluate(registers, method).unwrap();
+
+    // (|00> + i|11>) / sqrt(2)
+    let half = Complex64::new(0.5, 0.0);
+    let zero = Complex64::new(0.0, 0.0);
+    let i_half = Complex64::new(0.0, 0.5);
+    let expected: Array2<Complex64> = array![
+        [half, zero, zero, -i_half],
+        [zero, zero, zero, zero],
+        [zero, zero, zero, zero],
+        [i_half, zero, zero, half]
+    ];
+    assert!(max_difference(&density_matrix, &expected) < 0.05);
+    let trace: Complex64 = density_matrix.diag().sum();
+    assert!((trace - Complex64::new(1.0, 0.0)).norm() < 1e-10);
+}
+
+#[test_case(TomographyMethod::LinearInversion; "linear_inversion")]
+#[test_case(TomographyMethod::MaximumLikelihood { max_iterations: 200 }; "maximum_likelihood")]
+fn test_process_tomography(method: TomographyMethod) {
+    let mut process = Circuit::new();
+    process += Hadamard::new(1);
+    let measurement = ProcessTomography::new(process, vec![1], "ro".to_string(), 3000).unwrap();
+    assert_eq!(measurement.circuits().count(), 12);
+    assert!(measurement.constant_circuit().is_none());
+    let (registers, _, _) = StabilizerBackend::new(Some(2))
+        .run_measurement_registers(&measurement)
+        .unwrap();
+    let choi = measurement.evaluate(registers, method).unwrap();
+
+    let unitary = Hadamard::new(1).unitary_matrix().unwrap();
+    let mut expected: Array2<Complex64> = Array2::zeros((4, 4));
+    for ((row, column), value) in expected.indexed_iter_mut() {
+        *value = unitary[(row % 2, row / 2)] * unitary[(column % 2, column / 2)].conj();
+    }
+    assert!(max_difference(&choi, &expected) < 0.05);
+    let trace: Complex64 = choi.diag().sum();
+    assert!((trace - Complex64::new(2.0, 0.0)).norm() < 1e-10);
+}
+
+#[test]
+fn test_process_tomography_registers() {
+    let measurement =
+        ProcessTomography::new(Circuit::new(), vec![0, 1], "ro".to_string(), 10).unwrap();
+    assert_eq!(measurement.circuits.len(), 144);
+    let mut expected = Circuit::new();
+    expected += Hadamard::new(0);
+    expected += SGate::new(0);
+    expected += PauliX::new(1);
+    expected += DefinitionBit::new("ro_i1_ZZ".to_string(), 2, true);
+    expected += MeasureQubit::new(0, "ro_i1_ZZ".to_string(), 0);
+    expected += MeasureQubit::new(1, "ro_i1_ZZ".to_string(), 1);
+    expected += PragmaSetNumberOfMeasurements::new(10, "ro_i1_ZZ".to_string());
+    assert_eq!(measurement.circuits[7 * 9 + 8], expected);
+    assert!(measurement
+        .evaluate(HashMap::new(), TomographyMethod::LinearInversion)
+        .is_err());
+}
+
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_json_schema() {
+    let measurement = StateTomography::new(None, vec![0], "ro".to_string(), 10).unwrap();
+    let test_json = serde_json::to_string(&measurement).unwrap();
+    let test_value: serde_json::Value = serde_json::from_str(&test_json).unwrap();
+
+    let test_schema = schema_for!(StateTomography);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+    assert!(compiled_schema.validate(&test_value).is_ok());
+}
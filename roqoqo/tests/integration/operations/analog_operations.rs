@@ -16,12 +16,12 @@
 use jsonschema::{Draft, Validator};
 use qoqo_calculator::{Calculator, CalculatorError::VariableNotSet, CalculatorFloat};
 use roqoqo::operations::*;
-use roqoqo::RoqoqoError;
+use roqoqo::{Circuit, RoqoqoError};
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
 #[cfg(feature = "serialize")]
 use serde_test::{assert_tokens, Configure, Token};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use struqture::spins::{PauliHamiltonian, PauliProduct};
 use struqture::{prelude::*, STRUQTURE_VERSION};
 use test_case::test_case;
@@ -245,6 +245,84 @@ fn test_substitute_parameters_error(op: Operation, val: &str) {
     )
 }
 
+#[test]
+fn test_bind_parameters() {
+    let values: HashMap<String, f64> = [("omega".to_string(), 0.5)].into();
+    let op = Operation::from(create_apply_constant_spin_hamiltonian("2 * omega * delta"));
+    let result = op.bind_parameters(&values).unwrap();
+    assert_eq!(
+        result,
+        Operation::from(create_apply_constant_spin_hamiltonian("2 * 0.5 * delta"))
+    );
+
+    let op = Operation::from(create_apply_constant_spin_hamiltonian_param_time());
+    let result = op.bind_parameters(&values).unwrap();
+    assert_eq!(result, op);
+    let values: HashMap<String, f64> = [("time".to_string(), 2.0)].into();
+    let result = op.bind_parameters(&values).unwrap();
+    assert!(!result.is_parametrized());
+
+    // The symbol omega is driven by the schedule and is not bound
+    let values: HashMap<String, f64> =
+        [("omega".to_string(), 0.5), ("delta".to_string(), 2.0)].into();
+    let op = Operation::from(create_apply_timedependent_spin_hamiltonian("omega * delta"));
+    let result = op.bind_parameters(&values).unwrap();
+    assert_eq!(
+        result,
+        Operation::from(create_apply_timedependent_spin_hamiltonian("omega * 2.0"))
+    );
+}
+
+#[test]
+fn test_symbols_timedependent_spin() {
+    let op = create_apply_timedependent_spin_hamiltonian("omega * delta");
+    assert_eq!(op.symbols(), HashSet::from(["delta".to_string()]));
+    assert!(op.is_parametrized());
+
+    // The schedule symbols are not free but the operation stays parametrized until they are substituted
+    let op = create_apply_timedependent_spin_hamiltonian("omega");
+    assert!(op.symbols().is_empty());
+    assert!(op.is_parametrized());
+
+    let mut circuit = Circuit::new();
+    circuit += op;
+    assert_eq!(circuit.symbols(), Ok(HashSet::new()));
+
+    let mut circuit = Circuit::new();
+    circuit += create_apply_timedependent_spin_hamiltonian("2 * omega + delta");
+    assert_eq!(
+        circuit.symbols().unwrap(),
+        HashSet::from(["delta".to_string()])
+    );
+}
+
+#[test]
+fn test_rename_parameters_timedependent_spin() {
+    let op = create_apply_timedependent_spin_hamiltonian("2 * omega");
+    let mapping: HashMap<String, String> = [("omega".to_string(), "theta".to_string())].into();
+    let renamed = op.rename_parameters(&mapping).unwrap();
+    assert_eq!(
+        renamed.values(),
+        &HashMap::from([("theta".to_string(), vec![1.0])])
+    );
+    assert!(renamed.symbols().is_empty());
+
+    let mut expected = PauliHamiltonian::new();
+    expected
+        .add_operator_product(PauliProduct::new().z(0), 2.0.into())
+        .unwrap();
+    assert_eq!(
+        renamed.piecewise_constant(),
+        Ok(vec![ApplyConstantPauliHamiltonian::new(
+            expected,
+            1.0.into()
+        )])
+    );
+
+    let mapping: HashMap<String, String> = [("omega".to_string(), "(2 * x)".to_string())].into();
+    assert!(op.rename_parameters(&mapping).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn constant_spin_hamiltonian_json_schema() {
@@ -583,3 +661,70 @@ fn operate_analog_timedependent_spin() {
     assert!(!unparam_analog.is_parametrized());
     assert!(param_analog.is_parametrized());
 }
+
+#[test]
+fn test_piecewise_constant() {
+    let mut hamiltonian = PauliHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from("omega"))
+        .unwrap();
+    hamiltonian
+        .add_operator_product(
+            PauliProduct::new().x(0).x(1),
+            CalculatorFloat::from("2 * omega + delta"),
+        )
+        .unwrap();
+    let mut values = HashMap::new();
+    values.insert("omega".to_string(), vec![1.0, 0.5, 0.0]);
+    values.insert("delta".to_string(), vec![0.0, 1.0, 3.0]);
+    let op =
+        ApplyTimeDependentPauliHamiltonian::new(hamiltonian.clone(), vec![0.5, 1.0, 2.0], values);
+    assert_eq!(op.validate_schedule(), Ok(()));
+
+    let segments = op.piecewise_constant().unwrap();
+    let expected_values = [(1.0, 2.0, 0.5), (1.0, 2.0, 0.5), (0.5, 2.0, 1.0)];
+    assert_eq!(segments.len(), 3);
+    for (segment, (omega, coupling, duration)) in segments.iter().zip(expected_values.iter()) {
+        let mut expected = PauliHamiltonian::new();
+        expected
+            .add_operator_product(PauliProduct::new().z(0), (*omega).into())
+            .unwrap();
+        expected
+            .add_operator_product(PauliProduct::new().x(0).x(1), (*coupling).into())
+            .unwrap();
+        assert_eq!(
+            segment,
+            &ApplyConstantPauliHamiltonian::new(expected, (*duration).into())
+        );
+    }
+
+    // A range starting at zero has no segment before the first time
+    let mut values = HashMap::new();
+    values.insert("omega".to_string(), vec![1.0, 0.5]);
+    values.insert("delta".to_string(), vec![0.0, 1.0]);
+    let op = ApplyTimeDependentPauliHamiltonian::new(hamiltonian, vec![0.0, 1.0], values);
+    let segments = op.piecewise_constant().unwrap();
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].time, CalculatorFloat::from(1.0));
+}
+
+#[test_case(vec![], vec![("omega", vec![])], "Time range of ApplyTimeDependentPauliHamiltonian is empty"; "empty")]
+#[test_case(vec![1.0, 0.5], vec![("omega", vec![1.0, 1.0])], "Time range [1.0, 0.5] of ApplyTimeDependentPauliHamiltonian is not non-negative and strictly increasing"; "decreasing")]
+#[test_case(vec![0.5, 1.0], vec![("omega", vec![1.0])], "Parameter omega has 1 values but the time range has 2 entries"; "length")]
+#[test_case(vec![0.5, 1.0], vec![("delta", vec![1.0, 1.0])], "Symbol omega in the Hamiltonian of ApplyTimeDependentPauliHamiltonian is not covered by values"; "symbol")]
+fn test_piecewise_constant_error(time: Vec<f64>, values: Vec<(&str, Vec<f64>)>, msg: &str) {
+    let mut hamiltonian = PauliHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from("omega"))
+        .unwrap();
+    let values: HashMap<String, Vec<f64>> = values
+        .into_iter()
+        .map(|(name, values)| (name.to_string(), values))
+        .collect();
+    let op = ApplyTimeDependentPauliHamiltonian::new(hamiltonian, time, values);
+    let error = || RoqoqoError::GenericError {
+        msg: msg.to_string(),
+    };
+    assert_eq!(op.validate_schedule(), Err(error()));
+    assert_eq!(op.piecewise_constant(), Err(error()));
+}
@@ -0,0 +1,105 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for the default methods of operations implemented outside of roqoqo
+
+use qoqo_calculator::{Calculator, CalculatorFloat};
+use roqoqo::operations::{InvolveQubits, InvolvedQubits, Operate, Substitute, SupportedVersion};
+use roqoqo::RoqoqoError;
+use std::collections::HashMap;
+
+// Operation implemented by hand outside of roqoqo, relying on the default trait methods
+#[derive(Debug, Clone, PartialEq)]
+struct ExternalRotation {
+    qubit: usize,
+    theta: CalculatorFloat,
+}
+
+impl InvolveQubits for ExternalRotation {
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::Set([self.qubit].into())
+    }
+}
+
+impl SupportedVersion for ExternalRotation {}
+
+impl Substitute for ExternalRotation {
+    fn substitute_parameters(&self, calculator: &Calculator) -> Result<Self, RoqoqoError> {
+        Ok(ExternalRotation {
+            qubit: self.qubit,
+            theta: CalculatorFloat::from(calculator.parse_get(self.theta.clone())?),
+        })
+    }
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(ExternalRotation {
+            qubit: *mapping.get(&self.qubit).unwrap_or(&self.qubit),
+            theta: self.theta.clone(),
+        })
+    }
+}
+
+impl Operate for ExternalRotation {
+    fn tags(&self) -> &'static [&'static str] {
+        &["Operation", "ExternalRotation"]
+    }
+    fn hqslang(&self) -> &'static str {
+        "ExternalRotation"
+    }
+    fn is_parametrized(&self) -> bool {
+        !self.theta.is_float()
+    }
+}
+
+// Test the default symbols of an Operate implementation without symbols
+#[test]
+fn test_default_symbols() {
+    let operation = ExternalRotation {
+        qubit: 0,
+        theta: CalculatorFloat::from("theta"),
+    };
+    assert!(operation.is_parametrized());
+    assert!(operation.symbols().is_empty());
+}
+
+// Test the default bind_parameters falling back to substitute_parameters
+#[test]
+fn test_default_bind_parameters() {
+    let operation = ExternalRotation {
+        qubit: 0,
+        theta: CalculatorFloat::from("2 * theta"),
+    };
+    let values: HashMap<String, f64> = [("theta".to_string(), 0.5)].into();
+    assert_eq!(
+        operation.bind_parameters(&values).unwrap(),
+        ExternalRotation {
+            qubit: 0,
+            theta: CalculatorFloat::from(1.0),
+        }
+    );
+    assert!(operation.bind_parameters(&HashMap::new()).is_err());
+}
+
+// Test the default rename_parameters only supporting operations without symbols
+#[test]
+fn test_default_rename_parameters() {
+    let mapping: HashMap<String, String> = [("theta".to_string(), "phi".to_string())].into();
+    let operation = ExternalRotation {
+        qubit: 0,
+        theta: CalculatorFloat::from(0.5),
+    };
+    assert_eq!(operation.rename_parameters(&mapping).unwrap(), operation);
+    let operation = ExternalRotation {
+        qubit: 0,
+        theta: CalculatorFloat::from("theta"),
+    };
+    assert!(operation.rename_parameters(&mapping).is_err());
+}
@@ -143,6 +143,27 @@ fn involved_classical_all_qubits(operation: Operation, involved: InvolvedClassic
 #[test_case(Operation::from( InputSymbolic::new("a".to_string(), 20.0)), vec![("a", 0)]; "InputSymbolic")]
 #[test_case(Operation::from( InputBit::new("ro".to_string(), 2, false)), vec![("ro", 2)]; "InputBit")]
 #[test_case(Operation::from( PragmaConditional::new("ro".to_string(), 3, Circuit::new())), vec![("ro", 3)]; "PragmaConditional")]
+#[test_case(Operation::from( PragmaIfElse::new(
+        BooleanExpression::And(vec![BooleanExpression::Bit("ro".to_string(), 0), BooleanExpression::Bit("flag".to_string(), 1)]),
+        Circuit::from_iter(vec![Operation::from(MeasureQubit::new(0, "out".to_string(), 2))]),
+        Circuit::new(),
+    )), vec![("ro", 0), ("flag", 1), ("out", 2)]; "PragmaIfElse")]
+#[test_case(Operation::from( PragmaWhileLoop::new(
+        BooleanExpression::Not(Box::new(BooleanExpression::Bit("ro".to_string(), 1))),
+        Circuit::from_iter(vec![Operation::from(MeasureQubit::new(0, "ro".to_string(), 1))]),
+        10,
+    )), vec![("ro", 1)]; "PragmaWhileLoop")]
+#[test_case(Operation::from( PragmaSetBit::new(
+        "out".to_string(),
+        0,
+        BooleanExpression::Xor(vec![BooleanExpression::Bit("ro".to_string(), 0), BooleanExpression::Bit("ro".to_string(), 1)]),
+    )), vec![("out", 0), ("ro", 0), ("ro", 1)]; "PragmaSetBit")]
+#[test_case(Operation::from(PragmaBitwise::new(BitwiseOperator::And, "ro".to_string(), 0, "ro".to_string(), 2, "out".to_string(), 0, 2)), vec![("ro", 0), ("ro", 1), ("ro", 2), ("ro", 3), ("out", 0), ("out", 1)]; "PragmaBitwiseAnd")]
+#[test_case(Operation::from(PragmaBitwise::new(BitwiseOperator::Or, "ro".to_string(), 0, "out".to_string(), 0, "out".to_string(), 0, 1)), vec![("ro", 0), ("out", 0)]; "PragmaBitwiseOr")]
+#[test_case(Operation::from(PragmaBitwise::new(BitwiseOperator::Xor, "ro".to_string(), 1, "ro".to_string(), 0, "ro".to_string(), 1, 1)), vec![("ro", 0), ("ro", 1)]; "PragmaBitwiseXor")]
+#[test_case(Operation::from(PragmaParity::new("ro".to_string(), vec![0, 2], "out".to_string(), 1)), vec![("ro", 0), ("ro", 2), ("out", 1)]; "PragmaParity")]
+#[test_case(Operation::from(PragmaSetFloat::new("angle".to_string(), 0, CalculatorFloat::from("x * 2"), HashMap::from([("x".to_string(), ("ro".to_string(), 1))]))), vec![("angle", 0), ("ro", 1)]; "PragmaSetFloat")]
+#[test_case(Operation::from(MeasureQubitAndReset::new(0, "ro".to_string(), 2)), vec![("ro", 2)]; "MeasureQubitAndReset")]
 
 fn involved_classical_set(operation: Operation, involved: Vec<(&str, usize)>) {
     let a: HashSet<(String, usize)> = involved
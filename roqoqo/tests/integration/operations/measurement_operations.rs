@@ -174,6 +174,96 @@ fn measure_qubit_json_schema() {
     assert!(validation_result.is_ok());
 }
 
+/// Test MeasureQubitAndReset inputs, involved qubits and involved classical entries
+#[test]
+fn measure_qubit_and_reset_inputs_qubits() {
+    let measure = MeasureQubitAndReset::new(3, String::from("ro"), 1);
+
+    // Test inputs are correct
+    assert_eq!(measure.qubit(), &3_usize);
+    assert_eq!(measure.readout(), &String::from("ro"));
+    assert_eq!(measure.readout_index(), &1_usize);
+
+    // Test InvolveQubits trait
+    assert_eq!(
+        measure.involved_qubits(),
+        InvolvedQubits::Set(HashSet::from([3]))
+    );
+    assert_eq!(
+        measure.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([(String::from("ro"), 1)]))
+    );
+
+    // Test Debug, Clone and PartialEq traits
+    assert_eq!(
+        format!("{measure:?}"),
+        "MeasureQubitAndReset { qubit: 3, readout: \"ro\", readout_index: 1 }"
+    );
+    assert_eq!(measure.clone(), measure);
+    assert!(measure != MeasureQubitAndReset::new(3, String::from("ro"), 0));
+}
+
+/// Test MeasureQubitAndReset Operate and Substitute traits
+#[test]
+fn measure_qubit_and_reset_operate_substitute_trait() {
+    let measure = MeasureQubitAndReset::new(0, String::from("ro"), 1);
+
+    let tags: &[&str; 3] = &["Operation", "Measurement", "MeasureQubitAndReset"];
+    assert_eq!(measure.tags(), tags);
+    assert_eq!(measure.hqslang(), String::from("MeasureQubitAndReset"));
+    assert!(!measure.is_parametrized());
+    assert_eq!(measure.minimum_supported_roqoqo_version(), (1, 21, 0));
+
+    assert_eq!(
+        measure.substitute_parameters(&Calculator::new()).unwrap(),
+        measure
+    );
+    let qubit_mapping: HashMap<usize, usize> = HashMap::from([(0, 2), (2, 0)]);
+    assert_eq!(
+        measure.remap_qubits(&qubit_mapping).unwrap(),
+        MeasureQubitAndReset::new(2, String::from("ro"), 1)
+    );
+}
+
+/// Test MeasureQubitAndReset Serialization and Deserialization traits
+#[cfg(feature = "serialize")]
+#[test]
+fn measure_qubit_and_reset_serde() {
+    let measure_serialization = MeasureQubitAndReset::new(0, String::from("ro"), 1);
+    assert_tokens(
+        &measure_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "MeasureQubitAndReset",
+                len: 3,
+            },
+            Token::Str("qubit"),
+            Token::U64(0),
+            Token::Str("readout"),
+            Token::Str("ro"),
+            Token::Str("readout_index"),
+            Token::U64(1),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test MeasureQubitAndReset JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn measure_qubit_and_reset_json_schema() {
+    let op = MeasureQubitAndReset::new(0, String::from("ro"), 1);
+    let test_value: serde_json::Value = serde_json::to_value(&op).unwrap();
+    let test_schema = schema_for!(MeasureQubitAndReset);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+    assert!(compiled_schema.validate(&test_value).is_ok());
+}
+
 /// Test PragmaGetStateVector inputs and involved qubits
 #[test]
 fn pragma_get_statevector_inputs_qubits() {
@@ -37,6 +37,9 @@ mod spin_boson_operations;
 #[cfg(feature = "unstable_analog_operations")]
 mod analog_operations;
 
+#[cfg(not(feature = "dynamic"))]
+mod external_operation;
+
 use nalgebra as na;
 use ndarray::Array2;
 use num_complex::Complex64;
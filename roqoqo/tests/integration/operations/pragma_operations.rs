@@ -3528,6 +3528,727 @@ fn pragma_conditional_json_schema() {
     assert!(validation_result.is_ok());
 }
 
+fn control_flow_condition() -> BooleanExpression {
+    BooleanExpression::Or(vec![
+        BooleanExpression::Bit("ro".to_string(), 0),
+        BooleanExpression::Not(Box::new(BooleanExpression::Bit("flag".to_string(), 2))),
+    ])
+}
+
+/// Test BooleanExpression evaluation and involved bits
+#[test_case(BooleanExpression::Constant(true), true; "constant")]
+#[test_case(BooleanExpression::Bit("ro".to_string(), 1), true; "bit")]
+#[test_case(BooleanExpression::Not(Box::new(BooleanExpression::Bit("ro".to_string(), 1))), false; "not")]
+#[test_case(BooleanExpression::And(vec![BooleanExpression::Bit("ro".to_string(), 0), BooleanExpression::Bit("ro".to_string(), 1)]), false; "and")]
+#[test_case(BooleanExpression::And(vec![]), true; "and_empty")]
+#[test_case(BooleanExpression::Or(vec![BooleanExpression::Bit("ro".to_string(), 0), BooleanExpression::Bit("ro".to_string(), 1)]), true; "or")]
+#[test_case(BooleanExpression::Or(vec![]), false; "or_empty")]
+#[test_case(BooleanExpression::Xor(vec![BooleanExpression::Bit("ro".to_string(), 1), BooleanExpression::Bit("ro".to_string(), 2)]), false; "xor")]
+#[test_case(control_flow_condition(), true; "nested")]
+fn boolean_expression_evaluate(expression: BooleanExpression, expected: bool) {
+    let registers: HashMap<String, Vec<bool>> = HashMap::from([
+        ("ro".to_string(), vec![false, true, true]),
+        ("flag".to_string(), vec![false, false, false]),
+    ]);
+    assert_eq!(expression.evaluate(&registers), Ok(expected));
+}
+
+/// Test BooleanExpression errors and involved bits
+#[test]
+fn boolean_expression_involved_bits() {
+    let registers: HashMap<String, Vec<bool>> =
+        HashMap::from([("ro".to_string(), vec![false, true])]);
+    assert!(BooleanExpression::Bit("ro".to_string(), 2)
+        .evaluate(&registers)
+        .is_err());
+    assert!(control_flow_condition().evaluate(&registers).is_err());
+    // Short-circuiting skips entries that are not needed for the value
+    assert_eq!(
+        BooleanExpression::Or(vec![
+            BooleanExpression::Bit("ro".to_string(), 1),
+            BooleanExpression::Bit("missing".to_string(), 0),
+        ])
+        .evaluate(&registers),
+        Ok(true)
+    );
+
+    let involved: HashSet<(String, usize)> =
+        HashSet::from([("ro".to_string(), 0), ("flag".to_string(), 2)]);
+    assert_eq!(control_flow_condition().involved_bits(), involved);
+    assert!(BooleanExpression::Constant(false)
+        .involved_bits()
+        .is_empty());
+}
+
+/// Test PragmaIfElse inputs, involved qubits and involved classical entries
+#[test]
+fn pragma_if_else_inputs_qubits() {
+    let mut if_circuit = Circuit::new();
+    if_circuit += PauliX::new(0);
+    let mut else_circuit = Circuit::new();
+    else_circuit += MeasureQubit::new(3, "out".to_string(), 1);
+    let pragma = PragmaIfElse::new(
+        control_flow_condition(),
+        if_circuit.clone(),
+        else_circuit.clone(),
+    );
+
+    // Test inputs are correct
+    assert_eq!(pragma.condition(), &control_flow_condition());
+    assert_eq!(pragma.if_circuit(), &if_circuit);
+    assert_eq!(pragma.else_circuit(), &else_circuit);
+
+    // Test InvolveQubits trait
+    assert_eq!(
+        pragma.involved_qubits(),
+        InvolvedQubits::Set(HashSet::from([0, 3]))
+    );
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([
+            ("ro".to_string(), 0),
+            ("flag".to_string(), 2),
+            ("out".to_string(), 1)
+        ]))
+    );
+    let pragma = PragmaIfElse::new(
+        BooleanExpression::Constant(true),
+        Circuit::new(),
+        Circuit::new(),
+    );
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::None);
+    assert_eq!(pragma.involved_classical(), InvolvedClassical::None);
+    let mut else_circuit = Circuit::new();
+    else_circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    let pragma = PragmaIfElse::new(control_flow_condition(), if_circuit, else_circuit);
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::AllQubits("ro".to_string())
+    );
+}
+
+/// Test PragmaWhileLoop and PragmaSetBit inputs, involved qubits and involved classical entries
+#[test]
+fn pragma_while_loop_set_bit_inputs_qubits() {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(1);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 0);
+    let pragma = PragmaWhileLoop::new(control_flow_condition(), circuit.clone(), 20);
+    assert_eq!(pragma.condition(), &control_flow_condition());
+    assert_eq!(pragma.circuit(), &circuit);
+    assert_eq!(pragma.max_iterations(), &20);
+    assert_eq!(
+        pragma.involved_qubits(),
+        InvolvedQubits::Set(HashSet::from([1]))
+    );
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([
+            ("ro".to_string(), 0),
+            ("flag".to_string(), 2)
+        ]))
+    );
+
+    let pragma = PragmaSetBit::new("out".to_string(), 3, control_flow_condition());
+    assert_eq!(pragma.register(), &"out".to_string());
+    assert_eq!(pragma.index(), &3);
+    assert_eq!(pragma.value(), &control_flow_condition());
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::None);
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([
+            ("out".to_string(), 3),
+            ("ro".to_string(), 0),
+            ("flag".to_string(), 2)
+        ]))
+    );
+}
+
+/// Test control flow PRAGMA Operate trait
+#[test_case(Operation::from(PragmaIfElse::new(control_flow_condition(), Circuit::new(), Circuit::new())), "PragmaIfElse"; "PragmaIfElse")]
+#[test_case(Operation::from(PragmaWhileLoop::new(control_flow_condition(), Circuit::new(), 1)), "PragmaWhileLoop"; "PragmaWhileLoop")]
+#[test_case(Operation::from(PragmaSetBit::new("ro".to_string(), 0, control_flow_condition())), "PragmaSetBit"; "PragmaSetBit")]
+fn pragma_control_flow_operate_trait(operation: Operation, name: &str) {
+    assert_eq!(operation.tags(), &["Operation", "PragmaOperation", name]);
+    assert_eq!(operation.hqslang(), name);
+    assert!(!operation.is_parametrized());
+    assert_eq!(operation.clone(), operation);
+    assert!(format!("{operation:?}").contains(name));
+}
+
+/// Test control flow PRAGMA Substitute trait
+#[test]
+fn pragma_control_flow_substitute_trait() {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, "theta".into());
+    let mut substituted_circuit = Circuit::new();
+    substituted_circuit += RotateX::new(0, 0.5.into());
+    let mut remapped_circuit = Circuit::new();
+    remapped_circuit += RotateX::new(2, "theta".into());
+    let mapping: HashMap<usize, usize> = HashMap::from([(0, 2), (2, 0)]);
+    let mut calculator = Calculator::new();
+    calculator.set_variable("theta", 0.5);
+
+    let pragma = PragmaIfElse::new(control_flow_condition(), circuit.clone(), circuit.clone());
+    assert!(pragma.is_parametrized());
+    assert_eq!(
+        pragma.substitute_parameters(&calculator).unwrap(),
+        PragmaIfElse::new(
+            control_flow_condition(),
+            substituted_circuit.clone(),
+            substituted_circuit.clone()
+        )
+    );
+    assert_eq!(
+        pragma.remap_qubits(&mapping).unwrap(),
+        PragmaIfElse::new(
+            control_flow_condition(),
+            remapped_circuit.clone(),
+            remapped_circuit.clone()
+        )
+    );
+    assert!(pragma.substitute_parameters(&Calculator::new()).is_err());
+
+    let pragma = PragmaWhileLoop::new(control_flow_condition(), circuit, 4);
+    assert!(pragma.is_parametrized());
+    assert_eq!(
+        pragma.substitute_parameters(&calculator).unwrap(),
+        PragmaWhileLoop::new(control_flow_condition(), substituted_circuit, 4)
+    );
+    assert_eq!(
+        pragma.remap_qubits(&mapping).unwrap(),
+        PragmaWhileLoop::new(control_flow_condition(), remapped_circuit, 4)
+    );
+
+    let pragma = PragmaSetBit::new("out".to_string(), 0, control_flow_condition());
+    assert_eq!(pragma.substitute_parameters(&calculator).unwrap(), pragma);
+    assert_eq!(pragma.remap_qubits(&mapping).unwrap(), pragma);
+}
+
+/// Test control flow PRAGMA serialization round trip
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_control_flow_serde() {
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(0);
+    let operations: Vec<Operation> = vec![
+        PragmaIfElse::new(control_flow_condition(), circuit.clone(), Circuit::new()).into(),
+        PragmaWhileLoop::new(control_flow_condition(), circuit, 7).into(),
+        PragmaSetBit::new("out".to_string(), 1, control_flow_condition()).into(),
+    ];
+    for operation in operations {
+        let json = serde_json::to_string(&operation).unwrap();
+        let deserialized: Operation = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, operation);
+        let encoded = serialize(&operation).unwrap();
+        let decoded: Operation = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded, operation);
+    }
+
+    let pragma = PragmaSetBit::new("out".to_string(), 1, BooleanExpression::Constant(true));
+    assert_tokens(
+        &pragma.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaSetBit",
+                len: 3,
+            },
+            Token::Str("register"),
+            Token::Str("out"),
+            Token::Str("index"),
+            Token::U64(1),
+            Token::Str("value"),
+            Token::NewtypeVariant {
+                name: "BooleanExpression",
+                variant: "Constant",
+            },
+            Token::Bool(true),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test control flow PRAGMA JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_control_flow_json_schema() {
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(0);
+    let operations: Vec<(serde_json::Value, schemars::schema::RootSchema)> = vec![
+        (
+            serde_json::to_value(PragmaIfElse::new(
+                control_flow_condition(),
+                circuit.clone(),
+                Circuit::new(),
+            ))
+            .unwrap(),
+            schema_for!(PragmaIfElse),
+        ),
+        (
+            serde_json::to_value(PragmaWhileLoop::new(control_flow_condition(), circuit, 7))
+                .unwrap(),
+            schema_for!(PragmaWhileLoop),
+        ),
+        (
+            serde_json::to_value(PragmaSetBit::new(
+                "out".to_string(),
+                1,
+                control_flow_condition(),
+            ))
+            .unwrap(),
+            schema_for!(PragmaSetBit),
+        ),
+    ];
+    for (test_value, test_schema) in operations {
+        let schema = serde_json::to_string(&test_schema).unwrap();
+        let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let compiled_schema = Validator::options()
+            .with_draft(Draft::Draft7)
+            .build(&schema_value)
+            .unwrap();
+        assert!(compiled_schema.validate(&test_value).is_ok());
+    }
+}
+
+fn classical_arithmetic_registers() -> HashMap<String, Vec<bool>> {
+    HashMap::from([
+        ("a".to_string(), vec![false, true, true, false]),
+        ("b".to_string(), vec![true, true, false, false]),
+    ])
+}
+
+/// Test bitwise PRAGMA inputs, evaluation and involved classical entries
+#[test]
+fn pragma_bitwise_inputs_evaluate() {
+    let registers = classical_arithmetic_registers();
+    let pragma = PragmaBitwise::new(
+        BitwiseOperator::And,
+        "a".to_string(),
+        0,
+        "b".to_string(),
+        0,
+        "out".to_string(),
+        1,
+        4,
+    );
+    assert_eq!(pragma.first_register(), &"a".to_string());
+    assert_eq!(pragma.first_start(), &0);
+    assert_eq!(pragma.second_register(), &"b".to_string());
+    assert_eq!(pragma.second_start(), &0);
+    assert_eq!(pragma.target_register(), &"out".to_string());
+    assert_eq!(pragma.target_start(), &1);
+    assert_eq!(pragma.length(), &4);
+    assert_eq!(
+        pragma.evaluate(&registers),
+        Ok(vec![false, true, false, false])
+    );
+    assert_eq!(
+        PragmaBitwise::new(
+            BitwiseOperator::Or,
+            "a".to_string(),
+            0,
+            "b".to_string(),
+            0,
+            "out".to_string(),
+            0,
+            4
+        )
+        .evaluate(&registers),
+        Ok(vec![true, true, true, false])
+    );
+    assert_eq!(
+        PragmaBitwise::new(
+            BitwiseOperator::Xor,
+            "a".to_string(),
+            1,
+            "b".to_string(),
+            0,
+            "out".to_string(),
+            0,
+            3
+        )
+        .evaluate(&registers),
+        Ok(vec![false, false, false])
+    );
+    assert!(PragmaBitwise::new(
+        BitwiseOperator::Xor,
+        "a".to_string(),
+        2,
+        "b".to_string(),
+        0,
+        "out".to_string(),
+        0,
+        3
+    )
+    .evaluate(&registers)
+    .is_err());
+    assert!(PragmaBitwise::new(
+        BitwiseOperator::Or,
+        "c".to_string(),
+        0,
+        "b".to_string(),
+        0,
+        "out".to_string(),
+        0,
+        1
+    )
+    .evaluate(&registers)
+    .is_err());
+
+    let pragma = PragmaBitwise::new(
+        BitwiseOperator::Xor,
+        "a".to_string(),
+        1,
+        "b".to_string(),
+        0,
+        "a".to_string(),
+        0,
+        2,
+    );
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::None);
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([
+            ("a".to_string(), 0),
+            ("a".to_string(), 1),
+            ("a".to_string(), 2),
+            ("b".to_string(), 0),
+            ("b".to_string(), 1),
+        ]))
+    );
+}
+
+/// Test PragmaParity and PragmaSetFloat inputs, evaluation and involved classical entries
+#[test]
+fn pragma_parity_set_float_inputs_evaluate() {
+    let registers = classical_arithmetic_registers();
+    let pragma = PragmaParity::new("a".to_string(), vec![0, 1, 2], "syndrome".to_string(), 0);
+    assert_eq!(pragma.register(), &"a".to_string());
+    assert_eq!(pragma.indices(), &vec![0, 1, 2]);
+    assert_eq!(pragma.target_register(), &"syndrome".to_string());
+    assert_eq!(pragma.target_index(), &0);
+    assert_eq!(pragma.evaluate(&registers), Ok(false));
+    assert_eq!(
+        PragmaParity::new("b".to_string(), vec![0], "syndrome".to_string(), 0).evaluate(&registers),
+        Ok(true)
+    );
+    assert!(
+        PragmaParity::new("b".to_string(), vec![4], "syndrome".to_string(), 0)
+            .evaluate(&registers)
+            .is_err()
+    );
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::None);
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([
+            ("a".to_string(), 0),
+            ("a".to_string(), 1),
+            ("a".to_string(), 2),
+            ("syndrome".to_string(), 0),
+        ]))
+    );
+
+    let variables: HashMap<String, (String, usize)> = HashMap::from([
+        ("x".to_string(), ("angles".to_string(), 1)),
+        ("flag".to_string(), ("a".to_string(), 1)),
+    ]);
+    let pragma = PragmaSetFloat::new(
+        "angles".to_string(),
+        0,
+        CalculatorFloat::from("2 * x + flag"),
+        variables.clone(),
+    );
+    assert_eq!(pragma.register(), &"angles".to_string());
+    assert_eq!(pragma.index(), &0);
+    assert_eq!(pragma.expression(), &CalculatorFloat::from("2 * x + flag"));
+    assert_eq!(pragma.variables(), &variables);
+    let floats: HashMap<String, Vec<f64>> =
+        HashMap::from([("angles".to_string(), vec![0.0, 0.25])]);
+    assert_eq!(pragma.evaluate(&registers, &floats), Ok(1.5));
+    assert!(pragma.evaluate(&registers, &HashMap::new()).is_err());
+    assert!(PragmaSetFloat::new(
+        "angles".to_string(),
+        0,
+        CalculatorFloat::from("x + y"),
+        variables.clone()
+    )
+    .evaluate(&registers, &floats)
+    .is_err());
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::None);
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([
+            ("angles".to_string(), 0),
+            ("angles".to_string(), 1),
+            ("a".to_string(), 1),
+        ]))
+    );
+}
+
+fn set_float_example() -> PragmaSetFloat {
+    PragmaSetFloat::new(
+        "angles".to_string(),
+        0,
+        CalculatorFloat::from("sin(x)"),
+        HashMap::from([("x".to_string(), ("angles".to_string(), 1))]),
+    )
+}
+
+/// Test classical arithmetic PRAGMA Operate and Substitute traits
+#[test_case(Operation::from(PragmaBitwise::new(BitwiseOperator::And, "a".to_string(), 0, "b".to_string(), 0, "c".to_string(), 0, 2)), "PragmaBitwise"; "PragmaBitwiseAnd")]
+#[test_case(Operation::from(PragmaBitwise::new(BitwiseOperator::Or, "a".to_string(), 0, "b".to_string(), 0, "c".to_string(), 0, 2)), "PragmaBitwise"; "PragmaBitwiseOr")]
+#[test_case(Operation::from(PragmaBitwise::new(BitwiseOperator::Xor, "a".to_string(), 0, "b".to_string(), 0, "c".to_string(), 0, 2)), "PragmaBitwise"; "PragmaBitwiseXor")]
+#[test_case(Operation::from(PragmaParity::new("a".to_string(), vec![0, 1], "c".to_string(), 0)), "PragmaParity"; "PragmaParity")]
+#[test_case(Operation::from(set_float_example()), "PragmaSetFloat"; "PragmaSetFloat")]
+fn pragma_classical_arithmetic_operate_substitute(operation: Operation, name: &str) {
+    assert_eq!(operation.tags(), &["Operation", "PragmaOperation", name]);
+    assert_eq!(operation.hqslang(), name);
+    assert!(!operation.is_parametrized());
+    assert!(format!("{operation:?}").contains(name));
+
+    let mapping: HashMap<usize, usize> = HashMap::from([(0, 1), (1, 0)]);
+    assert_eq!(operation.remap_qubits(&mapping).unwrap(), operation);
+    assert_eq!(
+        operation.substitute_parameters(&Calculator::new()).unwrap(),
+        operation
+    );
+}
+
+/// Test BitwiseOperator string conversion
+#[test_case(BitwiseOperator::And, "AND", [false, false, false, true]; "And")]
+#[test_case(BitwiseOperator::Or, "OR", [false, true, true, true]; "Or")]
+#[test_case(BitwiseOperator::Xor, "XOR", [false, true, true, false]; "Xor")]
+fn bitwise_operator(operator: BitwiseOperator, name: &str, table: [bool; 4]) {
+    assert_eq!(operator.to_string(), name);
+    assert_eq!(name.parse::<BitwiseOperator>(), Ok(operator));
+    assert_eq!(operator.apply(false, false), table[0]);
+    assert_eq!(operator.apply(false, true), table[1]);
+    assert_eq!(operator.apply(true, false), table[2]);
+    assert_eq!(operator.apply(true, true), table[3]);
+    assert!("NAND".parse::<BitwiseOperator>().is_err());
+}
+
+/// Test that overflowing ranges of the bitwise PRAGMA are rejected
+#[test]
+fn pragma_bitwise_overflow() {
+    let registers = classical_arithmetic_registers();
+    let pragma = PragmaBitwise::new(
+        BitwiseOperator::And,
+        "a".to_string(),
+        usize::MAX,
+        "b".to_string(),
+        0,
+        "out".to_string(),
+        0,
+        2,
+    );
+    assert!(pragma.evaluate(&registers).is_err());
+    assert_eq!(
+        pragma.involved_classical(),
+        InvolvedClassical::Set(HashSet::from([
+            ("b".to_string(), 0),
+            ("b".to_string(), 1),
+            ("out".to_string(), 0),
+            ("out".to_string(), 1),
+        ]))
+    );
+    let pragma = PragmaBitwise::new(
+        BitwiseOperator::Or,
+        "a".to_string(),
+        0,
+        "b".to_string(),
+        0,
+        "out".to_string(),
+        usize::MAX,
+        2,
+    );
+    assert!(pragma.evaluate(&registers).is_err());
+}
+
+/// Test free parameters in the expression of PragmaSetFloat
+#[test]
+fn pragma_set_float_free_parameters() {
+    let variables: HashMap<String, (String, usize)> =
+        HashMap::from([("x".to_string(), ("angles".to_string(), 1))]);
+    let pragma = PragmaSetFloat::new(
+        "angles".to_string(),
+        0,
+        CalculatorFloat::from("theta * x + phi"),
+        variables.clone(),
+    );
+    assert!(pragma.is_parametrized());
+    assert_eq!(
+        pragma.symbols(),
+        HashSet::from(["theta".to_string(), "phi".to_string()])
+    );
+
+    let bound = pragma
+        .bind_parameters(&HashMap::from([
+            ("theta".to_string(), 2.0),
+            ("x".to_string(), 5.0),
+        ]))
+        .unwrap();
+    assert_eq!(bound.symbols(), HashSet::from(["phi".to_string()]));
+    assert_eq!(bound.variables(), &variables);
+
+    let renamed = pragma
+        .rename_parameters(&HashMap::from([
+            ("theta".to_string(), "alpha".to_string()),
+            ("x".to_string(), "y".to_string()),
+        ]))
+        .unwrap();
+    assert_eq!(
+        renamed.symbols(),
+        HashSet::from(["alpha".to_string(), "phi".to_string()])
+    );
+
+    let mut calculator = Calculator::new();
+    calculator.set_variable("theta", 2.0);
+    assert!(pragma.substitute_parameters(&calculator).is_err());
+    calculator.set_variable("phi", 0.5);
+    calculator.set_variable("x", 7.0);
+    let substituted = pragma.substitute_parameters(&calculator).unwrap();
+    assert!(!substituted.is_parametrized());
+    let registers: HashMap<String, Vec<bool>> = HashMap::new();
+    let floats: HashMap<String, Vec<f64>> =
+        HashMap::from([("angles".to_string(), vec![0.0, 0.25])]);
+    assert_eq!(substituted.evaluate(&registers, &floats), Ok(1.0));
+    assert!(pragma.evaluate(&registers, &floats).is_err());
+}
+
+/// Test that operations without symbols are returned unchanged by the default bind_parameters and rename_parameters
+#[test_case(Operation::from(PragmaRepeatedMeasurement::new("ro".to_string(), 20, None)); "PragmaRepeatedMeasurement")]
+#[test_case(Operation::from(PragmaStartDecompositionBlock::new(vec![0, 1], HashMap::from([(0, 1), (1, 0)]))); "PragmaStartDecompositionBlock")]
+#[test_case(Operation::from(PragmaSetBit::new("ro".to_string(), 0, control_flow_condition())); "PragmaSetBit")]
+#[test_case(Operation::from(ControlledSWAP::new(0, 1, 2)); "ControlledSWAP")]
+fn default_bind_rename_without_symbols(operation: Operation) {
+    let values: HashMap<String, f64> = HashMap::from([("theta".to_string(), 0.5)]);
+    assert_eq!(operation.bind_parameters(&values), Ok(operation.clone()));
+    let mapping: HashMap<String, String> =
+        HashMap::from([("theta".to_string(), "phi".to_string())]);
+    assert_eq!(operation.rename_parameters(&mapping), Ok(operation.clone()));
+}
+
+/// Test classical arithmetic PRAGMA serialization round trip and JsonSchema
+#[cfg(feature = "serialize")]
+#[test]
+fn pragma_classical_arithmetic_serde() {
+    let operations: Vec<Operation> = vec![
+        PragmaBitwise::new(
+            BitwiseOperator::And,
+            "a".to_string(),
+            0,
+            "b".to_string(),
+            1,
+            "c".to_string(),
+            2,
+            3,
+        )
+        .into(),
+        PragmaBitwise::new(
+            BitwiseOperator::Or,
+            "a".to_string(),
+            0,
+            "b".to_string(),
+            1,
+            "c".to_string(),
+            2,
+            3,
+        )
+        .into(),
+        PragmaBitwise::new(
+            BitwiseOperator::Xor,
+            "a".to_string(),
+            0,
+            "b".to_string(),
+            1,
+            "c".to_string(),
+            2,
+            3,
+        )
+        .into(),
+        PragmaParity::new("a".to_string(), vec![0, 3], "c".to_string(), 1).into(),
+        set_float_example().into(),
+    ];
+    for operation in operations {
+        let json = serde_json::to_string(&operation).unwrap();
+        let deserialized: Operation = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, operation);
+        let encoded = serialize(&operation).unwrap();
+        let decoded: Operation = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded, operation);
+    }
+
+    let pragma = PragmaParity::new("a".to_string(), vec![0, 3], "c".to_string(), 1);
+    assert_tokens(
+        &pragma.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaParity",
+                len: 4,
+            },
+            Token::Str("register"),
+            Token::Str("a"),
+            Token::Str("indices"),
+            Token::Seq { len: Some(2) },
+            Token::U64(0),
+            Token::U64(3),
+            Token::SeqEnd,
+            Token::Str("target_register"),
+            Token::Str("c"),
+            Token::Str("target_index"),
+            Token::U64(1),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test classical arithmetic PRAGMA JsonSchema trait
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_classical_arithmetic_json_schema() {
+    let operations: Vec<(serde_json::Value, schemars::schema::RootSchema)> = vec![
+        (
+            serde_json::to_value(PragmaBitwise::new(
+                BitwiseOperator::Xor,
+                "a".to_string(),
+                0,
+                "b".to_string(),
+                1,
+                "c".to_string(),
+                2,
+                3,
+            ))
+            .unwrap(),
+            schema_for!(PragmaBitwise),
+        ),
+        (
+            serde_json::to_value(PragmaParity::new(
+                "a".to_string(),
+                vec![0, 3],
+                "c".to_string(),
+                1,
+            ))
+            .unwrap(),
+            schema_for!(PragmaParity),
+        ),
+        (
+            serde_json::to_value(set_float_example()).unwrap(),
+            schema_for!(PragmaSetFloat),
+        ),
+    ];
+    for (test_value, test_schema) in operations {
+        let schema = serde_json::to_string(&test_schema).unwrap();
+        let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let compiled_schema = Validator::options()
+            .with_draft(Draft::Draft7)
+            .build(&schema_value)
+            .unwrap();
+        assert!(compiled_schema.validate(&test_value).is_ok());
+    }
+}
+
 /// Test PragmaChangeDevice inputs and involved qubits
 #[test]
 #[cfg(feature = "serialize")]
@@ -256,6 +256,19 @@ fn test_version_1_8_0_pragmas(operation: operations::Operation) {
     assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 8, 0));
 }
 
+#[test_case(operations::Operation::from(operations::PragmaIfElse::new(operations::BooleanExpression::Constant(true), roqoqo::Circuit::new(), roqoqo::Circuit::new())); "PragmaIfElse")]
+#[test_case(operations::Operation::from(operations::PragmaWhileLoop::new(operations::BooleanExpression::Bit("ro".to_string(), 0), roqoqo::Circuit::new(), 3)); "PragmaWhileLoop")]
+#[test_case(operations::Operation::from(operations::PragmaSetBit::new("ro".to_string(), 0, operations::BooleanExpression::Constant(false))); "PragmaSetBit")]
+#[test_case(operations::Operation::from(operations::MeasureQubitAndReset::new(0, "ro".to_string(), 0)); "MeasureQubitAndReset")]
+#[test_case(operations::Operation::from(operations::PragmaBitwise::new(operations::BitwiseOperator::And, "a".to_string(), 0, "b".to_string(), 0, "c".to_string(), 0, 1)); "PragmaBitwiseAnd")]
+#[test_case(operations::Operation::from(operations::PragmaBitwise::new(operations::BitwiseOperator::Or, "a".to_string(), 0, "b".to_string(), 0, "c".to_string(), 0, 1)); "PragmaBitwiseOr")]
+#[test_case(operations::Operation::from(operations::PragmaBitwise::new(operations::BitwiseOperator::Xor, "a".to_string(), 0, "b".to_string(), 0, "c".to_string(), 0, 1)); "PragmaBitwiseXor")]
+#[test_case(operations::Operation::from(operations::PragmaParity::new("a".to_string(), vec![0], "c".to_string(), 0)); "PragmaParity")]
+#[test_case(operations::Operation::from(operations::PragmaSetFloat::new("f".to_string(), 0, CalculatorFloat::from("1.0"), std::collections::HashMap::new())); "PragmaSetFloat")]
+fn test_version_1_21_0_pragmas(operation: operations::Operation) {
+    assert_eq!(operation.minimum_supported_roqoqo_version(), (1, 21, 0));
+}
+
 #[cfg(feature = "circuitdag")]
 #[test_case(roqoqo::Circuit::from_iter(vec![
     operations::Operation::from(operations::DefinitionBit::new("ro".to_string(), 2, true))
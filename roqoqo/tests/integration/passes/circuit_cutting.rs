@@ -0,0 +1,193 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for the circuit cutting pass
+
+use roqoqo::devices::GenericDevice;
+use roqoqo::operations::*;
+use roqoqo::passes::{cut_circuit, CircuitFragment, CutGate, CutGateSide};
+use roqoqo::Circuit;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use test_case::test_case;
+
+#[test]
+fn test_cut_chain() {
+    // ISwap gates are never cut, so the wire of qubit 1 is cut
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += ISwap::new(1, 2);
+    circuit += PragmaGlobalPhase::new(1.0.into());
+    circuit += RotateZ::new(2, 0.5.into());
+
+    let cut = cut_circuit(&circuit, &GenericDevice::new(2)).unwrap();
+    assert_eq!(cut.cut_qubits, vec![1]);
+    let mut first = Circuit::new();
+    first += Hadamard::new(0);
+    first += CNOT::new(0, 1);
+    let mut second = Circuit::new();
+    second += ISwap::new(1, 0);
+    second += RotateZ::new(0, 0.5.into());
+    assert_eq!(
+        cut.fragments,
+        vec![
+            CircuitFragment {
+                circuit: first,
+                number_qubits: 2,
+                input_cuts: vec![],
+                output_cuts: vec![(0, 1)],
+                output_qubits: HashMap::from([(0, 0)]),
+                gate_cuts: vec![],
+            },
+            CircuitFragment {
+                circuit: second,
+                number_qubits: 2,
+                input_cuts: vec![(0, 1)],
+                output_cuts: vec![],
+                output_qubits: HashMap::from([(1, 1), (2, 0)]),
+                gate_cuts: vec![],
+            }
+        ]
+    );
+    assert!(cut.cut_gates.is_empty());
+
+    // Enough qubits on the device leave the circuit uncut
+    let cut = cut_circuit(&circuit, &GenericDevice::new(3)).unwrap();
+    assert!(cut.cut_qubits.is_empty());
+    assert_eq!(cut.fragments.len(), 1);
+    assert_eq!(cut.fragments[0].number_qubits, 3);
+}
+
+#[test]
+fn test_cut_rejoined_wire() {
+    // The wire of qubit 0 is cut twice and its last segment rejoins the first fragment
+    let mut circuit = Circuit::new();
+    circuit += CNOT::new(0, 1);
+    circuit += CNOT::new(2, 3);
+    circuit += ISwap::new(0, 2);
+    circuit += ISwap::new(1, 0);
+
+    let cut = cut_circuit(&circuit, &GenericDevice::new(3)).unwrap();
+    assert_eq!(cut.cut_qubits, vec![0, 0]);
+    assert_eq!(cut.fragments.len(), 2);
+    assert!(cut
+        .fragments
+        .iter()
+        .all(|fragment| fragment.number_qubits <= 3));
+    let fragment = &cut.fragments[0];
+    assert_eq!(fragment.number_qubits, 3);
+    assert_eq!(fragment.output_cuts, vec![(0, 0)]);
+    assert_eq!(fragment.input_cuts, vec![(1, 2)]);
+    assert_eq!(fragment.output_qubits, HashMap::from([(0, 2), (1, 1)]));
+    let fragment = &cut.fragments[1];
+    assert_eq!(fragment.input_cuts, vec![(0, 2)]);
+    assert_eq!(fragment.output_cuts, vec![(1, 2)]);
+    assert_eq!(fragment.output_qubits, HashMap::from([(2, 0), (3, 1)]));
+}
+
+#[test]
+fn test_cut_gate() {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += CNOT::new(1, 2);
+    circuit += RotateZ::new(2, 0.5.into());
+
+    let cut = cut_circuit(&circuit, &GenericDevice::new(2)).unwrap();
+    assert!(cut.cut_qubits.is_empty());
+    assert_eq!(
+        cut.cut_gates,
+        vec![CutGate {
+            control: 1,
+            target: 2,
+            angle: PI / 4.0
+        }]
+    );
+    // CNOT = H_t CZ H_t and CZ is exp(i π/4 Z⊗Z) up to Z rotations and a global phase
+    let mut first = Circuit::new();
+    first += Hadamard::new(0);
+    first += CNOT::new(0, 1);
+    first += RotateZ::new(1, (PI / 2.0).into());
+    let mut second = Circuit::new();
+    second += Hadamard::new(0);
+    second += RotateZ::new(0, (PI / 2.0).into());
+    second += Hadamard::new(0);
+    second += RotateZ::new(0, 0.5.into());
+    assert_eq!(
+        cut.fragments,
+        vec![
+            CircuitFragment {
+                circuit: first,
+                number_qubits: 2,
+                input_cuts: vec![],
+                output_cuts: vec![],
+                output_qubits: HashMap::from([(0, 0), (1, 1)]),
+                gate_cuts: vec![CutGateSide {
+                    cut: 0,
+                    side: 0,
+                    qubit: 1,
+                    position: 2
+                }],
+            },
+            CircuitFragment {
+                circuit: second,
+                number_qubits: 1,
+                input_cuts: vec![],
+                output_cuts: vec![],
+                output_qubits: HashMap::from([(2, 0)]),
+                gate_cuts: vec![CutGateSide {
+                    cut: 0,
+                    side: 1,
+                    qubit: 0,
+                    position: 1
+                }],
+            }
+        ]
+    );
+}
+
+#[test_case(Operation::from(ControlledPauliZ::new(1, 2)), PI / 4.0; "controlled pauli z")]
+#[test_case(Operation::from(ControlledPhaseShift::new(1, 2, 1.0.into())), 0.25; "controlled phase shift")]
+fn test_cut_controlled_phase(operation: Operation, angle: f64) {
+    let mut circuit = Circuit::new();
+    circuit += CNOT::new(0, 1);
+    circuit += operation;
+    let cut = cut_circuit(&circuit, &GenericDevice::new(2)).unwrap();
+    assert!(cut.cut_qubits.is_empty());
+    assert_eq!(cut.cut_gates.len(), 1);
+    assert!((cut.cut_gates[0].angle - angle).abs() < 1e-12);
+    assert_eq!(cut.fragments[1].circuit.len(), 1);
+}
+
+#[test]
+fn test_cut_symbolic_phase_shift_wire() {
+    // The angle of a symbolic controlled phase shift is not known, so its wire is cut
+    let mut circuit = Circuit::new();
+    circuit += CNOT::new(0, 1);
+    circuit += ControlledPhaseShift::new(1, 2, "theta".into());
+    let cut = cut_circuit(&circuit, &GenericDevice::new(2)).unwrap();
+    assert_eq!(cut.cut_qubits, vec![1]);
+    assert!(cut.cut_gates.is_empty());
+}
+
+#[test_case(Operation::from(MeasureQubit::new(0, "ro".to_string(), 0)); "measurement")]
+#[test_case(Operation::from(PragmaRepeatedMeasurement::new("ro".to_string(), 10, None)); "all qubits")]
+#[test_case(Operation::from(PragmaSetBit::new("ro".to_string(), 0, BooleanExpression::Constant(true))); "classical")]
+#[test_case(Operation::from(Toffoli::new(0, 1, 2)); "too wide")]
+fn test_cut_errors(operation: Operation) {
+    let mut circuit = Circuit::new();
+    circuit += CNOT::new(0, 1);
+    circuit += operation;
+    assert!(cut_circuit(&circuit, &GenericDevice::new(2)).is_err());
+}
@@ -0,0 +1,159 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for circuit statistics
+
+#[cfg(feature = "json_schema")]
+use jsonschema::{Draft, Validator};
+use roqoqo::devices::GenericDevice;
+use roqoqo::noise_models::{
+    ContinuousDecoherenceModel, DecoherenceOnGateModel, ImperfectReadoutModel, NoiseModel,
+};
+use roqoqo::operations::*;
+use roqoqo::passes::CircuitStatistics;
+use roqoqo::Circuit;
+#[cfg(feature = "json_schema")]
+use schemars::schema_for;
+use std::collections::HashMap;
+use struqture::prelude::*;
+use struqture::spins::{PlusMinusLindbladNoiseOperator, PlusMinusProduct};
+use test_case::test_case;
+
+fn test_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += DefinitionFloat::new("fl".to_string(), 3, false);
+    circuit += Hadamard::new(0);
+    circuit += TGate::new(0);
+    circuit += InvTGate::new(2);
+    circuit += CNOT::new(0, 1);
+    circuit += RotateZ::new(1, "theta".into());
+    circuit += CNOT::new(1, 2);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    circuit
+}
+
+// All qubits have a damping rate of 0.01, the circuit takes a time of 7.0
+fn test_device() -> GenericDevice {
+    let mut device = GenericDevice::new(3);
+    for qubit in 0..3 {
+        for gate in ["Hadamard", "TGate", "InvTGate", "RotateZ"] {
+            device.set_single_qubit_gate_time(gate, qubit, 1.0).unwrap();
+        }
+        device.add_damping(qubit, 0.01).unwrap();
+    }
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 2.0).unwrap();
+    device.set_two_qubit_gate_time("CNOT", 1, 2, 2.0).unwrap();
+    device
+}
+
+#[test]
+fn test_structural_statistics() {
+    let statistics = CircuitStatistics::new(&test_circuit());
+    assert_eq!(statistics.number_operations, 10);
+    assert_eq!(statistics.depth, 6);
+    assert_eq!(statistics.two_qubit_depth, 2);
+    assert_eq!(
+        statistics.gate_counts,
+        HashMap::from([
+            ("Hadamard".to_string(), 1),
+            ("TGate".to_string(), 1),
+            ("InvTGate".to_string(), 1),
+            ("CNOT".to_string(), 2),
+            ("RotateZ".to_string(), 1),
+        ])
+    );
+    assert_eq!(
+        statistics.qubit_gate_counts,
+        HashMap::from([(0, 3), (1, 3), (2, 2)])
+    );
+    assert_eq!(statistics.t_count, 2);
+    assert_eq!(statistics.number_measurements, 2);
+    assert_eq!(statistics.number_parametric_operations, 1);
+    assert_eq!(
+        statistics.classical_registers,
+        HashMap::from([("ro".to_string(), 2), ("fl".to_string(), 3)])
+    );
+    assert_eq!(statistics.estimated_runtime, None);
+    assert_eq!(statistics.estimated_fidelity, None);
+}
+
+#[test]
+fn test_empty_circuit() {
+    let statistics = CircuitStatistics::new(&Circuit::new());
+    assert_eq!(statistics.number_operations, 0);
+    assert_eq!(statistics.depth, 0);
+    assert_eq!(statistics.two_qubit_depth, 0);
+    assert!(statistics.gate_counts.is_empty());
+}
+
+#[test_case(None, 0.21; "device")]
+#[test_case(Some(ContinuousDecoherenceModel::new().add_damping_rate(&[0, 1, 2, 5], 0.02).into()), 0.21 + 0.42; "continuous")]
+#[test_case(Some(ImperfectReadoutModel::new_with_uniform_error(3, 0.1, 0.1).unwrap().into()), 0.21; "readout")]
+fn test_estimates(noise_model: Option<NoiseModel>, exponent: f64) {
+    let statistics =
+        CircuitStatistics::with_device(&test_circuit(), &test_device(), noise_model.as_ref())
+            .unwrap();
+    assert_eq!(statistics.estimated_runtime, Some(7.0));
+    assert!((statistics.estimated_fidelity.unwrap() - (-exponent).exp()).abs() < 1e-10);
+    assert_eq!(statistics.depth, 6);
+}
+
+#[test]
+fn test_gate_noise_estimate() {
+    let mut noise = PlusMinusLindbladNoiseOperator::new();
+    noise
+        .add_operator_product(
+            (PlusMinusProduct::new().z(0), PlusMinusProduct::new().z(0)),
+            0.1.into(),
+        )
+        .unwrap();
+    let noise_model: NoiseModel = DecoherenceOnGateModel::new()
+        .set_two_qubit_gate_error("CNOT", 0, 1, noise)
+        .into();
+    let statistics =
+        CircuitStatistics::with_device(&test_circuit(), &test_device(), Some(&noise_model))
+            .unwrap();
+    assert!((statistics.estimated_fidelity.unwrap() - (-0.21_f64 - 0.2).exp()).abs() < 1e-10);
+}
+
+#[test]
+fn test_missing_gate_time() {
+    let mut circuit = test_circuit();
+    circuit += PauliX::new(0);
+    assert!(CircuitStatistics::with_device(&circuit, &test_device(), None).is_err());
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_serde() {
+    let statistics = CircuitStatistics::with_device(&test_circuit(), &test_device(), None).unwrap();
+    let serialized = serde_json::to_string(&statistics).unwrap();
+    let deserialized: CircuitStatistics = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, statistics);
+}
+
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_json_schema() {
+    let statistics = CircuitStatistics::with_device(&test_circuit(), &test_device(), None).unwrap();
+    let test_value = serde_json::to_value(&statistics).unwrap();
+    let test_schema = schema_for!(CircuitStatistics);
+    let schema = serde_json::to_string(&test_schema).unwrap();
+    let schema_value: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    let compiled_schema = Validator::options()
+        .with_draft(Draft::Draft7)
+        .build(&schema_value)
+        .unwrap();
+    assert!(compiled_schema.validate(&test_value).is_ok());
+}
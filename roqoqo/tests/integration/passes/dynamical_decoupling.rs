@@ -0,0 +1,154 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for scheduling and dynamical decoupling passes
+
+use roqoqo::devices::GenericDevice;
+use roqoqo::operations::*;
+use roqoqo::passes::{dynamical_decoupling, schedule_circuit, DecouplingSequence};
+use roqoqo::Circuit;
+use test_case::test_case;
+
+fn test_device() -> GenericDevice {
+    let mut device = GenericDevice::new(2);
+    for qubit in 0..2 {
+        device
+            .set_single_qubit_gate_time("RotateX", qubit, 10.0)
+            .unwrap();
+        device
+            .set_single_qubit_gate_time("PauliX", qubit, 1.0)
+            .unwrap();
+        device
+            .set_single_qubit_gate_time("PauliY", qubit, 1.0)
+            .unwrap();
+    }
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 2.0).unwrap();
+    device
+}
+
+// Qubit 1 is idle for 10.0 between the two CNOTs
+fn test_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += CNOT::new(0, 1);
+    circuit += RotateX::new(0, 1.0.into());
+    circuit += CNOT::new(0, 1);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    circuit
+}
+
+#[test]
+fn test_schedule() {
+    let schedule = schedule_circuit(&test_circuit(), &test_device()).unwrap();
+    let times: Vec<(f64, f64)> = schedule
+        .iter()
+        .map(|s| (s.start_time, s.end_time))
+        .collect();
+    assert_eq!(
+        times,
+        vec![
+            (0.0, 0.0),
+            (0.0, 2.0),
+            (2.0, 12.0),
+            (12.0, 14.0),
+            (14.0, 14.0)
+        ]
+    );
+    assert_eq!(schedule[4].qubits, (0..2).collect());
+}
+
+#[test]
+fn test_schedule_sleep_and_missing_gate() {
+    let mut circuit = Circuit::new();
+    circuit += PragmaSleep::new(vec![0], 3.0.into());
+    circuit += PauliX::new(0);
+    let schedule = schedule_circuit(&circuit, &test_device()).unwrap();
+    assert_eq!(schedule[1].start_time, 3.0);
+    assert_eq!(schedule[1].end_time, 4.0);
+
+    let mut circuit = Circuit::new();
+    circuit += PragmaSleep::new(vec![0], "t".into());
+    assert!(schedule_circuit(&circuit, &test_device()).is_err());
+
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    assert!(schedule_circuit(&circuit, &test_device()).is_err());
+}
+
+#[test_case(DecouplingSequence::XX, vec![PauliX::new(1).into(), PauliX::new(1).into()]; "XX")]
+#[test_case(DecouplingSequence::XY4, vec![PauliX::new(1).into(), PauliY::new(1).into(), PauliX::new(1).into(), PauliY::new(1).into()]; "XY4")]
+#[test_case(DecouplingSequence::CPMG(2), vec![PauliY::new(1).into(), PauliY::new(1).into()]; "CPMG")]
+fn test_decoupling_sequences(sequence: DecouplingSequence, pulses: Vec<Operation>) {
+    let device = test_device();
+    let new_circuit = dynamical_decoupling(&test_circuit(), &device, sequence).unwrap();
+
+    let number_pulses = pulses.len() as f64;
+    let spacing = (10.0 - number_pulses) / number_pulses;
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 2, true);
+    expected += CNOT::new(0, 1);
+    expected += RotateX::new(0, 1.0.into());
+    for (index, pulse) in pulses.into_iter().enumerate() {
+        let sleep = if index == 0 { spacing / 2.0 } else { spacing };
+        expected += PragmaSleep::new(vec![1], sleep.into());
+        expected.add_operation(pulse);
+    }
+    expected += PragmaSleep::new(vec![1], (spacing / 2.0).into());
+    expected += CNOT::new(0, 1);
+    expected += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    assert_eq!(new_circuit, expected);
+
+    // The schedule of the original operations is not changed
+    let schedule = schedule_circuit(&new_circuit, &device).unwrap();
+    let last_cnot = schedule
+        .iter()
+        .rfind(|s| s.operation == CNOT::new(0, 1).into())
+        .unwrap();
+    assert_eq!(last_cnot.start_time, 12.0);
+}
+
+#[test]
+fn test_window_too_short() {
+    let mut device = test_device();
+    device
+        .set_single_qubit_gate_time("RotateX", 0, 3.0)
+        .unwrap();
+    let new_circuit =
+        dynamical_decoupling(&test_circuit(), &device, DecouplingSequence::XY4).unwrap();
+    assert_eq!(new_circuit, test_circuit());
+    let new_circuit =
+        dynamical_decoupling(&test_circuit(), &device, DecouplingSequence::XX).unwrap();
+    assert_eq!(new_circuit.len(), test_circuit().len() + 5);
+}
+
+#[test]
+fn test_pulse_not_on_device() {
+    let mut device = GenericDevice::new(2);
+    device
+        .set_single_qubit_gate_time("RotateX", 0, 10.0)
+        .unwrap();
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 2.0).unwrap();
+    let new_circuit =
+        dynamical_decoupling(&test_circuit(), &device, DecouplingSequence::XX).unwrap();
+    assert_eq!(new_circuit, test_circuit());
+}
+
+#[test_case(0; "zero")]
+#[test_case(3; "odd")]
+fn test_invalid_cpmg(number_pulses: usize) {
+    let result = dynamical_decoupling(
+        &test_circuit(),
+        &test_device(),
+        DecouplingSequence::CPMG(number_pulses),
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,139 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the fidelity estimation
+
+use roqoqo::devices::GenericDevice;
+use roqoqo::noise_models::DecoherenceOnGateModel;
+use roqoqo::operations::*;
+use roqoqo::passes::estimate_fidelity;
+use roqoqo::Circuit;
+use struqture::prelude::*;
+use struqture::spins::{PlusMinusLindbladNoiseOperator, PlusMinusProduct};
+
+fn test_device() -> GenericDevice {
+    let mut device = GenericDevice::new(3);
+    for qubit in 0..3 {
+        device
+            .set_single_qubit_gate_time("RotateX", qubit, 10.0)
+            .unwrap();
+    }
+    device.set_two_qubit_gate_time("CNOT", 0, 1, 2.0).unwrap();
+    device.add_damping(0, 0.01).unwrap();
+    device.add_damping(1, 0.02).unwrap();
+    device
+}
+
+// Qubit 1 is idle for 10.0 between the two CNOTs, the circuit takes a time of 14.0
+fn test_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += CNOT::new(0, 1);
+    circuit += RotateX::new(0, 1.0.into());
+    circuit += CNOT::new(0, 1);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    circuit
+}
+
+fn cnot_noise() -> DecoherenceOnGateModel {
+    let mut noise = PlusMinusLindbladNoiseOperator::new();
+    noise
+        .add_operator_product(
+            (PlusMinusProduct::new().z(0), PlusMinusProduct::new().z(0)),
+            0.02.into(),
+        )
+        .unwrap();
+    noise
+        .add_operator_product(
+            (PlusMinusProduct::new().z(1), PlusMinusProduct::new().z(1)),
+            0.03.into(),
+        )
+        .unwrap();
+    DecoherenceOnGateModel::new().set_two_qubit_gate_error("CNOT", 0, 1, noise)
+}
+
+#[test]
+fn test_decoherence_and_idling() {
+    let estimate = estimate_fidelity(&test_circuit(), &test_device(), None).unwrap();
+    assert_eq!(estimate.runtime, 14.0);
+    assert_eq!(estimate.gate_infidelity, 0.0);
+    assert_eq!(estimate.qubit_infidelities.len(), 2);
+    assert!((estimate.qubit_infidelities[&0] - (1.0 - (-0.14_f64).exp())).abs() < 1e-10);
+    assert!((estimate.qubit_infidelities[&1] - (1.0 - (-0.28_f64).exp())).abs() < 1e-10);
+    assert!(estimate.idle_infidelities[&0].abs() < 1e-10);
+    assert!((estimate.idle_infidelities[&1] - (1.0 - (-0.2_f64).exp())).abs() < 1e-10);
+    assert!((estimate.fidelity - (-0.42_f64).exp()).abs() < 1e-10);
+}
+
+#[test]
+fn test_gate_errors() {
+    let estimate = estimate_fidelity(&test_circuit(), &test_device(), Some(&cnot_noise())).unwrap();
+    // Two CNOTs with a gate time of 2.0 and a total noise rate of 0.05
+    assert!((estimate.gate_infidelity - (1.0 - (-0.2_f64).exp())).abs() < 1e-10);
+    assert!((estimate.fidelity - (-0.62_f64).exp()).abs() < 1e-10);
+}
+
+#[test]
+fn test_four_qubit_gate_errors() {
+    let mut device = GenericDevice::new(4);
+    device
+        .set_multi_qubit_gate_time("TripleControlledPauliX", vec![0, 1, 2, 3], 2.0)
+        .unwrap();
+    let mut noise = PlusMinusLindbladNoiseOperator::new();
+    noise
+        .add_operator_product(
+            (PlusMinusProduct::new().z(3), PlusMinusProduct::new().z(3)),
+            0.05.into(),
+        )
+        .unwrap();
+    let gate_noise = DecoherenceOnGateModel::new().set_multi_qubit_gate_error(
+        "TripleControlledPauliX",
+        vec![0, 1, 2, 3],
+        noise,
+    );
+    let mut circuit = Circuit::new();
+    circuit += TripleControlledPauliX::new(0, 1, 2, 3);
+
+    let estimate = estimate_fidelity(&circuit, &device, Some(&gate_noise)).unwrap();
+    assert_eq!(estimate.runtime, 2.0);
+    assert!((estimate.gate_infidelity - (1.0 - (-0.1_f64).exp())).abs() < 1e-10);
+    assert!((estimate.fidelity - (-0.1_f64).exp()).abs() < 1e-10);
+}
+
+#[test]
+fn test_rank_compilations() {
+    // Executing the rotation on the qubit without decoherence is preferable
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(1, 1.0.into());
+    let worse = estimate_fidelity(&circuit, &test_device(), None).unwrap();
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(2, 1.0.into());
+    let better = estimate_fidelity(&circuit, &test_device(), None).unwrap();
+    assert!(better.fidelity > worse.fidelity);
+    assert_eq!(better.fidelity, 1.0);
+}
+
+#[test]
+fn test_missing_gate_time() {
+    let mut circuit = test_circuit();
+    circuit += PauliX::new(0);
+    assert!(estimate_fidelity(&circuit, &test_device(), None).is_err());
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_serde() {
+    let estimate = estimate_fidelity(&test_circuit(), &test_device(), Some(&cnot_noise())).unwrap();
+    let serialized = bincode::serialize(&estimate).unwrap();
+    let deserialized: roqoqo::passes::FidelityEstimate = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, estimate);
+}
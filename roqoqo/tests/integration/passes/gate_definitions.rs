@@ -0,0 +1,467 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for inlining and extracting gate definitions
+
+use ndarray::linalg::kron;
+use ndarray::Array2;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::operations::*;
+use roqoqo::passes::{extract_definitions, inline_definitions};
+use roqoqo::{Circuit, RoqoqoError};
+use test_case::test_case;
+
+fn rotation_definition() -> GateDefinition {
+    let mut body = Circuit::new();
+    body += RotateX::new(0, "theta".into());
+    body += CNOT::new(0, 1);
+    GateDefinition::new(
+        body,
+        "rotated_cnot".to_string(),
+        vec![0, 1],
+        vec!["theta".to_string()],
+    )
+}
+
+#[test]
+fn test_inline_definitions() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += rotation_definition();
+    circuit += CallDefinedGate::new("rotated_cnot".to_string(), vec![3, 1], vec![0.5.into()]);
+    circuit += PauliX::new(2);
+    circuit += CallDefinedGate::new("rotated_cnot".to_string(), vec![0, 3], vec![1.5.into()]);
+
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 1, true);
+    expected += RotateX::new(3, 0.5.into());
+    expected += CNOT::new(3, 1);
+    expected += PauliX::new(2);
+    expected += RotateX::new(0, 1.5.into());
+    expected += CNOT::new(0, 3);
+    assert_eq!(circuit.inline_definitions().unwrap(), expected);
+}
+
+#[test]
+fn test_inline_nested_definitions() {
+    // The outer definition calls the inner definition with a parameter expression
+    let mut body = Circuit::new();
+    body += Hadamard::new(1);
+    body += CallDefinedGate::new(
+        "rotated_cnot".to_string(),
+        vec![1, 0],
+        vec!["2 * phi".into()],
+    );
+    let outer = GateDefinition::new(
+        body,
+        "outer".to_string(),
+        vec![0, 1],
+        vec!["phi".to_string()],
+    );
+
+    let mut loop_circuit = Circuit::new();
+    loop_circuit += CallDefinedGate::new("outer".to_string(), vec![4, 2], vec![0.25.into()]);
+    let mut circuit = Circuit::new();
+    circuit += rotation_definition();
+    circuit += outer;
+    circuit += PragmaLoop::new(2.into(), loop_circuit);
+
+    let mut expected_loop = Circuit::new();
+    expected_loop += Hadamard::new(2);
+    expected_loop += RotateX::new(2, 0.5.into());
+    expected_loop += CNOT::new(2, 4);
+    let mut expected = Circuit::new();
+    expected += PragmaLoop::new(2.into(), expected_loop);
+    assert_eq!(inline_definitions(&circuit).unwrap(), expected);
+}
+
+#[test]
+fn test_inline_symbolic_parameters() {
+    // Symbols of the calls and symbols of the body that are not free parameters stay symbolic
+    let mut body = Circuit::new();
+    body += RotateZ::new(0, "theta + offset".into());
+    body += RotateX::new(1, "theta * phi".into());
+    let mut circuit = Circuit::new();
+    circuit += GateDefinition::new(
+        body,
+        "shifted".to_string(),
+        vec![0, 1],
+        vec!["theta".to_string(), "phi".to_string()],
+    );
+    circuit += CallDefinedGate::new(
+        "shifted".to_string(),
+        vec![2, 3],
+        vec!["2 * phi".into(), 0.5.into()],
+    );
+    circuit += CallDefinedGate::new(
+        "shifted".to_string(),
+        vec![0, 1],
+        vec![1.5.into(), (-2.0).into()],
+    );
+
+    let mut expected = Circuit::new();
+    expected += RotateZ::new(2, "(2 * phi) + offset".into());
+    expected += RotateX::new(3, "(2 * phi) * 0.5".into());
+    expected += RotateZ::new(0, "1.5 + offset".into());
+    expected += RotateX::new(1, (-3.0).into());
+    let inlined = circuit.inline_definitions().unwrap();
+    assert_eq!(inlined, expected);
+    assert_eq!(
+        inlined.symbols().unwrap(),
+        ["phi".to_string(), "offset".to_string()].into()
+    );
+}
+
+#[test]
+fn test_inline_cyclic_definitions() {
+    let mut body_a = Circuit::new();
+    body_a += CallDefinedGate::new("b".to_string(), vec![0], vec![]);
+    let mut body_b = Circuit::new();
+    body_b += PauliX::new(0);
+    body_b += CallDefinedGate::new("a".to_string(), vec![0], vec![]);
+    let mut circuit = Circuit::new();
+    circuit += GateDefinition::new(body_a, "a".to_string(), vec![0], vec![]);
+    circuit += GateDefinition::new(body_b, "b".to_string(), vec![0], vec![]);
+    circuit += CallDefinedGate::new("a".to_string(), vec![1], vec![]);
+    assert_eq!(
+        circuit.inline_definitions(),
+        Err(RoqoqoError::GenericError {
+            msg: "GateDefinitions call each other cyclically: a -> b -> a".to_string()
+        })
+    );
+}
+
+#[test_case(
+    CallDefinedGate::new("missing".to_string(), vec![0, 1], vec![0.5.into()]),
+    "GateDefinition missing called by CallDefinedGate is not defined";
+    "undefined")]
+#[test_case(
+    CallDefinedGate::new("rotated_cnot".to_string(), vec![0], vec![0.5.into()]),
+    "CallDefinedGate rotated_cnot acts on 1 qubits but the GateDefinition has 2 qubits";
+    "qubits")]
+#[test_case(
+    CallDefinedGate::new("rotated_cnot".to_string(), vec![0, 1], vec![]),
+    "CallDefinedGate rotated_cnot has 0 parameters but the GateDefinition has 1 free parameters";
+    "parameters")]
+#[test_case(
+    CallDefinedGate::new("rotated_cnot".to_string(), vec![1, 1], vec![0.5.into()]),
+    "CallDefinedGate rotated_cnot acts twice on qubit 1";
+    "repeated qubit")]
+#[test_case(
+    CallDefinedGate::new("rotated_cnot".to_string(), vec![0, 1], vec![f64::INFINITY.into()]),
+    "Parameter inf of CallDefinedGate rotated_cnot is not finite";
+    "not finite")]
+fn test_inline_errors(call: CallDefinedGate, msg: &str) {
+    let mut circuit = Circuit::new();
+    circuit += rotation_definition();
+    circuit += CallDefinedGate::new("rotated_cnot".to_string(), vec![0, 1], vec![0.5.into()]);
+    // Replacing an operation is not checked against the definitions
+    circuit[1] = call.into();
+    assert_eq!(
+        circuit.inline_definitions(),
+        Err(RoqoqoError::GenericError {
+            msg: msg.to_string()
+        })
+    );
+}
+
+#[test]
+fn test_extract_definitions() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += PauliX::new(2);
+    circuit += Hadamard::new(2);
+    circuit += CNOT::new(2, 0);
+    circuit += Hadamard::new(1);
+    circuit += CNOT::new(1, 2);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+
+    let compressed = extract_definitions(&circuit, 2, 3).unwrap();
+    let mut body = Circuit::new();
+    body += Hadamard::new(0);
+    body += CNOT::new(0, 1);
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 3, true);
+    expected += GateDefinition::new(body, "subcircuit_0".to_string(), vec![0, 1], vec![]);
+    expected += CallDefinedGate::new("subcircuit_0".to_string(), vec![0, 1], vec![]);
+    expected += PauliX::new(2);
+    expected += CallDefinedGate::new("subcircuit_0".to_string(), vec![2, 0], vec![]);
+    expected += CallDefinedGate::new("subcircuit_0".to_string(), vec![1, 2], vec![]);
+    expected += MeasureQubit::new(0, "ro".to_string(), 0);
+    assert_eq!(compressed, expected);
+    assert_eq!(compressed.inline_definitions().unwrap(), circuit);
+
+    // Blocks occurring less often than required are not extracted
+    assert_eq!(extract_definitions(&circuit, 2, 4).unwrap(), circuit);
+    assert!(extract_definitions(&circuit, 1, 2).is_err());
+    assert!(extract_definitions(&circuit, 2, 1).is_err());
+}
+
+#[test]
+fn test_extract_repeated_definitions() {
+    // Extraction is repeated until no block occurs often enough
+    let mut circuit = Circuit::new();
+    for qubit in 0..4 {
+        circuit += RotateZ::new(qubit, 0.1.into());
+        circuit += SqrtPauliX::new(qubit);
+    }
+    for qubit in 0..2 {
+        circuit += ControlledPauliZ::new(qubit, qubit + 2);
+        circuit += PauliY::new(qubit);
+    }
+    let compressed = extract_definitions(&circuit, 2, 2).unwrap();
+    assert_eq!(compressed.count_occurences(&["GateDefinition"]), 2);
+    assert_eq!(compressed.count_occurences(&["CallDefinedGate"]), 6);
+    assert_eq!(compressed.inline_definitions().unwrap(), circuit);
+}
+
+fn assert_matrix_close(left: &Array2<Complex64>, right: &Array2<Complex64>) {
+    assert_eq!(left.dim(), right.dim());
+    for (left, right) in left.iter().zip(right.iter()) {
+        assert!((left - right).norm() < 1e-12, "{left} != {right}");
+    }
+}
+
+#[test]
+fn test_definition_unitary() {
+    // The first qubit of the definition is the most significant qubit
+    let rotation = RotateX::new(0, 0.3.into()).unitary_matrix().unwrap();
+    let identity: Array2<Complex64> = Array2::eye(2);
+    let unitary = rotation_definition().unitary_matrix(&[0.3.into()]).unwrap();
+    let expected = CNOT::new(0, 1)
+        .unitary_matrix()
+        .unwrap()
+        .dot(&kron(&rotation, &identity));
+    assert_matrix_close(&unitary, &expected);
+
+    // Reversed qubits of the definition and a call from the body
+    let mut body = Circuit::new();
+    body += rotation_definition();
+    body += CallDefinedGate::new(
+        "rotated_cnot".to_string(),
+        vec![4, 2],
+        vec!["2 * phi".into()],
+    );
+    body += PragmaGlobalPhase::new(0.5.into());
+    let definition = GateDefinition::new(
+        body,
+        "outer".to_string(),
+        vec![2, 4],
+        vec!["phi".to_string()],
+    );
+    let call = CallDefinedGate::new("outer".to_string(), vec![0, 1], vec![0.15.into()]);
+    let unitary = call.unitary_matrix_from_definition(&definition).unwrap();
+    // The CNOT of the call is controlled by the least significant qubit
+    let swap = SWAP::new(0, 1).unitary_matrix().unwrap();
+    let reversed_cnot = swap
+        .dot(&CNOT::new(0, 1).unitary_matrix().unwrap())
+        .dot(&swap);
+    let expected = reversed_cnot.dot(&kron(&identity, &rotation)) * Complex64::new(0.0, 0.5).exp();
+    assert_matrix_close(&unitary, &expected);
+}
+
+#[test]
+fn test_definition_unitary_errors() {
+    let mut body = Circuit::new();
+    body += DefinitionBit::new("ro".to_string(), 1, true);
+    body += MeasureQubit::new(0, "ro".to_string(), 0);
+    let definition = GateDefinition::new(body, "measure".to_string(), vec![0], vec![]);
+    assert_eq!(
+        definition.unitary_matrix(&[]),
+        Err(RoqoqoError::GenericError {
+            msg: "Operation MeasureQubit in GateDefinition measure has no unitary matrix"
+                .to_string()
+        })
+    );
+
+    let call = CallDefinedGate::new("other".to_string(), vec![0], vec![]);
+    assert_eq!(
+        call.unitary_matrix_from_definition(&definition),
+        Err(RoqoqoError::GenericError {
+            msg: "CallDefinedGate other cannot use GateDefinition measure".to_string()
+        })
+    );
+    let call = CallDefinedGate::new("rotated_cnot".to_string(), vec![0, 1], vec![]);
+    assert_eq!(
+        call.unitary_matrix_from_definition(&rotation_definition()),
+        Err(RoqoqoError::GenericError {
+            msg: "CallDefinedGate rotated_cnot has 0 parameters but the GateDefinition has 1 free parameters".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_try_add_operation() {
+    let mut circuit = Circuit::new();
+    // Calls are accepted before their definition is added
+    circuit
+        .try_add_operation(CallDefinedGate::new(
+            "rotated_cnot".to_string(),
+            vec![0, 1],
+            vec![0.5.into()],
+        ))
+        .unwrap();
+    circuit.try_add_operation(rotation_definition()).unwrap();
+    assert_eq!(circuit.len(), 2);
+
+    assert_eq!(
+        circuit.try_add_operation(CallDefinedGate::new(
+            "rotated_cnot".to_string(),
+            vec![0, 1, 2],
+            vec![0.5.into()],
+        )),
+        Err(RoqoqoError::GenericError {
+            msg:
+                "CallDefinedGate rotated_cnot acts on 3 qubits but the GateDefinition has 2 qubits"
+                    .to_string()
+        })
+    );
+    assert_eq!(circuit.len(), 2);
+
+    let mut circuit = Circuit::new();
+    circuit += CallDefinedGate::new("rotated_cnot".to_string(), vec![0, 1], vec![]);
+    assert!(circuit.try_add_operation(rotation_definition()).is_err());
+    assert!(circuit.try_add_operation(PauliX::new(0)).is_ok());
+}
+
+#[test]
+fn test_try_add_operation_nested() {
+    let mut mismatching = Circuit::new();
+    mismatching += CallDefinedGate::new("rotated_cnot".to_string(), vec![0], vec![0.5.into()]);
+
+    // Calls in nested circuits are checked against the definitions of the Circuit
+    let mut circuit = Circuit::new();
+    circuit += rotation_definition();
+    assert!(circuit
+        .try_add_operation(PragmaLoop::new(2.0.into(), mismatching.clone()))
+        .is_err());
+    assert!(circuit
+        .try_add_operation(PragmaConditional::new(
+            "ro".to_string(),
+            0,
+            mismatching.clone()
+        ))
+        .is_err());
+
+    // Definitions are checked against the calls in nested circuits
+    let mut circuit = Circuit::new();
+    circuit += PragmaLoop::new(2.0.into(), mismatching.clone());
+    assert!(circuit.try_add_operation(rotation_definition()).is_err());
+
+    // A definition in the nested circuit shadows the definition of the Circuit
+    let mut shadowing = Circuit::new();
+    shadowing += GateDefinition::new(
+        Circuit::new(),
+        "rotated_cnot".to_string(),
+        vec![0],
+        vec!["theta".to_string()],
+    );
+    shadowing += mismatching[0].clone();
+    let mut circuit = Circuit::new();
+    circuit += rotation_definition();
+    assert!(circuit
+        .try_add_operation(PragmaLoop::new(2.0.into(), shadowing))
+        .is_ok());
+}
+
+#[test_case(vec![0], vec![0.5.into()], "CallDefinedGate rotated_cnot acts on 1 qubits but the GateDefinition has 2 qubits"; "qubits")]
+#[test_case(vec![0, 1], vec![], "CallDefinedGate rotated_cnot has 0 parameters but the GateDefinition has 1 free parameters"; "parameters")]
+fn test_try_add_operation_definition_body(
+    qubits: Vec<usize>,
+    parameters: Vec<CalculatorFloat>,
+    msg: &str,
+) {
+    let mut body = Circuit::new();
+    body += CallDefinedGate::new("rotated_cnot".to_string(), qubits, parameters);
+    let outer = GateDefinition::new(body, "outer".to_string(), vec![0, 1], vec![]);
+    let error = Err(RoqoqoError::GenericError {
+        msg: msg.to_string(),
+    });
+
+    // The calls in the body of the outer definition are checked against the inner definition
+    let mut circuit = Circuit::new();
+    circuit += rotation_definition();
+    assert_eq!(circuit.try_add_operation(outer.clone()), error);
+    assert_eq!(circuit.len(), 1);
+
+    // The inner definition is checked against the calls in the body of the outer definition
+    let mut circuit = Circuit::new();
+    circuit += outer;
+    assert_eq!(circuit.try_add_operation(rotation_definition()), error);
+    assert_eq!(circuit.len(), 1);
+}
+
+#[test]
+#[should_panic(
+    expected = "CallDefinedGate rotated_cnot acts on 1 qubits but the GateDefinition has 2 qubits"
+)]
+fn test_add_operation_mismatching_definition_body() {
+    let mut body = Circuit::new();
+    body += CallDefinedGate::new("rotated_cnot".to_string(), vec![0], vec![0.5.into()]);
+    let mut circuit = Circuit::new();
+    circuit += GateDefinition::new(body, "outer".to_string(), vec![0, 1], vec![]);
+    circuit += rotation_definition();
+}
+
+#[test]
+#[should_panic(
+    expected = "CallDefinedGate rotated_cnot acts on 1 qubits but the GateDefinition has 2 qubits"
+)]
+fn test_add_operation_mismatching_nested_call() {
+    let mut nested = Circuit::new();
+    nested += CallDefinedGate::new("rotated_cnot".to_string(), vec![0], vec![0.5.into()]);
+    let mut circuit = Circuit::new();
+    circuit += rotation_definition();
+    circuit += PragmaLoop::new(2.0.into(), nested);
+}
+
+#[test]
+#[should_panic(
+    expected = "CallDefinedGate rotated_cnot acts on 3 qubits but the GateDefinition has 2 qubits"
+)]
+fn test_add_operation_mismatching_call() {
+    let mut circuit = Circuit::new();
+    circuit += rotation_definition();
+    circuit += CallDefinedGate::new("rotated_cnot".to_string(), vec![0, 1, 2], vec![0.5.into()]);
+}
+
+#[test]
+#[should_panic(
+    expected = "CallDefinedGate rotated_cnot has 0 parameters but the GateDefinition has 1 free parameters"
+)]
+fn test_add_operation_mismatching_definition() {
+    let _circuit: Circuit = vec![
+        Operation::from(CallDefinedGate::new(
+            "rotated_cnot".to_string(),
+            vec![0, 1],
+            vec![],
+        )),
+        Operation::from(rotation_definition()),
+    ]
+    .into_iter()
+    .collect();
+}
+
+#[test]
+#[should_panic(
+    expected = "CallDefinedGate rotated_cnot acts on 1 qubits but the GateDefinition has 2 qubits"
+)]
+fn test_add_circuit_mismatching_call() {
+    let mut circuit = Circuit::new();
+    circuit += rotation_definition();
+    let mut other = Circuit::new();
+    other += CallDefinedGate::new("rotated_cnot".to_string(), vec![0], vec![0.5.into()]);
+    circuit += other;
+}
@@ -0,0 +1,22 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod circuit_cutting;
+#[cfg(feature = "circuitdag")]
+mod circuit_statistics;
+mod dynamical_decoupling;
+mod fidelity_estimation;
+#[cfg(feature = "unstable_operation_definition")]
+mod gate_definitions;
+mod qubit_reuse;
+#[cfg(feature = "unstable_analog_operations")]
+mod trotterization;
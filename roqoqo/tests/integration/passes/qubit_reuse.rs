@@ -0,0 +1,145 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+//
+//! Integration test for the qubit reuse pass
+
+use roqoqo::operations::*;
+use roqoqo::passes::reuse_qubits;
+use roqoqo::Circuit;
+use std::collections::HashMap;
+
+// Three sequential single-qubit experiments that only need one qubit at a time
+fn sequential_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    for qubit in 0..3 {
+        circuit += Hadamard::new(qubit);
+        circuit += MeasureQubitAndReset::new(qubit, "ro".to_string(), qubit);
+    }
+    circuit
+}
+
+#[test]
+fn test_reuse_sequential() {
+    let (circuit, mapping) = reuse_qubits(&sequential_circuit()).unwrap();
+    assert_eq!(mapping, HashMap::from([(0, 0), (1, 0), (2, 0)]));
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 3, true);
+    for index in 0..3 {
+        expected += Hadamard::new(0);
+        expected += MeasureQubitAndReset::new(0, "ro".to_string(), index);
+    }
+    assert_eq!(circuit, expected);
+    assert_eq!(circuit.number_of_qubits(), 1);
+    assert_eq!(circuit.qubit_reuses(), HashMap::from([(0, 2)]));
+    assert_eq!(
+        sequential_circuit().qubit_reuses(),
+        HashMap::from([(0, 0), (1, 0), (2, 0)])
+    );
+}
+
+#[test]
+fn test_reuse_overlapping() {
+    // Qubit 0 is freed by the active reset, qubit 1 is measured without reset and stays in use
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += CNOT::new(0, 1);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PragmaActiveReset::new(0);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += CNOT::new(3, 2);
+    circuit += MeasureQubit::new(2, "ro".to_string(), 2);
+
+    let (reused, mapping) = reuse_qubits(&circuit).unwrap();
+    assert_eq!(mapping, HashMap::from([(0, 0), (1, 1), (2, 0), (3, 2)]));
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 3, true);
+    expected += CNOT::new(0, 1);
+    expected += MeasureQubit::new(0, "ro".to_string(), 0);
+    expected += PragmaActiveReset::new(0);
+    expected += MeasureQubit::new(1, "ro".to_string(), 1);
+    expected += CNOT::new(2, 0);
+    expected += MeasureQubit::new(0, "ro".to_string(), 2);
+    assert_eq!(reused, expected);
+    assert_eq!(reused.number_of_qubits(), 3);
+    assert_eq!(
+        reused.qubit_reuses(),
+        HashMap::from([(0, 1), (1, 0), (2, 0)])
+    );
+}
+
+#[test]
+fn test_reuse_blocked_by_all_qubit_operation() {
+    let mut circuit = sequential_circuit();
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    let (reused, mapping) = reuse_qubits(&circuit).unwrap();
+    assert_eq!(reused, circuit);
+    assert_eq!(mapping, HashMap::from([(0, 0), (1, 1), (2, 2)]));
+    assert_eq!(
+        circuit.qubit_reuses(),
+        HashMap::from([(0, 1), (1, 1), (2, 1)])
+    );
+}
+
+#[test]
+fn test_reuse_single_gate_not_starting_at_zero() {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(1);
+    let (reused, mapping) = reuse_qubits(&circuit).unwrap();
+    assert_eq!(mapping, HashMap::from([(1, 0)]));
+    let mut expected = Circuit::new();
+    expected += Hadamard::new(0);
+    assert_eq!(reused, expected);
+}
+
+#[test]
+fn test_reuse_qubits_not_starting_at_zero() {
+    // Qubits 3 and 4 do not overlap in time and both end up on qubit 0
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(3, 0.5.into());
+    circuit += MeasureQubitAndReset::new(3, "ro".to_string(), 0);
+    circuit += PauliX::new(4);
+    circuit += MeasureQubitAndReset::new(4, "ro".to_string(), 1);
+
+    let (reused, mapping) = reuse_qubits(&circuit).unwrap();
+    assert_eq!(mapping, HashMap::from([(3, 0), (4, 0)]));
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 2, true);
+    expected += RotateX::new(0, 0.5.into());
+    expected += MeasureQubitAndReset::new(0, "ro".to_string(), 0);
+    expected += PauliX::new(0);
+    expected += MeasureQubitAndReset::new(0, "ro".to_string(), 1);
+    assert_eq!(reused, expected);
+    assert_eq!(reused.number_of_qubits(), 1);
+}
+
+#[test]
+fn test_reuse_two_qubit_gate_not_starting_at_zero() {
+    // The control is freed before the second pair of qubits is used
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += CNOT::new(5, 2);
+    circuit += MeasureQubitAndReset::new(5, "ro".to_string(), 0);
+    circuit += CNOT::new(7, 2);
+    circuit += MeasureQubit::new(7, "ro".to_string(), 1);
+
+    let (reused, mapping) = reuse_qubits(&circuit).unwrap();
+    assert_eq!(mapping, HashMap::from([(2, 0), (5, 1), (7, 1)]));
+    let mut expected = Circuit::new();
+    expected += DefinitionBit::new("ro".to_string(), 2, true);
+    expected += CNOT::new(1, 0);
+    expected += MeasureQubitAndReset::new(1, "ro".to_string(), 0);
+    expected += CNOT::new(1, 0);
+    expected += MeasureQubit::new(1, "ro".to_string(), 1);
+    assert_eq!(reused, expected);
+}
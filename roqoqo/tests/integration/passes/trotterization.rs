@@ -0,0 +1,327 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the Trotterization of analog operations
+
+use nalgebra::{DMatrix, DVector};
+use num_complex::Complex64;
+use roqoqo::operations::*;
+use roqoqo::passes::{
+    trotterize, trotterize_circuit, trotterize_time_dependent, TrotterGates, TrotterOrder,
+};
+use roqoqo::simulators::QubitResonatorState;
+use roqoqo::{Circuit, RoqoqoError};
+use std::collections::HashMap;
+use std::f64::consts::FRAC_PI_2;
+use struqture::prelude::*;
+use struqture::spins::{PauliHamiltonian, PauliProduct, SinglePauliOperator};
+use test_case::test_case;
+
+fn hamiltonian(terms: &[(PauliProduct, f64)]) -> PauliHamiltonian {
+    let mut hamiltonian = PauliHamiltonian::new();
+    for (product, coefficient) in terms.iter() {
+        hamiltonian
+            .add_operator_product(product.clone(), (*coefficient).into())
+            .unwrap();
+    }
+    hamiltonian
+}
+
+// Dense matrix of a Pauli Hamiltonian where qubit k is bit k of the basis index
+fn dense_hamiltonian(hamiltonian: &PauliHamiltonian, number_qubits: usize) -> DMatrix<Complex64> {
+    let dimension = 1 << number_qubits;
+    let mut matrix = DMatrix::<Complex64>::zeros(dimension, dimension);
+    for (product, coefficient) in hamiltonian.iter() {
+        let coefficient = *coefficient.float().unwrap();
+        for column in 0..dimension {
+            let mut row = column;
+            let mut value = Complex64::new(coefficient, 0.0);
+            for (qubit, pauli) in product.iter() {
+                let bit = (column >> qubit) & 1;
+                match pauli {
+                    SinglePauliOperator::X => row ^= 1 << qubit,
+                    SinglePauliOperator::Y => {
+                        row ^= 1 << qubit;
+                        value *= if bit == 0 {
+                            Complex64::new(0.0, 1.0)
+                        } else {
+                            Complex64::new(0.0, -1.0)
+                        };
+                    }
+                    SinglePauliOperator::Z => {
+                        if bit == 1 {
+                            value = -value
+                        }
+                    }
+                    SinglePauliOperator::Identity => (),
+                }
+            }
+            matrix[(row, column)] += value;
+        }
+    }
+    matrix
+}
+
+// Initial product state for the comparison, qubit k is rotated by different angles
+fn initial_state(number_qubits: usize) -> QubitResonatorState {
+    let mut state = QubitResonatorState::new(number_qubits, &[]).unwrap();
+    for qubit in 0..number_qubits {
+        state
+            .apply_gate(&RotateY::new(qubit, (0.4 + 0.5 * qubit as f64).into()).into())
+            .unwrap();
+        state
+            .apply_gate(&RotateZ::new(qubit, (0.3 * qubit as f64).into()).into())
+            .unwrap();
+    }
+    state
+}
+
+fn run(circuit: &Circuit, number_qubits: usize) -> DVector<Complex64> {
+    let mut state = initial_state(number_qubits);
+    let mut phase = Complex64::new(1.0, 0.0);
+    for operation in circuit.iter() {
+        match operation {
+            Operation::PragmaGlobalPhase(op) => {
+                phase *= Complex64::from_polar(1.0, *op.phase().float().unwrap())
+            }
+            _ => state.apply_gate(operation).unwrap(),
+        }
+    }
+    DVector::from_iterator(
+        state.amplitudes().len(),
+        state.amplitudes().iter().map(|amplitude| amplitude * phase),
+    )
+}
+
+fn exact(hamiltonian: &PauliHamiltonian, time: f64, number_qubits: usize) -> DVector<Complex64> {
+    let initial = initial_state(number_qubits);
+    let initial = DVector::from_column_slice(initial.amplitudes());
+    (dense_hamiltonian(hamiltonian, number_qubits) * Complex64::new(0.0, -time)).exp() * initial
+}
+
+#[test]
+fn test_single_qubit_terms() {
+    let hamiltonian = hamiltonian(&[
+        (PauliProduct::new().x(0), 0.5),
+        (PauliProduct::new().z(1), 0.25),
+    ]);
+    let operation = ApplyConstantPauliHamiltonian::new(hamiltonian, 2.0.into());
+    let circuit = trotterize(
+        &operation,
+        1,
+        TrotterOrder::First,
+        TrotterGates::CNOTLadder,
+        None,
+    )
+    .unwrap();
+    let mut expected = Circuit::new();
+    expected += RotateX::new(0, 2.0.into());
+    expected += RotateZ::new(1, 1.0.into());
+    assert_eq!(circuit, expected);
+}
+
+#[test_case(TrotterGates::CNOTLadder; "cnot_ladder")]
+#[test_case(TrotterGates::MultiQubitZZ; "multi_qubit_zz")]
+#[test_case(TrotterGates::MultiQubitMS; "multi_qubit_ms")]
+fn test_pauli_exponential_gates(gates: TrotterGates) {
+    let hamiltonian = hamiltonian(&[(PauliProduct::new().y(0).z(1), 0.5)]);
+    let operation = ApplyConstantPauliHamiltonian::new(hamiltonian, 1.0.into());
+    let circuit = trotterize(&operation, 1, TrotterOrder::First, gates, None).unwrap();
+    let mut expected = Circuit::new();
+    match gates {
+        TrotterGates::CNOTLadder => {
+            expected += RotateX::new(0, FRAC_PI_2.into());
+            expected += CNOT::new(0, 1);
+            expected += RotateZ::new(1, 1.0.into());
+            expected += CNOT::new(0, 1);
+            expected += RotateX::new(0, (-FRAC_PI_2).into());
+        }
+        TrotterGates::MultiQubitZZ => {
+            expected += RotateX::new(0, FRAC_PI_2.into());
+            expected += MultiQubitZZ::new(vec![0, 1], 1.0.into());
+            expected += RotateX::new(0, (-FRAC_PI_2).into());
+        }
+        TrotterGates::MultiQubitMS => {
+            expected += RotateZ::new(0, (-FRAC_PI_2).into());
+            expected += Hadamard::new(1);
+            expected += MultiQubitMS::new(vec![0, 1], 1.0.into());
+            expected += RotateZ::new(0, FRAC_PI_2.into());
+            expected += Hadamard::new(1);
+        }
+    }
+    assert_eq!(circuit, expected);
+}
+
+#[test_case(TrotterGates::CNOTLadder, TrotterOrder::First, 400, 1e-2; "cnot_ladder_first")]
+#[test_case(TrotterGates::CNOTLadder, TrotterOrder::Second, 40, 1e-3; "cnot_ladder_second")]
+#[test_case(TrotterGates::MultiQubitZZ, TrotterOrder::Second, 40, 1e-3; "multi_qubit_zz")]
+#[test_case(TrotterGates::MultiQubitMS, TrotterOrder::Second, 40, 1e-3; "multi_qubit_ms")]
+fn test_trotterized_evolution(
+    gates: TrotterGates,
+    order: TrotterOrder,
+    number_steps: usize,
+    tolerance: f64,
+) {
+    let hamiltonian = hamiltonian(&[
+        (PauliProduct::new(), 0.2),
+        (PauliProduct::new().x(0), 0.7),
+        (PauliProduct::new().z(1), -0.4),
+        (PauliProduct::new().x(0).x(1), 0.5),
+        (PauliProduct::new().y(0).y(1).z(2), 0.3),
+        (PauliProduct::new().z(0).x(1).y(2), -0.6),
+    ]);
+    let time = 1.3;
+    let operation = ApplyConstantPauliHamiltonian::new(hamiltonian.clone(), time.into());
+    let circuit = trotterize(&operation, number_steps, order, gates, None).unwrap();
+    let difference = run(&circuit, 3) - exact(&hamiltonian, time, 3);
+    assert!(difference.norm() < tolerance);
+}
+
+#[test]
+fn test_term_order() {
+    let hamiltonian = hamiltonian(&[
+        (PauliProduct::new().x(0), 0.5),
+        (PauliProduct::new().z(0), 0.25),
+        (PauliProduct::new().y(1), 1.0),
+    ]);
+    let operation = ApplyConstantPauliHamiltonian::new(hamiltonian, 1.0.into());
+    let circuit = trotterize(
+        &operation,
+        2,
+        TrotterOrder::Second,
+        TrotterGates::CNOTLadder,
+        Some(&[PauliProduct::new().z(0), PauliProduct::new()]),
+    )
+    .unwrap();
+    let mut step = Circuit::new();
+    step += RotateZ::new(0, 0.125.into());
+    step += RotateX::new(0, 0.25.into());
+    step += RotateY::new(1, 1.0.into());
+    step += RotateX::new(0, 0.25.into());
+    step += RotateZ::new(0, 0.125.into());
+    assert_eq!(circuit, step.clone() + step);
+
+    let error = trotterize(
+        &operation,
+        1,
+        TrotterOrder::First,
+        TrotterGates::CNOTLadder,
+        Some(&[PauliProduct::new().x(1)]),
+    );
+    assert_eq!(
+        error,
+        Err(RoqoqoError::GenericError {
+            msg: "Pauli product 1X of the term order is not in the Hamiltonian".to_string()
+        })
+    );
+    let error = trotterize(
+        &operation,
+        0,
+        TrotterOrder::First,
+        TrotterGates::CNOTLadder,
+        None,
+    );
+    assert_eq!(
+        error,
+        Err(RoqoqoError::GenericError {
+            msg: "Trotterization needs at least one Trotter step".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_symbolic_and_circuit() {
+    let hamiltonian = hamiltonian(&[(PauliProduct::new(), 0.5), (PauliProduct::new().x(0), 1.0)]);
+    let operation = ApplyConstantPauliHamiltonian::new(hamiltonian, "t".into());
+    let circuit = trotterize(
+        &operation,
+        1,
+        TrotterOrder::First,
+        TrotterGates::CNOTLadder,
+        None,
+    )
+    .unwrap();
+    assert_eq!(circuit.len(), 2);
+    assert!(circuit.is_parametrized());
+    let mut calculator = qoqo_calculator::Calculator::new();
+    calculator.set_variable("t", 2.0);
+    let substituted = circuit.substitute_parameters(&calculator).unwrap();
+    match (&substituted[0], &substituted[1]) {
+        (Operation::PragmaGlobalPhase(phase), Operation::RotateX(rotation)) => {
+            assert!((phase.phase().float().unwrap() + 1.0).abs() < 1e-12);
+            assert!((rotation.theta().float().unwrap() - 4.0).abs() < 1e-12);
+        }
+        _ => panic!("Unexpected operations {substituted:?}"),
+    }
+
+    let mut analog = Circuit::new();
+    analog += Hadamard::new(0);
+    analog += operation;
+    analog += MeasureQubit::new(0, "ro".to_string(), 0);
+    let trotterized =
+        trotterize_circuit(&analog, 1, TrotterOrder::First, TrotterGates::CNOTLadder).unwrap();
+    assert_eq!(trotterized.len(), 4);
+    assert_eq!(trotterized[0], Hadamard::new(0).into());
+    assert_eq!(
+        trotterized[3],
+        MeasureQubit::new(0, "ro".to_string(), 0).into()
+    );
+}
+
+#[test]
+fn test_trotterize_time_dependent() {
+    let mut hamiltonian = PauliHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().x(0), "omega".into())
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(0).z(1), 0.5.into())
+        .unwrap();
+    let mut values = HashMap::new();
+    values.insert("omega".to_string(), vec![1.0, 2.0]);
+    let operation = ApplyTimeDependentPauliHamiltonian::new(hamiltonian, vec![0.5, 1.0], values);
+
+    let circuit = trotterize_time_dependent(
+        &operation,
+        1,
+        TrotterOrder::First,
+        TrotterGates::MultiQubitZZ,
+        Some(&[PauliProduct::new().z(0).z(1)]),
+    )
+    .unwrap();
+    let mut expected = Circuit::new();
+    for omega in [1.0, 1.0] {
+        expected += MultiQubitZZ::new(vec![0, 1], 0.5.into());
+        expected += RotateX::new(0, omega.into());
+    }
+    assert_eq!(circuit, expected);
+
+    let mut analog = Circuit::new();
+    analog += operation.clone();
+    let trotterized =
+        trotterize_circuit(&analog, 1, TrotterOrder::First, TrotterGates::MultiQubitZZ).unwrap();
+    assert_eq!(trotterized.len(), 4);
+
+    let invalid = ApplyTimeDependentPauliHamiltonian::new(
+        operation.hamiltonian().clone(),
+        vec![0.5, 1.0],
+        HashMap::new(),
+    );
+    assert!(trotterize_time_dependent(
+        &invalid,
+        1,
+        TrotterOrder::First,
+        TrotterGates::CNOTLadder,
+        None
+    )
+    .is_err());
+}
@@ -20,10 +20,10 @@ use roqoqo::operations;
 use roqoqo::prelude::*;
 use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
 use roqoqo::Circuit;
-use roqoqo::QuantumProgram;
+use roqoqo::{QuantumProgram, RoqoqoError};
 #[cfg(feature = "json_schema")]
 use schemars::schema_for;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 #[cfg(feature = "unstable_parallel_run")]
 use std::time::Instant;
 
@@ -88,6 +88,141 @@ fn test_basis_rotation() {
     assert!(result_fail.is_err());
 }
 
+#[test]
+fn test_symbols() {
+    let mut circ1 = Circuit::new();
+    circ1 += operations::RotateX::new(0, "theta".into());
+    let mut circ2 = Circuit::new();
+    circ2 += operations::RotateZ::new(0, "2 * theta2".into());
+    let cr = ClassicalRegister {
+        constant_circuit: Some(circ2),
+        circuits: vec![circ1],
+    };
+    let expected: HashSet<String> = ["theta".to_string(), "theta2".to_string()].into();
+    assert_eq!(cr.symbols().unwrap(), expected);
+
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: cr.clone(),
+        input_parameter_names: vec!["theta2".to_string(), "theta".to_string()],
+    };
+    assert_eq!(program.symbols().unwrap(), expected);
+    assert_eq!(program.input_parameter_names(), &["theta2", "theta"]);
+    assert!(program.validate_input_parameter_names().is_ok());
+
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: cr.clone(),
+        input_parameter_names: vec!["theta".to_string()],
+    };
+    assert_eq!(
+        program.validate_input_parameter_names(),
+        Err(RoqoqoError::GenericError {
+            msg: "Symbols [\"theta2\"] of the circuits are not input parameters".to_string()
+        })
+    );
+
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: cr.clone(),
+        input_parameter_names: vec!["theta".to_string(), "theta2".to_string(), "phi".to_string()],
+    };
+    assert_eq!(
+        program.validate_input_parameter_names(),
+        Err(RoqoqoError::GenericError {
+            msg: "Input parameters [\"phi\"] are not used by the circuits".to_string()
+        })
+    );
+
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: cr,
+        input_parameter_names: vec![
+            "theta".to_string(),
+            "theta2".to_string(),
+            "theta".to_string(),
+        ],
+    };
+    assert_eq!(
+        program.validate_input_parameter_names(),
+        Err(RoqoqoError::GenericError {
+            msg: "Input parameter theta is listed more than once".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_bind_parameters() {
+    let mut circ1 = Circuit::new();
+    circ1 += operations::RotateX::new(0, "calibration * theta".into());
+    let mut circ2 = Circuit::new();
+    circ2 += operations::RotateZ::new(0, "calibration".into());
+    let cr = ClassicalRegister {
+        constant_circuit: Some(circ2),
+        circuits: vec![circ1],
+    };
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: cr,
+        input_parameter_names: vec!["calibration".to_string(), "theta".to_string()],
+    };
+
+    let values: HashMap<String, f64> = [("calibration".to_string(), 2.0)].into();
+    let bound = program.bind_parameters(&values).unwrap();
+
+    let mut circ1 = Circuit::new();
+    circ1 += operations::RotateX::new(0, "2.0 * theta".into());
+    let mut circ2 = Circuit::new();
+    circ2 += operations::RotateZ::new(0, 2.0.into());
+    let expected = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: Some(circ2),
+            circuits: vec![circ1],
+        },
+        input_parameter_names: vec!["theta".to_string()],
+    };
+    assert_eq!(bound, expected);
+    assert!(bound.validate_input_parameter_names().is_ok());
+}
+
+#[test]
+fn test_rename_parameters() {
+    let mut circ1 = Circuit::new();
+    circ1 += operations::RotateX::new(0, "theta".into());
+    let mut circ2 = Circuit::new();
+    circ2 += operations::RotateZ::new(0, "phi".into());
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: Some(circ2),
+            circuits: vec![circ1],
+        },
+        input_parameter_names: vec!["theta".to_string(), "phi".to_string()],
+    };
+
+    let mapping: HashMap<String, String> = [("theta".to_string(), "alpha".to_string())].into();
+    let renamed = program.rename_parameters(&mapping).unwrap();
+
+    let mut circ1 = Circuit::new();
+    circ1 += operations::RotateX::new(0, "alpha".into());
+    let mut circ2 = Circuit::new();
+    circ2 += operations::RotateZ::new(0, "phi".into());
+    let expected = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: Some(circ2),
+            circuits: vec![circ1],
+        },
+        input_parameter_names: vec!["alpha".to_string(), "phi".to_string()],
+    };
+    assert_eq!(renamed, expected);
+    assert!(renamed.validate_input_parameter_names().is_ok());
+
+    let reparametrized = program.reparametrize("ansatz_").unwrap();
+    assert_eq!(
+        reparametrized.input_parameter_names(),
+        &["ansatz_theta".to_string(), "ansatz_phi".to_string()]
+    );
+    assert_eq!(
+        reparametrized.symbols().unwrap(),
+        ["ansatz_theta".to_string(), "ansatz_phi".to_string()].into()
+    );
+    assert!(program.reparametrize("ansatz ").is_err());
+}
+
 #[test]
 fn test_cheated_basis_rotation() {
     // setting ub BR measurement
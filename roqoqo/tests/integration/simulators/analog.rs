@@ -0,0 +1,220 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the exact evolution of analog operations
+
+use nalgebra::DVector;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::measurements::ClassicalRegister;
+use roqoqo::operations::*;
+use roqoqo::passes::{trotterize, TrotterGates, TrotterOrder};
+use roqoqo::simulators::{pauli_hamiltonian_matrix, QubitResonatorBackend, QubitResonatorState};
+use roqoqo::{Circuit, QuantumProgram, RoqoqoBackendError};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use struqture::prelude::*;
+use struqture::spins::{PauliHamiltonian, PauliProduct};
+
+fn hamiltonian(terms: &[(PauliProduct, CalculatorFloat)]) -> PauliHamiltonian {
+    let mut hamiltonian = PauliHamiltonian::new();
+    for (product, coefficient) in terms.iter() {
+        hamiltonian
+            .add_operator_product(product.clone(), coefficient.clone())
+            .unwrap();
+    }
+    hamiltonian
+}
+
+// Entangled initial state on three qubits
+fn initial_state() -> QubitResonatorState {
+    let mut state = QubitResonatorState::new(3, &[]).unwrap();
+    let operations: Vec<Operation> = vec![
+        RotateY::new(0, 0.3.into()).into(),
+        RotateX::new(1, 1.1.into()).into(),
+        Hadamard::new(2).into(),
+        CNOT::new(2, 0).into(),
+    ];
+    for operation in operations.iter() {
+        state.apply_gate(operation).unwrap();
+    }
+    state
+}
+
+fn assert_close(left: &[Complex64], right: &[Complex64], tolerance: f64) {
+    assert_eq!(left.len(), right.len());
+    for (left, right) in left.iter().zip(right.iter()) {
+        assert!((left - right).norm() < tolerance, "{left} != {right}");
+    }
+}
+
+#[test]
+fn test_pauli_hamiltonian_matrix() {
+    let i = Complex64::new(0.0, 1.0);
+    let one = Complex64::new(1.0, 0.0);
+    let zero = Complex64::new(0.0, 0.0);
+    // Qubit 0 is the least significant bit of the basis index
+    let matrix = pauli_hamiltonian_matrix(
+        &hamiltonian(&[(PauliProduct::new().y(0).z(1), 2.0.into())]),
+        2,
+    )
+    .unwrap();
+    let expected = [
+        [zero, -i * 2.0, zero, zero],
+        [i * 2.0, zero, zero, zero],
+        [zero, zero, zero, i * 2.0],
+        [zero, zero, -i * 2.0, zero],
+    ];
+    for (row, values) in expected.iter().enumerate() {
+        for (column, value) in values.iter().enumerate() {
+            assert_eq!(matrix[(row, column)], *value);
+        }
+    }
+
+    let matrix = pauli_hamiltonian_matrix(
+        &hamiltonian(&[
+            (PauliProduct::new(), 0.5.into()),
+            (PauliProduct::new().x(1), 1.0.into()),
+        ]),
+        2,
+    )
+    .unwrap();
+    assert_eq!(matrix[(0, 0)], one * 0.5);
+    assert_eq!(matrix[(2, 0)], one);
+    assert_eq!(matrix[(1, 0)], zero);
+
+    assert_eq!(
+        pauli_hamiltonian_matrix(&hamiltonian(&[(PauliProduct::new().x(2), 1.0.into())]), 2),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Qubit 2 of the Hamiltonian is not part of a state with 2 qubits".to_string()
+        })
+    );
+    assert!(
+        pauli_hamiltonian_matrix(&hamiltonian(&[(PauliProduct::new().x(0), "a".into())]), 1)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_constant_evolution() {
+    // exp(-i π/4 X) rotates the qubit halfway
+    let mut state = QubitResonatorState::new(2, &[]).unwrap();
+    let operation = ApplyConstantPauliHamiltonian::new(
+        hamiltonian(&[(PauliProduct::new().x(1), (PI / 4.0).into())]),
+        1.0.into(),
+    );
+    state.apply_gate(&operation.into()).unwrap();
+    assert!((state.excited_state_probability(1).unwrap() - 0.5).abs() < 1e-12);
+    assert!(state.excited_state_probability(0).unwrap().abs() < 1e-12);
+
+    // Evolution on a subset of the qubits agrees with the full dense evolution
+    let hamiltonian = hamiltonian(&[
+        (PauliProduct::new(), 0.2.into()),
+        (PauliProduct::new().x(2), 0.7.into()),
+        (PauliProduct::new().y(0).z(2), (-0.4).into()),
+        (PauliProduct::new().z(0).z(2), 0.9.into()),
+    ]);
+    let time = 1.3;
+    let mut state = initial_state();
+    let initial = DVector::from_column_slice(state.amplitudes());
+    let expected =
+        (pauli_hamiltonian_matrix(&hamiltonian, 3).unwrap() * Complex64::new(0.0, -time)).exp()
+            * initial;
+    let operation = ApplyConstantPauliHamiltonian::new(hamiltonian.clone(), time.into());
+    state.apply_gate(&operation.clone().into()).unwrap();
+    assert_close(state.amplitudes(), expected.as_slice(), 1e-10);
+
+    // A finely Trotterized circuit converges to the exact evolution
+    let circuit = trotterize(
+        &operation,
+        200,
+        TrotterOrder::Second,
+        TrotterGates::CNOTLadder,
+        None,
+    )
+    .unwrap();
+    let mut trotterized = initial_state();
+    for operation in circuit.iter() {
+        match operation {
+            // The identity term only contributes the global phase applied below
+            Operation::PragmaGlobalPhase(_) => (),
+            _ => trotterized.apply_gate(operation).unwrap(),
+        }
+    }
+    let phase = Complex64::new(0.0, -0.2 * time).exp();
+    let rephased: Vec<Complex64> = trotterized
+        .amplitudes()
+        .iter()
+        .map(|amplitude| amplitude * phase)
+        .collect();
+    assert_close(&rephased, state.amplitudes(), 1e-4);
+
+    let mut state = QubitResonatorState::new(1, &[]).unwrap();
+    let operation = ApplyConstantPauliHamiltonian::new(
+        self::hamiltonian(&[(PauliProduct::new().x(1), 1.0.into())]),
+        1.0.into(),
+    );
+    assert_eq!(
+        state.apply_gate(&operation.into()),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Qubit 1 is not part of a state with 1 qubits".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_time_dependent_evolution() {
+    let hamiltonian = hamiltonian(&[
+        (PauliProduct::new().x(0), "omega".into()),
+        (PauliProduct::new().z(0).z(1), "delta".into()),
+    ]);
+    let values = HashMap::from([
+        ("omega".to_string(), vec![0.5, 1.0, 2.0]),
+        ("delta".to_string(), vec![0.3, -0.2, 0.0]),
+    ]);
+    let operation =
+        ApplyTimeDependentPauliHamiltonian::new(hamiltonian, vec![0.2, 0.5, 1.0], values);
+
+    let mut expected = initial_state();
+    for segment in operation.piecewise_constant().unwrap() {
+        expected.apply_gate(&segment.into()).unwrap();
+    }
+    let mut state = initial_state();
+    state.apply_gate(&operation.into()).unwrap();
+    assert_close(state.amplitudes(), expected.amplitudes(), 1e-12);
+    assert!((state.norm() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_quantum_program_run() {
+    // exp(-i π/2 X) flips the qubit, the time is an input parameter of the program
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += ApplyConstantPauliHamiltonian::new(
+        hamiltonian(&[(PauliProduct::new().x(1), (PI / 2.0).into())]),
+        "t".into(),
+    );
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec!["t".to_string()],
+    };
+
+    let backend = QubitResonatorBackend::new(2, Some(1));
+    let (bits, _, _) = program.run_registers(backend.clone(), &[1.0]).unwrap();
+    assert_eq!(bits["ro"], vec![vec![false, true]; 10]);
+    let (bits, _, _) = program.run_registers(backend, &[2.0]).unwrap();
+    assert_eq!(bits["ro"], vec![vec![false, false]; 10]);
+}
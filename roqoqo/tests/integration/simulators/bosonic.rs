@@ -0,0 +1,212 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the bosonic simulator
+
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::operations::*;
+use roqoqo::simulators::{BosonicBackend, FockState, GaussianState};
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::f64::consts::PI;
+use test_case::test_case;
+
+fn gaussian_from(operations: &[Operation], number_modes: usize) -> GaussianState {
+    let mut state = GaussianState::new(number_modes);
+    for operation in operations.iter() {
+        state.apply_gate(operation).unwrap();
+    }
+    state
+}
+
+fn fock_from(operations: &[Operation], number_modes: usize, cutoff: usize) -> FockState {
+    let mut state = FockState::new(number_modes, cutoff).unwrap();
+    for operation in operations.iter() {
+        state.apply_gate(operation).unwrap();
+    }
+    state
+}
+
+// Amplitude of the Fock state with the given photon numbers
+fn amplitude(state: &FockState, photons: &[usize]) -> Complex64 {
+    let index: usize = photons
+        .iter()
+        .enumerate()
+        .map(|(mode, n)| n * state.cutoff().pow(mode as u32))
+        .sum();
+    state.amplitudes()[index]
+}
+
+#[test]
+fn test_coherent_state() {
+    let operations: Vec<Operation> = vec![PhaseDisplacement::new(0, 1.5.into(), 0.3.into()).into()];
+    let gaussian = gaussian_from(&operations, 1);
+    assert!((gaussian.mean_photon_number(0).unwrap() - 2.25).abs() < 1e-12);
+    assert!((gaussian.displacement()[0] - Complex64::from_polar(1.5, 0.3)).norm() < 1e-12);
+
+    // Poisson distribution of the photon numbers
+    let fock = gaussian.to_fock(25).unwrap();
+    let probabilities = fock.photon_number_probabilities(0).unwrap();
+    let mut poisson = (-2.25_f64).exp();
+    for (n, probability) in probabilities.iter().enumerate().take(10) {
+        assert!((probability - poisson).abs() < 1e-10);
+        poisson *= 2.25 / (n + 1) as f64;
+    }
+    assert!((fock.mean_photon_number(0).unwrap() - 2.25).abs() < 1e-8);
+}
+
+#[test]
+fn test_squeezed_vacuum() {
+    let operations: Vec<Operation> = vec![Squeezing::new(0, 0.5.into(), 0.7.into()).into()];
+    let gaussian = gaussian_from(&operations, 1);
+    let expected = 0.5_f64.sinh().powi(2);
+    assert!((gaussian.mean_photon_number(0).unwrap() - expected).abs() < 1e-12);
+
+    let fock = gaussian.to_fock(30).unwrap();
+    assert!((fock.norm() - 1.0).abs() < 1e-8);
+    let probabilities = fock.photon_number_probabilities(0).unwrap();
+    assert!((probabilities[0] - 1.0 / 0.5_f64.cosh()).abs() < 1e-12);
+    for probability in probabilities.iter().skip(1).step_by(2) {
+        assert!(probability.abs() < 1e-14);
+    }
+}
+
+#[test_case(vec![Squeezing::new(0, 0.4.into(), 0.2.into()).into(), PhaseDisplacement::new(0, 0.6.into(), 1.0.into()).into()]; "squeezed_coherent")]
+#[test_case(vec![PhaseDisplacement::new(0, 0.8.into(), 0.0.into()).into(), PhaseShift::new(0, 0.9.into()).into(), Squeezing::new(0, 0.3.into(), 0.0.into()).into()]; "displaced_rotated_squeezed")]
+#[test_case(vec![PhaseDisplacement::new(0, 1.0.into(), 0.5.into()).into(), BeamSplitter::new(0, 1, (PI / 4.0).into(), 0.3.into()).into()]; "coherent_beam_splitter")]
+#[test_case(vec![Squeezing::new(0, 0.3.into(), 0.0.into()).into(), Squeezing::new(1, 0.2.into(), 1.0.into()).into(), BeamSplitter::new(1, 0, 0.7.into(), 0.4.into()).into(), PhaseShift::new(1, 0.5.into()).into()]; "two_mode_squeezing")]
+fn test_gaussian_matches_fock(operations: Vec<Operation>) {
+    let number_modes = 2;
+    let gaussian = gaussian_from(&operations, number_modes).to_fock(6).unwrap();
+    let fock = fock_from(&operations, number_modes, 18);
+    // Both states agree up to a global phase
+    let phase = amplitude(&fock, &[0, 0]) / amplitude(&gaussian, &[0, 0]);
+    assert!((phase.norm() - 1.0).abs() < 1e-6);
+    for n_0 in 0..6 {
+        for n_1 in 0..6 {
+            let difference =
+                amplitude(&gaussian, &[n_0, n_1]) * phase - amplitude(&fock, &[n_0, n_1]);
+            assert!(difference.norm() < 1e-6, "{n_0} {n_1} {difference}");
+        }
+    }
+    for mode in 0..number_modes {
+        let mean = gaussian_from(&operations, number_modes)
+            .mean_photon_number(mode)
+            .unwrap();
+        assert!((mean - fock.mean_photon_number(mode).unwrap()).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_beam_splitter_convention() {
+    // The coherent amplitude α in mode 0 is split into the transmitted cos(θ) α and the reflected exp(i φ) sin(θ) α
+    let operations: Vec<Operation> = vec![
+        PhaseDisplacement::new(0, 1.0.into(), 0.0.into()).into(),
+        BeamSplitter::new(0, 1, 0.4.into(), 0.2.into()).into(),
+    ];
+    let displacement = gaussian_from(&operations, 2).displacement();
+    assert!((displacement[0] - Complex64::from(0.4_f64.cos())).norm() < 1e-12);
+    assert!((displacement[1] - Complex64::from_polar(0.4_f64.sin(), 0.2)).norm() < 1e-12);
+}
+
+#[test]
+fn test_photon_detection() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionFloat::new("photons".to_string(), 2, true);
+    circuit += DefinitionBit::new("clicks".to_string(), 1, true);
+    circuit += PhaseDisplacement::new(0, 1.0.into(), 0.0.into());
+    circuit += PhotonDetection::new(0, "photons".to_string(), 0);
+    circuit += PhotonDetection::new(1, "photons".to_string(), 1);
+    circuit += PhotonDetection::new(0, "clicks".to_string(), 0);
+    circuit += PragmaSetNumberOfMeasurements::new(2000, "photons".to_string());
+
+    let backend = BosonicBackend::new(15, Some(11));
+    let (bits, floats, complex) = backend.run_circuit(&circuit).unwrap();
+    assert!(complex.is_empty());
+    let photons = &floats["photons"];
+    assert_eq!(photons.len(), 2000);
+    let mean = photons.iter().map(|row| row[0]).sum::<f64>() / 2000.0;
+    assert!((mean - 1.0).abs() < 0.1);
+    assert!(photons.iter().all(|row| row[1] == 0.0));
+    // The state collapsed by the first detection is measured again
+    assert!(photons
+        .iter()
+        .zip(bits["clicks"].iter())
+        .all(|(row, clicks)| clicks[0] == (row[0] > 0.0)));
+
+    // Runs with the same seed are reproducible
+    let (_, repeated, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(&repeated["photons"], photons);
+}
+
+#[test]
+fn test_fock_evolution_after_detection() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, false);
+    circuit += DefinitionFloat::new("photons".to_string(), 1, true);
+    circuit += PhotonDetection::new(0, "ro".to_string(), 0);
+    let mut displace = Circuit::new();
+    displace += PhaseDisplacement::new(0, 2.0.into(), 0.0.into());
+    circuit += PragmaIfElse::new(
+        BooleanExpression::Bit("ro".to_string(), 0),
+        Circuit::new(),
+        displace,
+    );
+    circuit += PhotonDetection::new(0, "photons".to_string(), 0);
+    circuit += PragmaSetNumberOfMeasurements::new(200, "photons".to_string());
+
+    let backend = BosonicBackend::new(16, Some(3));
+    let (_, floats, _) = backend.run_circuit(&circuit).unwrap();
+    let mean = floats["photons"].iter().map(|row| row[0]).sum::<f64>() / 200.0;
+    assert!((mean - 4.0).abs() < 0.5);
+}
+
+#[test]
+fn test_errors() {
+    let backend = BosonicBackend::new(5, Some(0));
+
+    let mut circuit = Circuit::new();
+    circuit += PauliX::new(0);
+    assert!(matches!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::OperationNotInBackend { .. })
+    ));
+
+    let mut circuit = Circuit::new();
+    circuit += PhaseShift::new(0, "theta".into());
+    assert!(matches!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::CalculatorError(_))
+    ));
+
+    let mut circuit = Circuit::new();
+    circuit += PhotonDetection::new(0, "ro".to_string(), 0);
+    assert!(matches!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::GenericError { .. })
+    ));
+
+    assert!(FockState::new(1, 0).is_err());
+    // The dimension cutoff^number_modes overflows or cannot be allocated
+    assert!(matches!(
+        FockState::new(20, 10),
+        Err(RoqoqoBackendError::GenericError { .. })
+    ));
+    assert!(FockState::new(usize::MAX, 2).is_err());
+    assert!(GaussianState::new(30).to_fock(10).is_err());
+    assert!(GaussianState::new(1).mean_photon_number(1).is_err());
+    let mut state = FockState::new(2, 3).unwrap();
+    assert!(state
+        .apply_gate(&BeamSplitter::new(0, 0, 0.1.into(), 0.0.into()).into())
+        .is_err());
+    assert!(state.apply_gate(&Hadamard::new(0).into()).is_err());
+}
@@ -0,0 +1,192 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the qubit-resonator simulator
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::operations::*;
+use roqoqo::simulators::{QubitResonatorBackend, QubitResonatorState};
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::f64::consts::PI;
+use test_case::test_case;
+
+fn state_from(
+    operations: &[Operation],
+    number_qubits: usize,
+    cutoffs: &[usize],
+) -> QubitResonatorState {
+    let mut state = QubitResonatorState::new(number_qubits, cutoffs).unwrap();
+    for operation in operations.iter() {
+        state.apply_gate(operation).unwrap();
+    }
+    state
+}
+
+#[test_case(PI / 4.0, 0.5; "half")]
+#[test_case(PI / 2.0, 0.0; "full")]
+#[test_case(PI, 1.0; "back")]
+fn test_jaynes_cummings_vacuum_rabi(theta: f64, excited: f64) {
+    let operations: Vec<Operation> = vec![
+        PauliX::new(0).into(),
+        JaynesCummings::new(0, 0, theta.into()).into(),
+    ];
+    let state = state_from(&operations, 1, &[4]);
+    assert!((state.excited_state_probability(0).unwrap() - excited).abs() < 1e-10);
+    assert!((state.mean_photon_number(0).unwrap() - (1.0 - excited)).abs() < 1e-10);
+    assert!((state.norm() - 1.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_displacing_couplings() {
+    // Both couplings displace the mode by -i θ for the qubit in an eigenstate with eigenvalue one
+    let theta = 0.5;
+    let longitudinal = state_from(
+        &[LongitudinalCoupling::new(0, 0, theta.into()).into()],
+        1,
+        &[20],
+    );
+    assert!((longitudinal.mean_photon_number(0).unwrap() - theta * theta).abs() < 1e-8);
+    assert!(longitudinal.excited_state_probability(0).unwrap().abs() < 1e-12);
+
+    let rabi = state_from(
+        &[
+            Hadamard::new(0).into(),
+            QuantumRabi::new(0, 0, theta.into()).into(),
+            Hadamard::new(0).into(),
+        ],
+        1,
+        &[20],
+    );
+    assert!((rabi.mean_photon_number(0).unwrap() - theta * theta).abs() < 1e-8);
+    assert!(rabi.excited_state_probability(0).unwrap().abs() < 1e-12);
+}
+
+#[test]
+fn test_store_and_load() {
+    let theta = 0.8;
+    let mut operations: Vec<Operation> = vec![
+        RotateY::new(0, theta.into()).into(),
+        SingleExcitationStore::new(0, 0).into(),
+    ];
+    let stored = state_from(&operations, 1, &[3]);
+    assert!(stored.excited_state_probability(0).unwrap().abs() < 1e-12);
+    let probabilities = stored.photon_number_probabilities(0).unwrap();
+    assert!((probabilities[0] - (theta / 2.0).cos().powi(2)).abs() < 1e-12);
+    assert!((probabilities[1] - (theta / 2.0).sin().powi(2)).abs() < 1e-12);
+
+    operations.push(SingleExcitationLoad::new(0, 0).into());
+    let loaded = state_from(&operations, 1, &[3]);
+    assert!(
+        (loaded.excited_state_probability(0).unwrap() - (theta / 2.0).sin().powi(2)).abs() < 1e-12
+    );
+    assert!(loaded.mean_photon_number(0).unwrap().abs() < 1e-12);
+
+    // Storing into an occupied mode is not defined
+    let mut state = state_from(&operations[..2], 1, &[3]);
+    let error = state.apply_gate(&SingleExcitationStore::new(0, 0).into());
+    assert_eq!(
+        error,
+        Err(RoqoqoBackendError::GenericError {
+            msg: "SingleExcitationStore is only defined for mode 0 in the vacuum state".to_string()
+        })
+    );
+    let mut state = state_from(
+        &[
+            PauliX::new(0).into(),
+            SingleExcitationStore::new(0, 0).into(),
+            PauliX::new(0).into(),
+        ],
+        1,
+        &[3],
+    );
+    assert!(state
+        .apply_gate(&SingleExcitationLoad::new(0, 0).into())
+        .is_err());
+}
+
+#[test]
+fn test_cz_qubit_resonator() {
+    let mut operations: Vec<Operation> = vec![
+        PauliX::new(1).into(),
+        SingleExcitationStore::new(1, 0).into(),
+    ];
+    operations.extend([
+        Hadamard::new(0).into(),
+        CZQubitResonator::new(0, 0).into(),
+        Hadamard::new(0).into(),
+    ]);
+    let state = state_from(&operations, 2, &[3]);
+    assert!((state.excited_state_probability(0).unwrap() - 1.0).abs() < 1e-12);
+
+    // Without a photon the gate acts trivially
+    let state = state_from(&operations[2..], 2, &[3]);
+    assert!(state.excited_state_probability(0).unwrap().abs() < 1e-12);
+}
+
+#[test]
+fn test_qubit_gates_and_mode_cutoffs() {
+    let operations: Vec<Operation> = vec![
+        PauliX::new(0).into(),
+        CNOT::new(0, 1).into(),
+        Toffoli::new(0, 1, 2).into(),
+        SingleExcitationStore::new(2, 0).into(),
+        BeamSplitter::new(0, 1, (PI / 2.0).into(), 0.0.into()).into(),
+    ];
+    let state = state_from(&operations, 3, &[2, 4]);
+    assert_eq!(state.amplitudes().len(), 64);
+    assert_eq!(state.cutoffs(), &[2, 4]);
+    assert!((state.excited_state_probability(1).unwrap() - 1.0).abs() < 1e-12);
+    assert!(state.excited_state_probability(2).unwrap().abs() < 1e-12);
+    assert!(state.mean_photon_number(0).unwrap().abs() < 1e-12);
+    assert!((state.mean_photon_number(1).unwrap() - 1.0).abs() < 1e-12);
+
+    assert!(QubitResonatorState::new(1, &[2, 0]).is_err());
+}
+
+#[test]
+fn test_backend_run() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += DefinitionFloat::new("photons".to_string(), 1, true);
+    circuit += PauliX::new(0);
+    circuit += JaynesCummings::new(0, 1, (PI / 2.0).into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PhotonDetection::new(1, "ro".to_string(), 1);
+    circuit += PhotonDetection::new(1, "photons".to_string(), 0);
+    circuit += PragmaSetNumberOfMeasurements::new(3, "ro".to_string());
+
+    let mut backend = QubitResonatorBackend::new(2, Some(1));
+    backend.set_mode_cutoff(1, 3);
+    let (bits, floats, complex) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bits["ro"], vec![vec![false, true]; 3]);
+    assert_eq!(floats["photons"], vec![vec![1.0]; 3]);
+    assert!(complex.is_empty());
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += Hadamard::new(0);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 100, None);
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    let ones = bits["ro"].iter().filter(|row| row[0]).count();
+    assert_eq!(bits["ro"].len(), 100);
+    assert!(ones > 20 && ones < 80);
+
+    let mut circuit = Circuit::new();
+    circuit += PragmaDamping::new(0, 1.0.into(), 1.0.into());
+    assert_eq!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::OperationNotInBackend {
+            backend: "QubitResonatorBackend",
+            hqslang: "PragmaDamping",
+        })
+    );
+}
@@ -0,0 +1,377 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the stabilizer simulator
+
+use qoqo_calculator::CalculatorFloat;
+use rand::SeedableRng;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::operations::*;
+use roqoqo::simulators::{CliffordTableau, StabilizerBackend};
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use test_case::test_case;
+
+fn tableau_from(operations: Vec<Operation>, number_qubits: usize) -> CliffordTableau {
+    let mut tableau = CliffordTableau::new(number_qubits);
+    for operation in operations.iter() {
+        tableau.apply_gate(operation).unwrap();
+    }
+    tableau
+}
+
+#[test]
+fn test_bell_state_stabilizers() {
+    let tableau = tableau_from(vec![Hadamard::new(0).into(), CNOT::new(0, 1).into()], 2);
+    assert_eq!(tableau.stabilizers(), vec!["+XX", "+ZZ"]);
+    assert_eq!(tableau.number_qubits(), 2);
+    let tableau = tableau_from(vec![PauliX::new(0).into()], 1);
+    assert_eq!(tableau.stabilizers(), vec!["-Z"]);
+}
+
+#[test_case(vec![PauliX::new(0).into()], vec![SqrtPauliX::new(0).into(), SqrtPauliX::new(0).into()]; "sqrt_x")]
+#[test_case(vec![SGate::new(0).into()], vec![RotateZ::new(0, (PI / 2.0).into()).into()]; "rotate_z")]
+#[test_case(vec![SGate::new(0).into()], vec![RotateZ::new(0, "3.141592653589793 / 2".into()).into()]; "rotate_z_symbolic")]
+#[test_case(vec![PauliY::new(0).into()], vec![PauliZ::new(0).into(), PauliX::new(0).into()]; "pauli_y")]
+#[test_case(vec![Hadamard::new(1).into(), CNOT::new(0, 1).into(), Hadamard::new(1).into()], vec![ControlledPauliZ::new(0, 1).into()]; "cz")]
+#[test_case(vec![InvSGate::new(1).into(), CNOT::new(0, 1).into(), SGate::new(1).into()], vec![ControlledPauliY::new(0, 1).into()]; "cy")]
+#[test_case(vec![InvSGate::new(0).into(), CNOT::new(1, 0).into(), SGate::new(0).into()], vec![ControlledPauliY::new(1, 0).into()]; "cy_reversed")]
+#[test_case(vec![CNOT::new(0, 1).into(), CNOT::new(1, 0).into(), CNOT::new(0, 1).into()], vec![SWAP::new(0, 1).into()]; "swap")]
+fn test_generic_gates(reference: Vec<Operation>, operations: Vec<Operation>) {
+    assert_eq!(tableau_from(reference, 2), tableau_from(operations, 2));
+}
+
+#[test_case(Hadamard::new(0).into(), true; "hadamard")]
+#[test_case(ISwap::new(0, 1).into(), true; "iswap")]
+#[test_case(RotateX::new(0, PI.into()).into(), true; "rotate_x_pi")]
+#[test_case(RotateX::new(0, "3 * 3.141592653589793 / 2".into()).into(), true; "rotate_x_symbolic")]
+#[test_case(MultiQubitZZ::new(vec![0, 1, 2], (PI / 2.0).into()).into(), true; "multi_qubit_zz")]
+#[test_case(TGate::new(0).into(), false; "t_gate")]
+#[test_case(RotateX::new(0, 0.3.into()).into(), false; "rotate_x")]
+#[test_case(RotateX::new(0, "theta".into()).into(), false; "free_symbol")]
+#[test_case(Toffoli::new(0, 1, 2).into(), false; "toffoli")]
+#[test_case(PragmaDamping::new(0, 1.0.into(), 0.1.into()).into(), false; "noise")]
+#[test_case(MeasureQubit::new(0, "ro".to_string(), 0).into(), true; "measure")]
+#[test_case(PragmaActiveReset::new(0).into(), true; "reset")]
+fn test_is_clifford(operation: Operation, expected: bool) {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += CNOT::new(0, 1);
+    circuit.add_operation(operation.clone());
+    assert_eq!(circuit.is_clifford(), expected);
+
+    let mut looped = Circuit::new();
+    looped += PragmaLoop::new(2.0.into(), circuit);
+    assert_eq!(looped.is_clifford(), expected);
+}
+
+#[test]
+fn test_repeated_measurement() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += CNOT::new(1, 2);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 100, None);
+    let backend = StabilizerBackend::new(Some(42));
+    let (bits, floats, complex) = backend.run_circuit(&circuit).unwrap();
+    assert!(floats.is_empty());
+    assert!(complex.is_empty());
+    let rows = bits.get("ro").unwrap();
+    assert_eq!(rows.len(), 100);
+    assert!(rows.iter().all(|row| row[0] == row[1] && row[1] == row[2]));
+    assert!(rows.iter().any(|row| row[0]));
+    assert!(rows.iter().any(|row| !row[0]));
+    // Fixed seed gives reproducible results
+    let (bits_again, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(&bits_again, &bits);
+}
+
+#[test]
+fn test_repeated_measurement_mapping() {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += PauliX::new(2);
+    let mapping: HashMap<usize, usize> = [(2, 0)].into_iter().collect();
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 3, Some(mapping));
+    let (bits, _, _) = StabilizerBackend::new(Some(1))
+        .run_circuit(&circuit)
+        .unwrap();
+    assert_eq!(bits.get("ro").unwrap(), &vec![vec![true, false]; 3]);
+}
+
+#[test]
+fn test_measure_reset_conditional() {
+    let mut conditional = Circuit::new();
+    conditional += PauliX::new(1);
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += DefinitionBit::new("internal".to_string(), 1, false);
+    circuit += DefinitionFloat::new("floats".to_string(), 1, true);
+    circuit += PauliX::new(0);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PragmaConditional::new("ro".to_string(), 0, conditional);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += PragmaActiveReset::new(0);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 2);
+    circuit += PragmaSetNumberOfMeasurements::new(5, "ro".to_string());
+    let (bits, floats, _) = StabilizerBackend::new(None).run_circuit(&circuit).unwrap();
+    assert_eq!(bits.get("ro").unwrap(), &vec![vec![true, true, false]; 5]);
+    assert!(!bits.contains_key("internal"));
+    assert_eq!(floats.get("floats").unwrap(), &vec![vec![0.0]; 5]);
+}
+
+#[test]
+fn test_control_flow() {
+    let mut correction = Circuit::new();
+    correction += PauliX::new(2);
+    let mut flip = Circuit::new();
+    flip += PauliX::new(3);
+    let mut repeat = Circuit::new();
+    repeat += PragmaActiveReset::new(4);
+    repeat += Hadamard::new(4);
+    repeat += MeasureQubit::new(4, "ro".to_string(), 4);
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 5, true);
+    circuit += DefinitionBit::new("parity".to_string(), 1, true);
+    circuit += PauliX::new(0);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += PragmaSetBit::new(
+        "parity".to_string(),
+        0,
+        BooleanExpression::Xor(vec![
+            BooleanExpression::Bit("ro".to_string(), 0),
+            BooleanExpression::Bit("ro".to_string(), 1),
+        ]),
+    );
+    circuit += PragmaIfElse::new(
+        BooleanExpression::And(vec![
+            BooleanExpression::Bit("parity".to_string(), 0),
+            BooleanExpression::Not(Box::new(BooleanExpression::Bit("ro".to_string(), 1))),
+        ]),
+        correction,
+        flip,
+    );
+    circuit += MeasureQubit::new(2, "ro".to_string(), 2);
+    circuit += MeasureQubit::new(3, "ro".to_string(), 3);
+    circuit += PragmaWhileLoop::new(
+        BooleanExpression::Not(Box::new(BooleanExpression::Bit("ro".to_string(), 4))),
+        repeat,
+        100,
+    );
+    assert!(circuit.is_clifford());
+
+    for seed in 0..5 {
+        let (bits, _, _) = StabilizerBackend::new(Some(seed))
+            .run_circuit(&circuit)
+            .unwrap();
+        assert_eq!(
+            bits.get("ro").unwrap(),
+            &vec![vec![true, false, true, false, true]]
+        );
+        assert_eq!(bits.get("parity").unwrap(), &vec![vec![true]]);
+    }
+
+    // The iteration cap ends a loop whose condition never becomes false
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += PragmaWhileLoop::new(
+        BooleanExpression::Constant(true),
+        Circuit::from_iter(vec![Operation::from(PauliX::new(0))]),
+        3,
+    );
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    let (bits, _, _) = StabilizerBackend::new(None).run_circuit(&circuit).unwrap();
+    assert_eq!(bits.get("ro").unwrap(), &vec![vec![true]]);
+
+    let mut circuit = Circuit::new();
+    circuit += PragmaSetBit::new("missing".to_string(), 0, BooleanExpression::Constant(true));
+    assert!(StabilizerBackend::new(None).run_circuit(&circuit).is_err());
+}
+
+#[test]
+fn test_classical_arithmetic() {
+    // Syndrome extraction of a three qubit repetition code with an error on qubit 1
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += DefinitionBit::new("syndrome".to_string(), 2, true);
+    circuit += DefinitionBit::new("combined".to_string(), 3, true);
+    circuit += DefinitionFloat::new("angles".to_string(), 2, true);
+    circuit += PauliX::new(1);
+    for qubit in 0..3 {
+        circuit += MeasureQubit::new(qubit, "ro".to_string(), qubit);
+    }
+    circuit += PragmaParity::new("ro".to_string(), vec![0, 1], "syndrome".to_string(), 0);
+    circuit += PragmaParity::new("ro".to_string(), vec![1, 2], "syndrome".to_string(), 1);
+    circuit += PragmaBitwise::new(
+        BitwiseOperator::And,
+        "syndrome".to_string(),
+        0,
+        "syndrome".to_string(),
+        1,
+        "combined".to_string(),
+        0,
+        1,
+    );
+    circuit += PragmaBitwise::new(
+        BitwiseOperator::Or,
+        "ro".to_string(),
+        0,
+        "ro".to_string(),
+        2,
+        "combined".to_string(),
+        1,
+        1,
+    );
+    circuit += PragmaBitwise::new(
+        BitwiseOperator::Xor,
+        "ro".to_string(),
+        0,
+        "ro".to_string(),
+        1,
+        "combined".to_string(),
+        2,
+        1,
+    );
+    circuit += PragmaSetFloat::new(
+        "angles".to_string(),
+        1,
+        CalculatorFloat::from("0.5 * s0 + s1"),
+        HashMap::from([
+            ("s0".to_string(), ("syndrome".to_string(), 0)),
+            ("s1".to_string(), ("syndrome".to_string(), 1)),
+        ]),
+    );
+    circuit += PragmaSetFloat::new(
+        "angles".to_string(),
+        0,
+        CalculatorFloat::from("2 * a"),
+        HashMap::from([("a".to_string(), ("angles".to_string(), 1))]),
+    );
+    circuit += PragmaIfElse::new(
+        BooleanExpression::Bit("combined".to_string(), 0),
+        Circuit::from_iter(vec![Operation::from(PauliX::new(1))]),
+        Circuit::new(),
+    );
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    assert!(circuit.is_clifford());
+
+    let (bits, floats, _) = StabilizerBackend::new(Some(1))
+        .run_circuit(&circuit)
+        .unwrap();
+    assert_eq!(bits.get("ro").unwrap(), &vec![vec![false, false, false]]);
+    assert_eq!(bits.get("syndrome").unwrap(), &vec![vec![true, true]]);
+    assert_eq!(
+        bits.get("combined").unwrap(),
+        &vec![vec![true, false, true]]
+    );
+    assert_eq!(floats.get("angles").unwrap(), &vec![vec![3.0, 1.5]]);
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += PragmaBitwise::new(
+        BitwiseOperator::Xor,
+        "ro".to_string(),
+        0,
+        "ro".to_string(),
+        0,
+        "ro".to_string(),
+        1,
+        2,
+    );
+    assert!(StabilizerBackend::new(None).run_circuit(&circuit).is_err());
+    let mut circuit = Circuit::new();
+    circuit += PragmaSetFloat::new(
+        "missing".to_string(),
+        0,
+        CalculatorFloat::from("1.0"),
+        HashMap::new(),
+    );
+    assert!(StabilizerBackend::new(None).run_circuit(&circuit).is_err());
+}
+
+#[test]
+fn test_measure_qubit_and_reset() {
+    // A single qubit is reused for three measurements after being reset
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += PauliX::new(0);
+    circuit += MeasureQubitAndReset::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubitAndReset::new(0, "ro".to_string(), 1);
+    circuit += PauliX::new(0);
+    circuit += MeasureQubitAndReset::new(0, "ro".to_string(), 2);
+    assert!(circuit.is_clifford());
+    let (bits, _, _) = StabilizerBackend::new(Some(3))
+        .run_circuit(&circuit)
+        .unwrap();
+    assert_eq!(bits.get("ro").unwrap(), &vec![vec![true, false, true]]);
+}
+
+#[test]
+fn test_unsupported_operations() {
+    let backend = StabilizerBackend::new(Some(0));
+    let mut circuit = Circuit::new();
+    circuit += TGate::new(0);
+    assert_eq!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::OperationNotInBackend {
+            backend: "StabilizerBackend",
+            hqslang: "TGate"
+        })
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += RotateZ::new(0, CalculatorFloat::from("theta"));
+    assert!(backend.run_circuit(&circuit).is_err());
+
+    let mut circuit = Circuit::new();
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    assert!(backend.run_circuit(&circuit).is_err());
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 1);
+    assert!(backend.run_circuit(&circuit).is_err());
+}
+
+#[test]
+fn test_nested_circuit_qubits() {
+    // The repeated measurement inside the loop measures both qubits of the register
+    let mut inner = Circuit::new();
+    inner += PragmaRepeatedMeasurement::new("ro".to_string(), 3, None);
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += PragmaLoop::new(1.0.into(), inner);
+    let (bits, _, _) = StabilizerBackend::new(Some(0))
+        .run_circuit(&circuit)
+        .unwrap();
+    let rows = bits.get("ro").unwrap();
+    assert_eq!(rows.len(), 3);
+    assert!(rows.iter().all(|row| row.len() == 2 && !row[1]));
+}
+
+#[test]
+fn test_tableau_qubit_out_of_range() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let mut tableau = CliffordTableau::new(1);
+    assert!(tableau.measure(1, &mut rng).is_err());
+    assert!(tableau.reset(1, &mut rng).is_err());
+    assert!(tableau.hadamard(1).is_err());
+    assert!(tableau.s_gate(1).is_err());
+    assert!(tableau.cnot(0, 1).is_err());
+    assert!(tableau.apply_gate(&PauliX::new(1).into()).is_err());
+    assert_eq!(tableau, CliffordTableau::new(1));
+}